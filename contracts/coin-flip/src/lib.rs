@@ -9,6 +9,9 @@
 //! 2. Oracle fulfills randomness on the RNG contract (off-chain step).
 //! 3. Anyone calls `resolve_bet` → reads RNG result, settles payout.
 //!
+//! If the RNG request expires before the oracle fulfills it, anyone can call
+//! `refund_expired` to return the wager to the player instead.
+//!
 //! ## House Edge
 //! Configured at init via `house_edge_bps` (basis points). A 250 bps edge
 //! means a winning bet on a 100-token wager pays 195 tokens (2x minus 5%).
@@ -18,7 +21,7 @@
 
 use soroban_sdk::{
     contract, contracterror, contractevent, contractimpl, contracttype, token::TokenClient,
-    Address, Env, Vec,
+    Address, BytesN, Env, Vec,
 };
 
 use stellarcade_random_generator::RandomGeneratorClient;
@@ -55,6 +58,7 @@ pub enum Error {
     WagerTooLow = 10,
     WagerTooHigh = 11,
     Overflow = 12,
+    RngNotExpired = 13,
 }
 
 // ---------------------------------------------------------------------------
@@ -83,6 +87,7 @@ pub struct Game {
     pub resolved: bool,
     pub won: bool,
     pub payout: i128,
+    pub refunded: bool,
 }
 
 #[contracttype]
@@ -118,6 +123,15 @@ pub struct BetResolved {
     pub payout: i128,
 }
 
+#[contractevent]
+pub struct BetRefunded {
+    #[topic]
+    pub game_id: u64,
+    #[topic]
+    pub player: Address,
+    pub amount: i128,
+}
+
 // ---------------------------------------------------------------------------
 // Contract
 // ---------------------------------------------------------------------------
@@ -161,12 +175,14 @@ impl CoinFlip {
     /// A randomness request is submitted to the RNG contract.
     ///
     /// `side`: 0 = Heads, 1 = Tails.
+    /// `client_seed`: optional player entropy mixed into the RNG result.
     pub fn place_bet(
         env: Env,
         player: Address,
         side: u32,
         wager: i128,
         game_id: u64,
+        client_seed: Option<BytesN<32>>,
     ) -> Result<(), Error> {
         require_initialized(&env)?;
         player.require_auth();
@@ -202,6 +218,7 @@ impl CoinFlip {
             &env.current_contract_address(),
             &game_id,
             &2u64,
+            &client_seed,
         );
 
         // Store game state
@@ -212,6 +229,7 @@ impl CoinFlip {
             resolved: false,
             won: false,
             payout: 0,
+            refunded: false,
         };
         env.storage().persistent().set(&game_key, &game);
         env.storage().persistent().extend_ttl(
@@ -319,6 +337,54 @@ impl CoinFlip {
         Ok(())
     }
 
+    /// Return the wager of a game whose RNG request expired unfulfilled.
+    /// Anyone can call this; the wager only ever goes back to the player.
+    pub fn refund_expired(env: Env, game_id: u64) -> Result<(), Error> {
+        require_initialized(&env)?;
+
+        let game_key = DataKey::Game(game_id);
+        let mut game: Game = env
+            .storage()
+            .persistent()
+            .get(&game_key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.resolved {
+            return Err(Error::GameAlreadyResolved);
+        }
+
+        let rng_addr: Address = env.storage().instance().get(&DataKey::RngContract).unwrap();
+        if !RandomGeneratorClient::new(&env, &rng_addr).is_expired(&game_id) {
+            return Err(Error::RngNotExpired);
+        }
+
+        // State update before transfer for safety
+        game.resolved = true;
+        game.refunded = true;
+        env.storage().persistent().set(&game_key, &game);
+        env.storage().persistent().extend_ttl(
+            &game_key,
+            PERSISTENT_BUMP_LEDGERS,
+            PERSISTENT_BUMP_LEDGERS,
+        );
+
+        let token = get_token(&env);
+        TokenClient::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &game.player,
+            &game.wager,
+        );
+
+        BetRefunded {
+            game_id,
+            player: game.player,
+            amount: game.wager,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
     /// View a game's state.
     pub fn get_game(env: Env, game_id: u64) -> Result<Game, Error> {
         env.storage()
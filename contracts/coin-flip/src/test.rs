@@ -1,8 +1,8 @@
 use super::*;
 use soroban_sdk::{
-    testutils::Address as _,
+    testutils::{Address as _, Ledger as _},
     token::{StellarAssetClient, TokenClient},
-    Address, BytesN, Env,
+    Address, Bytes, BytesN, Env,
 };
 use stellarcade_random_generator::{RandomGenerator, RandomGeneratorClient};
 
@@ -31,7 +31,18 @@ struct Setup<'a> {
     token_sac: StellarAssetClient<'a>,
 }
 
+/// `setup_without_chain` plus seed capacity for bets that are never
+/// fulfilled. A chain can only be replaced once it is exhausted, so tests
+/// that fulfill start from `setup_without_chain` and commit their own seed
+/// with `commit_seed`.
 fn setup(env: &Env) -> Setup<'_> {
+    let s = setup_without_chain(env);
+    s.rng_client
+        .commit_seed_chain(&s.oracle, &seed(env, 0xFF), &64);
+    s
+}
+
+fn setup_without_chain(env: &Env) -> Setup<'_> {
     let admin = Address::generate(env);
     let oracle = Address::generate(env);
     let token_admin = Address::generate(env);
@@ -51,7 +62,6 @@ fn setup(env: &Env) -> Setup<'_> {
     // Init RNG and authorize coin flip as a caller
     rng_client.init(&admin, &oracle);
     rng_client.authorize(&admin, &flip_id);
-
     // Init CoinFlip: min=10, max=1000, house edge 250 bps (2.5%)
     flip_client.init(&admin, &rng_id, &token_addr, &10i128, &1000i128, &250i128);
 
@@ -68,6 +78,12 @@ fn setup(env: &Env) -> Setup<'_> {
     }
 }
 
+/// Commit a one-seed chain so the next bet is fulfilled with `server_seed`.
+fn commit_seed(env: &Env, s: &Setup, server_seed: &BytesN<32>) {
+    let tip: BytesN<32> = env.crypto().sha256(&Bytes::from(server_seed)).into();
+    s.rng_client.commit_seed_chain(&s.oracle, &tip, &1);
+}
+
 fn tc<'a>(env: &'a Env, token: &Address) -> TokenClient<'a> {
     TokenClient::new(env, token)
 }
@@ -103,7 +119,7 @@ fn test_place_bet_stores_game() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.flip_client.place_bet(&player, &HEADS, &100, &1u64);
+    s.flip_client.place_bet(&player, &HEADS, &100, &1u64, &None);
 
     let game = s.flip_client.get_game(&1u64);
     assert_eq!(game.player, player);
@@ -119,16 +135,13 @@ fn test_place_bet_stores_game() {
 #[test]
 fn test_resolve_win() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    // Place bet on Heads (0)
-    s.flip_client.place_bet(&player, &HEADS, &100, &1u64);
-
-    // Fulfill RNG — we need to find a seed that gives result 0 (Heads)
+    // We need a seed that gives result 0 (Heads)
     // result = sha256(seed || request_id)[0..8] % 2
     // Try seeds until we get 0
     let mut winning_seed = seed(&env, 0);
@@ -140,6 +153,10 @@ fn test_resolve_win() {
             break;
         }
     }
+    commit_seed(&env, &s, &winning_seed);
+
+    // Place bet on Heads (0)
+    s.flip_client.place_bet(&player, &HEADS, &100, &1u64, &None);
 
     s.rng_client.fulfill_random(&s.oracle, &1u64, &winning_seed);
     s.flip_client.resolve_bet(&1u64);
@@ -161,14 +178,12 @@ fn test_resolve_win() {
 #[test]
 fn test_resolve_loss() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.flip_client.place_bet(&player, &HEADS, &100, &2u64);
-
     // Find a seed that gives result 1 (Tails) — player bet Heads, so they lose
     let mut losing_seed = seed(&env, 0);
     for i in 0u8..=255 {
@@ -179,6 +194,9 @@ fn test_resolve_loss() {
             break;
         }
     }
+    commit_seed(&env, &s, &losing_seed);
+
+    s.flip_client.place_bet(&player, &HEADS, &100, &2u64, &None);
 
     s.rng_client.fulfill_random(&s.oracle, &2u64, &losing_seed);
     s.flip_client.resolve_bet(&2u64);
@@ -205,8 +223,10 @@ fn test_duplicate_game_id_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.flip_client.place_bet(&player, &HEADS, &100, &1u64);
-    let result = s.flip_client.try_place_bet(&player, &TAILS, &100, &1u64);
+    s.flip_client.place_bet(&player, &HEADS, &100, &1u64, &None);
+    let result = s
+        .flip_client
+        .try_place_bet(&player, &TAILS, &100, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -223,7 +243,9 @@ fn test_invalid_side_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    let result = s.flip_client.try_place_bet(&player, &2u32, &100, &1u64);
+    let result = s
+        .flip_client
+        .try_place_bet(&player, &2u32, &100, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -240,7 +262,9 @@ fn test_wager_too_low_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    let result = s.flip_client.try_place_bet(&player, &HEADS, &5, &1u64); // min is 10
+    let result = s
+        .flip_client
+        .try_place_bet(&player, &HEADS, &5, &1u64, &None); // min is 10
     assert!(result.is_err());
 }
 
@@ -253,7 +277,9 @@ fn test_wager_too_high_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &5000);
 
-    let result = s.flip_client.try_place_bet(&player, &HEADS, &1001, &1u64); // max is 1000
+    let result = s
+        .flip_client
+        .try_place_bet(&player, &HEADS, &1001, &1u64, &None); // max is 1000
     assert!(result.is_err());
 }
 
@@ -264,7 +290,9 @@ fn test_zero_wager_rejected() {
     env.mock_all_auths();
 
     let player = Address::generate(&env);
-    let result = s.flip_client.try_place_bet(&player, &HEADS, &0, &1u64);
+    let result = s
+        .flip_client
+        .try_place_bet(&player, &HEADS, &0, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -281,7 +309,7 @@ fn test_resolve_before_rng_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.flip_client.place_bet(&player, &HEADS, &100, &1u64);
+    s.flip_client.place_bet(&player, &HEADS, &100, &1u64, &None);
 
     // Don't fulfill RNG — try to resolve
     let result = s.flip_client.try_resolve_bet(&1u64);
@@ -295,15 +323,16 @@ fn test_resolve_before_rng_rejected() {
 #[test]
 fn test_double_resolve_rejected() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.flip_client.place_bet(&player, &HEADS, &100, &1u64);
-
     let rng_seed = seed(&env, 42);
+    commit_seed(&env, &s, &rng_seed);
+    s.flip_client.place_bet(&player, &HEADS, &100, &1u64, &None);
+
     s.rng_client.fulfill_random(&s.oracle, &1u64, &rng_seed);
     s.flip_client.resolve_bet(&1u64);
 
@@ -339,8 +368,10 @@ fn test_multiple_games() {
     s.token_sac.mint(&player1, &500);
     s.token_sac.mint(&player2, &500);
 
-    s.flip_client.place_bet(&player1, &HEADS, &100, &10u64);
-    s.flip_client.place_bet(&player2, &TAILS, &200, &20u64);
+    s.flip_client
+        .place_bet(&player1, &HEADS, &100, &10u64, &None);
+    s.flip_client
+        .place_bet(&player2, &TAILS, &200, &20u64, &None);
 
     let g1 = s.flip_client.get_game(&10u64);
     let g2 = s.flip_client.get_game(&20u64);
@@ -370,9 +401,12 @@ fn test_recent_history_ordering() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &1_000);
 
-    s.flip_client.place_bet(&player, &HEADS, &100, &11u64);
-    s.flip_client.place_bet(&player, &TAILS, &100, &12u64);
-    s.flip_client.place_bet(&player, &HEADS, &100, &13u64);
+    s.flip_client
+        .place_bet(&player, &HEADS, &100, &11u64, &None);
+    s.flip_client
+        .place_bet(&player, &TAILS, &100, &12u64, &None);
+    s.flip_client
+        .place_bet(&player, &HEADS, &100, &13u64, &None);
 
     let history = s.flip_client.get_recent_games(&player, &0, &10);
     assert_eq!(history.total, 3);
@@ -391,7 +425,8 @@ fn test_recent_history_retention_limit() {
     s.token_sac.mint(&player, &5_000);
 
     for game_id in 1u64..=12 {
-        s.flip_client.place_bet(&player, &HEADS, &100, &game_id);
+        s.flip_client
+            .place_bet(&player, &HEADS, &100, &game_id, &None);
     }
 
     let history = s.flip_client.get_recent_games(&player, &0, &20);
@@ -401,12 +436,68 @@ fn test_recent_history_retention_limit() {
     assert_eq!(history.game_ids.get(9), Some(3u64));
 }
 
+#[test]
+fn test_client_seed_forwarded_to_rng() {
+    let env = Env::default();
+    let s = setup_without_chain(&env);
+    env.mock_all_auths();
+
+    let player = Address::generate(&env);
+    s.token_sac.mint(&player, &1_000);
+
+    let client_seed = seed(&env, 0x42);
+    let server_seed = seed(&env, 7);
+    commit_seed(&env, &s, &server_seed);
+    s.flip_client
+        .place_bet(&player, &HEADS, &100, &1u64, &Some(client_seed.clone()));
+    s.rng_client.fulfill_random(&s.oracle, &1u64, &server_seed);
+
+    let entry = s.rng_client.get_result(&1u64);
+    assert_eq!(entry.client_seed, Some(client_seed));
+    assert!(s.rng_client.verify_result(&1u64));
+
+    s.flip_client.resolve_bet(&1u64);
+    assert_eq!(s.flip_client.get_game(&1u64).won, entry.result == 0);
+}
+
+#[test]
+fn test_refund_expired() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let player = Address::generate(&env);
+    s.token_sac.mint(&player, &500);
+    s.flip_client.place_bet(&player, &HEADS, &100, &1u64, &None);
+
+    assert_eq!(
+        s.flip_client.try_refund_expired(&1u64),
+        Err(Ok(Error::RngNotExpired))
+    );
+
+    let timeout = s.rng_client.get_request_timeout();
+    env.ledger().with_mut(|l| l.sequence_number += timeout);
+    s.flip_client.refund_expired(&1u64);
+
+    assert_eq!(tc(&env, &s.token_addr).balance(&player), 500);
+    let game = s.flip_client.get_game(&1u64);
+    assert!(game.resolved);
+    assert!(game.refunded);
+    assert_eq!(
+        s.flip_client.try_refund_expired(&1u64),
+        Err(Ok(Error::GameAlreadyResolved))
+    );
+    assert_eq!(
+        s.flip_client.try_resolve_bet(&1u64),
+        Err(Ok(Error::GameAlreadyResolved))
+    );
+}
+
 // -------------------------------------------------------------------
 // Helper: reproduce RNG derivation for test seed selection
 // -------------------------------------------------------------------
 
 fn derive_rng_result(env: &Env, server_seed: &BytesN<32>, request_id: u64, max: u64) -> u64 {
-    use soroban_sdk::Bytes;
     let mut preimage = [0u8; 40];
     preimage[..32].copy_from_slice(&server_seed.to_array());
     preimage[32..].copy_from_slice(&request_id.to_be_bytes());
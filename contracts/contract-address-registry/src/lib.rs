@@ -645,7 +645,7 @@ mod test {
         let env = Env::default();
         env.mock_all_auths();
 
-        let contract_id = env.register_contract(None, ContractAddressRegistry);
+        let contract_id = env.register(ContractAddressRegistry, ());
         let client = ContractAddressRegistryClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
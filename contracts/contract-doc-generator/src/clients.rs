@@ -0,0 +1,356 @@
+use crate::parser::{has_attr, is_cfg_test, is_named, str_value};
+use crate::DependencyDoc;
+use std::collections::{BTreeMap, BTreeSet};
+use syn::visit::{self, Visit};
+use syn::{
+    Expr, ExprCall, ExprMethodCall, File, FnArg, ImplItemFn, Item, ItemFn, ItemMod, ItemTrait,
+    Local, Pat, ReturnType, Signature, TraitItem, Type, UseTree,
+};
+
+/// Finds every contract client the source constructs outside `#[cfg(test)]`
+/// modules and the methods it calls through each.
+///
+/// A call is attributed to a client when its receiver is `XClient::new(..)`,
+/// a local or parameter bound to one, or a helper function returning one.
+pub fn collect_dependencies(file: &File) -> Vec<DependencyDoc> {
+    let mut imports = BTreeMap::new();
+    let mut interfaces = BTreeMap::new();
+    collect_declarations(&file.items, &mut imports, &mut interfaces);
+
+    let mut helpers = Helpers::default();
+    helpers.visit_file(file);
+
+    let mut usage = Usage {
+        helpers: &helpers.0,
+        bindings: BTreeMap::new(),
+        calls: BTreeMap::new(),
+    };
+    usage.visit_file(file);
+
+    let mut calls = usage.calls;
+    for client in interfaces.keys() {
+        calls.entry(client.clone()).or_default();
+    }
+    calls
+        .into_iter()
+        .map(|(client, methods)| DependencyDoc {
+            source_crate: source_crate(&client, &imports),
+            interface: interfaces.get(&client).cloned().unwrap_or_default(),
+            calls: methods.into_iter().collect(),
+            client,
+        })
+        .collect()
+}
+
+/// Records `use` imports (name → root crate) and `#[contractclient]` traits
+/// (client name → trait methods)
+fn collect_declarations(
+    items: &[Item],
+    imports: &mut BTreeMap<String, String>,
+    interfaces: &mut BTreeMap<String, Vec<String>>,
+) {
+    for item in items {
+        match item {
+            Item::Use(u) => {
+                if let UseTree::Path(path) = &u.tree {
+                    collect_imports(&path.ident.to_string(), &path.tree, imports);
+                }
+            }
+            Item::Trait(t) if has_attr(&t.attrs, "contractclient") => {
+                if let Some(name) = client_name(t) {
+                    interfaces.insert(name, trait_methods(t));
+                }
+            }
+            Item::Mod(m) if !is_cfg_test(&m.attrs) => {
+                if let Some((_, items)) = &m.content {
+                    collect_declarations(items, imports, interfaces);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_imports(root: &str, tree: &UseTree, imports: &mut BTreeMap<String, String>) {
+    match tree {
+        UseTree::Path(path) => collect_imports(root, &path.tree, imports),
+        UseTree::Name(name) => {
+            imports.insert(name.ident.to_string(), root.to_string());
+        }
+        UseTree::Rename(rename) => {
+            imports.insert(rename.rename.to_string(), root.to_string());
+        }
+        UseTree::Group(group) => {
+            for tree in &group.items {
+                collect_imports(root, tree, imports);
+            }
+        }
+        UseTree::Glob(_) => {}
+    }
+}
+
+/// `#[contractclient(name = "PrizePoolClient")]`
+fn client_name(item: &ItemTrait) -> Option<String> {
+    let mut name = None;
+    for attr in item.attrs.iter().filter(|a| is_named(a, "contractclient")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            let value: Expr = meta.value()?.parse()?;
+            if meta.path.is_ident("name") {
+                name = str_value(&value);
+            }
+            Ok(())
+        });
+    }
+    name
+}
+
+fn trait_methods(item: &ItemTrait) -> Vec<String> {
+    item.items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Fn(f) => Some(f.sig.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn source_crate(client: &str, imports: &BTreeMap<String, String>) -> Option<String> {
+    let (first, rest) = client.split_once("::").unwrap_or((client, ""));
+    match imports.get(first) {
+        Some(root) => Some(root.clone()),
+        None if !rest.is_empty() && !matches!(first, "crate" | "self" | "super") => {
+            Some(first.to_string())
+        }
+        None => None,
+    }
+}
+
+/// The client a type names, e.g. `&TokenClient<'a>` → `TokenClient`
+fn client_type(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Reference(r) => client_type(&r.elem),
+        Type::Path(p) if p.path.segments.last()?.ident.to_string().ends_with("Client") => {
+            let segments: Vec<String> = p.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            Some(segments.join("::"))
+        }
+        _ => None,
+    }
+}
+
+fn binding_name(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(ident) => Some(ident.ident.to_string()),
+        Pat::Type(typed) => binding_name(&typed.pat),
+        _ => None,
+    }
+}
+
+/// Functions returning a client, by name
+#[derive(Default)]
+struct Helpers(BTreeMap<String, String>);
+
+impl Helpers {
+    fn record(&mut self, sig: &Signature) {
+        if let ReturnType::Type(_, ty) = &sig.output {
+            if let Some(client) = client_type(ty) {
+                self.0.insert(sig.ident.to_string(), client);
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Helpers {
+    fn visit_item_mod(&mut self, item: &'ast ItemMod) {
+        if !is_cfg_test(&item.attrs) {
+            visit::visit_item_mod(self, item);
+        }
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        self.record(&item.sig);
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast ImplItemFn) {
+        self.record(&item.sig);
+    }
+}
+
+struct Usage<'a> {
+    helpers: &'a BTreeMap<String, String>,
+    /// Locals and parameters holding a client in the function being visited
+    bindings: BTreeMap<String, String>,
+    calls: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Usage<'_> {
+    fn enter(&mut self, sig: &Signature) {
+        self.bindings.clear();
+        for input in &sig.inputs {
+            if let FnArg::Typed(arg) = input {
+                if let (Some(name), Some(client)) = (binding_name(&arg.pat), client_type(&arg.ty)) {
+                    self.bindings.insert(name, client);
+                }
+            }
+        }
+    }
+
+    /// The client an expression evaluates to, if any
+    fn client_of(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Call(call) => self.constructed(call),
+            Expr::Path(p) => self.bindings.get(&p.path.get_ident()?.to_string()).cloned(),
+            Expr::Reference(r) => self.client_of(&r.expr),
+            Expr::Paren(p) => self.client_of(&p.expr),
+            _ => None,
+        }
+    }
+
+    /// `XClient::new(..)` or a call to a helper returning a client
+    fn constructed(&self, call: &ExprCall) -> Option<String> {
+        let Expr::Path(func) = &*call.func else { return None };
+        let segments: Vec<String> = func.path.segments.iter().map(|s| s.ident.to_string()).collect();
+        match segments.as_slice() {
+            [client @ .., new] if new == "new" && client.last()?.ends_with("Client") => {
+                Some(client.join("::"))
+            }
+            [.., helper] => self.helpers.get(helper).cloned(),
+            [] => None,
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Usage<'_> {
+    fn visit_item_mod(&mut self, item: &'ast ItemMod) {
+        if !is_cfg_test(&item.attrs) {
+            visit::visit_item_mod(self, item);
+        }
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        self.enter(&item.sig);
+        visit::visit_item_fn(self, item);
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast ImplItemFn) {
+        self.enter(&item.sig);
+        visit::visit_impl_item_fn(self, item);
+    }
+
+    fn visit_local(&mut self, local: &'ast Local) {
+        if let (Some(name), Some(init)) = (binding_name(&local.pat), &local.init) {
+            if let Some(client) = self.client_of(&init.expr) {
+                self.bindings.insert(name, client);
+            }
+        }
+        visit::visit_local(self, local);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let Some(client) = self.constructed(call) {
+            self.calls.entry(client).or_default();
+        }
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        if let Some(client) = self.client_of(&call.receiver) {
+            let method = call.method.to_string();
+            let method = method.strip_prefix("try_").unwrap_or(&method).to_string();
+            self.calls.entry(client).or_default().insert(method);
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependencies(source: &str) -> Vec<DependencyDoc> {
+        collect_dependencies(&syn::parse_file(source).unwrap())
+    }
+
+    #[test]
+    fn test_imported_and_sdk_clients() {
+        let deps = dependencies(
+            r#"
+use soroban_sdk::{contract, token::TokenClient, Address, Env};
+use stellarcade_random_generator::RandomGeneratorClient;
+
+#[contractimpl]
+impl CoinFlip {
+    pub fn place_bet(env: Env, token: Address, rng_addr: Address) {
+        TokenClient::new(&env, &token).transfer(&player, &to, &wager);
+        RandomGeneratorClient::new(&env, &rng_addr).request_random(&env.current_contract_address(), &id);
+    }
+
+    pub fn resolve_bet(env: Env, rng_addr: Address) {
+        let rng_client = RandomGeneratorClient::new(&env, &rng_addr);
+        let result = rng_client.try_get_result(&id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn setup(env: &Env) {
+        CoinFlipClient::new(env, &id).init(&admin);
+    }
+}
+"#,
+        );
+
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].client, "RandomGeneratorClient");
+        assert_eq!(deps[0].source_crate.as_deref(), Some("stellarcade_random_generator"));
+        assert_eq!(deps[0].calls, ["get_result", "request_random"]);
+        assert_eq!(deps[1].client, "TokenClient");
+        assert_eq!(deps[1].source_crate.as_deref(), Some("soroban_sdk"));
+        assert_eq!(deps[1].calls, ["transfer"]);
+    }
+
+    #[test]
+    fn test_contractclient_traits_and_helpers() {
+        let deps = dependencies(
+            r#"
+use soroban_sdk::{contractclient, token, Address, Env};
+
+#[contractclient(name = "PrizePoolClient")]
+pub trait PrizePoolContract {
+    fn reserve(env: Env, admin: Address, game_id: u64, amount: i128);
+    fn payout(env: Env, admin: Address, to: Address, game_id: u64, amount: i128);
+}
+
+#[contractclient(name = "BalanceClient")]
+pub trait UserBalanceContract {
+    fn credit(env: Env, user: Address, amount: i128);
+}
+
+fn pool(env: &Env) -> PrizePoolClient<'_> {
+    PrizePoolClient::new(env, &address(env))
+}
+
+fn pay(token_client: &token::Client, to: &Address) {
+    token_client.transfer(&from, to, &1);
+}
+
+#[contractimpl]
+impl SpeedTrivia {
+    pub fn submit(env: Env) {
+        pool(&env).reserve(&admin, &1, &10);
+        let p = pool(&env);
+        p.payout(&admin, &to, &1, &10);
+    }
+}
+"#,
+        );
+
+        let clients: Vec<&str> = deps.iter().map(|d| d.client.as_str()).collect();
+        assert_eq!(clients, ["BalanceClient", "PrizePoolClient", "token::Client"]);
+        assert_eq!(deps[0].interface, ["credit"]);
+        assert!(deps[0].calls.is_empty());
+        assert_eq!(deps[1].source_crate, None);
+        assert_eq!(deps[1].calls, ["payout", "reserve"]);
+        assert_eq!(deps[2].source_crate.as_deref(), Some("soroban_sdk"));
+        assert_eq!(deps[2].calls, ["transfer"]);
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{Catalogue, ContractDoc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// How a committed file differs from what the generator produces
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Drift {
+    /// Generated but not committed
+    Missing,
+    /// Committed with different content; lists what changed
+    Changed(Vec<String>),
+    /// A contract page that no contract generates any more
+    Stale,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDrift {
+    pub file: String,
+    pub drift: Drift,
+}
+
+impl fmt::Display for FileDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.drift {
+            Drift::Missing => write!(f, "{}: missing", self.file),
+            Drift::Stale => write!(f, "{}: no matching contract", self.file),
+            Drift::Changed(changes) => {
+                write!(f, "{}: out of date", self.file)?;
+                for change in changes {
+                    write!(f, "\n  - {}", change)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Compares generated `files` with those in `output`. Changes to a contract
+/// page are described item by item against the committed catalogue when it
+/// has the contract, and by the first differing line otherwise.
+pub fn compare(
+    output: &Path,
+    files: &BTreeMap<String, String>,
+    docs: &[ContractDoc],
+    committed: Option<&Catalogue>,
+) -> Result<Vec<FileDrift>, String> {
+    let mut report = Vec::new();
+    for (file, generated) in files {
+        let path = output.join(file);
+        let current = match fs::read_to_string(&path) {
+            Ok(current) => current,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                report.push(FileDrift { file: file.clone(), drift: Drift::Missing });
+                continue;
+            }
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+        if &current == generated {
+            continue;
+        }
+
+        let old = committed.and_then(|c| {
+            let name = file.strip_suffix(".md")?;
+            c.contracts.get(name)
+        });
+        let new = docs.iter().find(|doc| format!("{}.md", doc.name) == *file);
+        let mut changes = match (old, new) {
+            (Some(old), Some(new)) => surface_changes(old, new),
+            _ => Vec::new(),
+        };
+        if changes.is_empty() {
+            changes.push(first_difference(&current, generated));
+        }
+        report.push(FileDrift { file: file.clone(), drift: Drift::Changed(changes) });
+    }
+    Ok(report)
+}
+
+/// Markdown pages in `output` that are not generated, other than the
+/// hand-written `README.md`
+pub fn stale_pages(output: &Path, files: &BTreeMap<String, String>) -> Result<Vec<FileDrift>, String> {
+    let entries = match fs::read_dir(output) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("{}: {}", output.display(), e)),
+    };
+    let mut stale = Vec::new();
+    for entry in entries {
+        let file = entry.map_err(|e| e.to_string())?.file_name().to_string_lossy().to_string();
+        if file.ends_with(".md") && file != "README.md" && !files.contains_key(&file) {
+            stale.push(FileDrift { file, drift: Drift::Stale });
+        }
+    }
+    stale.sort_by(|a, b| a.file.cmp(&b.file));
+    Ok(stale)
+}
+
+/// Methods, types, errors and events added, removed or changed between two
+/// versions of a contract
+pub fn surface_changes(old: &ContractDoc, new: &ContractDoc) -> Vec<String> {
+    let mut changes = Vec::new();
+    diff_items("method", &old.methods, &new.methods, |m| &m.name, &mut changes);
+    diff_items("type", &old.types, &new.types, |t| &t.name, &mut changes);
+    diff_items("error", &old.errors, &new.errors, |e| &e.name, &mut changes);
+    diff_items("event", &old.events, &new.events, |e| &e.name, &mut changes);
+    changes
+}
+
+fn diff_items<T: Serialize>(
+    kind: &str,
+    old: &[T],
+    new: &[T],
+    name: impl Fn(&T) -> &String,
+    changes: &mut Vec<String>,
+) {
+    let old: BTreeMap<&String, serde_json::Value> = old
+        .iter()
+        .map(|item| (name(item), serde_json::to_value(item).unwrap_or_default()))
+        .collect();
+    for item in new {
+        match old.get(name(item)) {
+            None => changes.push(format!("{} `{}` added", kind, name(item))),
+            Some(value) if *value != serde_json::to_value(item).unwrap_or_default() => {
+                changes.push(format!("{} `{}` changed", kind, name(item)))
+            }
+            Some(_) => {}
+        }
+    }
+    for removed in old.keys().filter(|n| !new.iter().any(|item| name(item) == **n)) {
+        changes.push(format!("{} `{}` removed", kind, removed));
+    }
+}
+
+fn first_difference(current: &str, generated: &str) -> String {
+    let line = current
+        .lines()
+        .zip(generated.lines())
+        .position(|(a, b)| a != b)
+        .unwrap_or_else(|| current.lines().count().min(generated.lines().count()));
+    format!("first difference at line {}", line + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_source;
+    use tempfile::tempdir;
+
+    const BEFORE: &str = r#"
+#[contracterror]
+#[repr(u32)]
+pub enum Error {
+    NotAuthorized = 3,
+    Overflow = 4,
+}
+
+#[contractimpl]
+impl CoinFlip {
+    pub fn place_bet(env: Env, player: Address, wager: i128) {}
+}
+"#;
+
+    const AFTER: &str = r#"
+#[contracterror]
+#[repr(u32)]
+pub enum Error {
+    NotAuthorized = 3,
+}
+
+#[contractevent]
+pub struct BetPlaced {
+    #[topic]
+    pub player: Address,
+}
+
+#[contractimpl]
+impl CoinFlip {
+    pub fn place_bet(env: Env, player: Address, wager: i128, side: u32) {}
+}
+"#;
+
+    #[test]
+    fn test_surface_changes() {
+        let old = parse_source("coin-flip", BEFORE).unwrap();
+        let new = parse_source("coin-flip", AFTER).unwrap();
+        assert_eq!(
+            surface_changes(&old, &new),
+            [
+                "method `place_bet` changed",
+                "error `Overflow` removed",
+                "event `BetPlaced` added",
+            ]
+        );
+        assert!(surface_changes(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn test_compare_reports_each_file() {
+        let dir = tempdir().unwrap();
+        let old = parse_source("coin-flip", BEFORE).unwrap();
+        let new = parse_source("coin-flip", AFTER).unwrap();
+        fs::write(dir.path().join("coin-flip.md"), "# coin-flip\n\nold\n").unwrap();
+        fs::write(dir.path().join("dice-roll.md"), "# dice-roll\n").unwrap();
+        fs::write(dir.path().join("README.md"), "# Contracts\n").unwrap();
+        fs::write(dir.path().join("index.md"), "# Contract Index\n").unwrap();
+
+        let files: BTreeMap<String, String> = [
+            ("coin-flip.md", "# coin-flip\n\nnew\n"),
+            ("dice-roll.md", "# dice-roll\n"),
+            ("index.md", "# Contract Index\n\nchanged\n"),
+            ("catalogue.json", "{}\n"),
+        ]
+        .into_iter()
+        .map(|(f, c)| (f.to_string(), c.to_string()))
+        .collect();
+        let committed = Catalogue {
+            contracts: [("coin-flip".to_string(), old)].into_iter().collect(),
+        };
+
+        let report = compare(dir.path(), &files, &[new], Some(&committed)).unwrap();
+        assert_eq!(
+            report,
+            [
+                FileDrift { file: "catalogue.json".to_string(), drift: Drift::Missing },
+                FileDrift {
+                    file: "coin-flip.md".to_string(),
+                    drift: Drift::Changed(vec![
+                        "method `place_bet` changed".to_string(),
+                        "error `Overflow` removed".to_string(),
+                        "event `BetPlaced` added".to_string(),
+                    ]),
+                },
+                FileDrift {
+                    file: "index.md".to_string(),
+                    drift: Drift::Changed(vec!["first difference at line 2".to_string()]),
+                },
+            ]
+        );
+        assert_eq!(
+            report[1].to_string(),
+            "coin-flip.md: out of date\n  - method `place_bet` changed\n  - error `Overflow` removed\n  - event `BetPlaced` added"
+        );
+
+        fs::write(dir.path().join("lottery.md"), "# lottery\n").unwrap();
+        assert_eq!(
+            stale_pages(dir.path(), &files).unwrap(),
+            [FileDrift { file: "lottery.md".to_string(), drift: Drift::Stale }]
+        );
+    }
+}
@@ -0,0 +1,369 @@
+use crate::typescript::TYPESCRIPT_FILE;
+use crate::{ContractDoc, DependencyDoc, CATALOGUE_FILE};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// File the Graphviz graph is written to
+pub const DOT_FILE: &str = "dependency-graph.dot";
+/// File the Mermaid graph is written to
+pub const MERMAID_FILE: &str = "dependency-graph.mmd";
+/// Generated index page linking every contract page and artifact
+pub const INDEX_FILE: &str = "index.md";
+
+/// Node for Stellar Asset Contract clients (`token::Client`, `TokenClient`,
+/// `StellarAssetClient`); token contracts are deployed per asset, not by us
+pub const TOKEN_NODE: &str = "token";
+
+/// One contract calling another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+    /// `to` is not one of the workspace contracts
+    pub external: bool,
+    pub methods: BTreeSet<String>,
+}
+
+/// Resolves each contract's client usages to the contract being called.
+///
+/// A client resolves, in order, through:
+/// - its crate: `soroban_sdk` clients are the token, `stellarcade_<name>` is `<name>`;
+/// - the contract whose `#[contract]` struct generates it (`PrizePool` → `PrizePoolClient`);
+/// - for a `#[contractclient]` trait, the only contract exposing all its methods.
+///
+/// Anything else becomes an external node named after the client.
+pub fn dependency_edges(docs: &[ContractDoc]) -> Vec<Edge> {
+    let mut edges: BTreeMap<(String, String), Edge> = BTreeMap::new();
+    for doc in docs {
+        for dependency in &doc.dependencies {
+            let (to, external) = resolve(dependency, docs);
+            if to == doc.name {
+                continue;
+            }
+            edges
+                .entry((doc.name.clone(), to.clone()))
+                .or_insert_with(|| Edge {
+                    from: doc.name.clone(),
+                    to,
+                    external,
+                    methods: BTreeSet::new(),
+                })
+                .methods
+                .extend(dependency.calls.iter().cloned());
+        }
+    }
+    edges.into_values().collect()
+}
+
+fn resolve(dependency: &DependencyDoc, docs: &[ContractDoc]) -> (String, bool) {
+    let exists = |name: &str| docs.iter().any(|doc| doc.name == name);
+
+    match dependency.source_crate.as_deref() {
+        Some("soroban_sdk") => return (TOKEN_NODE.to_string(), true),
+        Some(krate) => {
+            if let Some(name) = krate.strip_prefix("stellarcade_").map(|n| n.replace('_', "-")) {
+                if exists(&name) {
+                    return (name, false);
+                }
+            }
+        }
+        None => {}
+    }
+
+    let client = dependency.client.rsplit("::").next().unwrap_or(&dependency.client);
+    let generated = docs
+        .iter()
+        .find(|doc| doc.contract_type.as_ref().is_some_and(|t| format!("{}Client", t) == client));
+    if let Some(doc) = generated {
+        return (doc.name.clone(), false);
+    }
+
+    if !dependency.interface.is_empty() {
+        let implementors: Vec<&ContractDoc> = docs
+            .iter()
+            .filter(|doc| {
+                dependency
+                    .interface
+                    .iter()
+                    .all(|method| doc.methods.iter().any(|m| &m.name == method))
+            })
+            .collect();
+        if let [doc] = implementors.as_slice() {
+            return (doc.name.clone(), false);
+        }
+    }
+
+    (client.to_string(), true)
+}
+
+pub fn render_dot(edges: &[Edge]) -> String {
+    let mut out = String::from("digraph contracts {\n    rankdir=LR;\n    node [shape=box];\n");
+    let external: BTreeSet<&str> = edges.iter().filter(|e| e.external).map(|e| e.to.as_str()).collect();
+    for node in external {
+        out.push_str(&format!("    \"{}\" [style=dashed];\n", node));
+    }
+    for edge in edges {
+        let methods: Vec<&str> = edge.methods.iter().map(String::as_str).collect();
+        out.push_str(&format!(
+            "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+            edge.from,
+            edge.to,
+            methods.join("\\n")
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+pub fn render_mermaid(edges: &[Edge]) -> String {
+    let mut out = String::from("flowchart LR\n");
+    let mut nodes: BTreeMap<&str, bool> = BTreeMap::new();
+    for edge in edges {
+        nodes.entry(&edge.from).or_insert(false);
+        nodes.insert(&edge.to, edge.external);
+    }
+    for (node, external) in &nodes {
+        if *external {
+            out.push_str(&format!("    {}([\"{}\"])\n", mermaid_id(node), node));
+        } else {
+            out.push_str(&format!("    {}[\"{}\"]\n", mermaid_id(node), node));
+        }
+    }
+    for edge in edges {
+        let methods: Vec<&str> = edge.methods.iter().map(String::as_str).collect();
+        if methods.is_empty() {
+            out.push_str(&format!("    {} --> {}\n", mermaid_id(&edge.from), mermaid_id(&edge.to)));
+        } else {
+            out.push_str(&format!(
+                "    {} -->|\"{}\"| {}\n",
+                mermaid_id(&edge.from),
+                methods.join("<br/>"),
+                mermaid_id(&edge.to)
+            ));
+        }
+    }
+    out
+}
+
+fn mermaid_id(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+/// Renders the index page: links to every contract page and artifact, the
+/// dependency graph and a caller → callee table
+pub fn render_index(docs: &[ContractDoc], edges: &[Edge]) -> String {
+    let mut out = String::from("# Contract Index\n\n");
+    out.push_str("Generated by `stellarcade-contract-doc-generator`; do not edit.\n\n");
+    out.push_str(&format!("- [JSON catalogue]({})\n", CATALOGUE_FILE));
+    out.push_str(&format!("- [TypeScript definitions]({})\n", TYPESCRIPT_FILE));
+    out.push_str(&format!(
+        "- Dependency graph: [DOT]({}), [Mermaid]({})\n\n",
+        DOT_FILE, MERMAID_FILE
+    ));
+
+    out.push_str("## Contracts\n\n");
+    out.push_str("| Contract | Methods | Errors | Events | Calls |\n");
+    out.push_str("|----------|---------|--------|--------|-------|\n");
+    for doc in docs {
+        let calls: Vec<String> = edges
+            .iter()
+            .filter(|e| e.from == doc.name)
+            .map(|e| format!("`{}`", e.to))
+            .collect();
+        out.push_str(&format!(
+            "| [`{}`]({}.md) | {} | {} | {} | {} |\n",
+            doc.name,
+            doc.name,
+            doc.methods.len(),
+            doc.errors.len(),
+            doc.events.len(),
+            calls.join(", ")
+        ));
+    }
+    out.push('\n');
+
+    if edges.is_empty() {
+        return out;
+    }
+
+    out.push_str("## Dependency Graph\n\n");
+    out.push_str(&format!("```mermaid\n{}```\n\n", render_mermaid(edges)));
+
+    out.push_str("## Cross-Contract Calls\n\n");
+    out.push_str("| Caller | Callee | Methods |\n");
+    out.push_str("|--------|--------|---------|\n");
+    for edge in edges {
+        let callee = if edge.external {
+            format!("`{}`", edge.to)
+        } else {
+            format!("[`{}`]({}.md)", edge.to, edge.to)
+        };
+        let methods: Vec<String> = edge.methods.iter().map(|m| format!("`{}`", m)).collect();
+        out.push_str(&format!(
+            "| [`{}`]({}.md) | {} | {} |\n",
+            edge.from,
+            edge.from,
+            callee,
+            methods.join(", ")
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_source;
+
+    fn contracts() -> Vec<ContractDoc> {
+        let sources = [
+            (
+                "coin-flip",
+                r#"
+use soroban_sdk::token::TokenClient;
+use stellarcade_random_generator::RandomGeneratorClient;
+
+#[contract]
+pub struct CoinFlip;
+
+#[contractimpl]
+impl CoinFlip {
+    pub fn place_bet(env: Env) {
+        TokenClient::new(&env, &token).transfer(&player, &to, &wager);
+        RandomGeneratorClient::new(&env, &rng).request_random(&caller, &id);
+    }
+}
+"#,
+            ),
+            (
+                "random-generator",
+                r#"
+#[contract]
+pub struct RandomGenerator;
+
+#[contractimpl]
+impl RandomGenerator {
+    pub fn request_random(env: Env, caller: Address, id: u64) {}
+}
+"#,
+            ),
+            (
+                "prize-pool",
+                r#"
+#[contract]
+pub struct PrizePool;
+
+#[contractimpl]
+impl PrizePool {
+    pub fn reserve(env: Env, admin: Address, game_id: u64, amount: i128) {}
+}
+"#,
+            ),
+            (
+                "balance-management",
+                r#"
+#[contract]
+pub struct BalanceManager;
+
+#[contractimpl]
+impl BalanceManager {
+    pub fn credit(env: Env, game: Address, user: Address, amount: i128) {}
+    pub fn debit(env: Env, game: Address, user: Address, amount: i128) {}
+}
+"#,
+            ),
+            (
+                "speed-trivia",
+                r#"
+#[contractclient(name = "PrizePoolClient")]
+pub trait PrizePoolContract {
+    fn reserve(env: Env, admin: Address, game_id: u64, amount: i128);
+}
+
+#[contractclient(name = "BalanceClient")]
+pub trait UserBalanceContract {
+    fn credit(env: Env, game: Address, user: Address, amount: i128);
+}
+
+#[contractclient(name = "OracleClient")]
+pub trait Oracle {
+    fn price(env: Env) -> i128;
+}
+
+#[contractimpl]
+impl SpeedTrivia {
+    pub fn submit(env: Env) {
+        let pool_client = PrizePoolClient::new(&env, &pool);
+        pool_client.reserve(&admin, &1, &10);
+        BalanceClient::new(&env, &balance).credit(&game, &user, &10);
+        OracleClient::new(&env, &oracle).price();
+    }
+}
+"#,
+            ),
+        ];
+        sources
+            .iter()
+            .map(|(name, source)| parse_source(name, source).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_edges_resolve_clients_to_contracts() {
+        let edges = dependency_edges(&contracts());
+        let summary: Vec<(&str, &str, bool, Vec<&str>)> = edges
+            .iter()
+            .map(|e| {
+                (
+                    e.from.as_str(),
+                    e.to.as_str(),
+                    e.external,
+                    e.methods.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("coin-flip", "random-generator", false, vec!["request_random"]),
+                ("coin-flip", "token", true, vec!["transfer"]),
+                ("speed-trivia", "OracleClient", true, vec!["price"]),
+                ("speed-trivia", "balance-management", false, vec!["credit"]),
+                ("speed-trivia", "prize-pool", false, vec!["reserve"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dot_and_mermaid() {
+        let edges = dependency_edges(&contracts());
+
+        let dot = render_dot(&edges);
+        assert!(dot.starts_with("digraph contracts {\n"));
+        assert!(dot.contains("    \"token\" [style=dashed];\n"));
+        assert!(dot.contains("    \"coin-flip\" -> \"random-generator\" [label=\"request_random\"];\n"));
+
+        let mermaid = render_mermaid(&edges);
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("    coin_flip[\"coin-flip\"]\n"));
+        assert!(mermaid.contains("    token([\"token\"])\n"));
+        assert!(mermaid.contains("    speed_trivia -->|\"reserve\"| prize_pool\n"));
+    }
+
+    #[test]
+    fn test_index_links_contracts_and_calls() {
+        let docs = contracts();
+        let edges = dependency_edges(&docs);
+        let index = render_index(&docs, &edges);
+
+        assert!(index.contains("- [JSON catalogue](catalogue.json)\n"));
+        assert!(index.contains(
+            "| [`coin-flip`](coin-flip.md) | 1 | 0 | 0 | `random-generator`, `token` |\n"
+        ));
+        assert!(index.contains("```mermaid\nflowchart LR\n"));
+        assert!(index.contains(
+            "| [`coin-flip`](coin-flip.md) | [`random-generator`](random-generator.md) | `request_random` |\n"
+        ));
+        assert!(index.contains("| [`coin-flip`](coin-flip.md) | `token` | `transfer` |\n"));
+    }
+}
@@ -1,5 +1,13 @@
-use regex::Regex;
+pub mod clients;
+pub mod drift;
+pub mod graph;
+pub mod parser;
+pub mod typescript;
+
+pub use drift::{Drift, FileDrift};
+
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -21,9 +29,14 @@ pub enum GeneratorState {
 pub struct ContractDoc {
     pub name: String,
     pub description: Option<String>,
+    /// Name of the `#[contract]` struct; its generated client is `<contract_type>Client`
+    pub contract_type: Option<String>,
     pub methods: Vec<MethodDoc>,
     pub types: Vec<TypeDoc>,
+    pub errors: Vec<ErrorDoc>,
     pub events: Vec<EventDoc>,
+    /// Clients of other contracts this contract calls
+    pub dependencies: Vec<DependencyDoc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,17 +54,76 @@ pub struct ParameterDoc {
     pub type_name: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeKind {
+    Struct,
+    Enum,
+}
+
+/// A `#[contracttype]` struct or enum
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypeDoc {
     pub name: String,
     pub description: Option<String>,
+    pub kind: TypeKind,
+    /// Struct fields; tuple struct fields have an empty name
+    pub fields: Vec<FieldDoc>,
+    /// Enum variants
+    pub variants: Vec<VariantDoc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldDoc {
+    pub name: String,
+    pub type_name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantDoc {
+    pub name: String,
+    pub description: Option<String>,
+    /// Types of the variant's tuple fields, e.g. `Game(u64)`
     pub fields: Vec<String>,
+    /// Discriminant of integer enums
+    pub value: Option<u32>,
 }
 
+/// A variant of the contract's `#[contracterror]` enum
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorDoc {
+    pub name: String,
+    pub code: u32,
+    pub description: Option<String>,
+}
+
+/// A `#[contractevent]` struct
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventDoc {
     pub name: String,
     pub description: Option<String>,
+    /// Static topics published before the `#[topic]` fields; the snake_case
+    /// struct name unless the attribute sets `topics = [...]`
+    pub prefix: Vec<String>,
+    /// How the data fields are encoded: `map`, `vec` or `single-value`
+    pub data_format: String,
+    /// Fields marked `#[topic]`, in order
+    pub topics: Vec<FieldDoc>,
+    pub data: Vec<FieldDoc>,
+}
+
+/// A contract client used outside tests, such as `RandomGeneratorClient` or `token::Client`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyDoc {
+    /// Client type as written, without generics
+    pub client: String,
+    /// Crate the client comes from, e.g. `stellarcade_random_generator`; `None`
+    /// for `#[contractclient]` traits declared in the contract itself
+    pub source_crate: Option<String>,
+    /// Methods of the `#[contractclient]` trait, when declared in the contract
+    pub interface: Vec<String>,
+    /// Methods invoked through the client, sorted, with `try_` prefixes removed
+    pub calls: Vec<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -62,6 +134,9 @@ pub struct DocGenerator {
     pub base_path: PathBuf,
     pub output_path: PathBuf,
     pub state: GeneratorState,
+    /// Only document this contract; the catalogue, TypeScript definitions,
+    /// graphs and index cover every contract and are skipped
+    pub contract: Option<String>,
 }
 
 impl DocGenerator {
@@ -70,11 +145,48 @@ impl DocGenerator {
             base_path,
             output_path,
             state: GeneratorState::Idle,
+            contract: None,
         }
     }
 
     /// Primary routine to generate documentation for all contracts
     pub fn run(&mut self) -> Result<(), String> {
+        let docs = self.parse_all()?;
+
+        self.state = GeneratorState::Generation;
+        println!("EVENT: Finalizing Markdown generation");
+        
+        self.write_docs(&self.render_files(&docs)?)?;
+
+        self.state = GeneratorState::Complete;
+        println!("EVENT: Documentation generation successful");
+        
+        Ok(())
+    }
+
+    /// Regenerates the docs in memory and compares them with the files in
+    /// `output_path`, without writing anything. An empty result means the
+    /// committed docs are up to date.
+    pub fn check(&mut self) -> Result<Vec<FileDrift>, String> {
+        let docs = self.parse_all()?;
+
+        self.state = GeneratorState::Generation;
+        println!("EVENT: Comparing generated docs with {:?}", self.output_path);
+
+        let files = self.render_files(&docs)?;
+        let committed = fs::read_to_string(self.output_path.join(CATALOGUE_FILE))
+            .ok()
+            .and_then(|json| serde_json::from_str::<Catalogue>(&json).ok());
+        let mut report = drift::compare(&self.output_path, &files, &docs, committed.as_ref())?;
+        if self.contract.is_none() {
+            report.extend(drift::stale_pages(&self.output_path, &files)?);
+        }
+
+        self.state = GeneratorState::Complete;
+        Ok(report)
+    }
+
+    fn parse_all(&mut self) -> Result<Vec<ContractDoc>, String> {
         self.state = GeneratorState::Discovery;
         println!("EVENT: Starting contract discovery in {:?}", self.base_path);
         
@@ -93,16 +205,8 @@ impl DocGenerator {
                 }
             }
         }
-
-        self.state = GeneratorState::Generation;
-        println!("EVENT: Finalizing Markdown generation");
-        
-        self.write_docs(docs)?;
-
-        self.state = GeneratorState::Complete;
-        println!("EVENT: Documentation generation successful");
-        
-        Ok(())
+        docs.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(docs)
     }
 
     fn discover_contracts(&self) -> Result<Vec<PathBuf>, String> {
@@ -121,12 +225,18 @@ impl DocGenerator {
                 if name == "shared" || name == "contract-doc-generator" || name == "deployment-scripts" {
                     continue;
                 }
+                if self.contract.as_deref().is_some_and(|only| only != name) {
+                    continue;
+                }
 
                 if cargo_toml.exists() && src_lib.exists() {
                     contracts.push(path);
                 }
             }
         }
+        if let (Some(name), true) = (&self.contract, contracts.is_empty()) {
+            return Err(format!("Unknown contract {}", name));
+        }
         Ok(contracts)
     }
 
@@ -135,197 +245,217 @@ impl DocGenerator {
         let lib_path = path.join("src").join("lib.rs");
         let content = fs::read_to_string(lib_path).map_err(|e| e.to_string())?;
 
-        let mut doc = ContractDoc {
-            name: name.clone(),
-            description: None,
-            methods: Vec::new(),
-            types: Vec::new(),
-            events: Vec::new(),
-        };
-
-        let mut lines = content.lines().peekable();
-        let mut current_docs = Vec::new();
-
-        while let Some(line) = lines.next() {
-            let trimmed = line.trim();
-            
-            if trimmed.is_empty() {
-                continue;
-            }
-
-            if trimmed.starts_with("///") {
-                current_docs.push(trimmed[3..].trim().to_string());
-                continue;
-            }
-
-            // Skip decorators but keep current_docs
-            if trimmed.starts_with("#[") {
-                continue;
-            }
-
-            // Extract Contract Header
-            if doc.description.is_none() && !current_docs.is_empty() && (trimmed.contains("pub struct") || trimmed.contains("impl")) {
-                 doc.description = Some(current_docs.join(" "));
-            }
+        parser::parse_source(&name, &content)
+    }
 
-            // Detect Methods
-            if trimmed.contains("pub fn") {
-                let name_regex = Regex::new(r"fn\s+([a-zA-Z0-9_]+)").unwrap();
-                // Capture multi-line signatures (common in contract methods).
-                let mut signature = trimmed.to_string();
-                while !signature.contains('{') {
-                    let Some(next_line) = lines.peek() else { break };
-                    let next_trimmed = next_line.trim();
-                    if next_trimmed.is_empty() {
-                        break;
-                    }
-                    signature.push(' ');
-                    signature.push_str(next_trimmed);
-                    lines.next();
-                    if next_trimmed.contains('{') || next_trimmed.ends_with(';') {
-                        break;
-                    }
-                }
+    /// Every generated file, keyed by its name in `output_path`
+    fn render_files(&self, docs: &[ContractDoc]) -> Result<BTreeMap<String, String>, String> {
+        let mut files: BTreeMap<String, String> = docs
+            .iter()
+            .map(|doc| (format!("{}.md", doc.name), render_markdown(doc)))
+            .collect();
+        if self.contract.is_some() {
+            return Ok(files);
+        }
 
-                let normalized_signature = signature
-                    .replace('{', "")
-                    .split_whitespace()
-                    .collect::<Vec<_>>()
-                    .join(" ")
-                    .replace("( ", "(")
-                    .replace(", )", ")")
-                    .replace(" )", ")");
-
-                if let Some(cap) = name_regex.captures(&normalized_signature) {
-                    let (parameters, return_type) = self.extract_params_and_return(&normalized_signature);
-                    doc.methods.push(MethodDoc {
-                        name: cap[1].to_string(),
-                        description: if current_docs.is_empty() { None } else { Some(current_docs.join(" ")) },
-                        signature: normalized_signature,
-                        parameters,
-                        return_type,
-                    });
-                }
-            }
+        files.insert(CATALOGUE_FILE.to_string(), render_catalogue(docs)?);
+        files.insert(
+            typescript::TYPESCRIPT_FILE.to_string(),
+            typescript::render_typescript(docs),
+        );
+        let edges = graph::dependency_edges(docs);
+        files.insert(graph::DOT_FILE.to_string(), graph::render_dot(&edges));
+        files.insert(graph::MERMAID_FILE.to_string(), graph::render_mermaid(&edges));
+        files.insert(graph::INDEX_FILE.to_string(), graph::render_index(docs, &edges));
+        Ok(files)
+    }
 
-            // Detect Events (marked with #[contractevent])
-            // Since we skipped #[ earlier, we need to check if the NEXT line is an event struct
-            // Actually, let's look back or handle decorators specifically
-            if trimmed.contains("struct") {
-                 if !current_docs.is_empty() {
-                    let name_regex = Regex::new(r"struct\s+([a-zA-Z0-9_]+)").unwrap();
-                    if let Some(cap) = name_regex.captures(trimmed) {
-                        let struct_name = cap[1].to_string();
-                        if struct_name.contains("Event") || struct_name.contains("Claimed") {
-                            doc.events.push(EventDoc {
-                                name: struct_name,
-                                description: Some(current_docs.join(" ")),
-                            });
-                        } else {
-                            doc.types.push(TypeDoc {
-                                name: struct_name,
-                                description: Some(current_docs.join(" ")),
-                                fields: Vec::new(),
-                            });
-                        }
-                    }
-                 }
-            }
+    fn write_docs(&self, files: &BTreeMap<String, String>) -> Result<(), String> {
+        if !self.output_path.exists() {
+            fs::create_dir_all(&self.output_path).map_err(|e| e.to_string())?;
+        }
 
-            current_docs.clear();
+        for (file_name, content) in files {
+            let file_path = self.output_path.join(file_name);
+            fs::write(file_path, content).map_err(|e| e.to_string())?;
         }
 
-        Ok(doc)
+        Ok(())
     }
+}
 
-    fn extract_params_and_return(&self, signature: &str) -> (Vec<ParameterDoc>, Option<String>) {
-        let mut parameters = Vec::new();
-        let mut return_type = None;
+// ---------------------------------------------------------------------------
+// Catalogue
+// ---------------------------------------------------------------------------
 
-        let base_signature;
-        if let Some(arrow_pos) = signature.find("->") {
-            return_type = Some(signature[arrow_pos + 2..].trim().to_string());
-            base_signature = &signature[..arrow_pos];
-        } else {
-            base_signature = signature;
-        }
+/// File the JSON catalogue is written to, next to the Markdown pages
+pub const CATALOGUE_FILE: &str = "catalogue.json";
 
-        // Extract parameters between first ( and last ) of the base_signature
-        if let Some(start_paren) = base_signature.find('(') {
-            if let Some(end_paren) = base_signature.rfind(')') {
-                let params_str = &base_signature[start_paren + 1..end_paren];
-                if !params_str.trim().is_empty() {
-                    for param in params_str.split(',') {
-                        let parts: Vec<&str> = param.split(':').collect();
-                        if parts.len() == 2 {
-                            parameters.push(ParameterDoc {
-                                name: parts[0].trim().to_string(),
-                                type_name: parts[1].trim().to_string(),
-                            });
-                        }
-                    }
-                }
-            }
-        }
+/// Every contract's methods, types, error codes and events, keyed by contract name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Catalogue {
+    pub contracts: BTreeMap<String, ContractDoc>,
+}
 
-        (parameters, return_type)
-    }
+pub fn render_catalogue(docs: &[ContractDoc]) -> Result<String, String> {
+    let catalogue = Catalogue {
+        contracts: docs.iter().map(|doc| (doc.name.clone(), doc.clone())).collect(),
+    };
+    serde_json::to_string_pretty(&catalogue)
+        .map(|json| json + "\n")
+        .map_err(|e| e.to_string())
+}
 
-    fn write_docs(&self, mut docs: Vec<ContractDoc>) -> Result<(), String> {
-        if !self.output_path.exists() {
-            fs::create_dir_all(&self.output_path).map_err(|e| e.to_string())?;
-        }
+// ---------------------------------------------------------------------------
+// Markdown
+// ---------------------------------------------------------------------------
 
-        docs.sort_by(|a, b| a.name.cmp(&b.name));
+/// Renders the Markdown page for one contract
+pub fn render_markdown(doc: &ContractDoc) -> String {
+    let mut content = format!("# {}\n\n", doc.name);
+    if let Some(desc) = &doc.description {
+        content.push_str(&format!("{}\n\n", desc));
+    }
 
-        for doc in docs {
-            let file_name = format!("{}.md", doc.name);
-            let file_path = self.output_path.join(&file_name);
+    if !doc.methods.is_empty() {
+        content.push_str("## Public Methods\n\n");
+        for m in &doc.methods {
+            content.push_str(&format!("### `{}`\n", m.name));
+            if let Some(d) = &m.description {
+                content.push_str(&format!("{}\n\n", d));
+            }
+            content.push_str(&format!("```rust\n{}\n```\n\n", m.signature));
+
+            if !m.parameters.is_empty() {
+                content.push_str("#### Parameters\n\n");
+                content.push_str("| Name | Type |\n");
+                content.push_str("|------|------|\n");
+                for p in &m.parameters {
+                    content.push_str(&format!("| `{}` | `{}` |\n", p.name, p.type_name));
+                }
+                content.push('\n');
+            }
 
-            let mut content = format!("# {}\n\n", doc.name);
-            if let Some(desc) = &doc.description {
-                content.push_str(&format!("{}\n\n", desc));
+            if let Some(rt) = &m.return_type {
+                content.push_str("#### Return Type\n\n");
+                content.push_str(&format!("`{}`\n\n", rt));
             }
+        }
+    }
 
-            if !doc.methods.is_empty() {
-                content.push_str("## Public Methods\n\n");
-                for m in &doc.methods {
-                    content.push_str(&format!("### `{}`\n", m.name));
-                    if let Some(d) = &m.description {
-                        content.push_str(&format!("{}\n\n", d));
-                    }
-                    content.push_str(&format!("```rust\n{}\n```\n\n", m.signature));
-
-                    if !m.parameters.is_empty() {
-                        content.push_str("#### Parameters\n\n");
-                        content.push_str("| Name | Type |\n");
-                        content.push_str("|------|------|\n");
-                        for p in &m.parameters {
-                            content.push_str(&format!("| `{}` | `{}` |\n", p.name, p.type_name));
-                        }
-                        content.push_str("\n");
+    if !doc.types.is_empty() {
+        content.push_str("## Types\n\n");
+        for t in &doc.types {
+            content.push_str(&format!("### `{}`\n\n", t.name));
+            if let Some(d) = &t.description {
+                content.push_str(&format!("{}\n\n", d));
+            }
+            match t.kind {
+                TypeKind::Struct if !t.fields.is_empty() => {
+                    content.push_str("| Field | Type | Description |\n");
+                    content.push_str("|-------|------|-------------|\n");
+                    for f in &t.fields {
+                        content.push_str(&format!(
+                            "| `{}` | `{}` | {} |\n",
+                            f.name,
+                            f.type_name,
+                            cell(&f.description)
+                        ));
                     }
-
-                    if let Some(rt) = &m.return_type {
-                        content.push_str("#### Return Type\n\n");
-                        content.push_str(&format!("`{}`\n\n", rt));
+                    content.push('\n');
+                }
+                TypeKind::Enum if !t.variants.is_empty() => {
+                    content.push_str("| Variant | Value | Description |\n");
+                    content.push_str("|---------|-------|-------------|\n");
+                    for v in &t.variants {
+                        let value = match v.value {
+                            Some(value) => value.to_string(),
+                            None if v.fields.is_empty() => String::new(),
+                            None => format!("`({})`", v.fields.join(", ")),
+                        };
+                        content.push_str(&format!(
+                            "| `{}` | {} | {} |\n",
+                            v.name,
+                            value,
+                            cell(&v.description)
+                        ));
                     }
+                    content.push('\n');
                 }
+                _ => {}
             }
+        }
+    }
 
-            if !doc.events.is_empty() {
-                content.push_str("## Events\n\n");
-                for e in &doc.events {
-                    content.push_str(&format!("- **{}**: {}\n", e.name, e.description.as_deref().unwrap_or("No description")));
+    if !doc.errors.is_empty() {
+        content.push_str("## Errors\n\n");
+        content.push_str("| Code | Name | Description |\n");
+        content.push_str("|------|------|-------------|\n");
+        for e in &doc.errors {
+            content.push_str(&format!("| {} | `{}` | {} |\n", e.code, e.name, cell(&e.description)));
+        }
+        content.push('\n');
+    }
+
+    if !doc.events.is_empty() {
+        content.push_str("## Events\n\n");
+        for e in &doc.events {
+            content.push_str(&format!("### `{}`\n\n", e.name));
+            if let Some(d) = &e.description {
+                content.push_str(&format!("{}\n\n", d));
+            }
+            let prefix: Vec<String> = e.prefix.iter().map(|t| format!("`\"{}\"`", t)).collect();
+            content.push_str(&format!(
+                "Topics: {} · Data format: `{}`\n\n",
+                prefix.join(", "),
+                e.data_format
+            ));
+            if !e.topics.is_empty() || !e.data.is_empty() {
+                content.push_str("| Field | Type | Kind | Description |\n");
+                content.push_str("|-------|------|------|-------------|\n");
+                let fields = e
+                    .topics
+                    .iter()
+                    .map(|f| (f, "topic"))
+                    .chain(e.data.iter().map(|f| (f, "data")));
+                for (f, kind) in fields {
+                    content.push_str(&format!(
+                        "| `{}` | `{}` | {} | {} |\n",
+                        f.name,
+                        f.type_name,
+                        kind,
+                        cell(&f.description)
+                    ));
                 }
-                content.push_str("\n");
+                content.push('\n');
             }
-
-            fs::write(file_path, content).map_err(|e| e.to_string())?;
         }
+    }
 
-        Ok(())
+    if !doc.dependencies.is_empty() {
+        content.push_str("## Dependencies\n\n");
+        content.push_str("| Client | Crate | Methods called |\n");
+        content.push_str("|--------|-------|----------------|\n");
+        for d in &doc.dependencies {
+            let calls: Vec<String> = d.calls.iter().map(|m| format!("`{}`", m)).collect();
+            let source = d.source_crate.as_deref().map(|c| format!("`{}`", c)).unwrap_or_default();
+            content.push_str(&format!("| `{}` | {} | {} |\n", d.client, source, calls.join(", ")));
+        }
+        content.push('\n');
     }
+
+    content
+}
+
+/// Flattens a doc comment so it fits in a table cell
+fn cell(description: &Option<String>) -> String {
+    description
+        .as_deref()
+        .unwrap_or("")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|")
 }
 
 // ---------------------------------------------------------------------------
@@ -423,4 +553,112 @@ impl PrizePool {
         assert_eq!(doc.methods[0].parameters[2].type_name, "u64");
         assert_eq!(doc.methods[0].return_type.as_deref(), Some("Result<(), Error>"));
     }
+
+    #[test]
+    fn test_markdown_lists_errors_and_event_fields() {
+        let source = r#"
+#[contracterror]
+#[repr(u32)]
+pub enum Error {
+    /// Caller is not the admin.
+    NotAuthorized = 3,
+}
+
+#[contractevent]
+pub struct BetPlaced {
+    #[topic]
+    pub game_id: u64,
+    pub wager: i128,
+}
+"#;
+        let doc = parser::parse_source("coin-flip", source).unwrap();
+        let markdown = render_markdown(&doc);
+
+        assert!(markdown.contains("## Errors"));
+        assert!(markdown.contains("| 3 | `NotAuthorized` | Caller is not the admin. |"));
+        assert!(markdown.contains("Topics: `\"bet_placed\"` · Data format: `map`"));
+        assert!(markdown.contains("| `game_id` | `u64` | topic |  |"));
+        assert!(markdown.contains("| `wager` | `i128` | data |  |"));
+    }
+
+    #[test]
+    fn test_run_writes_catalogue_and_typescript() {
+        let dir = tempdir().unwrap();
+        let contract = dir.path().join("contracts").join("coin-flip");
+        fs::create_dir_all(contract.join("src")).unwrap();
+        fs::write(contract.join("Cargo.toml"), "").unwrap();
+        fs::write(
+            contract.join("src").join("lib.rs"),
+            r#"
+#[contracterror]
+#[repr(u32)]
+pub enum Error {
+    NotAuthorized = 3,
+}
+
+#[contractimpl]
+impl CoinFlip {
+    pub fn place_bet(env: Env, player: Address, wager: i128) -> Result<(), Error> {}
+}
+"#,
+        )
+        .unwrap();
+
+        let output = dir.path().join("docs");
+        let mut generator = DocGenerator::new(dir.path().join("contracts"), output.clone());
+        generator.run().unwrap();
+        assert_eq!(generator.state, GeneratorState::Complete);
+
+        let json = fs::read_to_string(output.join(CATALOGUE_FILE)).unwrap();
+        let catalogue: Catalogue = serde_json::from_str(&json).unwrap();
+        let doc = &catalogue.contracts["coin-flip"];
+        assert_eq!(doc.methods[0].parameters[2].type_name, "i128");
+        assert_eq!(doc.methods[0].return_type.as_deref(), Some("Result<(), Error>"));
+        assert_eq!(doc.errors[0].code, 3);
+
+        let ts = fs::read_to_string(output.join(typescript::TYPESCRIPT_FILE)).unwrap();
+        assert!(ts.contains("export declare namespace CoinFlip {"));
+        assert!(ts.contains("place_bet: { args: { player: string; wager: bigint }; returns: void };"));
+    }
+
+    #[test]
+    fn test_check_with_contract_filter() {
+        let dir = tempdir().unwrap();
+        for name in ["coin-flip", "dice-roll"] {
+            let contract = dir.path().join("contracts").join(name);
+            fs::create_dir_all(contract.join("src")).unwrap();
+            fs::write(contract.join("Cargo.toml"), "").unwrap();
+            fs::write(
+                contract.join("src").join("lib.rs"),
+                "#[contractimpl]\nimpl Game {\n    pub fn play(env: Env) {}\n}\n",
+            )
+            .unwrap();
+        }
+        let output = dir.path().join("docs");
+        DocGenerator::new(dir.path().join("contracts"), output.clone()).run().unwrap();
+
+        let mut generator = DocGenerator::new(dir.path().join("contracts"), output.clone());
+        assert!(generator.check().unwrap().is_empty());
+
+        fs::write(
+            dir.path().join("contracts/dice-roll/src/lib.rs"),
+            "#[contractimpl]\nimpl Game {\n    pub fn play(env: Env) {}\n    pub fn roll(env: Env) {}\n}\n",
+        )
+        .unwrap();
+        generator.contract = Some("coin-flip".to_string());
+        assert!(generator.check().unwrap().is_empty());
+
+        generator.contract = Some("dice-roll".to_string());
+        assert_eq!(
+            generator.check().unwrap(),
+            [FileDrift {
+                file: "dice-roll.md".to_string(),
+                drift: Drift::Changed(vec!["method `roll` added".to_string()]),
+            }]
+        );
+        assert_eq!(generator.state, GeneratorState::Complete);
+
+        generator.contract = Some("lottery".to_string());
+        assert_eq!(generator.check(), Err("Unknown contract lottery".to_string()));
+    }
 }
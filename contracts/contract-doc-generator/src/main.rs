@@ -1,9 +1,24 @@
+use clap::Parser;
 use stellarcade_contract_doc_generator::DocGenerator;
 use std::path::PathBuf;
 use std::env;
 
+/// Generates Markdown, JSON and TypeScript docs for the StellarCade contracts
+#[derive(Parser)]
+#[command(name = "stellarcade-contract-doc-generator")]
+struct Cli {
+    /// Compare freshly generated docs with the committed ones instead of
+    /// writing them; exits non-zero when any file is out of date
+    #[arg(long)]
+    check: bool,
+
+    /// Only document this contract (its directory name, e.g. `coin-flip`)
+    #[arg(long)]
+    contract: Option<String>,
+}
+
 fn main() {
-    let _args: Vec<String> = env::args().collect();
+    let cli = Cli::parse();
 
     let current_dir = env::current_dir().unwrap_or_default();
     let is_in_pkg = current_dir.to_str().map(|s| s.contains("contract-doc-generator")).unwrap_or(false);
@@ -12,7 +27,29 @@ fn main() {
     let output_path = if is_in_pkg { PathBuf::from("../../docs/contracts") } else { PathBuf::from("docs/contracts") };
 
     let mut generator = DocGenerator::new(base_path, output_path);
-    
+    generator.contract = cli.contract;
+
+    if cli.check {
+        match generator.check() {
+            Ok(report) if report.is_empty() => println!("Docs are up to date"),
+            Ok(report) => {
+                for drift in &report {
+                    println!("{}", drift);
+                }
+                eprintln!(
+                    "ERROR: {} file(s) out of date; run `cargo run --package stellarcade-contract-doc-generator`",
+                    report.len()
+                );
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("ERROR: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     if let Err(e) = generator.run() {
         eprintln!("ERROR: {}", e);
         std::process::exit(1);
@@ -0,0 +1,444 @@
+use crate::clients;
+use crate::{
+    ContractDoc, ErrorDoc, EventDoc, FieldDoc, MethodDoc, ParameterDoc, TypeDoc, TypeKind,
+    VariantDoc,
+};
+use quote::ToTokens;
+use syn::{
+    Attribute, Expr, ExprLit, Fields, FnArg, ImplItem, Item, ItemEnum, ItemImpl, ItemStruct, Lit,
+    Meta, ReturnType, Signature, Visibility,
+};
+
+/// Parses a contract's `lib.rs` into its documented public surface
+pub fn parse_source(name: &str, source: &str) -> Result<ContractDoc, String> {
+    let file = syn::parse_file(source).map_err(|e| e.to_string())?;
+
+    let mut doc = ContractDoc {
+        name: name.to_string(),
+        description: docs(&file.attrs),
+        contract_type: None,
+        methods: Vec::new(),
+        types: Vec::new(),
+        errors: Vec::new(),
+        events: Vec::new(),
+        dependencies: clients::collect_dependencies(&file),
+    };
+    collect_items(&file.items, &mut doc);
+    Ok(doc)
+}
+
+fn collect_items(items: &[Item], doc: &mut ContractDoc) {
+    for item in items {
+        match item {
+            Item::Struct(s) if has_attr(&s.attrs, "contract") => {
+                if doc.contract_type.is_none() {
+                    doc.contract_type = Some(s.ident.to_string());
+                }
+                if doc.description.is_none() {
+                    doc.description = docs(&s.attrs);
+                }
+            }
+            Item::Struct(s) if has_attr(&s.attrs, "contractevent") => doc.events.push(event_doc(s)),
+            Item::Struct(s) if has_attr(&s.attrs, "contracttype") => doc.types.push(struct_doc(s)),
+            Item::Enum(e) if has_attr(&e.attrs, "contracterror") => doc.errors.extend(error_docs(e)),
+            Item::Enum(e) if has_attr(&e.attrs, "contracttype") => doc.types.push(enum_doc(e)),
+            Item::Impl(i) if has_attr(&i.attrs, "contractimpl") => doc.methods.extend(method_docs(i)),
+            Item::Mod(m) if !is_cfg_test(&m.attrs) => {
+                if let Some((_, items)) = &m.content {
+                    collect_items(items, doc);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Exported methods: every `pub fn` of an inherent impl, every fn of a trait impl
+fn method_docs(item: &ItemImpl) -> Vec<MethodDoc> {
+    let is_trait_impl = item.trait_.is_some();
+    item.items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(f) if is_trait_impl || matches!(f.vis, Visibility::Public(_)) => {
+                Some(method_doc(&f.attrs, &f.sig))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn method_doc(attrs: &[Attribute], sig: &Signature) -> MethodDoc {
+    let parameters: Vec<ParameterDoc> = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(arg) => Some(ParameterDoc {
+                name: render(&arg.pat),
+                type_name: render(&arg.ty),
+            }),
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    let return_type = match &sig.output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => Some(render(ty)),
+    };
+
+    let params: Vec<String> = parameters
+        .iter()
+        .map(|p| format!("{}: {}", p.name, p.type_name))
+        .collect();
+    let mut signature = format!("pub fn {}({})", sig.ident, params.join(", "));
+    if let Some(ty) = &return_type {
+        signature.push_str(&format!(" -> {}", ty));
+    }
+
+    MethodDoc {
+        name: sig.ident.to_string(),
+        description: docs(attrs),
+        signature,
+        parameters,
+        return_type,
+    }
+}
+
+fn struct_doc(item: &ItemStruct) -> TypeDoc {
+    TypeDoc {
+        name: item.ident.to_string(),
+        description: docs(&item.attrs),
+        kind: TypeKind::Struct,
+        fields: field_docs(&item.fields),
+        variants: Vec::new(),
+    }
+}
+
+fn enum_doc(item: &ItemEnum) -> TypeDoc {
+    let variants = item
+        .variants
+        .iter()
+        .map(|variant| VariantDoc {
+            name: variant.ident.to_string(),
+            description: docs(&variant.attrs),
+            fields: variant.fields.iter().map(|f| render(&f.ty)).collect(),
+            value: variant.discriminant.as_ref().and_then(|(_, expr)| int_value(expr)),
+        })
+        .collect();
+    TypeDoc {
+        name: item.ident.to_string(),
+        description: docs(&item.attrs),
+        kind: TypeKind::Enum,
+        fields: Vec::new(),
+        variants,
+    }
+}
+
+fn error_docs(item: &ItemEnum) -> Vec<ErrorDoc> {
+    item.variants
+        .iter()
+        .filter_map(|variant| {
+            let (_, expr) = variant.discriminant.as_ref()?;
+            Some(ErrorDoc {
+                name: variant.ident.to_string(),
+                code: int_value(expr)?,
+                description: docs(&variant.attrs),
+            })
+        })
+        .collect()
+}
+
+fn event_doc(item: &ItemStruct) -> EventDoc {
+    let mut prefix = vec![snake_case(&item.ident.to_string())];
+    let mut data_format = "map".to_string();
+    for attr in item.attrs.iter().filter(|a| is_named(a, "contractevent")) {
+        if !matches!(attr.meta, Meta::List(_)) {
+            continue;
+        }
+        // Unknown options are skipped; the macro itself rejects invalid ones
+        let _ = attr.parse_nested_meta(|meta| {
+            let value: Expr = meta.value()?.parse()?;
+            if meta.path.is_ident("topics") {
+                if let Expr::Array(topics) = &value {
+                    prefix = topics.elems.iter().filter_map(str_value).collect();
+                }
+            } else if meta.path.is_ident("data_format") {
+                if let Some(format) = str_value(&value) {
+                    data_format = format;
+                }
+            }
+            Ok(())
+        });
+    }
+
+    let (topics, data) = item
+        .fields
+        .iter()
+        .partition::<Vec<_>, _>(|field| has_attr(&field.attrs, "topic"));
+    EventDoc {
+        name: item.ident.to_string(),
+        description: docs(&item.attrs),
+        prefix,
+        data_format,
+        topics: topics.into_iter().map(field_doc).collect(),
+        data: data.into_iter().map(field_doc).collect(),
+    }
+}
+
+fn field_docs(fields: &Fields) -> Vec<FieldDoc> {
+    fields.iter().map(field_doc).collect()
+}
+
+fn field_doc(field: &syn::Field) -> FieldDoc {
+    FieldDoc {
+        name: field.ident.as_ref().map(|i| i.to_string()).unwrap_or_default(),
+        type_name: render(&field.ty),
+        description: docs(&field.attrs),
+    }
+}
+
+/// Joins `///` lines, dropping the space after the slashes
+fn docs(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(nv) if nv.path.is_ident("doc") => str_value(&nv.value),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').unwrap_or(&line).trim_end().to_string())
+        .collect();
+    let text = lines.join("\n").trim().to_string();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// Matches `#[name]` and path forms such as `#[soroban_sdk::name]`
+pub(crate) fn is_named(attr: &Attribute, name: &str) -> bool {
+    attr.path().segments.last().is_some_and(|s| s.ident == name)
+}
+
+pub(crate) fn has_attr(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|a| is_named(a, name))
+}
+
+pub(crate) fn is_cfg_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| match &attr.meta {
+        Meta::List(list) => list.path.is_ident("cfg") && list.tokens.to_string() == "test",
+        _ => false,
+    })
+}
+
+pub(crate) fn str_value(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Some(s.value()),
+        _ => None,
+    }
+}
+
+fn int_value(expr: &Expr) -> Option<u32> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => i.base10_parse().ok(),
+        _ => None,
+    }
+}
+
+/// Renders a type or pattern the way it is usually written, e.g. `Result<(), Error>`
+/// rather than the token stream's `Result < () , Error >`
+pub(crate) fn render<T: ToTokens>(node: &T) -> String {
+    node.to_token_stream()
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace(" ;", ";")
+        .replace("& ", "&")
+}
+
+/// Event names become topics the way `#[contractevent]` converts them
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+//! Stellarcade Example Contract
+//!
+//! Does example things.
+#![no_std]
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    /// `init` was already called.
+    AlreadyInitialized = 1,
+    NotAuthorized = 3,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Game(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Copy)]
+#[repr(u32)]
+pub enum Side {
+    Heads = 0,
+    Tails = 1,
+}
+
+/// A single wager.
+#[contracttype]
+pub struct Game {
+    /// Who placed the bet.
+    pub player: Address,
+    pub wagers: Map<u32, Vec<i128>>,
+}
+
+#[contractevent]
+pub struct BetPlaced {
+    #[topic]
+    pub game_id: u64,
+    /// Amount staked.
+    pub wager: i128,
+}
+
+#[contractevent(topics = ["role_assigned"], data_format = "single-value")]
+pub struct RoleAssigned {
+    #[topic]
+    pub target: Address,
+    pub role: Symbol,
+}
+
+#[contract]
+pub struct Example;
+
+#[contractimpl]
+impl Example {
+    pub fn get(env: Env, key: &BytesN<32>) -> Option<soroban_sdk::Address> {
+        None
+    }
+
+    fn helper(env: &Env) {}
+}
+
+#[contractimpl]
+impl Registry for Example {
+    /// Exported through the trait.
+    fn register(_env: Env, mut ids: Vec<(Address, u32)>) {}
+}
+
+#[cfg(test)]
+mod test {
+    #[contracttype]
+    pub struct Ignored;
+}
+"#;
+
+    #[test]
+    fn test_description_prefers_crate_docs() {
+        let doc = parse_source("example", SOURCE).unwrap();
+        assert_eq!(
+            doc.description.as_deref(),
+            Some("Stellarcade Example Contract\n\nDoes example things.")
+        );
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let doc = parse_source("example", SOURCE).unwrap();
+        assert_eq!(doc.errors.len(), 2);
+        assert_eq!(doc.errors[0].name, "AlreadyInitialized");
+        assert_eq!(doc.errors[0].code, 1);
+        assert_eq!(doc.errors[0].description.as_deref(), Some("`init` was already called."));
+        assert_eq!(doc.errors[1].code, 3);
+        assert_eq!(doc.errors[1].description, None);
+    }
+
+    #[test]
+    fn test_types_with_fields_and_variants() {
+        let doc = parse_source("example", SOURCE).unwrap();
+        let names: Vec<&str> = doc.types.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["DataKey", "Side", "Game"]);
+
+        let data_key = &doc.types[0];
+        assert_eq!(data_key.kind, TypeKind::Enum);
+        assert_eq!(data_key.variants[1].name, "Game");
+        assert_eq!(data_key.variants[1].fields, ["u64"]);
+        assert_eq!(data_key.variants[1].value, None);
+        assert_eq!(doc.types[1].variants[1].value, Some(1));
+
+        let game = &doc.types[2];
+        assert_eq!(game.kind, TypeKind::Struct);
+        assert_eq!(game.description.as_deref(), Some("A single wager."));
+        assert_eq!(game.fields[0].description.as_deref(), Some("Who placed the bet."));
+        assert_eq!(game.fields[1].type_name, "Map<u32, Vec<i128>>");
+    }
+
+    #[test]
+    fn test_event_topics_and_data() {
+        let doc = parse_source("example", SOURCE).unwrap();
+        let bet = &doc.events[0];
+        assert_eq!(bet.prefix, ["bet_placed"]);
+        assert_eq!(bet.data_format, "map");
+        assert_eq!(bet.topics.len(), 1);
+        assert_eq!(bet.topics[0].name, "game_id");
+        assert_eq!(bet.data[0].name, "wager");
+        assert_eq!(bet.data[0].description.as_deref(), Some("Amount staked."));
+
+        let role = &doc.events[1];
+        assert_eq!(role.prefix, ["role_assigned"]);
+        assert_eq!(role.data_format, "single-value");
+        assert_eq!(role.topics[0].type_name, "Address");
+        assert_eq!(role.data[0].type_name, "Symbol");
+    }
+
+    #[test]
+    fn test_exported_methods_only() {
+        let doc = parse_source("example", SOURCE).unwrap();
+        let names: Vec<&str> = doc.methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["get", "register"]);
+        assert_eq!(
+            doc.methods[0].signature,
+            "pub fn get(env: Env, key: &BytesN<32>) -> Option<soroban_sdk::Address>"
+        );
+        assert_eq!(
+            doc.methods[1].signature,
+            "pub fn register(_env: Env, mut ids: Vec<(Address, u32)>)"
+        );
+        assert_eq!(doc.methods[1].description.as_deref(), Some("Exported through the trait."));
+    }
+
+    #[test]
+    fn test_snake_case_topics() {
+        assert_eq!(snake_case("BetPlaced"), "bet_placed");
+        assert_eq!(snake_case("RNGFulfilled"), "rng_fulfilled");
+        assert_eq!(snake_case("Round2Started"), "round2_started");
+    }
+
+    #[test]
+    fn test_invalid_source_is_an_error() {
+        assert!(parse_source("broken", "pub fn (").is_err());
+    }
+}
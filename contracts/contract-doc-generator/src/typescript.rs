@@ -0,0 +1,370 @@
+use crate::{ContractDoc, EventDoc, FieldDoc, TypeDoc, TypeKind};
+use std::collections::BTreeSet;
+use syn::{GenericArgument, PathArguments, Type};
+
+/// File the TypeScript definitions are written to, next to the Markdown pages
+pub const TYPESCRIPT_FILE: &str = "stellarcade-contracts.d.ts";
+
+/// Renders one `declare namespace` per contract, named after it in PascalCase
+/// (`coin-flip` → `CoinFlip`), holding its types, error codes, events and methods.
+/// Types follow the Stellar JS SDK's conversion of Soroban values.
+pub fn render_typescript(docs: &[ContractDoc]) -> String {
+    let mut out = String::from(
+        "// Generated by stellarcade-contract-doc-generator from the contract sources.\n\
+         // Do not edit; rerun the generator instead.\n",
+    );
+    for doc in docs {
+        out.push('\n');
+        out.push_str(&render_contract(doc));
+    }
+    out
+}
+
+fn render_contract(doc: &ContractDoc) -> String {
+    let known: BTreeSet<&str> = doc.types.iter().map(|t| t.name.as_str()).collect();
+    let mut out = String::new();
+    push_comment(&mut out, "", &doc.description);
+    out.push_str(&format!("export declare namespace {} {{\n", pascal_case(&doc.name)));
+
+    for t in &doc.types {
+        push_comment(&mut out, "  ", &t.description);
+        out.push_str(&render_type(t, &known));
+    }
+
+    if !doc.errors.is_empty() {
+        out.push_str("  export const enum ErrorCode {\n");
+        for e in &doc.errors {
+            push_comment(&mut out, "    ", &e.description);
+            out.push_str(&format!("    {} = {},\n", e.name, e.code));
+        }
+        out.push_str("  }\n");
+    }
+
+    for e in &doc.events {
+        push_comment(&mut out, "  ", &e.description);
+        out.push_str(&render_event(e, &known));
+    }
+    if !doc.events.is_empty() {
+        let names: Vec<String> = doc.events.iter().map(event_interface).collect();
+        out.push_str(&format!("  export type ContractEvent = {};\n", names.join(" | ")));
+    }
+
+    out.push_str("  export interface Methods {\n");
+    for m in &doc.methods {
+        push_comment(&mut out, "    ", &m.description);
+        let args: Vec<String> = m
+            .parameters
+            .iter()
+            .filter(|p| !is_env(&p.type_name))
+            .map(|p| format!("{}: {}", p.name, ts_type(&p.type_name, &known)))
+            .collect();
+        let returns = m
+            .return_type
+            .as_deref()
+            .map(|ty| ts_type(ty, &known))
+            .unwrap_or_else(|| "void".to_string());
+        out.push_str(&format!(
+            "    {}: {{ args: {}; returns: {} }};\n",
+            m.name,
+            object(&args),
+            returns
+        ));
+    }
+    out.push_str("  }\n}\n");
+    out
+}
+
+fn render_type(t: &TypeDoc, known: &BTreeSet<&str>) -> String {
+    match t.kind {
+        TypeKind::Struct if t.fields.iter().all(|f| f.name.is_empty()) => {
+            let types: Vec<String> = t.fields.iter().map(|f| ts_type(&f.type_name, known)).collect();
+            format!("  export type {} = {};\n", t.name, tuple(&types))
+        }
+        TypeKind::Struct => {
+            let mut out = format!("  export interface {} {{\n", t.name);
+            for f in &t.fields {
+                push_comment(&mut out, "    ", &f.description);
+                out.push_str(&format!("    {}: {};\n", f.name, ts_type(&f.type_name, known)));
+            }
+            out.push_str("  }\n");
+            out
+        }
+        // Integer enums: `#[repr(u32)]` with explicit discriminants
+        TypeKind::Enum if t.variants.iter().all(|v| v.value.is_some()) => {
+            let mut out = format!("  export const enum {} {{\n", t.name);
+            for v in &t.variants {
+                push_comment(&mut out, "    ", &v.description);
+                out.push_str(&format!("    {} = {},\n", v.name, v.value.unwrap_or_default()));
+            }
+            out.push_str("  }\n");
+            out
+        }
+        TypeKind::Enum => {
+            let variants: Vec<String> = t
+                .variants
+                .iter()
+                .map(|v| {
+                    let values = if v.fields.is_empty() {
+                        "void".to_string()
+                    } else {
+                        let types: Vec<String> = v.fields.iter().map(|f| ts_type(f, known)).collect();
+                        tuple(&types)
+                    };
+                    format!("{{ tag: \"{}\"; values: {} }}", v.name, values)
+                })
+                .collect();
+            format!("  export type {} =\n    | {};\n", t.name, variants.join("\n    | "))
+        }
+    }
+}
+
+fn render_event(e: &EventDoc, known: &BTreeSet<&str>) -> String {
+    let topics: Vec<String> = e
+        .prefix
+        .iter()
+        .map(|t| format!("\"{}\"", t))
+        .chain(e.topics.iter().map(|f| ts_type(&f.type_name, known)))
+        .collect();
+    let data = match e.data_format.as_str() {
+        "single-value" => e
+            .data
+            .first()
+            .map(|f| ts_type(&f.type_name, known))
+            .unwrap_or_else(|| "void".to_string()),
+        "vec" => {
+            let types: Vec<String> = e.data.iter().map(|f| ts_type(&f.type_name, known)).collect();
+            tuple(&types)
+        }
+        _ => object(&fields(&e.data, known)),
+    };
+    format!(
+        "  export interface {} {{\n    topics: {};\n    data: {};\n  }}\n",
+        event_interface(e),
+        tuple(&topics),
+        data
+    )
+}
+
+fn event_interface(e: &EventDoc) -> String {
+    if e.name.ends_with("Event") {
+        e.name.clone()
+    } else {
+        format!("{}Event", e.name)
+    }
+}
+
+fn fields(fields: &[FieldDoc], known: &BTreeSet<&str>) -> Vec<String> {
+    fields
+        .iter()
+        .map(|f| format!("{}: {}", f.name, ts_type(&f.type_name, known)))
+        .collect()
+}
+
+fn object(members: &[String]) -> String {
+    if members.is_empty() {
+        "Record<string, never>".to_string()
+    } else {
+        format!("{{ {} }}", members.join("; "))
+    }
+}
+
+fn tuple(types: &[String]) -> String {
+    format!("readonly [{}]", types.join(", "))
+}
+
+fn is_env(type_name: &str) -> bool {
+    matches!(type_name.trim_start_matches('&'), "Env" | "soroban_sdk::Env")
+}
+
+/// Maps a Rust type as written in the contract to its TypeScript form. Types
+/// the contract does not declare itself, such as another crate's structs,
+/// become `unknown`.
+pub fn ts_type(type_name: &str, known: &BTreeSet<&str>) -> String {
+    match syn::parse_str::<Type>(type_name) {
+        Ok(ty) => map_type(&ty, known),
+        Err(_) => "unknown".to_string(),
+    }
+}
+
+fn map_type(ty: &Type, known: &BTreeSet<&str>) -> String {
+    match ty {
+        Type::Reference(r) => map_type(&r.elem, known),
+        Type::Paren(p) => map_type(&p.elem, known),
+        Type::Tuple(t) if t.elems.is_empty() => "void".to_string(),
+        Type::Tuple(t) => {
+            let types: Vec<String> = t.elems.iter().map(|e| map_type(e, known)).collect();
+            tuple(&types)
+        }
+        Type::Path(p) => {
+            let Some(segment) = p.path.segments.last() else {
+                return "unknown".to_string();
+            };
+            let args: Vec<String> = match &segment.arguments {
+                PathArguments::AngleBracketed(a) => a
+                    .args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        GenericArgument::Type(t) => Some(map_type(t, known)),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            let name = segment.ident.to_string();
+            match (name.as_str(), args.as_slice()) {
+                ("u8" | "u16" | "u32" | "i8" | "i16" | "i32", _) => "number".to_string(),
+                ("u64" | "i64" | "u128" | "i128" | "U256" | "I256" | "Timepoint" | "Duration", _) => {
+                    "bigint".to_string()
+                }
+                ("bool", _) => "boolean".to_string(),
+                ("Address" | "MuxedAddress" | "String" | "Symbol", _) => "string".to_string(),
+                ("Bytes" | "BytesN", _) => "Buffer".to_string(),
+                ("Vec", [item]) => format!("Array<{}>", item),
+                ("Map", [key, value]) => format!("Map<{}, {}>", key, value),
+                ("Option", [inner]) => format!("{} | undefined", inner),
+                // Contract errors are raised, not returned
+                ("Result", [ok, ..]) => ok.clone(),
+                (name, _) if known.contains(name) => name.to_string(),
+                _ => "unknown".to_string(),
+            }
+        }
+        _ => "unknown".to_string(),
+    }
+}
+
+fn push_comment(out: &mut String, indent: &str, description: &Option<String>) {
+    let Some(text) = description else { return };
+    let lines: Vec<&str> = text.lines().collect();
+    if let [line] = lines.as_slice() {
+        out.push_str(&format!("{}/** {} */\n", indent, line.replace("*/", "*\\/")));
+        return;
+    }
+    out.push_str(&format!("{}/**\n", indent));
+    for line in lines {
+        let line = line.replace("*/", "*\\/");
+        if line.is_empty() {
+            out.push_str(&format!("{} *\n", indent));
+        } else {
+            out.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{} */\n", indent));
+}
+
+pub fn pascal_case(name: &str) -> String {
+    name.split(['-', '_'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_source;
+
+    const SOURCE: &str = r#"
+/// Coin flip game.
+#[contract]
+pub struct CoinFlip;
+
+#[contracterror]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+}
+
+#[contracttype]
+#[repr(u32)]
+pub enum Side {
+    Heads = 0,
+    Tails = 1,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Game(u64),
+}
+
+#[contracttype]
+pub struct Game {
+    /// Who placed the bet.
+    pub player: Address,
+    pub side: Side,
+    pub history: Vec<Option<i128>>,
+}
+
+#[contractevent]
+pub struct BetPlaced {
+    #[topic]
+    pub game_id: u64,
+    pub wager: i128,
+}
+
+#[contractevent(topics = ["flip"], data_format = "single-value")]
+pub struct FlipEvent {
+    pub payout: i128,
+}
+
+#[contractimpl]
+impl CoinFlip {
+    pub fn get_game(env: Env, game_id: u64) -> Result<Game, Error> {}
+    pub fn peek(env: &Env) -> (u32, Bytes) {}
+}
+"#;
+
+    fn known() -> BTreeSet<&'static str> {
+        ["Game"].into_iter().collect()
+    }
+
+    #[test]
+    fn test_type_mapping() {
+        assert_eq!(ts_type("u32", &known()), "number");
+        assert_eq!(ts_type("i128", &known()), "bigint");
+        assert_eq!(ts_type("soroban_sdk::Address", &known()), "string");
+        assert_eq!(ts_type("BytesN<32>", &known()), "Buffer");
+        assert_eq!(ts_type("Vec<(Address, i128)>", &known()), "Array<readonly [string, bigint]>");
+        assert_eq!(ts_type("Map<Symbol, Vec<u64>>", &known()), "Map<string, Array<bigint>>");
+        assert_eq!(ts_type("Option<Game>", &known()), "Game | undefined");
+        assert_eq!(ts_type("Result<(), Error>", &known()), "void");
+        assert_eq!(ts_type("OtherCrateType", &known()), "unknown");
+    }
+
+    #[test]
+    fn test_contract_namespace() {
+        let doc = parse_source("coin-flip", SOURCE).unwrap();
+        let ts = render_typescript(&[doc]);
+
+        assert!(ts.contains("/** Coin flip game. */\nexport declare namespace CoinFlip {\n"));
+        assert!(ts.contains("  export const enum ErrorCode {\n    AlreadyInitialized = 1,\n  }\n"));
+        assert!(ts.contains("  export const enum Side {\n    Heads = 0,\n    Tails = 1,\n  }\n"));
+        assert!(ts.contains(
+            "  export type DataKey =\n    | { tag: \"Admin\"; values: void }\n    | { tag: \"Game\"; values: readonly [bigint] };\n"
+        ));
+        assert!(ts.contains(
+            "  export interface Game {\n    /** Who placed the bet. */\n    player: string;\n    side: Side;\n    history: Array<bigint | undefined>;\n  }\n"
+        ));
+        assert!(ts.contains(
+            "  export interface BetPlacedEvent {\n    topics: readonly [\"bet_placed\", bigint];\n    data: { wager: bigint };\n  }\n"
+        ));
+        assert!(ts.contains("    topics: readonly [\"flip\"];\n    data: bigint;\n"));
+        assert!(ts.contains("  export type ContractEvent = BetPlacedEvent | FlipEvent;\n"));
+        assert!(ts.contains("    get_game: { args: { game_id: bigint }; returns: Game };\n"));
+        assert!(ts.contains(
+            "    peek: { args: Record<string, never>; returns: readonly [number, Buffer] };\n"
+        ));
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        assert_eq!(pascal_case("coin-flip"), "CoinFlip");
+        assert_eq!(pascal_case("contract_address-registry"), "ContractAddressRegistry");
+    }
+}
@@ -2,12 +2,25 @@
 //!
 //! Players can submit one answer per round/day. Correct answers share a
 //! fixed reward amount reserved for that round.
+//!
+//! Answers use commit-reveal: until the round's `deadline` players commit
+//! `sha256(answer || salt || player)` (see [`answer_commitment`]), then reveal
+//! the answer and salt until `reveal_deadline`.
+//!
+//! Reveals can happen in two ways:
+//! - on-chain, with `reveal_answer`, which evaluates correctness immediately;
+//! - in bulk, off-chain to the operator. When closing the round the admin
+//!   publishes the plaintext answer and a Merkle root of `(player, correct)`
+//!   results (see [`result_leaf`]), and those players claim with a proof.
+//!   Closing stays O(1) however many players revealed this way.
+//!
+//! Commitments revealed neither way are forfeited.
 #![no_std]
 #![allow(unexpected_cfgs)]
 
 use soroban_sdk::{
     contract, contractclient, contracterror, contractevent, contractimpl, contracttype,
-    symbol_short, Address, Bytes, BytesN, Env, Symbol,
+    symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec,
 };
 
 // ---------------------------------------------------------------------------
@@ -48,6 +61,17 @@ pub enum Error {
     NoRewardAvailable = 10,
     InvalidAmount = 11,
     Overflow = 12,
+    InvalidDeadline = 13,
+    PastDeadline = 14,
+    RevealNotOpen = 15,
+    RevealClosed = 16,
+    CommitmentNotFound = 17,
+    AlreadyRevealed = 18,
+    CommitmentMismatch = 19,
+    RevealInProgress = 20,
+    AnswerMismatch = 21,
+    InvalidProof = 22,
+    BulkClaimsExhausted = 23,
 }
 
 // ---------------------------------------------------------------------------
@@ -67,17 +91,36 @@ pub struct RoundData {
     pub answer_commitment: BytesN<32>,
     pub reward_amount: i128,
     pub payout_per_winner: i128,
+    /// Correct reveals, on-chain and in bulk
     pub winner_count: u32,
+    /// Winners in `results_root`, published at close
+    pub bulk_winner_count: u32,
+    /// Rewards claimed with a proof against `results_root`; never exceeds
+    /// `bulk_winner_count`
+    pub bulk_claimed_count: u32,
+    /// Commitments
     pub participant_count: u32,
+    pub revealed_count: u32,
     pub status: RoundStatus,
     pub opened_at: u64,
+    /// Last timestamp at which answers can be committed
+    pub deadline: u64,
+    /// Last timestamp at which commitments can be revealed
+    pub reveal_deadline: u64,
     pub closed_at: u64,
+    /// Plaintext answer, published at close
+    pub answer: Bytes,
+    /// Merkle root of the bulk-revealed results, published at close; all
+    /// zeroes before
+    pub results_root: BytesN<32>,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub struct Submission {
-    pub answer_hash: BytesN<32>,
+    /// `sha256(answer || salt || player)`, see [`answer_commitment`]
+    pub commitment: BytesN<32>,
+    pub revealed: bool,
     pub correct: bool,
     pub claimed: bool,
 }
@@ -111,6 +154,8 @@ pub struct RoundSnapshot {
     pub reward_amount: i128,
     pub payout_per_winner: i128,
     pub opened_at: u64,
+    pub deadline: u64,
+    pub reveal_deadline: u64,
     pub closed_at: u64,
 }
 
@@ -123,6 +168,8 @@ pub struct ParticipantAnswerSummary {
     pub participant_count: u32,
     pub correct_count: u32,
     pub incorrect_count: u32,
+    /// Commitments not revealed yet; forfeited once the reveal window closes
+    pub unrevealed_count: u32,
 }
 
 #[contracttype]
@@ -150,7 +197,14 @@ pub struct RoundOpened {
 }
 
 #[contractevent]
-pub struct AnswerSubmitted {
+pub struct AnswerCommitted {
+    #[topic]
+    pub round_id: u64,
+    pub player: Address,
+}
+
+#[contractevent]
+pub struct AnswerRevealed {
     #[topic]
     pub round_id: u64,
     pub player: Address,
@@ -163,6 +217,8 @@ pub struct RoundClosed {
     pub round_id: u64,
     pub winners: u32,
     pub payout_per_winner: i128,
+    pub answer: Bytes,
+    pub results_root: BytesN<32>,
 }
 
 #[contractevent]
@@ -202,15 +258,23 @@ impl DailyTrivia {
         Ok(())
     }
 
+    /// Answers are committed until `deadline` and revealed until `reveal_deadline`.
     pub fn open_round(
         env: Env,
         round_id: u64,
         answer_commitment: BytesN<32>,
+        deadline: u64,
+        reveal_deadline: u64,
         reward_amount: i128,
     ) -> Result<(), Error> {
         let admin = require_admin(&env)?;
         require_positive(reward_amount)?;
 
+        let now = env.ledger().timestamp();
+        if deadline <= now || reveal_deadline <= deadline {
+            return Err(Error::InvalidDeadline);
+        }
+
         let key = DataKey::Round(round_id);
         if env.storage().persistent().has(&key) {
             return Err(Error::RoundAlreadyExists);
@@ -220,16 +284,22 @@ impl DailyTrivia {
         let pool_client = PrizePoolClient::new(&env, &prize_pool);
         pool_client.reserve(&admin, &round_id, &reward_amount);
 
-        let now = env.ledger().timestamp();
         let round = RoundData {
             answer_commitment,
             reward_amount,
             payout_per_winner: 0,
             winner_count: 0,
+            bulk_winner_count: 0,
+            bulk_claimed_count: 0,
             participant_count: 0,
+            revealed_count: 0,
             status: RoundStatus::Open,
             opened_at: now,
+            deadline,
+            reveal_deadline,
             closed_at: 0,
+            answer: Bytes::new(&env),
+            results_root: BytesN::from_array(&env, &[0; 32]),
         };
         env.storage().persistent().set(&key, &round);
         env.storage()
@@ -244,11 +314,12 @@ impl DailyTrivia {
         Ok(())
     }
 
-    pub fn submit_answer(
+    /// Commit to an answer while the answer window is open.
+    pub fn commit_answer(
         env: Env,
         player: Address,
         round_id: u64,
-        answer_payload: Bytes,
+        commitment: BytesN<32>,
     ) -> Result<(), Error> {
         require_initialized(&env)?;
         player.require_auth();
@@ -263,44 +334,117 @@ impl DailyTrivia {
         if round.status != RoundStatus::Open {
             return Err(Error::RoundClosed);
         }
-        if env.ledger().timestamp() < round.opened_at {
+        let now = env.ledger().timestamp();
+        if now < round.opened_at {
             return Err(Error::RoundNotOpen);
         }
+        if now > round.deadline {
+            return Err(Error::PastDeadline);
+        }
 
         let submission_key = DataKey::Submission(round_id, player.clone());
         if env.storage().persistent().has(&submission_key) {
             return Err(Error::AlreadySubmitted);
         }
 
-        let answer_hash: BytesN<32> = env.crypto().sha256(&answer_payload).into();
-        let correct = answer_hash == round.answer_commitment;
         round.participant_count = round
             .participant_count
             .checked_add(1)
             .ok_or(Error::Overflow)?;
+        env.storage().persistent().set(&key, &round);
 
+        let submission = Submission {
+            commitment,
+            revealed: false,
+            correct: false,
+            claimed: false,
+        };
+        env.storage().persistent().set(&submission_key, &submission);
+
+        AnswerCommitted { round_id, player }.publish(&env);
+        Ok(())
+    }
+
+    /// Reveal a committed answer between `deadline` and `reveal_deadline`.
+    /// Returns whether `sha256(answer_payload)` matches the round's commitment.
+    pub fn reveal_answer(
+        env: Env,
+        player: Address,
+        round_id: u64,
+        answer_payload: Bytes,
+        salt: BytesN<32>,
+    ) -> Result<bool, Error> {
+        require_initialized(&env)?;
+        player.require_auth();
+
+        let key = DataKey::Round(round_id);
+        let mut round: RoundData = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::RoundNotFound)?;
+
+        if round.status != RoundStatus::Open {
+            return Err(Error::RoundClosed);
+        }
+        let now = env.ledger().timestamp();
+        if now <= round.deadline {
+            return Err(Error::RevealNotOpen);
+        }
+        if now > round.reveal_deadline {
+            return Err(Error::RevealClosed);
+        }
+
+        let submission_key = DataKey::Submission(round_id, player.clone());
+        let mut submission: Submission = env
+            .storage()
+            .persistent()
+            .get(&submission_key)
+            .ok_or(Error::CommitmentNotFound)?;
+        if submission.revealed {
+            return Err(Error::AlreadyRevealed);
+        }
+        if answer_commitment(&env, &answer_payload, &salt, &player) != submission.commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+
+        let answer_hash: BytesN<32> = env.crypto().sha256(&answer_payload).into();
+        let correct = answer_hash == round.answer_commitment;
+        round.revealed_count = round.revealed_count.checked_add(1).ok_or(Error::Overflow)?;
         if correct {
             round.winner_count = round.winner_count.checked_add(1).ok_or(Error::Overflow)?;
         }
         env.storage().persistent().set(&key, &round);
 
-        let submission = Submission {
-            answer_hash,
-            correct,
-            claimed: false,
-        };
+        submission.revealed = true;
+        submission.correct = correct;
         env.storage().persistent().set(&submission_key, &submission);
 
-        AnswerSubmitted {
+        AnswerRevealed {
             round_id,
             player,
             correct,
         }
         .publish(&env);
-        Ok(())
+        Ok(correct)
     }
 
-    pub fn close_round(env: Env, round_id: u64) -> Result<(), Error> {
+    /// Close the round after the reveal window, publishing the plaintext
+    /// `answer` and the bulk reveal results.
+    ///
+    /// `results_root` is the Merkle root of [`result_leaf`]s for players who
+    /// revealed off-chain, `bulk_winner_count` the number of those marked
+    /// correct. Players who revealed on-chain must be left out of the tree;
+    /// pass an all-zero root and 0 when nobody revealed in bulk. At most
+    /// `bulk_winner_count` proofs are paid, so an understated count cannot
+    /// push payouts past the reserved reward.
+    pub fn close_round(
+        env: Env,
+        round_id: u64,
+        answer: Bytes,
+        results_root: BytesN<32>,
+        bulk_winner_count: u32,
+    ) -> Result<(), Error> {
         let admin = require_admin(&env)?;
         let key = DataKey::Round(round_id);
         let mut round: RoundData = env
@@ -313,9 +457,19 @@ impl DailyTrivia {
             return Err(Error::RoundNotOpen);
         }
         let now = env.ledger().timestamp();
-        if now < round.opened_at {
-            return Err(Error::RoundNotOpen);
+        if now <= round.reveal_deadline {
+            return Err(Error::RevealInProgress);
         }
+        let answer_hash: BytesN<32> = env.crypto().sha256(&answer).into();
+        if answer_hash != round.answer_commitment {
+            return Err(Error::AnswerMismatch);
+        }
+
+        round.winner_count = round
+            .winner_count
+            .checked_add(bulk_winner_count)
+            .ok_or(Error::Overflow)?;
+        round.bulk_winner_count = bulk_winner_count;
 
         let payout_per_winner = if round.winner_count == 0 {
             0
@@ -335,25 +489,39 @@ impl DailyTrivia {
         round.status = RoundStatus::Closed;
         round.closed_at = now;
         round.payout_per_winner = payout_per_winner;
+        round.answer = answer.clone();
+        round.results_root = results_root.clone();
         env.storage().persistent().set(&key, &round);
 
         RoundClosed {
             round_id,
             winners: round.winner_count,
             payout_per_winner,
+            answer,
+            results_root,
         }
         .publish(&env);
         Ok(())
     }
 
-    pub fn claim_reward(env: Env, player: Address, round_id: u64) -> Result<i128, Error> {
+    /// Claim a winner's share of a closed round. Players who revealed
+    /// on-chain pass an empty `proof`; players revealed in bulk pass the
+    /// Merkle proof of their `(player, true)` result, until
+    /// `bulk_winner_count` of them have claimed.
+    pub fn claim_reward(
+        env: Env,
+        player: Address,
+        round_id: u64,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<i128, Error> {
         require_initialized(&env)?;
         player.require_auth();
 
-        let round: RoundData = env
+        let round_key = DataKey::Round(round_id);
+        let mut round: RoundData = env
             .storage()
             .persistent()
-            .get(&DataKey::Round(round_id))
+            .get(&round_key)
             .ok_or(Error::RoundNotFound)?;
 
         if round.status != RoundStatus::Closed {
@@ -371,7 +539,20 @@ impl DailyTrivia {
             return Err(Error::AlreadyClaimed);
         }
 
-        if !submission.correct || round.payout_per_winner <= 0 {
+        let correct = if submission.revealed {
+            submission.correct
+        } else {
+            let leaf = result_leaf(&env, &player, true);
+            if !verify_merkle_proof(&env, &leaf, &proof, &round.results_root) {
+                return Err(Error::InvalidProof);
+            }
+            if round.bulk_claimed_count >= round.bulk_winner_count {
+                return Err(Error::BulkClaimsExhausted);
+            }
+            round.bulk_claimed_count += 1;
+            true
+        };
+        if !correct || round.payout_per_winner <= 0 {
             return Err(Error::NoRewardAvailable);
         }
 
@@ -404,6 +585,7 @@ impl DailyTrivia {
 
         submission.claimed = true;
         env.storage().persistent().set(&submission_key, &submission);
+        env.storage().persistent().set(&round_key, &round);
 
         RewardClaimed {
             round_id,
@@ -436,6 +618,8 @@ impl DailyTrivia {
                 reward_amount: 0,
                 payout_per_winner: 0,
                 opened_at: 0,
+                deadline: 0,
+                reveal_deadline: 0,
                 closed_at: 0,
             });
         };
@@ -458,11 +642,16 @@ impl DailyTrivia {
             reward_amount: round.reward_amount,
             payout_per_winner: round.payout_per_winner,
             opened_at: round.opened_at,
+            deadline: round.deadline,
+            reveal_deadline: round.reveal_deadline,
             closed_at: round.closed_at,
         })
     }
 
     /// Returns participation/correctness counters for the latest round.
+    /// `participant_count` counts commitments. `correct_count` includes bulk
+    /// winners once the round is closed; `incorrect_count` and
+    /// `unrevealed_count` only reflect on-chain reveals.
     ///
     /// If no round exists yet, returns an `Uninitialized` summary.
     /// If the latest round is closed, status is `Resolved` with final counters.
@@ -481,6 +670,7 @@ impl DailyTrivia {
                 participant_count: 0,
                 correct_count: 0,
                 incorrect_count: 0,
+                unrevealed_count: 0,
             });
         };
 
@@ -491,8 +681,12 @@ impl DailyTrivia {
             .ok_or(Error::RoundNotFound)?;
 
         let incorrect_count = round
+            .revealed_count
+            .checked_sub(round.winner_count - round.bulk_winner_count)
+            .ok_or(Error::Overflow)?;
+        let unrevealed_count = round
             .participant_count
-            .checked_sub(round.winner_count)
+            .checked_sub(round.revealed_count)
             .ok_or(Error::Overflow)?;
 
         Ok(ParticipantAnswerSummary {
@@ -505,6 +699,7 @@ impl DailyTrivia {
             participant_count: round.participant_count,
             correct_count: round.winner_count,
             incorrect_count,
+            unrevealed_count,
         })
     }
 
@@ -576,6 +771,51 @@ impl DailyTrivia {
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// The value a player commits to: `sha256(answer || salt || player)`, with
+/// `player` encoded as its `ScVal` XDR so commitments cannot be replayed by
+/// another player.
+pub fn answer_commitment(
+    env: &Env,
+    answer: &Bytes,
+    salt: &BytesN<32>,
+    player: &Address,
+) -> BytesN<32> {
+    let mut preimage = answer.clone();
+    preimage.append(&Bytes::from(salt));
+    preimage.append(&player.clone().to_xdr(env));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// A bulk reveal result: `sha256(player || correct)`, with `player` as its
+/// `ScVal` XDR and `correct` as one byte, 1 or 0.
+pub fn result_leaf(env: &Env, player: &Address, correct: bool) -> BytesN<32> {
+    let mut preimage = player.clone().to_xdr(env);
+    preimage.push_back(correct as u8);
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Folds `proof` into `leaf`, hashing each pair of nodes in sorted order, and
+/// compares the result with `root`.
+pub fn verify_merkle_proof(
+    env: &Env,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+    root: &BytesN<32>,
+) -> bool {
+    let mut node = leaf.clone();
+    for sibling in proof.iter() {
+        node = hash_pair(env, &node, &sibling);
+    }
+    &node == root
+}
+
+fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut preimage = Bytes::from(first);
+    preimage.append(&Bytes::from(second));
+    env.crypto().sha256(&preimage).into()
+}
+
 fn require_initialized(env: &Env) -> Result<(), Error> {
     if !env.storage().instance().has(&DataKey::Admin) {
         return Err(Error::NotInitialized);
@@ -625,7 +865,9 @@ fn get_balance_contract(env: &Env) -> Result<Address, Error> {
 mod test {
     use super::*;
     use soroban_sdk::{
-        contract, contractimpl, contracttype, testutils::Address as _, Address, Env, IntoVal,
+        contract, contractimpl, contracttype,
+        testutils::{Address as _, Ledger},
+        Address, Env, IntoVal,
     };
 
     #[contract]
@@ -732,34 +974,139 @@ mod test {
         env.crypto().sha256(payload).into()
     }
 
+    const DEADLINE: u64 = 100;
+    const REVEAL_DEADLINE: u64 = 200;
+
+    fn open(env: &Env, client: &DailyTriviaClient, round_id: u64, answer: &Bytes, reward: i128) {
+        env.ledger().set_timestamp(0);
+        let commitment = hash_answer(env, answer);
+        client.open_round(&round_id, &commitment, &DEADLINE, &REVEAL_DEADLINE, &reward);
+    }
+
+    fn commit(
+        env: &Env,
+        client: &DailyTriviaClient,
+        player: &Address,
+        round_id: u64,
+        payload: &Bytes,
+    ) -> BytesN<32> {
+        let salt = BytesN::from_array(env, &[5; 32]);
+        client.commit_answer(
+            player,
+            &round_id,
+            &answer_commitment(env, payload, &salt, player),
+        );
+        salt
+    }
+
+    /// Commits and reveals `payload`, leaving the ledger inside the reveal window
+    fn answer(
+        env: &Env,
+        client: &DailyTriviaClient,
+        player: &Address,
+        round_id: u64,
+        payload: &Bytes,
+    ) -> bool {
+        let salt = commit(env, client, player, round_id, payload);
+        env.ledger().set_timestamp(DEADLINE + 1);
+        client.reveal_answer(player, &round_id, payload, &salt)
+    }
+
+    fn end_reveal(env: &Env) {
+        env.ledger().set_timestamp(REVEAL_DEADLINE + 1);
+    }
+
+    /// Closes the round with nobody revealed in bulk
+    fn close(env: &Env, client: &DailyTriviaClient, round_id: u64, answer: &Bytes) {
+        client.close_round(&round_id, answer, &BytesN::from_array(env, &[0; 32]), &0);
+    }
+
     #[test]
     fn test_round_open_close_lifecycle() {
         let env = Env::default();
         let (client, _admin, _player, _trivia_id, _balance) = setup(&env);
 
-        let commitment = hash_answer(&env, &Bytes::from_array(&env, &[1, 2, 3]));
-        client.open_round(&1, &commitment, &100);
+        open(&env, &client, 1, &Bytes::from_array(&env, &[1, 2, 3]), 100);
 
         let round = client.get_round(&1).unwrap();
         assert_eq!(round.status, RoundStatus::Open);
 
-        client.close_round(&1);
+        let payload = Bytes::from_array(&env, &[1, 2, 3]);
+        let root = BytesN::from_array(&env, &[0; 32]);
+        assert_eq!(
+            client.try_close_round(&1, &payload, &root, &0),
+            Err(Ok(Error::RevealInProgress))
+        );
+        end_reveal(&env);
+        assert_eq!(
+            client.try_close_round(&1, &Bytes::from_array(&env, &[1, 2]), &root, &0),
+            Err(Ok(Error::AnswerMismatch))
+        );
+        close(&env, &client, 1, &payload);
         let round = client.get_round(&1).unwrap();
         assert_eq!(round.status, RoundStatus::Closed);
     }
 
+    #[test]
+    fn test_invalid_deadlines_rejected() {
+        let env = Env::default();
+        let (client, _admin, _player, _trivia_id, _balance) = setup(&env);
+
+        env.ledger().set_timestamp(50);
+        let commitment = hash_answer(&env, &Bytes::from_array(&env, &[1]));
+        let result = client.try_open_round(&1, &commitment, &50, &100, &100);
+        assert_eq!(result, Err(Ok(Error::InvalidDeadline)));
+        let result = client.try_open_round(&1, &commitment, &100, &100, &100);
+        assert_eq!(result, Err(Ok(Error::InvalidDeadline)));
+    }
+
     #[test]
     fn test_one_entry_per_round() {
         let env = Env::default();
         let (client, _admin, player, _trivia_id, _balance) = setup(&env);
 
         let payload = Bytes::from_array(&env, &[9]);
-        let commitment = hash_answer(&env, &payload);
-        client.open_round(&2, &commitment, &100);
+        open(&env, &client, 2, &payload, 100);
 
-        client.submit_answer(&player, &2, &payload);
-        let result = client.try_submit_answer(&player, &2, &payload);
-        assert!(result.is_err());
+        let salt = commit(&env, &client, &player, 2, &payload);
+        let result = client.try_commit_answer(&player, &2, &hash_answer(&env, &payload));
+        assert_eq!(result, Err(Ok(Error::AlreadySubmitted)));
+
+        env.ledger().set_timestamp(DEADLINE + 1);
+        client.reveal_answer(&player, &2, &payload, &salt);
+        let result = client.try_reveal_answer(&player, &2, &payload, &salt);
+        assert_eq!(result, Err(Ok(Error::AlreadyRevealed)));
+    }
+
+    #[test]
+    fn test_commit_reveal_windows() {
+        let env = Env::default();
+        let (client, _admin, player, _trivia_id, _balance) = setup(&env);
+        let late = Address::generate(&env);
+
+        let payload = Bytes::from_array(&env, &[9]);
+        open(&env, &client, 2, &payload, 100);
+        let salt = commit(&env, &client, &player, 2, &payload);
+        let late_salt = commit(&env, &client, &late, 2, &payload);
+
+        let result = client.try_reveal_answer(&player, &2, &payload, &salt);
+        assert_eq!(result, Err(Ok(Error::RevealNotOpen)));
+
+        env.ledger().set_timestamp(DEADLINE + 1);
+        let stranger = Address::generate(&env);
+        let result = client.try_commit_answer(&stranger, &2, &hash_answer(&env, &payload));
+        assert_eq!(result, Err(Ok(Error::PastDeadline)));
+
+        // The reveal must use the committed salt
+        let result =
+            client.try_reveal_answer(&late, &2, &payload, &BytesN::from_array(&env, &[6; 32]));
+        assert_eq!(result, Err(Ok(Error::CommitmentMismatch)));
+        let result = client.try_reveal_answer(&stranger, &2, &payload, &salt);
+        assert_eq!(result, Err(Ok(Error::CommitmentNotFound)));
+
+        end_reveal(&env);
+        let result = client.try_reveal_answer(&late, &2, &payload, &late_salt);
+        assert_eq!(result, Err(Ok(Error::RevealClosed)));
     }
 
     #[test]
@@ -768,13 +1115,13 @@ mod test {
         let (client, _admin, player, _trivia_id, balance) = setup(&env);
 
         let payload = Bytes::from_array(&env, &[7, 7]);
-        let commitment = hash_answer(&env, &payload);
-        client.open_round(&3, &commitment, &100);
+        open(&env, &client, 3, &payload, 100);
 
-        client.submit_answer(&player, &3, &payload);
-        client.close_round(&3);
+        assert!(answer(&env, &client, &player, 3, &payload));
+        end_reveal(&env);
+        close(&env, &client, 3, &payload);
 
-        let reward = client.claim_reward(&player, &3);
+        let reward = client.claim_reward(&player, &3, &Vec::new(&env));
         assert_eq!(reward, 100);
         assert_eq!(balance.balance_of(&player), 100);
     }
@@ -784,31 +1131,59 @@ mod test {
         let env = Env::default();
         let (client, _admin, player, _trivia_id, _balance) = setup(&env);
 
-        let commitment = hash_answer(&env, &Bytes::from_array(&env, &[1]));
-        client.open_round(&4, &commitment, &100);
+        let payload = Bytes::from_array(&env, &[1]);
+        open(&env, &client, 4, &payload, 100);
 
         let wrong = Bytes::from_array(&env, &[2]);
-        client.submit_answer(&player, &4, &wrong);
-        client.close_round(&4);
+        assert!(!answer(&env, &client, &player, 4, &wrong));
+        end_reveal(&env);
+        close(&env, &client, 4, &payload);
 
-        let result = client.try_claim_reward(&player, &4);
+        let result = client.try_claim_reward(&player, &4, &Vec::new(&env));
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_unrevealed_commitment_forfeited() {
+        let env = Env::default();
+        let (client, _admin, player, _trivia_id, _balance) = setup(&env);
+        let silent = Address::generate(&env);
+
+        let payload = Bytes::from_array(&env, &[7, 7]);
+        open(&env, &client, 3, &payload, 100);
+        commit(&env, &client, &silent, 3, &payload);
+        answer(&env, &client, &player, 3, &payload);
+
+        let summary = client.get_participant_answer_summary();
+        assert_eq!(summary.participant_count, 2);
+        assert_eq!(summary.correct_count, 1);
+        assert_eq!(summary.incorrect_count, 0);
+        assert_eq!(summary.unrevealed_count, 1);
+
+        end_reveal(&env);
+        close(&env, &client, 3, &payload);
+        assert_eq!(client.get_round(&3).unwrap().payout_per_winner, 100);
+        assert_eq!(
+            client.try_claim_reward(&silent, &3, &Vec::new(&env)),
+            Err(Ok(Error::InvalidProof))
+        );
+        assert_eq!(client.claim_reward(&player, &3, &Vec::new(&env)), 100);
+    }
+
     #[test]
     fn test_double_claim_rejected() {
         let env = Env::default();
         let (client, _admin, player, _trivia_id, _balance) = setup(&env);
 
         let payload = Bytes::from_array(&env, &[4, 4]);
-        let commitment = hash_answer(&env, &payload);
-        client.open_round(&5, &commitment, &100);
+        open(&env, &client, 5, &payload, 100);
 
-        client.submit_answer(&player, &5, &payload);
-        client.close_round(&5);
+        answer(&env, &client, &player, 5, &payload);
+        end_reveal(&env);
+        close(&env, &client, 5, &payload);
 
-        client.claim_reward(&player, &5);
-        let result = client.try_claim_reward(&player, &5);
+        client.claim_reward(&player, &5, &Vec::new(&env));
+        let result = client.try_claim_reward(&player, &5, &Vec::new(&env));
         assert!(result.is_err());
     }
 
@@ -829,13 +1204,15 @@ mod test {
                     &env,
                     6u64.into_val(&env),
                     commitment.into_val(&env),
+                    DEADLINE.into_val(&env),
+                    REVEAL_DEADLINE.into_val(&env),
                     100i128.into_val(&env)
                 ],
                 sub_invokes: &[],
             },
         }]);
 
-        let result = client.try_open_round(&6, &commitment, &100);
+        let result = client.try_open_round(&6, &commitment, &DEADLINE, &REVEAL_DEADLINE, &100);
         assert!(result.is_err());
     }
 
@@ -856,9 +1233,8 @@ mod test {
         let (client, _admin, player, _trivia_id, _balance) = setup(&env);
 
         let payload = Bytes::from_array(&env, &[3, 1, 4]);
-        let commitment = hash_answer(&env, &payload);
-        client.open_round(&7, &commitment, &250);
-        client.submit_answer(&player, &7, &payload);
+        open(&env, &client, 7, &payload, 250);
+        answer(&env, &client, &player, 7, &payload);
 
         let snapshot = client.get_round_snapshot();
         assert_eq!(snapshot.status, RoundSnapshotStatus::Active);
@@ -868,6 +1244,8 @@ mod test {
         assert_eq!(snapshot.winner_count, 1);
         assert_eq!(snapshot.reward_amount, 250);
         assert_eq!(snapshot.payout_per_winner, 0);
+        assert_eq!(snapshot.deadline, DEADLINE);
+        assert_eq!(snapshot.reveal_deadline, REVEAL_DEADLINE);
     }
 
     #[test]
@@ -876,10 +1254,10 @@ mod test {
         let (client, _admin, player, _trivia_id, _balance) = setup(&env);
 
         let payload = Bytes::from_array(&env, &[6, 2]);
-        let commitment = hash_answer(&env, &payload);
-        client.open_round(&8, &commitment, &300);
-        client.submit_answer(&player, &8, &payload);
-        client.close_round(&8);
+        open(&env, &client, 8, &payload, 300);
+        answer(&env, &client, &player, 8, &payload);
+        end_reveal(&env);
+        close(&env, &client, 8, &payload);
 
         let snapshot = client.get_round_snapshot();
         assert_eq!(snapshot.status, RoundSnapshotStatus::Resolved);
@@ -891,4 +1269,115 @@ mod test {
         assert_eq!(snapshot.payout_per_winner, 300);
         assert!(snapshot.closed_at >= snapshot.opened_at);
     }
+
+    #[test]
+    fn test_bulk_reveal_claims_with_proof() {
+        let env = Env::default();
+        let (client, _admin, player, _trivia_id, balance) = setup(&env);
+        let bulk_winner = Address::generate(&env);
+        let bulk_loser = Address::generate(&env);
+
+        let payload = Bytes::from_array(&env, &[2, 7]);
+        open(&env, &client, 9, &payload, 300);
+        commit(&env, &client, &bulk_winner, 9, &payload);
+        commit(
+            &env,
+            &client,
+            &bulk_loser,
+            9,
+            &Bytes::from_array(&env, &[1]),
+        );
+        assert!(answer(&env, &client, &player, 9, &payload));
+
+        // Operator-side reveal of the two players who did not reveal on-chain
+        let winner_leaf = result_leaf(&env, &bulk_winner, true);
+        let loser_leaf = result_leaf(&env, &bulk_loser, false);
+        let root = hash_pair(&env, &winner_leaf, &loser_leaf);
+
+        end_reveal(&env);
+        client.close_round(&9, &payload, &root, &1);
+        let round = client.get_round(&9).unwrap();
+        assert_eq!(round.winner_count, 2);
+        assert_eq!(round.payout_per_winner, 150);
+        assert_eq!(round.answer, payload);
+        assert_eq!(round.results_root, root);
+
+        let summary = client.get_participant_answer_summary();
+        assert_eq!(summary.correct_count, 2);
+        assert_eq!(summary.incorrect_count, 0);
+        assert_eq!(summary.unrevealed_count, 2);
+
+        assert_eq!(client.claim_reward(&player, &9, &Vec::new(&env)), 150);
+        assert_eq!(
+            client.try_claim_reward(&bulk_winner, &9, &Vec::new(&env)),
+            Err(Ok(Error::InvalidProof))
+        );
+        let proof = Vec::from_array(&env, [loser_leaf.clone()]);
+        assert_eq!(client.claim_reward(&bulk_winner, &9, &proof), 150);
+        assert_eq!(balance.balance_of(&bulk_winner), 150);
+
+        // A loser cannot prove a `correct` leaf with the winner's sibling
+        let proof = Vec::from_array(&env, [winner_leaf]);
+        assert_eq!(
+            client.try_claim_reward(&bulk_loser, &9, &proof),
+            Err(Ok(Error::InvalidProof))
+        );
+        assert_eq!(client.get_round(&9).unwrap().bulk_claimed_count, 1);
+    }
+
+    #[test]
+    fn test_understated_bulk_winner_count_caps_claims() {
+        let env = Env::default();
+        let (client, _admin, _player, _trivia_id, balance) = setup(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+
+        let payload = Bytes::from_array(&env, &[4]);
+        open(&env, &client, 11, &payload, 300);
+        commit(&env, &client, &first, 11, &payload);
+        commit(&env, &client, &second, 11, &payload);
+
+        // Both leaves are correct, but the admin reports a single winner
+        let first_leaf = result_leaf(&env, &first, true);
+        let second_leaf = result_leaf(&env, &second, true);
+        let root = hash_pair(&env, &first_leaf, &second_leaf);
+        end_reveal(&env);
+        client.close_round(&11, &payload, &root, &1);
+        assert_eq!(client.get_round(&11).unwrap().payout_per_winner, 300);
+
+        let proof = Vec::from_array(&env, [second_leaf]);
+        assert_eq!(client.claim_reward(&first, &11, &proof), 300);
+        let proof = Vec::from_array(&env, [first_leaf]);
+        assert_eq!(
+            client.try_claim_reward(&second, &11, &proof),
+            Err(Ok(Error::BulkClaimsExhausted))
+        );
+        assert_eq!(balance.balance_of(&second), 0);
+    }
+
+    #[test]
+    fn test_on_chain_reveal_overrides_bulk_result() {
+        let env = Env::default();
+        let (client, _admin, player, _trivia_id, _balance) = setup(&env);
+
+        let payload = Bytes::from_array(&env, &[5]);
+        open(&env, &client, 10, &payload, 100);
+        assert!(!answer(
+            &env,
+            &client,
+            &player,
+            10,
+            &Bytes::from_array(&env, &[6])
+        ));
+
+        // Even if the root wrongly includes the player as correct, their
+        // on-chain reveal decides
+        let root = result_leaf(&env, &player, true);
+        end_reveal(&env);
+        client.close_round(&10, &payload, &root, &1);
+        assert_eq!(
+            client.try_claim_reward(&player, &10, &Vec::new(&env)),
+            Err(Ok(Error::NoRewardAvailable))
+        );
+    }
 }
@@ -0,0 +1,546 @@
+use crate::manifest::ArgValue;
+use crate::registry::{abi, issue_type_name, RegistryIssue};
+use sha2::{Digest, Sha256};
+use soroban_sdk::xdr::{
+    ContractDataDurability, LedgerEntryData, LedgerKey, LedgerKeyContractData, Limits, ReadXdr,
+    ScAddress, ScVal, WriteXdr,
+};
+use soroban_sdk::{
+    testutils::Address as _, Address, Bytes, BytesN, Env, IntoVal, String as SorobanString,
+    Symbol, TryFromVal, Val, Vec as SorobanVec,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::rc::Rc;
+
+/// Default location of release WASM artifacts relative to the workspace root
+pub const DEFAULT_WASM_DIR: &str = "target/wasm32-unknown-unknown/release";
+
+/// Pluggable chain access used by `Deployer` to upload and instantiate contracts
+pub trait DeployBackend {
+    /// Uploads the WASM for `name` and returns its hex-encoded SHA-256 hash
+    fn upload(&mut self, name: &str) -> Result<String, String>;
+
+    /// Instantiates `name` from previously uploaded code and returns its address
+    fn instantiate(&mut self, name: &str, wasm_hash: &str) -> Result<String, String>;
+
+    /// Returns the address `instantiate` derives for `name` if a contract is
+    /// already deployed there, so a deploy that landed without being recorded
+    /// can be adopted instead of colliding with itself on retry
+    fn find_deployed(&mut self, name: &str) -> Result<Option<String>, String>;
+
+    /// Whether the contract at `address` already holds instance storage,
+    /// which is what its `init` writes, so an init that landed without being
+    /// recorded can be adopted instead of failing as already initialized
+    fn is_initialized(&mut self, address: &str) -> Result<bool, String>;
+
+    /// Calls `function` on a deployed contract, authorized by the deployment admin.
+    /// `params` holds the argument names when the manifest provides them.
+    fn invoke(
+        &mut self,
+        address: &str,
+        function: &str,
+        params: &[String],
+        args: &[ArgValue],
+    ) -> Result<(), String>;
+
+    /// Runs `validation_report` on the address registry at `registry`
+    fn validation_report(&mut self, registry: &str) -> Result<Vec<RegistryIssue>, String>;
+}
+
+/// Computes the hex-encoded SHA-256 hash Soroban uses to identify WASM code
+pub fn wasm_hash(wasm: &[u8]) -> String {
+    hex::encode(Sha256::digest(wasm))
+}
+
+/// Resolves contract names to built WASM files in a release directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmArtifacts {
+    pub dir: PathBuf,
+}
+
+impl WasmArtifacts {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Finds the artifact for `name`, accepting both `coin_flip` and the
+    /// crate-derived `stellarcade_coin_flip` file names
+    pub fn locate(&self, name: &str) -> Result<PathBuf, String> {
+        let stem = name.replace('-', "_");
+        let candidates = [
+            self.dir.join(format!("{}.wasm", stem)),
+            self.dir.join(format!("stellarcade_{}.wasm", stem)),
+        ];
+        candidates
+            .iter()
+            .find(|path| path.is_file())
+            .cloned()
+            .ok_or_else(|| format!("WASM artifact not found for {} in {}", name, self.dir.display()))
+    }
+
+    pub fn read(&self, name: &str) -> Result<Vec<u8>, String> {
+        let path = self.locate(name)?;
+        fs::read(&path).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+/// Offline backend producing placeholder identifiers, used when no chain is available
+pub struct SimulatedBackend {
+    pub timestamp: u64,
+}
+
+impl DeployBackend for SimulatedBackend {
+    fn upload(&mut self, name: &str) -> Result<String, String> {
+        Ok(format!("W_{}", name))
+    }
+
+    fn instantiate(&mut self, name: &str, _wasm_hash: &str) -> Result<String, String> {
+        Ok(format!("C_{}_{}", name, self.timestamp))
+    }
+
+    fn find_deployed(&mut self, _name: &str) -> Result<Option<String>, String> {
+        Ok(None)
+    }
+
+    fn is_initialized(&mut self, _address: &str) -> Result<bool, String> {
+        Ok(false)
+    }
+
+    fn invoke(
+        &mut self,
+        _address: &str,
+        _function: &str,
+        _params: &[String],
+        _args: &[ArgValue],
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn validation_report(&mut self, _registry: &str) -> Result<Vec<RegistryIssue>, String> {
+        Ok(Vec::new())
+    }
+}
+
+/// Uploads real WASM artifacts into an in-process Soroban test `Env`, so the
+/// whole deployment flow can be exercised locally
+pub struct LocalEnvBackend {
+    env: Env,
+    artifacts: WasmArtifacts,
+    deployer: Address,
+}
+
+impl LocalEnvBackend {
+    pub fn new(artifacts: WasmArtifacts) -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        let deployer = Address::generate(&env);
+        Self {
+            env,
+            artifacts,
+            deployer,
+        }
+    }
+
+    /// The environment contracts are deployed into, for invoking them afterwards
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+}
+
+impl DeployBackend for LocalEnvBackend {
+    fn upload(&mut self, name: &str) -> Result<String, String> {
+        let wasm = self.artifacts.read(name)?;
+        let expected = wasm_hash(&wasm);
+
+        let uploaded = self
+            .env
+            .deployer()
+            .upload_contract_wasm(Bytes::from_slice(&self.env, &wasm));
+        let uploaded = hex::encode(uploaded.to_array());
+        if uploaded != expected {
+            return Err(format!("WASM hash mismatch for {}: {} != {}", name, uploaded, expected));
+        }
+        Ok(uploaded)
+    }
+
+    fn instantiate(&mut self, name: &str, wasm_hash: &str) -> Result<String, String> {
+        let hash = decode_hash(wasm_hash)?;
+        let address = self
+            .env
+            .deployer()
+            .with_address(self.deployer.clone(), deploy_salt(&self.env, name))
+            .deploy_v2(BytesN::from_array(&self.env, &hash), ());
+        Ok(address_to_string(&address))
+    }
+
+    fn find_deployed(&mut self, name: &str) -> Result<Option<String>, String> {
+        let address = self
+            .env
+            .deployer()
+            .with_address(self.deployer.clone(), deploy_salt(&self.env, name))
+            .deployed_address();
+        let exists = self
+            .env
+            .host()
+            .get_contract_instance_live_until_ledger(address.to_object())
+            .is_ok();
+        Ok(exists.then(|| address_to_string(&address)))
+    }
+
+    fn is_initialized(&mut self, address: &str) -> Result<bool, String> {
+        let contract = parse_address(&self.env, address)?;
+        let key = Rc::new(instance_key(ScAddress::from(&contract)));
+        let entry = self
+            .env
+            .host()
+            .get_ledger_entry(&key)
+            .map_err(|e| format!("Failed to read instance of {}: {:?}", address, e))?
+            .ok_or_else(|| format!("No contract deployed at {}", address))?;
+        match &entry.0.data {
+            LedgerEntryData::ContractData(data) => Ok(instance_has_storage(&data.val)),
+            _ => Ok(false),
+        }
+    }
+
+    fn invoke(
+        &mut self,
+        address: &str,
+        function: &str,
+        _params: &[String],
+        args: &[ArgValue],
+    ) -> Result<(), String> {
+        let contract = parse_address(&self.env, address)?;
+        let symbol = Symbol::try_from_val(&self.env, &function)
+            .map_err(|_| format!("Invalid function name {}", function))?;
+
+        let mut vals = SorobanVec::<Val>::new(&self.env);
+        for arg in args {
+            vals.push_back(arg_to_val(&self.env, arg)?);
+        }
+
+        match self
+            .env
+            .try_invoke_contract::<Val, soroban_sdk::Error>(&contract, &symbol, vals)
+        {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(e)) => Err(format!("{} returned an unexpected value: {:?}", function, e)),
+            Err(e) => Err(format!("{} failed: {:?}", function, e)),
+        }
+    }
+
+    fn validation_report(&mut self, registry: &str) -> Result<Vec<RegistryIssue>, String> {
+        let contract = parse_address(&self.env, registry)?;
+        let report = match self.env.try_invoke_contract::<abi::ValidationReport, soroban_sdk::Error>(
+            &contract,
+            &Symbol::new(&self.env, "validation_report"),
+            SorobanVec::new(&self.env),
+        ) {
+            Ok(Ok(report)) => report,
+            Ok(Err(e)) => return Err(format!("validation_report returned an unexpected value: {:?}", e)),
+            Err(e) => return Err(format!("validation_report failed: {:?}", e)),
+        };
+
+        Ok(report
+            .issues
+            .iter()
+            .map(|issue| RegistryIssue {
+                contract_name: string_to_std(&issue.contract_name),
+                issue_type: issue_type_name(issue.issue_type as u32),
+                details: string_to_std(&issue.details),
+            })
+            .collect())
+    }
+}
+
+/// Deploys to a real network by shelling out to the `stellar` CLI, which
+/// signs with `source` (a key alias or secret known to the CLI)
+pub struct StellarCliBackend {
+    /// CLI executable, normally `stellar`
+    pub program: String,
+    pub rpc_url: String,
+    pub network_passphrase: String,
+    pub source: String,
+    pub artifacts: WasmArtifacts,
+}
+
+impl StellarCliBackend {
+    fn network_args(&self) -> Vec<String> {
+        vec![
+            "--source-account".to_string(),
+            self.source.clone(),
+            "--rpc-url".to_string(),
+            self.rpc_url.clone(),
+            "--network-passphrase".to_string(),
+            self.network_passphrase.clone(),
+        ]
+    }
+
+    /// Runs the CLI and returns the last line it printed, where it reports
+    /// hashes and contract ids
+    fn run(&self, args: &[String]) -> Result<String, String> {
+        let output = Command::new(&self.program)
+            .args(args)
+            .output()
+            .map_err(|e| format!("Failed to run {}: {}", self.program, e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "{} {} failed: {}",
+                self.program,
+                args[..2].join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .last()
+            .unwrap_or_default()
+            .trim()
+            .to_string())
+    }
+
+    /// Arguments for `stellar contract invoke`, which only accepts named parameters
+    pub fn invoke_args(
+        &self,
+        address: &str,
+        function: &str,
+        params: &[String],
+        args: &[ArgValue],
+    ) -> Result<Vec<String>, String> {
+        if params.len() != args.len() {
+            return Err(format!(
+                "{} needs a parameter name for each of its {} arguments",
+                function,
+                args.len()
+            ));
+        }
+
+        let mut cli_args = vec![
+            "contract".to_string(),
+            "invoke".to_string(),
+            "--id".to_string(),
+            address.to_string(),
+        ];
+        cli_args.extend(self.network_args());
+        cli_args.push("--".to_string());
+        cli_args.push(function.to_string());
+        for (param, arg) in params.iter().zip(args) {
+            cli_args.push(format!("--{}", param));
+            cli_args.push(match arg {
+                ArgValue::Address(value)
+                | ArgValue::Symbol(value)
+                | ArgValue::String(value)
+                | ArgValue::Bytes32(value) => value.clone(),
+                ArgValue::U32(value) => value.to_string(),
+                ArgValue::U64(value) => value.to_string(),
+                ArgValue::I128(value) => value.to_string(),
+                ArgValue::Bool(value) => value.to_string(),
+            });
+        }
+        Ok(cli_args)
+    }
+}
+
+impl DeployBackend for StellarCliBackend {
+    fn upload(&mut self, name: &str) -> Result<String, String> {
+        let path = self.artifacts.locate(name)?;
+        let expected = wasm_hash(&self.artifacts.read(name)?);
+
+        let mut args = vec![
+            "contract".to_string(),
+            "upload".to_string(),
+            "--wasm".to_string(),
+            path.display().to_string(),
+        ];
+        args.extend(self.network_args());
+        let uploaded = self.run(&args)?;
+        if uploaded != expected {
+            return Err(format!("WASM hash mismatch for {}: {} != {}", name, uploaded, expected));
+        }
+        Ok(uploaded)
+    }
+
+    fn instantiate(&mut self, name: &str, wasm_hash: &str) -> Result<String, String> {
+        // Same salt as LocalEnvBackend so addresses are predictable per deployer
+        let mut args = vec![
+            "contract".to_string(),
+            "deploy".to_string(),
+            "--wasm-hash".to_string(),
+            wasm_hash.to_string(),
+            "--salt".to_string(),
+            hex::encode(salt_bytes(name)),
+        ];
+        args.extend(self.network_args());
+        let address = self.run(&args)?;
+        stellar_strkey::Contract::from_string(&address)
+            .map_err(|_| format!("Unexpected contract id from deploy of {}: {}", name, address))?;
+        Ok(address)
+    }
+
+    fn find_deployed(&mut self, name: &str) -> Result<Option<String>, String> {
+        let mut args = vec![
+            "contract".to_string(),
+            "id".to_string(),
+            "wasm".to_string(),
+            "--salt".to_string(),
+            hex::encode(salt_bytes(name)),
+        ];
+        args.extend(self.network_args());
+        let address = self.run(&args)?;
+        stellar_strkey::Contract::from_string(&address)
+            .map_err(|_| format!("Unexpected contract id derived for {}: {}", name, address))?;
+
+        // `fetch` fails when nothing is deployed at the address. Any other
+        // failure also reads as absent; the retried deploy then reports it.
+        let fetch = vec![
+            "contract".to_string(),
+            "fetch".to_string(),
+            "--id".to_string(),
+            address.clone(),
+            "--rpc-url".to_string(),
+            self.rpc_url.clone(),
+            "--network-passphrase".to_string(),
+            self.network_passphrase.clone(),
+        ];
+        Ok(self.run(&fetch).ok().map(|_| address))
+    }
+
+    fn is_initialized(&mut self, address: &str) -> Result<bool, String> {
+        let key = ScVal::LedgerKeyContractInstance
+            .to_xdr_base64(Limits::none())
+            .map_err(|e| format!("Failed to encode instance key: {}", e))?;
+        let args = vec![
+            "contract".to_string(),
+            "read".to_string(),
+            "--id".to_string(),
+            address.to_string(),
+            "--key-xdr".to_string(),
+            key,
+            "--durability".to_string(),
+            "persistent".to_string(),
+            "--output".to_string(),
+            "xdr".to_string(),
+            "--rpc-url".to_string(),
+            self.rpc_url.clone(),
+            "--network-passphrase".to_string(),
+            self.network_passphrase.clone(),
+        ];
+        let row = self.run(&args)?;
+
+        // The row holds the key and value as base64 XDR, then ledger numbers
+        row.split(',')
+            .filter_map(|field| ScVal::from_xdr_base64(field.trim(), Limits::none()).ok())
+            .find(|val| matches!(val, ScVal::ContractInstance(_)))
+            .map(|val| instance_has_storage(&val))
+            .ok_or_else(|| format!("Unexpected instance read for {}: {}", address, row))
+    }
+
+    fn invoke(
+        &mut self,
+        address: &str,
+        function: &str,
+        params: &[String],
+        args: &[ArgValue],
+    ) -> Result<(), String> {
+        let args = self.invoke_args(address, function, params, args)?;
+        self.run(&args).map(|_| ())
+    }
+
+    fn validation_report(&mut self, registry: &str) -> Result<Vec<RegistryIssue>, String> {
+        let args = self.invoke_args(registry, "validation_report", &[], &[])?;
+        let json = self.run(&args)?;
+        parse_validation_report(&json)
+    }
+}
+
+/// Reads the JSON the `stellar` CLI prints for a `ValidationReport`
+pub fn parse_validation_report(json: &str) -> Result<Vec<RegistryIssue>, String> {
+    let report: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| format!("Unreadable validation report {}: {}", json, e))?;
+    let issues = report["issues"]
+        .as_array()
+        .ok_or_else(|| format!("Validation report has no issues list: {}", json))?;
+
+    Ok(issues
+        .iter()
+        .map(|issue| RegistryIssue {
+            contract_name: issue["contract_name"].as_str().unwrap_or_default().to_string(),
+            issue_type: match &issue["issue_type"] {
+                serde_json::Value::Number(n) => issue_type_name(n.as_u64().unwrap_or_default() as u32),
+                other => other.as_str().unwrap_or_default().to_string(),
+            },
+            details: issue["details"].as_str().unwrap_or_default().to_string(),
+        })
+        .collect())
+}
+
+/// Ledger key of a contract's instance entry, where `init` leaves its storage
+fn instance_key(contract: ScAddress) -> LedgerKey {
+    LedgerKey::ContractData(LedgerKeyContractData {
+        contract,
+        key: ScVal::LedgerKeyContractInstance,
+        durability: ContractDataDurability::Persistent,
+    })
+}
+
+fn instance_has_storage(val: &ScVal) -> bool {
+    match val {
+        ScVal::ContractInstance(instance) => {
+            instance.storage.as_ref().is_some_and(|storage| !storage.is_empty())
+        }
+        _ => false,
+    }
+}
+
+/// Salt by name so redeploying the same code under another name yields a new address
+fn salt_bytes(name: &str) -> [u8; 32] {
+    Sha256::digest(name.as_bytes()).into()
+}
+
+fn deploy_salt(env: &Env, name: &str) -> BytesN<32> {
+    BytesN::from_array(env, &salt_bytes(name))
+}
+
+/// Parses a strkey into an `Address`, rejecting malformed input up front
+/// because `Address::from_str` panics on it
+fn parse_address(env: &Env, address: &str) -> Result<Address, String> {
+    stellar_strkey::Strkey::from_string(address)
+        .map_err(|_| format!("Invalid address {}", address))?;
+    Ok(Address::from_str(env, address))
+}
+
+fn arg_to_val(env: &Env, arg: &ArgValue) -> Result<Val, String> {
+    Ok(match arg {
+        ArgValue::Address(address) => parse_address(env, address)?.into_val(env),
+        ArgValue::U32(value) => value.into_val(env),
+        ArgValue::U64(value) => value.into_val(env),
+        ArgValue::I128(value) => value.into_val(env),
+        ArgValue::Bool(value) => value.into_val(env),
+        ArgValue::Symbol(value) => Symbol::try_from_val(env, &value.as_str())
+            .map_err(|_| format!("Invalid symbol {}", value))?
+            .into_val(env),
+        ArgValue::String(value) => SorobanString::from_str(env, value).into_val(env),
+        ArgValue::Bytes32(value) => BytesN::from_array(env, &decode_hash(value)?).into_val(env),
+    })
+}
+
+fn decode_hash(wasm_hash: &str) -> Result<[u8; 32], String> {
+    let bytes = hex::decode(wasm_hash).map_err(|e| format!("Invalid wasm hash {}: {}", wasm_hash, e))?;
+    bytes
+        .try_into()
+        .map_err(|_| format!("Invalid wasm hash {}: expected 32 bytes", wasm_hash))
+}
+
+/// Renders a Soroban address as its strkey (`C...`/`G...`) string
+pub fn address_to_string(address: &Address) -> String {
+    string_to_std(&address.to_string())
+}
+
+fn string_to_std(value: &SorobanString) -> String {
+    let mut buf = vec![0u8; value.len() as usize];
+    value.copy_into_slice(&mut buf);
+    String::from_utf8(buf).unwrap_or_default()
+}
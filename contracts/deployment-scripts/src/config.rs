@@ -0,0 +1,294 @@
+use crate::backend::{
+    DeployBackend, LocalEnvBackend, SimulatedBackend, StellarCliBackend, WasmArtifacts,
+    DEFAULT_WASM_DIR,
+};
+use crate::{Deployer, NetworkProfile};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Passphrase of the public Stellar network
+pub const MAINNET_PASSPHRASE: &str = "Public Global Stellar Network ; September 2015";
+
+/// Example admin address in the bundled `deploy.toml`, refused on mainnet
+pub const PLACEHOLDER_ADMIN: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+
+/// Which `DeployBackend` a profile deploys through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BackendKind {
+    Simulated,
+    Local,
+    #[default]
+    StellarCli,
+}
+
+/// Settings for one deployment target
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Profile {
+    pub network: NetworkProfile,
+    #[serde(default)]
+    pub backend: BackendKind,
+    pub rpc_url: String,
+    pub network_passphrase: String,
+    /// Admin account address, passed to `init` calls and required as caller.
+    /// May be left out of the config, but the profile cannot be used until set.
+    #[serde(default)]
+    pub admin: String,
+    /// Key alias the `stellar` CLI signs with
+    pub admin_identity: String,
+    /// Deployment state file
+    pub output: PathBuf,
+    #[serde(default = "default_manifest")]
+    pub manifest: PathBuf,
+    #[serde(default = "default_wasm_dir")]
+    pub wasm_dir: PathBuf,
+    #[serde(default = "default_cli")]
+    pub cli: String,
+}
+
+fn default_manifest() -> PathBuf {
+    PathBuf::from("manifests/stellarcade.toml")
+}
+
+fn default_wasm_dir() -> PathBuf {
+    Path::new("..").join(DEFAULT_WASM_DIR)
+}
+
+fn default_cli() -> String {
+    "stellar".to_string()
+}
+
+impl Profile {
+    pub fn is_mainnet(&self) -> bool {
+        self.network == NetworkProfile::Mainnet
+    }
+
+    /// Rejects profiles whose network label and passphrase disagree, so a
+    /// mislabelled profile cannot reach mainnet without its guards, and
+    /// mainnet profiles still using the placeholder admin
+    pub fn validate(&self, name: &str) -> Result<(), String> {
+        if self.admin_identity.is_empty() {
+            return Err(format!("Profile {} needs admin_identity", name));
+        }
+        let public_passphrase = self.network_passphrase == MAINNET_PASSPHRASE;
+        if self.is_mainnet() != public_passphrase {
+            return Err(format!(
+                "Profile {} is {:?} but uses the passphrase \"{}\"",
+                name, self.network, self.network_passphrase
+            ));
+        }
+        if self.is_mainnet() && self.backend != BackendKind::StellarCli {
+            return Err(format!("Mainnet profile {} must use the stellar-cli backend", name));
+        }
+        if self.is_mainnet() && self.admin == PLACEHOLDER_ADMIN {
+            return Err(format!(
+                "Mainnet profile {} uses the placeholder admin {}; set admin to an account you control",
+                name, PLACEHOLDER_ADMIN
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn backend(&self) -> Box<dyn DeployBackend> {
+        let artifacts = WasmArtifacts::new(&self.wasm_dir);
+        match self.backend {
+            BackendKind::Simulated => Box::new(SimulatedBackend { timestamp: crate::now() }),
+            BackendKind::Local => Box::new(LocalEnvBackend::new(artifacts)),
+            BackendKind::StellarCli => Box::new(StellarCliBackend {
+                program: self.cli.clone(),
+                rpc_url: self.rpc_url.clone(),
+                network_passphrase: self.network_passphrase.clone(),
+                source: self.admin_identity.clone(),
+                artifacts,
+            }),
+        }
+    }
+
+    /// Opens the profile's state file with its configured backend
+    pub fn deployer(&self) -> Result<Deployer, String> {
+        if let Some(dir) = self.output.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        Deployer::with_backend(
+            self.network.clone(),
+            self.admin.clone(),
+            self.output.clone(),
+            self.backend(),
+        )
+    }
+
+    /// Guards commands that change mainnet. The operator must pass the profile
+    /// name as `confirmation`, or type it when `prompt` can ask interactively.
+    pub fn confirm_mainnet(
+        &self,
+        name: &str,
+        confirmation: Option<&str>,
+        prompt: impl FnOnce() -> Option<String>,
+    ) -> Result<(), String> {
+        if !self.is_mainnet() {
+            return Ok(());
+        }
+        let answer = match confirmation {
+            Some(answer) => Some(answer.to_string()),
+            None => prompt(),
+        };
+        match answer {
+            Some(answer) if answer.trim() == name => Ok(()),
+            Some(_) => Err("Mainnet confirmation did not match the profile name".to_string()),
+            None => Err(format!("Mainnet changes need --confirm {}", name)),
+        }
+    }
+}
+
+/// Deployment profiles keyed by name, read from `deploy.toml`
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+pub struct DeployConfig {
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl DeployConfig {
+    pub fn parse(toml_str: &str) -> Result<Self, String> {
+        let config: DeployConfig = toml::from_str(toml_str).map_err(|e| e.to_string())?;
+        for (name, profile) in &config.profiles {
+            profile.validate(name)?;
+        }
+        Ok(config)
+    }
+
+    /// Loads a config file, resolving relative paths against its directory
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let toml_str = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mut config = Self::parse(&toml_str)?;
+
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        for profile in config.profiles.values_mut() {
+            for field in [&mut profile.output, &mut profile.manifest, &mut profile.wasm_dir] {
+                if field.is_relative() {
+                    *field = base.join(&*field);
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// The named profile, which must have an admin set
+    pub fn profile(&self, name: &str) -> Result<&Profile, String> {
+        let profile = self.profiles.get(name).ok_or_else(|| {
+            let known: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+            format!("Unknown profile {} (known: {})", name, known.join(", "))
+        })?;
+        if profile.admin.is_empty() {
+            return Err(format!("Profile {} has no admin; set admin in the config", name));
+        }
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+        [profiles.testnet]
+        network = "testnet"
+        rpc_url = "https://soroban-testnet.stellar.org"
+        network_passphrase = "Test SDF Network ; September 2015"
+        admin = "GADMIN"
+        admin_identity = "stellarcade-testnet"
+        output = "deployments/testnet_deployments.json"
+
+        [profiles.mainnet]
+        network = "Mainnet"
+        rpc_url = "https://rpc.example.org"
+        network_passphrase = "Public Global Stellar Network ; September 2015"
+        admin = "GADMIN"
+        admin_identity = "stellarcade-mainnet"
+        output = "deployments/mainnet_deployments.json"
+    "#;
+
+    #[test]
+    fn test_load_resolves_paths_against_config_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("deploy.toml");
+        fs::write(&path, CONFIG).unwrap();
+
+        let config = DeployConfig::load(&path).unwrap();
+        let testnet = config.profile("testnet").unwrap();
+        assert_eq!(testnet.network, NetworkProfile::Testnet);
+        assert_eq!(testnet.backend, BackendKind::StellarCli);
+        assert_eq!(testnet.output, dir.path().join("deployments/testnet_deployments.json"));
+        assert_eq!(testnet.manifest, dir.path().join("manifests/stellarcade.toml"));
+        assert_eq!(testnet.cli, "stellar");
+
+        assert_eq!(
+            config.profile("dev").unwrap_err(),
+            "Unknown profile dev (known: mainnet, testnet)"
+        );
+    }
+
+    #[test]
+    fn test_bundled_profiles_are_valid() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("deploy.toml");
+        let config = DeployConfig::load(path).unwrap();
+        assert!(config.profile("dev").unwrap().manifest.is_file());
+        assert!(config.profiles["mainnet"].is_mainnet());
+    }
+
+    #[test]
+    fn test_mainnet_needs_a_real_admin() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("deploy.toml");
+        let config = DeployConfig::load(path).unwrap();
+        assert_eq!(
+            config.profile("mainnet").unwrap_err(),
+            "Profile mainnet has no admin; set admin in the config"
+        );
+
+        let placeholder = CONFIG.replace("admin = \"GADMIN\"", &format!("admin = \"{}\"", PLACEHOLDER_ADMIN));
+        assert!(DeployConfig::parse(&placeholder)
+            .unwrap_err()
+            .starts_with("Mainnet profile mainnet uses the placeholder admin"));
+    }
+
+    #[test]
+    fn test_passphrase_must_match_network() {
+        let mislabelled = CONFIG.replace(
+            "Test SDF Network ; September 2015",
+            "Public Global Stellar Network ; September 2015",
+        );
+        assert!(DeployConfig::parse(&mislabelled)
+            .unwrap_err()
+            .starts_with("Profile testnet is Testnet but uses the passphrase"));
+
+        let simulated_mainnet = format!("{}\nbackend = \"simulated\"\n", CONFIG);
+        assert_eq!(
+            DeployConfig::parse(&simulated_mainnet),
+            Err("Mainnet profile mainnet must use the stellar-cli backend".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mainnet_requires_confirmation() {
+        let config = DeployConfig::parse(CONFIG).unwrap();
+        let testnet = config.profile("testnet").unwrap();
+        let mainnet = config.profile("mainnet").unwrap();
+
+        assert_eq!(testnet.confirm_mainnet("testnet", None, || None), Ok(()));
+        assert_eq!(
+            mainnet.confirm_mainnet("mainnet", None, || None),
+            Err("Mainnet changes need --confirm mainnet".to_string())
+        );
+        assert_eq!(
+            mainnet.confirm_mainnet("mainnet", Some("yes"), || None),
+            Err("Mainnet confirmation did not match the profile name".to_string())
+        );
+        assert_eq!(mainnet.confirm_mainnet("mainnet", Some("mainnet"), || None), Ok(()));
+        assert_eq!(
+            mainnet.confirm_mainnet("mainnet", None, || Some("mainnet\n".to_string())),
+            Ok(())
+        );
+    }
+}
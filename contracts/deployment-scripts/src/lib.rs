@@ -1,13 +1,32 @@
+pub mod backend;
+pub mod config;
+pub mod manifest;
+pub mod registry;
+pub mod run;
+
+pub use backend::{
+    DeployBackend, LocalEnvBackend, SimulatedBackend, StellarCliBackend, WasmArtifacts,
+};
+pub use config::{BackendKind, DeployConfig, Profile};
+pub use manifest::{ArgValue, ContractSpec, InitArg, Manifest};
+pub use registry::{PartialRegistration, RegisteredContract, RegistryCall, RegistryIssue};
+pub use run::{
+    PlanAction, PlannedStep, RunMode, RunReport, Step, StepAttempt, StepFailure, StepOutcome,
+};
+
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 /// Environment profiles for deploying contracts
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NetworkProfile {
+    #[serde(alias = "dev")]
     Dev,
+    #[serde(alias = "testnet")]
     Testnet,
+    #[serde(alias = "mainnet")]
     Mainnet,
 }
 
@@ -27,6 +46,19 @@ pub struct DeploymentOutput {
     pub admin_address: String,
     pub contracts: HashMap<String, ContractState>,
     pub timestamp: u64,
+    /// Number of `Deployer::run` invocations against this file
+    #[serde(default)]
+    pub runs: u32,
+    /// Every deploy and initialize attempt, in the order they were made
+    #[serde(default)]
+    pub run_log: Vec<StepAttempt>,
+    /// What has been written to the on-chain registries per contract
+    #[serde(default)]
+    pub registered: HashMap<String, RegisteredContract>,
+    /// Registry calls that landed for a contract whose registration has not
+    /// finished, so a resumed run does not send them again
+    #[serde(default)]
+    pub partial_registrations: HashMap<String, PartialRegistration>,
 }
 
 impl DeploymentOutput {
@@ -36,6 +68,10 @@ impl DeploymentOutput {
             admin_address,
             contracts: HashMap::new(),
             timestamp,
+            runs: 0,
+            run_log: Vec::new(),
+            registered: HashMap::new(),
+            partial_registrations: HashMap::new(),
         }
     }
 
@@ -48,29 +84,98 @@ impl DeploymentOutput {
         let json = fs::read_to_string(path).map_err(|e| e.to_string())?;
         serde_json::from_str(&json).map_err(|e| e.to_string())
     }
+
+    /// Addresses of every deployed or initialized contract
+    pub fn addresses(&self) -> BTreeMap<String, String> {
+        self.contracts
+            .iter()
+            .filter_map(|(name, state)| match state {
+                ContractState::Deployed { address, .. } | ContractState::Initialized { address, .. } => {
+                    Some((name.clone(), address.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 /// Core deployment manager class enforcing business logic and sequences
 pub struct Deployer {
     pub output: DeploymentOutput,
     pub storage_path: PathBuf,
+    backend: Box<dyn DeployBackend>,
 }
 
 impl Deployer {
     /// Loads an existing profile or creates a new deterministic manager
-    pub fn new(network: NetworkProfile, admin: String, path: PathBuf) -> Self {
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-
-        let output = if path.exists() {
-            DeploymentOutput::load(&path).unwrap_or_else(|_| DeploymentOutput::new(network, admin, timestamp))
-        } else {
-            DeploymentOutput::new(network, admin, timestamp)
-        };
-        
-        Self { output, storage_path: path }
+    /// backed by `SimulatedBackend`
+    pub fn new(network: NetworkProfile, admin: String, path: PathBuf) -> Result<Self, String> {
+        let output = Self::load_output(network, admin, &path)?;
+        let backend = Box::new(SimulatedBackend {
+            timestamp: output.timestamp,
+        });
+        Ok(Self {
+            output,
+            storage_path: path,
+            backend,
+        })
+    }
+
+    /// Loads an existing profile or creates a new manager deploying through `backend`
+    pub fn with_backend(
+        network: NetworkProfile,
+        admin: String,
+        path: PathBuf,
+        backend: Box<dyn DeployBackend>,
+    ) -> Result<Self, String> {
+        let output = Self::load_output(network, admin, &path)?;
+        Ok(Self {
+            output,
+            storage_path: path,
+            backend,
+        })
+    }
+
+    /// Starts a new profile when `path` is missing or empty. An existing file
+    /// that does not parse is an error rather than something to overwrite,
+    /// since it may be the only record of contracts already on chain. A file
+    /// recorded for another network is refused too, so its contracts are never
+    /// skipped as deployed or exported under the wrong profile.
+    fn load_output(network: NetworkProfile, admin: String, path: &Path) -> Result<DeploymentOutput, String> {
+        let is_empty = fs::metadata(path).map(|meta| meta.len() == 0).unwrap_or(true);
+        if is_empty {
+            return Ok(DeploymentOutput::new(network, admin, now()));
+        }
+        let output = DeploymentOutput::load(path)
+            .map_err(|e| format!("Refusing to use corrupted state file {}: {}", path.display(), e))?;
+        if output.network != network {
+            return Err(format!(
+                "Refusing to use state file {} recorded for {:?} with the {:?} profile",
+                path.display(),
+                output.network,
+                network
+            ));
+        }
+        Ok(output)
+    }
+
+    /// Appends an attempt at `step` for `name` to the run log
+    fn record_attempt(&mut self, name: &str, step: Step, outcome: StepOutcome) {
+        let attempt = self
+            .output
+            .run_log
+            .iter()
+            .filter(|entry| entry.contract == name && entry.step == step)
+            .count() as u32
+            + 1;
+        self.output.run_log.push(StepAttempt {
+            run: self.output.runs,
+            contract: name.to_string(),
+            step,
+            attempt,
+            outcome,
+            timestamp: now(),
+        });
     }
 
     /// Primary routine to sequence deployment of a contract
@@ -93,13 +198,40 @@ impl Deployer {
             return Err("Contract already initialized".to_string());
         }
 
+        // A Pending or Failed deploy may have landed without being recorded
+        let retrying = self.output.contracts.contains_key(name);
+
         // Explicit logic for Pending transition
         self.output.contracts.insert(name.to_string(), ContractState::Pending);
         self.output.save(&self.storage_path)?;
 
-        // Simulate deployment yielding output addresses and hashes seamlessly
-        let address = format!("C_{}_{}", name, self.output.timestamp);
-        let wasm_hash = format!("W_{}", name);
+        // Upload then instantiate through the configured backend, adopting a
+        // contract already at the derived address when retrying
+        let deployed = self.backend.upload(name).and_then(|wasm_hash| {
+            let existing = match retrying {
+                true => self.backend.find_deployed(name)?,
+                false => None,
+            };
+            let address = match existing {
+                Some(address) => {
+                    println!("EVENT: Adopting {} already deployed at {}", name, address);
+                    address
+                }
+                None => self.backend.instantiate(name, &wasm_hash)?,
+            };
+            Ok((address, wasm_hash))
+        });
+        let (address, wasm_hash) = match deployed {
+            Ok(deployed) => deployed,
+            Err(e) => {
+                self.output
+                    .contracts
+                    .insert(name.to_string(), ContractState::Failed(e.clone()));
+                self.record_attempt(name, Step::Deploy, StepOutcome::Failed(e.clone()));
+                self.output.save(&self.storage_path)?;
+                return Err(e);
+            }
+        };
 
         self.output.contracts.insert(
             name.to_string(),
@@ -108,6 +240,7 @@ impl Deployer {
                 wasm_hash: wasm_hash.clone(),
             },
         );
+        self.record_attempt(name, Step::Deploy, StepOutcome::Succeeded);
         self.output.save(&self.storage_path)?;
 
         // Emit consistent event loop
@@ -118,23 +251,100 @@ impl Deployer {
 
     /// Enforces initialization sequencing onto an already deployed contract
     pub fn initialize_contract(&mut self, name: &str, caller: &str) -> Result<(), String> {
+        self.initialize(name, caller, None, false)
+    }
+
+    /// Calls `function` with `args` on a deployed contract, then marks it initialized.
+    /// `params` names the arguments and may be empty for positional backends.
+    pub fn initialize_contract_with(
+        &mut self,
+        name: &str,
+        caller: &str,
+        function: &str,
+        params: &[String],
+        args: &[ArgValue],
+    ) -> Result<(), String> {
+        self.initialize(name, caller, Some((function, params, args)), false)
+    }
+
+    /// Initializes a deployed contract as its manifest entry describes
+    pub fn initialize_from_manifest(
+        &mut self,
+        manifest: &Manifest,
+        name: &str,
+        caller: &str,
+    ) -> Result<(), String> {
+        self.initialize_manifest_entry(manifest, name, caller, false)
+    }
+
+    fn initialize_manifest_entry(
+        &mut self,
+        manifest: &Manifest,
+        name: &str,
+        caller: &str,
+        resuming: bool,
+    ) -> Result<(), String> {
+        let spec = manifest
+            .contracts
+            .get(name)
+            .ok_or_else(|| format!("Contract {} is not in the manifest", name))?;
+        match &spec.init {
+            Some(function) => {
+                let args = self.resolve_args(manifest, spec)?;
+                self.initialize(name, caller, Some((function, &spec.params, &args)), resuming)
+            }
+            None => self.initialize(name, caller, None, resuming),
+        }
+    }
+
+    /// `resuming` is set when an earlier run left the contract deployed but
+    /// not recorded as initialized, so its `init` may already have landed
+    fn initialize(
+        &mut self,
+        name: &str,
+        caller: &str,
+        call: Option<(&str, &[String], &[ArgValue])>,
+        resuming: bool,
+    ) -> Result<(), String> {
         // Enforce strict authorization
         if caller != self.output.admin_address {
             return Err("Unauthorized caller".to_string());
         }
 
         let state = self.output.contracts.get(name).ok_or("Contract not found")?;
-        
+
         // Ensure deterministic handling and transitions
         match state {
             ContractState::Deployed { address, wasm_hash } => {
-                self.output.contracts.insert(
-                    name.to_string(),
-                    ContractState::Initialized {
-                        address: address.clone(),
-                        wasm_hash: wasm_hash.clone(),
-                    },
-                );
+                let (address, wasm_hash) = (address.clone(), wasm_hash.clone());
+                // Adopt an init that landed without being recorded
+                let landed = match (resuming, call.is_some()) {
+                    (true, true) => self.backend.is_initialized(&address),
+                    _ => Ok(false),
+                };
+                let call = match landed {
+                    Ok(true) => {
+                        println!("EVENT: Adopting {} already initialized at {}", name, address);
+                        None
+                    }
+                    Ok(false) => call,
+                    Err(e) => {
+                        self.record_attempt(name, Step::Initialize, StepOutcome::Failed(e.clone()));
+                        self.output.save(&self.storage_path)?;
+                        return Err(e);
+                    }
+                };
+                if let Some((function, params, args)) = call {
+                    if let Err(e) = self.backend.invoke(&address, function, params, args) {
+                        self.record_attempt(name, Step::Initialize, StepOutcome::Failed(e.clone()));
+                        self.output.save(&self.storage_path)?;
+                        return Err(e);
+                    }
+                }
+                self.output
+                    .contracts
+                    .insert(name.to_string(), ContractState::Initialized { address, wasm_hash });
+                self.record_attempt(name, Step::Initialize, StepOutcome::Succeeded);
                 self.output.save(&self.storage_path)?;
                 println!("EVENT: Contract {} initialized", name);
                 Ok(())
@@ -143,11 +353,407 @@ impl Deployer {
             _ => Err("Invalid state transition".to_string()),
         }
     }
+
+    /// Deploys and initializes every manifest contract in dependency order,
+    /// returning the resulting addresses. Contracts that are already
+    /// initialized are left untouched.
+    pub fn deploy_all(
+        &mut self,
+        manifest: &Manifest,
+        caller: &str,
+    ) -> Result<BTreeMap<String, String>, String> {
+        let report = self.run(manifest, caller, RunMode::Fresh)?;
+        match report.failure {
+            Some(failure) => Err(format!(
+                "Failed to {} {}: {}",
+                failure.step, failure.contract, failure.error
+            )),
+            None => Ok(report.addresses),
+        }
+    }
+
+    /// Executes a manifest, stopping at the first failed step. Errors are
+    /// returned only when the run cannot start; step failures are reported
+    /// in the `RunReport` and persisted so a `Resume` run can pick them up.
+    pub fn run(&mut self, manifest: &Manifest, caller: &str, mode: RunMode) -> Result<RunReport, String> {
+        if caller != self.output.admin_address {
+            return Err("Unauthorized".to_string());
+        }
+
+        let order = manifest.deployment_order()?;
+        if mode == RunMode::Fresh {
+            let unfinished = order.iter().find(|name| {
+                !matches!(
+                    self.output.contracts.get(*name),
+                    None | Some(ContractState::Initialized { .. })
+                )
+            });
+            if let Some(name) = unfinished {
+                return Err(format!(
+                    "Deployment of {} is unfinished from an earlier run; resume to continue",
+                    name
+                ));
+            }
+        }
+
+        self.output.runs += 1;
+        self.output.save(&self.storage_path)?;
+        let mut report = RunReport {
+            run: self.output.runs,
+            ..RunReport::default()
+        };
+
+        for (i, name) in order.iter().enumerate() {
+            if let Some(ContractState::Initialized { .. }) = self.output.contracts.get(name) {
+                report.skipped.push(name.clone());
+                continue;
+            }
+
+            // Deployed but not initialized means an earlier run stopped at init
+            let resuming = matches!(
+                self.output.contracts.get(name),
+                Some(ContractState::Deployed { .. })
+            );
+            let result = self
+                .deploy_contract(name, caller)
+                .map_err(|e| (Step::Deploy, e))
+                .and_then(|_| {
+                    self.initialize_manifest_entry(manifest, name, caller, resuming)
+                        .map_err(|e| (Step::Initialize, e))
+                });
+
+            match result {
+                Ok(()) => report.completed.push(name.clone()),
+                Err((step, error)) => {
+                    report.failure = Some(StepFailure {
+                        contract: name.clone(),
+                        step,
+                        error,
+                    });
+                    report.remaining = order[i + 1..].to_vec();
+                    break;
+                }
+            }
+        }
+
+        if report.failure.is_none() {
+            match self.register_contracts(manifest, caller) {
+                Ok(calls) => {
+                    if !calls.is_empty() {
+                        self.record_attempt(registry::ADDRESS_REGISTRY, Step::Register, StepOutcome::Succeeded);
+                        self.output.save(&self.storage_path)?;
+                    }
+                    report.registry_calls = calls;
+                }
+                Err(error) => {
+                    self.record_attempt(
+                        registry::ADDRESS_REGISTRY,
+                        Step::Register,
+                        StepOutcome::Failed(error.clone()),
+                    );
+                    self.output.save(&self.storage_path)?;
+                    report.failure = Some(StepFailure {
+                        contract: registry::ADDRESS_REGISTRY.to_string(),
+                        step: Step::Register,
+                        error,
+                    });
+                }
+            }
+        }
+
+        report.addresses = self
+            .output
+            .addresses()
+            .into_iter()
+            .filter(|(name, _)| manifest.contracts.contains_key(name))
+            .collect();
+        Ok(report)
+    }
+
+    /// Diffs `manifest` against the persisted state without touching the chain
+    pub fn plan(&self, manifest: &Manifest) -> Result<Vec<PlannedStep>, String> {
+        let order = manifest.deployment_order()?;
+        let mut steps: Vec<PlannedStep> = order
+            .iter()
+            .map(|name| {
+                let action = match self.output.contracts.get(name) {
+                    None => PlanAction::Deploy,
+                    Some(ContractState::Pending) => {
+                        PlanAction::Retry("interrupted before deployment finished".to_string())
+                    }
+                    Some(ContractState::Failed(e)) => PlanAction::Retry(e.clone()),
+                    Some(ContractState::Deployed { .. }) => PlanAction::Initialize,
+                    Some(ContractState::Initialized { .. }) => PlanAction::UpToDate,
+                };
+                PlannedStep {
+                    contract: name.clone(),
+                    action,
+                }
+            })
+            .collect();
+
+        let mut untracked: Vec<&String> = self
+            .output
+            .contracts
+            .keys()
+            .filter(|name| !manifest.contracts.contains_key(*name))
+            .collect();
+        untracked.sort();
+        steps.extend(untracked.into_iter().map(|name| PlannedStep {
+            contract: name.clone(),
+            action: PlanAction::Untracked,
+        }));
+        Ok(steps)
+    }
+
+    /// Calls that would bring the registries in the manifest up to date with
+    /// the initialized contracts, grouped by contract with the record each
+    /// group writes. Contracts whose address and code are already registered
+    /// produce no group, and calls that already landed for an interrupted
+    /// registration are left out of it.
+    fn pending_registrations(
+        &self,
+        manifest: &Manifest,
+    ) -> Result<Vec<(String, RegisteredContract, Vec<RegistryCall>)>, String> {
+        let registry_address = |name: &str| match self.output.contracts.get(name) {
+            Some(ContractState::Initialized { address, .. }) if manifest.contracts.contains_key(name) => {
+                Some(address.clone())
+            }
+            _ => None,
+        };
+        let address_registry = registry_address(registry::ADDRESS_REGISTRY);
+        let metadata_registry = registry_address(registry::METADATA_REGISTRY);
+        if address_registry.is_none() && metadata_registry.is_none() {
+            return Ok(Vec::new());
+        }
+
+        let mut pending = Vec::new();
+        for name in manifest.deployment_order()? {
+            let (address, wasm_hash) = match self.output.contracts.get(&name) {
+                Some(ContractState::Initialized { address, wasm_hash }) => (address, wasm_hash),
+                _ => continue,
+            };
+            let previous = self.output.registered.get(&name);
+            if previous.is_some_and(|p| &p.address == address && &p.wasm_hash == wasm_hash) {
+                continue;
+            }
+
+            // What each registry last received: the partial record if its call
+            // landed, otherwise the last complete one
+            let partial = self.output.partial_registrations.get(&name);
+            let holds_partial = |registry: &str| partial.is_some_and(|p| p.registries.iter().any(|r| r == registry));
+            let last_written = |registry: &str| {
+                if holds_partial(registry) {
+                    partial.map(|p| &p.record)
+                } else {
+                    previous
+                }
+            };
+
+            let resumed = partial.filter(|p| &p.record.address == address && &p.record.wasm_hash == wasm_hash);
+            let record = match resumed {
+                Some(partial) => partial.record.clone(),
+                None => RegisteredContract {
+                    version: previous
+                        .into_iter()
+                        .chain(partial.map(|p| &p.record))
+                        .map(|r| r.version + 1)
+                        .max()
+                        .unwrap_or(1),
+                    address: address.clone(),
+                    wasm_hash: wasm_hash.clone(),
+                },
+            };
+            let needs_call = |registry: &str| resumed.is_none() || !holds_partial(registry);
+            let registry_name = registry::registry_name(&name);
+            let mut calls = Vec::new();
+
+            if address_registry.is_some() && needs_call(registry::ADDRESS_REGISTRY) {
+                let registered = last_written(registry::ADDRESS_REGISTRY).is_some();
+                calls.push(RegistryCall {
+                    contract: name.clone(),
+                    registry: registry::ADDRESS_REGISTRY.to_string(),
+                    function: if registered { "update" } else { "register" }.to_string(),
+                    params: vec!["name".to_string(), "address".to_string(), "version".to_string()],
+                    args: vec![
+                        ArgValue::String(registry_name.clone()),
+                        ArgValue::Address(address.clone()),
+                        ArgValue::U32(record.version),
+                    ],
+                });
+            }
+
+            // Metadata is keyed by contract address, so a redeployment registers afresh
+            if metadata_registry.is_some() && needs_call(registry::METADATA_REGISTRY) {
+                let same_address = last_written(registry::METADATA_REGISTRY).is_some_and(|p| &p.address == address);
+                let docs_uri = manifest.contracts[&name]
+                    .docs_uri
+                    .clone()
+                    .unwrap_or_else(|| format!("docs/contracts/{}.md", registry_name));
+                calls.push(RegistryCall {
+                    contract: name.clone(),
+                    registry: registry::METADATA_REGISTRY.to_string(),
+                    function: if same_address { "update_metadata" } else { "register_metadata" }.to_string(),
+                    params: vec![
+                        "contract_id".to_string(),
+                        "version".to_string(),
+                        "schema_hash".to_string(),
+                        "docs_uri".to_string(),
+                    ],
+                    args: vec![
+                        ArgValue::Address(address.clone()),
+                        ArgValue::U32(record.version),
+                        ArgValue::Bytes32(wasm_hash.clone()),
+                        ArgValue::String(docs_uri),
+                    ],
+                });
+            }
+
+            pending.push((name, record, calls));
+        }
+        Ok(pending)
+    }
+
+    /// Registry calls a run would make for the contracts initialized so far
+    pub fn registry_calls(&self, manifest: &Manifest) -> Result<Vec<RegistryCall>, String> {
+        Ok(self
+            .pending_registrations(manifest)?
+            .into_iter()
+            .flat_map(|(_, _, calls)| calls)
+            .collect())
+    }
+
+    /// Registers every initialized contract in the manifest's registries,
+    /// then fails if the address registry's `validation_report` flags any issue.
+    /// Each call is saved as it lands, so a failed run resumes with the next one.
+    pub fn register_contracts(&mut self, manifest: &Manifest, caller: &str) -> Result<Vec<RegistryCall>, String> {
+        if caller != self.output.admin_address {
+            return Err("Unauthorized".to_string());
+        }
+
+        let mut executed = Vec::new();
+        for (name, record, calls) in self.pending_registrations(manifest)? {
+            for call in &calls {
+                let registry = self.output.addresses()[&call.registry].clone();
+                self.backend
+                    .invoke(&registry, &call.function, &call.params, &call.args)
+                    .map_err(|e| format!("{} failed: {}", call, e))?;
+                println!("EVENT: {}", call);
+
+                match self.output.partial_registrations.get_mut(&name) {
+                    Some(partial) if partial.record == record => partial.registries.push(call.registry.clone()),
+                    _ => {
+                        let partial = PartialRegistration {
+                            record: record.clone(),
+                            registries: vec![call.registry.clone()],
+                        };
+                        self.output.partial_registrations.insert(name.clone(), partial);
+                    }
+                }
+                self.output.save(&self.storage_path)?;
+            }
+            self.output.partial_registrations.remove(&name);
+            self.output.registered.insert(name, record);
+            self.output.save(&self.storage_path)?;
+            executed.extend(calls);
+        }
+
+        if let Some(registry) = self.output.addresses().get(registry::ADDRESS_REGISTRY) {
+            if manifest.contracts.contains_key(registry::ADDRESS_REGISTRY) {
+                let issues = self.backend.validation_report(registry)?;
+                if !issues.is_empty() {
+                    let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+                    return Err(format!("Registry validation failed: {}", issues.join("; ")));
+                }
+            }
+        }
+        Ok(executed)
+    }
+
+    /// Checks that every manifest contract is initialized and that the code
+    /// recorded for it matches the built artifact, returning any problems
+    pub fn verify(&self, manifest: &Manifest, artifacts: &WasmArtifacts) -> Vec<String> {
+        let mut issues = Vec::new();
+        let mut names: Vec<&String> = manifest.contracts.keys().collect();
+        names.sort();
+        for name in names {
+            let wasm_hash = match self.output.contracts.get(name) {
+                Some(ContractState::Initialized { wasm_hash, .. }) => wasm_hash,
+                Some(ContractState::Deployed { .. }) => {
+                    issues.push(format!("{} is deployed but not initialized", name));
+                    continue;
+                }
+                Some(_) => {
+                    issues.push(format!("{} did not finish deploying", name));
+                    continue;
+                }
+                None => {
+                    issues.push(format!("{} is not deployed", name));
+                    continue;
+                }
+            };
+            match artifacts.read(name) {
+                Ok(wasm) => {
+                    let built = backend::wasm_hash(&wasm);
+                    if &built != wasm_hash {
+                        issues.push(format!(
+                            "{} runs {} but the built artifact is {}",
+                            name, wasm_hash, built
+                        ));
+                    }
+                }
+                Err(e) => issues.push(e),
+            }
+        }
+        issues
+    }
+
+    /// Replaces admin, contract and external references with concrete values
+    fn resolve_args(&self, manifest: &Manifest, spec: &ContractSpec) -> Result<Vec<ArgValue>, String> {
+        spec.args
+            .iter()
+            .map(|arg| {
+                Ok(match arg {
+                    InitArg::Admin => ArgValue::Address(self.output.admin_address.clone()),
+                    InitArg::Contract { name } => match self.output.contracts.get(name) {
+                        Some(ContractState::Deployed { address, .. })
+                        | Some(ContractState::Initialized { address, .. }) => {
+                            ArgValue::Address(address.clone())
+                        }
+                        _ => return Err(format!("Dependency {} is not deployed", name)),
+                    },
+                    InitArg::External { name } => ArgValue::Address(
+                        manifest
+                            .external
+                            .get(name)
+                            .cloned()
+                            .ok_or_else(|| format!("Unknown external address {}", name))?,
+                    ),
+                    InitArg::Address { value } => ArgValue::Address(value.clone()),
+                    InitArg::U32 { value } => ArgValue::U32(*value),
+                    InitArg::U64 { value } => ArgValue::U64(*value),
+                    InitArg::I128 { value } => ArgValue::I128(*value as i128),
+                    InitArg::Bool { value } => ArgValue::Bool(*value),
+                    InitArg::Symbol { value } => ArgValue::Symbol(value.clone()),
+                    InitArg::String { value } => ArgValue::String(value.clone()),
+                })
+            })
+            .collect()
+    }
+}
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
     use tempfile::NamedTempFile;
 
     #[test]
@@ -155,7 +761,7 @@ mod tests {
         let temp_file = NamedTempFile::new().unwrap();
         let path = temp_file.path().to_path_buf();
         
-        let mut deployer = Deployer::new(NetworkProfile::Testnet, "GAdmin".to_string(), path.clone());
+        let mut deployer = Deployer::new(NetworkProfile::Testnet, "GAdmin".to_string(), path.clone()).unwrap();
         
         let deploy_req = deployer.deploy_contract("reward_distribution", "GAdmin");
         assert!(deploy_req.is_ok());
@@ -175,7 +781,7 @@ mod tests {
     #[test]
     fn test_unauthorized_deploy() {
         let temp_file = NamedTempFile::new().unwrap();
-        let mut deployer = Deployer::new(NetworkProfile::Dev, "GAdmin".to_string(), temp_file.path().to_path_buf());
+        let mut deployer = Deployer::new(NetworkProfile::Dev, "GAdmin".to_string(), temp_file.path().to_path_buf()).unwrap();
         
         let res = deployer.deploy_contract("coin_flip", "GHacker");
         assert_eq!(res, Err("Unauthorized".to_string()));
@@ -184,7 +790,7 @@ mod tests {
     #[test]
     fn test_invalid_state_transition() {
         let temp_file = NamedTempFile::new().unwrap();
-        let mut deployer = Deployer::new(NetworkProfile::Mainnet, "GAdmin".to_string(), temp_file.path().to_path_buf());
+        let mut deployer = Deployer::new(NetworkProfile::Mainnet, "GAdmin".to_string(), temp_file.path().to_path_buf()).unwrap();
         
         let res_init_early = deployer.initialize_contract("missing", "GAdmin");
         assert_eq!(res_init_early, Err("Contract not found".to_string()));
@@ -196,4 +802,1037 @@ mod tests {
         let duplicate_init = deployer.initialize_contract("dice_roll", "GAdmin");
         assert_eq!(duplicate_init, Err("Already initialized".to_string()));
     }
+
+    fn fixture_artifacts(names: &[&str]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/add_u64.wasm");
+        for name in names {
+            fs::copy(&fixture, dir.path().join(format!("stellarcade_{}.wasm", name))).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_local_env_backend_deploys_real_wasm() {
+        let wasm_dir = fixture_artifacts(&["coin_flip", "dice_roll"]);
+        let temp_file = NamedTempFile::new().unwrap();
+        let backend = LocalEnvBackend::new(WasmArtifacts::new(wasm_dir.path()));
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        let coin_flip = deployer.deploy_contract("coin_flip", "GAdmin").unwrap();
+        let dice_roll = deployer.deploy_contract("dice_roll", "GAdmin").unwrap();
+        assert!(coin_flip.starts_with('C'));
+        assert_eq!(coin_flip.len(), 56);
+        assert_ne!(coin_flip, dice_roll);
+
+        let wasm = fs::read(wasm_dir.path().join("stellarcade_coin_flip.wasm")).unwrap();
+        let loaded = DeploymentOutput::load(temp_file.path()).unwrap();
+        match loaded.contracts.get("coin_flip") {
+            Some(ContractState::Deployed { address, wasm_hash }) => {
+                assert_eq!(address, &coin_flip);
+                assert_eq!(wasm_hash, &backend::wasm_hash(&wasm));
+                assert_eq!(wasm_hash.len(), 64);
+            }
+            _ => panic!("Expected deployed state"),
+        }
+    }
+
+    #[test]
+    fn test_missing_artifact_marks_failed() {
+        let wasm_dir = tempfile::tempdir().unwrap();
+        let temp_file = NamedTempFile::new().unwrap();
+        let backend = LocalEnvBackend::new(WasmArtifacts::new(wasm_dir.path()));
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        let res = deployer.deploy_contract("prize_pool", "GAdmin");
+        assert!(res.unwrap_err().starts_with("WASM artifact not found for prize_pool"));
+        assert!(matches!(
+            deployer.output.contracts.get("prize_pool"),
+            Some(ContractState::Failed(_))
+        ));
+    }
+
+    /// Records every backend call so tests can assert on ordering and arguments,
+    /// fails any call touching the contract named in `fail_on` or invoking the
+    /// function named in `fail_function`, and reports `issues` from
+    /// `validation_report`
+    #[derive(Default)]
+    struct RecordingBackend {
+        calls: Rc<RefCell<Vec<String>>>,
+        fail_on: Rc<RefCell<Option<String>>>,
+        fail_function: Rc<RefCell<Option<String>>>,
+        issues: Rc<RefCell<Vec<RegistryIssue>>>,
+        /// Contracts already on chain, as `find_deployed` reports them
+        landed: Rc<RefCell<Vec<String>>>,
+        /// Addresses whose `init` already ran, as `is_initialized` reports them
+        initialized: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl RecordingBackend {
+        fn check(&self, name: &str) -> Result<(), String> {
+            match self.fail_on.borrow().as_deref() {
+                Some(failing) if name.ends_with(failing) => Err(format!("{} unavailable", failing)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    impl DeployBackend for RecordingBackend {
+        fn upload(&mut self, name: &str) -> Result<String, String> {
+            Ok(format!("W_{}", name))
+        }
+
+        fn instantiate(&mut self, name: &str, _wasm_hash: &str) -> Result<String, String> {
+            self.check(name)?;
+            self.calls.borrow_mut().push(format!("deploy {}", name));
+            Ok(format!("C_{}", name))
+        }
+
+        fn find_deployed(&mut self, name: &str) -> Result<Option<String>, String> {
+            self.calls.borrow_mut().push(format!("find {}", name));
+            let landed = self.landed.borrow().iter().any(|landed| landed == name);
+            Ok(landed.then(|| format!("C_{}", name)))
+        }
+
+        fn is_initialized(&mut self, address: &str) -> Result<bool, String> {
+            self.calls.borrow_mut().push(format!("initialized? {}", address));
+            Ok(self.initialized.borrow().iter().any(|initialized| initialized == address))
+        }
+
+        fn invoke(
+            &mut self,
+            address: &str,
+            function: &str,
+            _params: &[String],
+            args: &[ArgValue],
+        ) -> Result<(), String> {
+            self.check(address)?;
+            if self.fail_function.borrow().as_deref() == Some(function) {
+                return Err(format!("{} rejected", function));
+            }
+            self.calls
+                .borrow_mut()
+                .push(format!("{}.{}{:?}", address, function, args));
+            Ok(())
+        }
+
+        fn validation_report(&mut self, registry: &str) -> Result<Vec<RegistryIssue>, String> {
+            self.calls.borrow_mut().push(format!("{}.validation_report", registry));
+            Ok(self.issues.borrow().clone())
+        }
+    }
+
+    #[test]
+    fn test_deploy_all_follows_dependency_order() {
+        let manifest = Manifest::parse(
+            r#"
+            [external]
+            token = "CTOKEN"
+
+            [contracts.coin_flip]
+            init = "init"
+            args = [
+                { type = "admin" },
+                { type = "contract", ref = "random_generator" },
+                { type = "external", ref = "token" },
+                { type = "i128", value = 10 },
+            ]
+
+            [contracts.random_generator]
+            init = "init"
+            args = [{ type = "admin" }, { type = "address", value = "GORACLE" }]
+
+            [contracts.balance_management]
+            depends_on = ["coin_flip"]
+            "#,
+        )
+        .unwrap();
+
+        let backend = RecordingBackend::default();
+        let calls = backend.calls.clone();
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        let addresses = deployer.deploy_all(&manifest, "GAdmin").unwrap();
+        assert_eq!(addresses["coin_flip"], "C_coin_flip");
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                "deploy random_generator".to_string(),
+                r#"C_random_generator.init[Address("GAdmin"), Address("GORACLE")]"#.to_string(),
+                "deploy coin_flip".to_string(),
+                r#"C_coin_flip.init[Address("GAdmin"), Address("C_random_generator"), Address("CTOKEN"), I128(10)]"#
+                    .to_string(),
+                "deploy balance_management".to_string(),
+            ]
+        );
+        let loaded = DeploymentOutput::load(temp_file.path()).unwrap();
+        assert!(loaded
+            .contracts
+            .values()
+            .all(|state| matches!(state, ContractState::Initialized { .. })));
+
+        // A second run leaves initialized contracts alone
+        calls.borrow_mut().clear();
+        assert_eq!(deployer.deploy_all(&manifest, "GAdmin").unwrap(), addresses);
+        assert!(calls.borrow().is_empty());
+
+        assert_eq!(
+            deployer.deploy_all(&manifest, "GHacker"),
+            Err("Unauthorized".to_string())
+        );
+    }
+
+    #[test]
+    fn test_deploy_all_invokes_init_in_local_env() {
+        let wasm_dir = fixture_artifacts(&["adder", "broken"]);
+        let temp_file = NamedTempFile::new().unwrap();
+        let backend = LocalEnvBackend::new(WasmArtifacts::new(wasm_dir.path()));
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        let manifest = Manifest::parse(
+            r#"
+            [contracts.adder]
+            init = "add"
+            args = [{ type = "u64", value = 1 }, { type = "u64", value = 2 }]
+            "#,
+        )
+        .unwrap();
+        let addresses = deployer.deploy_all(&manifest, "GAdmin").unwrap();
+        assert!(matches!(
+            deployer.output.contracts.get("adder"),
+            Some(ContractState::Initialized { address, .. }) if address == &addresses["adder"]
+        ));
+
+        // Wrong argument types make the init call fail and leave the contract deployed
+        let manifest = Manifest::parse(
+            r#"
+            [contracts.broken]
+            init = "add"
+            args = [{ type = "bool", value = true }]
+            "#,
+        )
+        .unwrap();
+        let res = deployer.deploy_all(&manifest, "GAdmin");
+        assert!(res.unwrap_err().starts_with("Failed to initialize broken"));
+        assert!(matches!(
+            deployer.output.contracts.get("broken"),
+            Some(ContractState::Deployed { .. })
+        ));
+    }
+
+    const CHAIN: &str = r#"
+        [contracts.a]
+        init = "init"
+
+        [contracts.b]
+        init = "init"
+        args = [{ type = "contract", ref = "a" }]
+
+        [contracts.c]
+        init = "init"
+        args = [{ type = "contract", ref = "b" }]
+    "#;
+
+    #[test]
+    fn test_failed_run_can_be_planned_and_resumed() {
+        let manifest = Manifest::parse(CHAIN).unwrap();
+        let backend = RecordingBackend::default();
+        let fail_on = backend.fail_on.clone();
+        let temp_file = NamedTempFile::new().unwrap();
+        let path = temp_file.path().to_path_buf();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            path.clone(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        // b deploys but its init call fails; c is never reached
+        *fail_on.borrow_mut() = Some("C_b".to_string());
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Fresh).unwrap();
+        assert_eq!(report.run, 1);
+        assert_eq!(report.completed, vec!["a".to_string()]);
+        assert_eq!(
+            report.failure,
+            Some(StepFailure {
+                contract: "b".to_string(),
+                step: Step::Initialize,
+                error: "C_b unavailable".to_string(),
+            })
+        );
+        assert_eq!(report.remaining, vec!["c".to_string()]);
+        assert!(report.to_string().contains("b is deployed at C_b but not initialized"));
+
+        // A fresh run refuses to continue over the leftovers
+        assert_eq!(
+            deployer.run(&manifest, "GAdmin", RunMode::Fresh),
+            Err("Deployment of b is unfinished from an earlier run; resume to continue".to_string())
+        );
+
+        let plan: Vec<String> = deployer.plan(&manifest).unwrap().iter().map(|s| s.to_string()).collect();
+        assert_eq!(plan, vec!["= a", "~ b (initialize)", "+ c (deploy, initialize)"]);
+
+        // Resume from a reloaded state file with a healthy backend
+        let backend = RecordingBackend::default();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            path.clone(),
+            Box::new(backend),
+        )
+        .unwrap();
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Resume).unwrap();
+        assert!(report.is_success());
+        assert_eq!(report.run, 2);
+        assert_eq!(report.skipped, vec!["a".to_string()]);
+        assert_eq!(report.completed, vec!["b".to_string(), "c".to_string()]);
+
+        let loaded = DeploymentOutput::load(&path).unwrap();
+        let attempts: Vec<(u32, &str, Step, u32, bool)> = loaded
+            .run_log
+            .iter()
+            .map(|a| {
+                (a.run, a.contract.as_str(), a.step, a.attempt, a.outcome == StepOutcome::Succeeded)
+            })
+            .collect();
+        assert_eq!(
+            attempts,
+            vec![
+                (1, "a", Step::Deploy, 1, true),
+                (1, "a", Step::Initialize, 1, true),
+                (1, "b", Step::Deploy, 1, true),
+                (1, "b", Step::Initialize, 1, false),
+                (2, "b", Step::Initialize, 2, true),
+                (2, "c", Step::Deploy, 1, true),
+                (2, "c", Step::Initialize, 1, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failed_deploy_is_retried_on_resume() {
+        let manifest = Manifest::parse(CHAIN).unwrap();
+        let backend = RecordingBackend::default();
+        let fail_on = backend.fail_on.clone();
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        *fail_on.borrow_mut() = Some("a".to_string());
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Fresh).unwrap();
+        assert_eq!(report.failure.unwrap().step, Step::Deploy);
+        assert_eq!(report.remaining, vec!["b".to_string(), "c".to_string()]);
+        assert_eq!(
+            deployer.plan(&manifest).unwrap()[0].action,
+            PlanAction::Retry("a unavailable".to_string())
+        );
+
+        *fail_on.borrow_mut() = None;
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Resume).unwrap();
+        assert_eq!(report.completed.len(), 3);
+        assert_eq!(deployer.output.run_log[1].attempt, 2);
+    }
+
+    #[test]
+    fn test_resume_adopts_deploy_that_landed() {
+        let manifest = Manifest::parse(CHAIN).unwrap();
+        let backend = RecordingBackend::default();
+        let calls = backend.calls.clone();
+        let fail_on = backend.fail_on.clone();
+        let landed = backend.landed.clone();
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        // The deploy of a reports failure but reaches the chain anyway
+        *fail_on.borrow_mut() = Some("a".to_string());
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Fresh).unwrap();
+        assert_eq!(report.failure.unwrap().step, Step::Deploy);
+        landed.borrow_mut().push("a".to_string());
+
+        *fail_on.borrow_mut() = None;
+        calls.borrow_mut().clear();
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Resume).unwrap();
+        assert!(report.is_success());
+        assert_eq!(report.addresses["a"], "C_a");
+        assert_eq!(calls.borrow()[0], "find a");
+        assert!(!calls.borrow().contains(&"deploy a".to_string()));
+        // Contracts without an earlier attempt are deployed without a lookup
+        assert!(!calls.borrow().contains(&"find b".to_string()));
+    }
+
+    #[test]
+    fn test_resume_adopts_init_that_landed() {
+        let manifest = Manifest::parse(CHAIN).unwrap();
+        let backend = RecordingBackend::default();
+        let calls = backend.calls.clone();
+        let fail_function = backend.fail_function.clone();
+        let initialized = backend.initialized.clone();
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        // The init of a reports failure but reaches the chain anyway
+        *fail_function.borrow_mut() = Some("init".to_string());
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Fresh).unwrap();
+        assert_eq!(report.failure.unwrap().step, Step::Initialize);
+        assert!(!calls.borrow().contains(&"initialized? C_a".to_string()));
+        initialized.borrow_mut().push("C_a".to_string());
+
+        *fail_function.borrow_mut() = None;
+        calls.borrow_mut().clear();
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Resume).unwrap();
+        assert!(report.is_success());
+        assert_eq!(calls.borrow()[0], "initialized? C_a");
+        assert!(!calls.borrow().iter().any(|call| call.starts_with("C_a.init")));
+        assert!(matches!(
+            deployer.output.contracts.get("a"),
+            Some(ContractState::Initialized { .. })
+        ));
+        // Contracts deployed in this run are initialized without a lookup
+        assert!(calls.borrow().contains(&"C_b.init[Address(\"C_a\")]".to_string()));
+        assert!(!calls.borrow().contains(&"initialized? C_b".to_string()));
+    }
+
+    #[test]
+    fn test_local_env_adopts_initialized_contract() {
+        use soroban_sdk::testutils::Address as _;
+        use stellarcade_contract_address_registry::{ContractAddressRegistry, ContractAddressRegistryClient};
+
+        let wasm_dir = fixture_artifacts(&[]);
+        let mut backend = LocalEnvBackend::new(WasmArtifacts::new(wasm_dir.path()));
+        let env = backend.env().clone();
+        let registry = env.register(ContractAddressRegistry, ());
+        let address = backend::address_to_string(&registry);
+        assert_eq!(backend.is_initialized(&address), Ok(false));
+
+        // Initialized outside the deployer, e.g. by a run that crashed before saving
+        ContractAddressRegistryClient::new(&env, &registry).init(&soroban_sdk::Address::generate(&env));
+        assert_eq!(backend.is_initialized(&address), Ok(true));
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+        deployer.output.contracts.insert(
+            "registry".to_string(),
+            ContractState::Deployed {
+                address: address.clone(),
+                wasm_hash: "00".repeat(32),
+            },
+        );
+        let manifest = Manifest::parse(
+            r#"
+            [contracts.registry]
+            init = "init"
+            args = [{ type = "admin" }]
+            "#,
+        )
+        .unwrap();
+
+        // Calling init again would fail with AlreadyInitialized
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Resume).unwrap();
+        assert!(report.is_success(), "{:?}", report.failure);
+        assert!(matches!(
+            deployer.output.contracts.get("registry"),
+            Some(ContractState::Initialized { address: adopted, .. }) if adopted == &address
+        ));
+    }
+
+    #[test]
+    fn test_local_env_adopts_existing_contract() {
+        let wasm_dir = fixture_artifacts(&["adder"]);
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut backend = LocalEnvBackend::new(WasmArtifacts::new(wasm_dir.path()));
+        assert_eq!(backend.find_deployed("adder"), Ok(None));
+
+        // Deployed outside the deployer, e.g. by a run that crashed before saving
+        let wasm_hash = backend.upload("adder").unwrap();
+        let address = backend.instantiate("adder", &wasm_hash).unwrap();
+        assert_eq!(backend.find_deployed("adder"), Ok(Some(address.clone())));
+
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+        deployer
+            .output
+            .contracts
+            .insert("adder".to_string(), ContractState::Pending);
+        assert_eq!(deployer.deploy_contract("adder", "GAdmin"), Ok(address.clone()));
+        assert!(matches!(
+            deployer.output.contracts.get("adder"),
+            Some(ContractState::Deployed { address: adopted, .. }) if adopted == &address
+        ));
+    }
+
+    #[test]
+    fn test_plan_reports_untracked_contracts() {
+        let manifest = Manifest::parse(CHAIN).unwrap();
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer =
+            Deployer::new(NetworkProfile::Dev, "GAdmin".to_string(), temp_file.path().to_path_buf()).unwrap();
+        deployer.deploy_contract("legacy", "GAdmin").unwrap();
+
+        let plan = deployer.plan(&manifest).unwrap();
+        assert_eq!(plan.len(), 4);
+        assert_eq!(plan[3].to_string(), "? legacy (not in manifest)");
+        // Planning never writes state
+        assert!(!deployer.output.contracts.contains_key("a"));
+    }
+
+    #[test]
+    fn test_corrupted_state_file_is_refused() {
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(temp_file.path(), "{ not json").unwrap();
+
+        let res = Deployer::new(NetworkProfile::Dev, "GAdmin".to_string(), temp_file.path().to_path_buf());
+        assert!(res.err().unwrap().starts_with("Refusing to use corrupted state file"));
+        // The file is left as it was for inspection
+        assert_eq!(fs::read_to_string(temp_file.path()).unwrap(), "{ not json");
+    }
+
+    #[test]
+    fn test_state_file_for_other_network_is_refused() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer =
+            Deployer::new(NetworkProfile::Testnet, "GAdmin".to_string(), temp_file.path().to_path_buf()).unwrap();
+        deployer.deploy_contract("coin-flip", "GAdmin").unwrap();
+        let saved = fs::read_to_string(temp_file.path()).unwrap();
+
+        let res = Deployer::new(NetworkProfile::Mainnet, "GAdmin".to_string(), temp_file.path().to_path_buf());
+        assert!(res.err().unwrap().contains("recorded for Testnet with the Mainnet profile"));
+        assert_eq!(fs::read_to_string(temp_file.path()).unwrap(), saved);
+
+        // The matching profile still resumes from it
+        let deployer =
+            Deployer::new(NetworkProfile::Testnet, "GAdmin".to_string(), temp_file.path().to_path_buf()).unwrap();
+        assert!(deployer.output.contracts.contains_key("coin-flip"));
+    }
+
+    fn cli_backend(program: &str, wasm_dir: &Path) -> StellarCliBackend {
+        StellarCliBackend {
+            program: program.to_string(),
+            rpc_url: "http://localhost:8000/rpc".to_string(),
+            network_passphrase: "Standalone Network ; February 2017".to_string(),
+            source: "stellarcade-dev".to_string(),
+            artifacts: WasmArtifacts::new(wasm_dir),
+        }
+    }
+
+    #[test]
+    fn test_stellar_cli_invoke_uses_named_params() {
+        let backend = cli_backend("stellar", Path::new("."));
+        let args = backend
+            .invoke_args(
+                "CCOIN",
+                "init",
+                &["admin".to_string(), "max_wager".to_string()],
+                &[ArgValue::Address("GADMIN".to_string()), ArgValue::I128(1_000)],
+            )
+            .unwrap();
+        assert_eq!(
+            args.join(" "),
+            "contract invoke --id CCOIN --source-account stellarcade-dev --rpc-url http://localhost:8000/rpc \
+             --network-passphrase Standalone Network ; February 2017 -- init --admin GADMIN --max_wager 1000"
+        );
+
+        assert_eq!(
+            backend.invoke_args("CCOIN", "init", &[], &[ArgValue::Bool(true)]),
+            Err("init needs a parameter name for each of its 1 arguments".to_string())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stellar_cli_backend_runs_cli() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let wasm_dir = fixture_artifacts(&["coin_flip"]);
+        let wasm = fs::read(wasm_dir.path().join("stellarcade_coin_flip.wasm")).unwrap();
+        let contract_id = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+
+        // Stand-in CLI that logs its arguments and answers like `stellar` would
+        let bin_dir = tempfile::tempdir().unwrap();
+        let log = bin_dir.path().join("calls.log");
+        let program = bin_dir.path().join("stellar");
+        fs::write(
+            &program,
+            format!(
+                "#!/bin/sh\necho \"$*\" >> {}\ncase \"$2\" in\n  upload) echo {} ;;\n  deploy) echo {} ;;\nesac\n",
+                log.display(),
+                backend::wasm_hash(&wasm),
+                contract_id
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(cli_backend(program.to_str().unwrap(), wasm_dir.path())),
+        )
+        .unwrap();
+        let manifest = Manifest::parse(
+            r#"
+            [contracts.coin_flip]
+            init = "init"
+            args = [{ type = "admin" }]
+            params = ["admin"]
+            "#,
+        )
+        .unwrap();
+
+        let addresses = deployer.deploy_all(&manifest, "GAdmin").unwrap();
+        assert_eq!(addresses["coin_flip"], contract_id);
+
+        let calls = fs::read_to_string(&log).unwrap();
+        let calls: Vec<&str> = calls.lines().collect();
+        assert_eq!(calls.len(), 3);
+        assert!(calls[0].starts_with("contract upload --wasm "));
+        assert!(calls[1].starts_with("contract deploy --wasm-hash "));
+        assert!(calls[2].ends_with("-- init --admin GAdmin"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stellar_cli_backend_adopts_landed_deploy() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let wasm_dir = fixture_artifacts(&["coin_flip"]);
+        let wasm = fs::read(wasm_dir.path().join("stellarcade_coin_flip.wasm")).unwrap();
+        let contract_id = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+
+        // Stand-in CLI whose deploy always fails, while `fetch` finds the contract
+        let bin_dir = tempfile::tempdir().unwrap();
+        let log = bin_dir.path().join("calls.log");
+        let program = bin_dir.path().join("stellar");
+        fs::write(
+            &program,
+            format!(
+                "#!/bin/sh\necho \"$*\" >> {}\ncase \"$2\" in\n  upload) echo {} ;;\n  id) echo {} ;;\n  fetch) echo wasm ;;\n  deploy) exit 1 ;;\nesac\n",
+                log.display(),
+                backend::wasm_hash(&wasm),
+                contract_id
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(cli_backend(program.to_str().unwrap(), wasm_dir.path())),
+        )
+        .unwrap();
+        assert!(deployer.deploy_contract("coin_flip", "GAdmin").is_err());
+        assert_eq!(deployer.deploy_contract("coin_flip", "GAdmin"), Ok(contract_id.to_string()));
+
+        let calls = fs::read_to_string(&log).unwrap();
+        let calls: Vec<&str> = calls.lines().collect();
+        assert_eq!(calls.len(), 5);
+        assert!(calls[1].starts_with("contract deploy --wasm-hash "));
+        assert!(calls[3].starts_with("contract id wasm --salt "));
+        assert!(calls[3].contains("--source-account stellarcade-dev"));
+        assert!(calls[4].starts_with(&format!("contract fetch --id {}", contract_id)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stellar_cli_backend_reads_instance_storage() {
+        use soroban_sdk::xdr::{
+            ContractExecutable, Hash, Limits, ScContractInstance, ScMap, ScMapEntry, ScVal, WriteXdr,
+        };
+        use std::os::unix::fs::PermissionsExt;
+
+        let contract_id = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+        let instance = |storage: Vec<ScMapEntry>| {
+            ScVal::ContractInstance(ScContractInstance {
+                executable: ContractExecutable::Wasm(Hash([0; 32])),
+                storage: Some(ScMap(storage.try_into().unwrap())),
+            })
+            .to_xdr_base64(Limits::none())
+            .unwrap()
+        };
+        let admin = ScMapEntry {
+            key: ScVal::U32(0),
+            val: ScVal::Bool(true),
+        };
+
+        let wasm_dir = fixture_artifacts(&[]);
+        let bin_dir = tempfile::tempdir().unwrap();
+        let log = bin_dir.path().join("calls.log");
+        let program = bin_dir.path().join("stellar");
+        for (storage, initialized) in [(vec![], false), (vec![admin], true)] {
+            // Stand-in CLI that prints the instance row as `contract read --output xdr` does
+            fs::write(
+                &program,
+                format!(
+                    "#!/bin/sh\necho \"$*\" >> {}\necho AAAAFA==,{},100,200\n",
+                    log.display(),
+                    instance(storage)
+                ),
+            )
+            .unwrap();
+            fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+
+            let mut backend = cli_backend(program.to_str().unwrap(), wasm_dir.path());
+            assert_eq!(backend.is_initialized(contract_id), Ok(initialized));
+        }
+
+        let calls = fs::read_to_string(&log).unwrap();
+        assert!(calls.starts_with(&format!(
+            "contract read --id {} --key-xdr AAAAFA== --durability persistent --output xdr",
+            contract_id
+        )));
+    }
+
+    const REGISTERED_STACK: &str = r#"
+        [contracts.contract_address_registry]
+        init = "init"
+        args = [{ type = "admin" }]
+
+        [contracts.contract_metadata_registry]
+        init = "init"
+        args = [{ type = "admin" }]
+
+        [contracts.prize_pool]
+        init = "init"
+        args = [{ type = "admin" }]
+        docs_uri = "ipfs://prize-pool"
+    "#;
+
+    #[test]
+    fn test_run_registers_contracts_and_validates() {
+        let manifest = Manifest::parse(REGISTERED_STACK).unwrap();
+        let backend = RecordingBackend::default();
+        let calls = backend.calls.clone();
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Fresh).unwrap();
+        assert!(report.is_success());
+        let emitted: Vec<String> = report.registry_calls.iter().map(|c| c.to_string()).collect();
+        assert_eq!(emitted.len(), 6);
+        assert_eq!(
+            emitted[4],
+            "contract_address_registry.register(name=\"prize-pool\", address=C_prize_pool, version=1)"
+        );
+        assert_eq!(
+            emitted[5],
+            "contract_metadata_registry.register_metadata(contract_id=C_prize_pool, version=1, \
+             schema_hash=W_prize_pool, docs_uri=\"ipfs://prize-pool\")"
+        );
+        assert_eq!(
+            calls.borrow().last().unwrap(),
+            "C_contract_address_registry.validation_report"
+        );
+        assert_eq!(
+            deployer.output.registered["prize_pool"],
+            RegisteredContract {
+                version: 1,
+                address: "C_prize_pool".to_string(),
+                wasm_hash: "W_prize_pool".to_string(),
+            }
+        );
+
+        // Nothing changed, so a second run only re-validates
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Fresh).unwrap();
+        assert!(report.registry_calls.is_empty());
+
+        // A redeployed contract is updated with the next version
+        deployer.output.contracts.insert(
+            "prize_pool".to_string(),
+            ContractState::Initialized {
+                address: "C_prize_pool_v2".to_string(),
+                wasm_hash: "W_prize_pool_v2".to_string(),
+            },
+        );
+        let emitted: Vec<String> = deployer
+            .registry_calls(&manifest)
+            .unwrap()
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(
+            emitted,
+            vec![
+                "contract_address_registry.update(name=\"prize-pool\", address=C_prize_pool_v2, version=2)",
+                "contract_metadata_registry.register_metadata(contract_id=C_prize_pool_v2, version=2, \
+                 schema_hash=W_prize_pool_v2, docs_uri=\"ipfs://prize-pool\")",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resume_skips_registry_calls_that_landed() {
+        let manifest = Manifest::parse(REGISTERED_STACK).unwrap();
+        let backend = RecordingBackend::default();
+        let calls = backend.calls.clone();
+        let fail_function = backend.fail_function.clone();
+        *fail_function.borrow_mut() = Some("register_metadata".to_string());
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        // The address registry accepts the first registration, the metadata registry rejects it
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Fresh).unwrap();
+        assert_eq!(report.failure.unwrap().step, Step::Register);
+        let loaded = DeploymentOutput::load(temp_file.path()).unwrap();
+        assert!(loaded.registered.is_empty());
+        assert_eq!(
+            loaded.partial_registrations["contract_address_registry"],
+            PartialRegistration {
+                record: RegisteredContract {
+                    version: 1,
+                    address: "C_contract_address_registry".to_string(),
+                    wasm_hash: "W_contract_address_registry".to_string(),
+                },
+                registries: vec![registry::ADDRESS_REGISTRY.to_string()],
+            }
+        );
+
+        let address_registrations = || {
+            calls
+                .borrow()
+                .iter()
+                .filter(|call| call.contains(r#".register[String("contract-address-registry")"#))
+                .count()
+        };
+        assert_eq!(address_registrations(), 1);
+
+        *fail_function.borrow_mut() = None;
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Resume).unwrap();
+        assert!(report.is_success());
+        let emitted: Vec<String> = report.registry_calls.iter().map(|c| c.to_string()).collect();
+        assert_eq!(emitted.len(), 5);
+        assert_eq!(
+            emitted[0],
+            "contract_metadata_registry.register_metadata(contract_id=C_contract_address_registry, version=1, \
+             schema_hash=W_contract_address_registry, docs_uri=\"docs/contracts/contract-address-registry.md\")"
+        );
+        assert_eq!(address_registrations(), 1);
+        assert!(deployer.output.partial_registrations.is_empty());
+        assert_eq!(deployer.output.registered.len(), 3);
+    }
+
+    #[test]
+    fn test_registry_issues_fail_the_run() {
+        let manifest = Manifest::parse(REGISTERED_STACK).unwrap();
+        let backend = RecordingBackend::default();
+        backend.issues.borrow_mut().push(RegistryIssue {
+            contract_name: "coin-flip".to_string(),
+            issue_type: "Missing".to_string(),
+            details: "Required core contract is not registered".to_string(),
+        });
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Fresh).unwrap();
+        assert_eq!(
+            report.failure,
+            Some(StepFailure {
+                contract: "contract_address_registry".to_string(),
+                step: Step::Register,
+                error: "Registry validation failed: Missing coin-flip: Required core contract is not registered"
+                    .to_string(),
+            })
+        );
+        // Registrations made before validation are kept for the next run
+        assert_eq!(deployer.output.registered.len(), 3);
+        assert_eq!(deployer.output.run_log.last().unwrap().step, Step::Register);
+    }
+
+    #[test]
+    fn test_parse_cli_validation_report() {
+        let issues = backend::parse_validation_report(
+            r#"{"issues":[{"contract_name":"coin-flip","details":"Required core contract is not registered","issue_type":1}],"timestamp":7,"total_checked":2}"#,
+        )
+        .unwrap();
+        assert_eq!(issues[0].to_string(), "Missing coin-flip: Required core contract is not registered");
+        assert!(backend::parse_validation_report("not json").is_err());
+    }
+
+    #[test]
+    fn test_registration_executes_in_local_env() {
+        use soroban_sdk::testutils::Address as _;
+        use stellarcade_contract_address_registry::{ContractAddressRegistry, ContractAddressRegistryClient};
+        use stellarcade_contract_metadata_registry::{
+            ContractMetadataRegistry, ContractMetadataRegistryClient,
+        };
+
+        let wasm_dir = fixture_artifacts(&["prize_pool", "random_generator", "coin_flip"]);
+        let backend = LocalEnvBackend::new(WasmArtifacts::new(wasm_dir.path()));
+        let env = backend.env().clone();
+
+        // Registries run natively in the same Env and are recorded as already initialized
+        let registry_admin = soroban_sdk::Address::generate(&env);
+        let address_registry = env.register(ContractAddressRegistry, ());
+        let address_client = ContractAddressRegistryClient::new(&env, &address_registry);
+        address_client.init(&registry_admin);
+        let metadata_registry = env.register(ContractMetadataRegistry, ());
+        let metadata_client = ContractMetadataRegistryClient::new(&env, &metadata_registry);
+        metadata_client.init(&registry_admin);
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut deployer = Deployer::with_backend(
+            NetworkProfile::Dev,
+            "GAdmin".to_string(),
+            temp_file.path().to_path_buf(),
+            Box::new(backend),
+        )
+        .unwrap();
+        for (name, address) in [
+            (registry::ADDRESS_REGISTRY, &address_registry),
+            (registry::METADATA_REGISTRY, &metadata_registry),
+        ] {
+            deployer.output.contracts.insert(
+                name.to_string(),
+                ContractState::Initialized {
+                    address: backend::address_to_string(address),
+                    wasm_hash: "00".repeat(32),
+                },
+            );
+        }
+
+        let mut manifest = Manifest::parse(
+            r#"
+            [contracts.contract_address_registry]
+            [contracts.contract_metadata_registry]
+            [contracts.prize_pool]
+            "#,
+        )
+        .unwrap();
+
+        // Only prize-pool is registered, so the report flags the other core contracts
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Fresh).unwrap();
+        let failure = report.failure.unwrap();
+        assert_eq!(failure.step, Step::Register);
+        assert_eq!(
+            failure.error,
+            "Registry validation failed: Missing random-generator: Required core contract is not registered; \
+             Missing coin-flip: Required core contract is not registered"
+        );
+
+        manifest.contracts.insert("random_generator".to_string(), ContractSpec::default());
+        manifest.contracts.insert("coin_flip".to_string(), ContractSpec::default());
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Resume).unwrap();
+        assert!(report.is_success());
+
+        let coin_flip = report.addresses["coin_flip"].clone();
+        let record = metadata_client
+            .metadata_of(&soroban_sdk::Address::from_str(&env, &coin_flip))
+            .unwrap();
+        let wasm = fs::read(wasm_dir.path().join("stellarcade_coin_flip.wasm")).unwrap();
+        assert_eq!(record.version, 1);
+        assert_eq!(hex::encode(record.schema_hash.to_array()), backend::wasm_hash(&wasm));
+        assert_eq!(
+            record.docs_uri,
+            soroban_sdk::String::from_str(&env, "docs/contracts/coin-flip.md")
+        );
+        let name = |name: &str| soroban_sdk::String::from_str(&env, name);
+        assert_eq!(
+            backend::address_to_string(&address_client.resolve(&name("coin-flip"))),
+            coin_flip
+        );
+
+        // New code recorded for prize-pool is written as version 2 through
+        // `update`, which the registry only accepts for a registered name
+        let prize_pool = report.addresses["prize_pool"].clone();
+        deployer.output.contracts.insert(
+            "prize_pool".to_string(),
+            ContractState::Initialized {
+                address: prize_pool.clone(),
+                wasm_hash: "11".repeat(32),
+            },
+        );
+        let report = deployer.run(&manifest, "GAdmin", RunMode::Resume).unwrap();
+        assert!(report.is_success());
+        assert_eq!(report.registry_calls[0].function, "update");
+        assert_eq!(address_client.get_version(&name("prize-pool")), 2);
+        assert_eq!(address_client.history(&name("prize-pool")).len(), 2);
+        let record = metadata_client
+            .metadata_of(&soroban_sdk::Address::from_str(&env, &prize_pool))
+            .unwrap();
+        assert_eq!(record.version, 2);
+        assert_eq!(hex::encode(record.schema_hash.to_array()), "11".repeat(32));
+    }
+
+    #[test]
+    fn test_wasm_hash_is_sha256() {
+        assert_eq!(
+            backend::wasm_hash(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
 }
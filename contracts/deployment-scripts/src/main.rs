@@ -0,0 +1,210 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use deployment_scripts::{
+    ContractState, DeployConfig, Manifest, Profile, RunMode, StepOutcome, WasmArtifacts,
+};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process;
+
+/// Deploys and manages the StellarCade contract stack
+#[derive(Parser)]
+#[command(name = "stellarcade-deploy")]
+struct Cli {
+    /// Profile configuration file
+    #[arg(long, default_value = "deploy.toml")]
+    config: PathBuf,
+
+    /// Profile to operate on
+    #[arg(long, short, default_value = "dev")]
+    profile: String,
+
+    /// Manifest to use instead of the profile's
+    #[arg(long)]
+    manifest: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show what `deploy` would change, without touching the network
+    Plan,
+    /// Deploy and initialize every contract in the manifest
+    Deploy {
+        /// Continue an interrupted run, retrying failed steps
+        #[arg(long)]
+        resume: bool,
+        /// Profile name, required for mainnet when not prompting
+        #[arg(long)]
+        confirm: Option<String>,
+    },
+    /// Initialize one deployed contract as the manifest describes
+    Init {
+        contract: String,
+        #[arg(long)]
+        confirm: Option<String>,
+    },
+    /// Print the persisted deployment state
+    Status,
+    /// Check every contract is initialized from the currently built WASM
+    Verify,
+    /// Print deployed contract addresses
+    ExportAddresses {
+        #[arg(long, value_enum, default_value_t = Format::Json)]
+        format: Format,
+        /// Write to a file instead of stdout
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// `{ "coin_flip": "C..." }`
+    Json,
+    /// `COIN_FLIP_CONTRACT_ID=C...`
+    Env,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    if let Err(e) = run(cli) {
+        eprintln!("ERROR: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let config = DeployConfig::load(&cli.config)?;
+    let profile = config.profile(&cli.profile)?;
+    let manifest_path = cli.manifest.clone().unwrap_or_else(|| profile.manifest.clone());
+
+    match cli.command {
+        Command::Plan => {
+            let manifest = Manifest::load(&manifest_path)?;
+            print_plan(profile, &manifest)
+        }
+        Command::Deploy { resume, confirm } => {
+            let manifest = Manifest::load(&manifest_path)?;
+            if profile.is_mainnet() {
+                print_plan(profile, &manifest)?;
+            }
+            profile.confirm_mainnet(&cli.profile, confirm.as_deref(), prompt)?;
+
+            let mode = if resume { RunMode::Resume } else { RunMode::Fresh };
+            let report = profile.deployer()?.run(&manifest, &profile.admin, mode)?;
+            print!("{}", report);
+            if report.is_success() {
+                Ok(())
+            } else {
+                Err("Deployment stopped; rerun with --resume once fixed".to_string())
+            }
+        }
+        Command::Init { contract, confirm } => {
+            let manifest = Manifest::load(&manifest_path)?;
+            profile.confirm_mainnet(&cli.profile, confirm.as_deref(), prompt)?;
+            profile
+                .deployer()?
+                .initialize_from_manifest(&manifest, &contract, &profile.admin)
+        }
+        Command::Status => print_status(profile),
+        Command::Verify => {
+            let manifest = Manifest::load(&manifest_path)?;
+            let issues = profile
+                .deployer()?
+                .verify(&manifest, &WasmArtifacts::new(&profile.wasm_dir));
+            if issues.is_empty() {
+                println!("All {} contracts verified", manifest.contracts.len());
+                return Ok(());
+            }
+            for issue in &issues {
+                println!("✗ {}", issue);
+            }
+            Err(format!("{} verification issue(s)", issues.len()))
+        }
+        Command::ExportAddresses { format, out } => {
+            let addresses = profile.deployer()?.output.addresses();
+            let rendered = render_addresses(&addresses, format)?;
+            match out {
+                Some(path) => fs::write(&path, rendered).map_err(|e| format!("{}: {}", path.display(), e)),
+                None => {
+                    print!("{}", rendered);
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+fn print_plan(profile: &Profile, manifest: &Manifest) -> Result<(), String> {
+    println!("Plan for {:?} ({})", profile.network, profile.output.display());
+    let deployer = profile.deployer()?;
+    for step in deployer.plan(manifest)? {
+        println!("  {}", step);
+    }
+    for call in deployer.registry_calls(manifest)? {
+        println!("  @ {}", call);
+    }
+    Ok(())
+}
+
+fn print_status(profile: &Profile) -> Result<(), String> {
+    let deployer = profile.deployer()?;
+    let output = &deployer.output;
+    println!("Network: {:?}", output.network);
+    println!("Admin:   {}", output.admin_address);
+    println!("Runs:    {}", output.runs);
+
+    let contracts: BTreeMap<_, _> = output.contracts.iter().collect();
+    for (name, state) in contracts {
+        let line = match state {
+            ContractState::Pending => "pending".to_string(),
+            ContractState::Deployed { address, .. } => format!("deployed     {}", address),
+            ContractState::Initialized { address, .. } => format!("initialized  {}", address),
+            ContractState::Failed(e) => format!("failed       {}", e),
+        };
+        println!("  {:<28} {}", name, line);
+    }
+
+    let last_failure = output
+        .run_log
+        .iter()
+        .rev()
+        .find(|attempt| matches!(attempt.outcome, StepOutcome::Failed(_)));
+    if let Some(attempt) = last_failure {
+        println!(
+            "Last failure: run {} {} {} (attempt {})",
+            attempt.run, attempt.step, attempt.contract, attempt.attempt
+        );
+    }
+    Ok(())
+}
+
+fn render_addresses(addresses: &BTreeMap<String, String>, format: Format) -> Result<String, String> {
+    match format {
+        Format::Json => serde_json::to_string_pretty(addresses)
+            .map(|json| json + "\n")
+            .map_err(|e| e.to_string()),
+        Format::Env => Ok(addresses
+            .iter()
+            .map(|(name, address)| {
+                format!("{}_CONTRACT_ID={}\n", name.replace('-', "_").to_uppercase(), address)
+            })
+            .collect()),
+    }
+}
+
+/// Asks for the profile name on an interactive terminal; `None` otherwise
+fn prompt() -> Option<String> {
+    if !io::stdin().is_terminal() {
+        return None;
+    }
+    print!("This will change MAINNET. Type the profile name to continue: ");
+    io::stdout().flush().ok()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer).ok()?;
+    Some(answer)
+}
@@ -0,0 +1,309 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// A single `init` argument as written in the manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InitArg {
+    /// The deployment admin address
+    Admin,
+    /// The address of another contract in the manifest
+    Contract {
+        #[serde(rename = "ref")]
+        name: String,
+    },
+    /// An address declared in the manifest's `[external]` table
+    External {
+        #[serde(rename = "ref")]
+        name: String,
+    },
+    Address { value: String },
+    U32 { value: u32 },
+    U64 { value: u64 },
+    I128 { value: i64 },
+    Bool { value: bool },
+    Symbol { value: String },
+    String { value: String },
+}
+
+/// An `init` argument with every reference replaced by a concrete value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArgValue {
+    Address(String),
+    U32(u32),
+    U64(u64),
+    I128(i128),
+    Bool(bool),
+    Symbol(String),
+    String(String),
+    /// Hex-encoded `BytesN<32>`
+    Bytes32(String),
+}
+
+impl fmt::Display for ArgValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgValue::Address(value) | ArgValue::Symbol(value) | ArgValue::Bytes32(value) => {
+                write!(f, "{}", value)
+            }
+            ArgValue::U32(value) => write!(f, "{}", value),
+            ArgValue::U64(value) => write!(f, "{}", value),
+            ArgValue::I128(value) => write!(f, "{}", value),
+            ArgValue::Bool(value) => write!(f, "{}", value),
+            ArgValue::String(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+/// How one contract is deployed and initialized
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ContractSpec {
+    /// Function called after deployment; the contract is only deployed if absent
+    #[serde(default)]
+    pub init: Option<String>,
+    #[serde(default)]
+    pub args: Vec<InitArg>,
+    /// Parameter names of `init`, one per argument, for backends that call by name
+    #[serde(default)]
+    pub params: Vec<String>,
+    /// Ordering constraints not already implied by `contract` arguments
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// Documentation link recorded in the metadata registry; defaults to
+    /// the generated `docs/contracts/<name>.md`
+    #[serde(default)]
+    pub docs_uri: Option<String>,
+}
+
+impl ContractSpec {
+    /// Names of manifest contracts that must be deployed before this one
+    pub fn dependencies(&self) -> BTreeSet<String> {
+        let mut deps: BTreeSet<String> = self.depends_on.iter().cloned().collect();
+        for arg in &self.args {
+            if let InitArg::Contract { name } = arg {
+                deps.insert(name.clone());
+            }
+        }
+        deps
+    }
+}
+
+/// Declarative description of a full StellarCade stack deployment
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Addresses that exist outside the manifest, such as token contracts
+    #[serde(default)]
+    pub external: BTreeMap<String, String>,
+    #[serde(default)]
+    pub contracts: BTreeMap<String, ContractSpec>,
+}
+
+impl Manifest {
+    pub fn parse(toml_str: &str) -> Result<Self, String> {
+        let manifest: Manifest = toml::from_str(toml_str).map_err(|e| e.to_string())?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let toml_str = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::parse(&toml_str)
+    }
+
+    /// Checks that every reference points at a declared contract or external address
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, spec) in &self.contracts {
+            if !spec.params.is_empty() && spec.params.len() != spec.args.len() {
+                return Err(format!(
+                    "Contract {} names {} params for {} args",
+                    name,
+                    spec.params.len(),
+                    spec.args.len()
+                ));
+            }
+            for dep in spec.dependencies() {
+                if !self.contracts.contains_key(&dep) {
+                    return Err(format!("Contract {} references unknown contract {}", name, dep));
+                }
+            }
+            for arg in &spec.args {
+                if let InitArg::External { name: external } = arg {
+                    if !self.external.contains_key(external) {
+                        return Err(format!(
+                            "Contract {} references unknown external address {}",
+                            name, external
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Topologically sorts contracts so each is deployed after its dependencies.
+    /// Ties are broken alphabetically so the order is deterministic.
+    pub fn deployment_order(&self) -> Result<Vec<String>, String> {
+        self.validate()?;
+
+        let mut remaining: BTreeMap<&str, BTreeSet<String>> = self
+            .contracts
+            .iter()
+            .map(|(name, spec)| (name.as_str(), spec.dependencies()))
+            .collect();
+        let mut order = Vec::with_capacity(remaining.len());
+
+        while !remaining.is_empty() {
+            let ready: Vec<&str> = remaining
+                .iter()
+                .filter(|(_, deps)| deps.is_empty())
+                .map(|(name, _)| *name)
+                .collect();
+            if ready.is_empty() {
+                return Err(format!("Dependency cycle: {}", self.find_cycle(&remaining)));
+            }
+            for name in ready {
+                remaining.remove(name);
+                for deps in remaining.values_mut() {
+                    deps.remove(name);
+                }
+                order.push(name.to_string());
+            }
+        }
+        Ok(order)
+    }
+
+    /// Walks unresolved dependencies from the first blocked contract until a
+    /// name repeats, returning the loop as `a -> b -> a`
+    fn find_cycle(&self, remaining: &BTreeMap<&str, BTreeSet<String>>) -> String {
+        let mut path: Vec<String> = Vec::new();
+        let mut current = remaining.keys().next().map(|name| name.to_string());
+        while let Some(name) = current {
+            if let Some(start) = path.iter().position(|seen| seen == &name) {
+                let mut cycle = path[start..].to_vec();
+                cycle.push(name);
+                return cycle.join(" -> ");
+            }
+            current = remaining
+                .get(name.as_str())
+                .and_then(|deps| deps.iter().next().cloned());
+            path.push(name);
+        }
+        path.join(" -> ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STACK: &str = r#"
+        [external]
+        token = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC"
+
+        [contracts.random_generator]
+        init = "init"
+        args = [{ type = "admin" }, { type = "admin" }]
+
+        [contracts.coin_flip]
+        init = "init"
+        args = [
+            { type = "admin" },
+            { type = "contract", ref = "random_generator" },
+            { type = "external", ref = "token" },
+            { type = "i128", value = 10 },
+            { type = "i128", value = 1000 },
+            { type = "i128", value = 250 },
+        ]
+    "#;
+
+    #[test]
+    fn test_parse_and_order() {
+        let manifest = Manifest::parse(STACK).unwrap();
+        assert_eq!(
+            manifest.contracts["coin_flip"].args[1],
+            InitArg::Contract {
+                name: "random_generator".to_string()
+            }
+        );
+        assert_eq!(
+            manifest.deployment_order().unwrap(),
+            vec!["random_generator".to_string(), "coin_flip".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_unknown_reference_rejected() {
+        let res = Manifest::parse(
+            r#"
+            [contracts.coin_flip]
+            args = [{ type = "contract", ref = "missing" }]
+            "#,
+        );
+        assert_eq!(
+            res,
+            Err("Contract coin_flip references unknown contract missing".to_string())
+        );
+
+        let res = Manifest::parse(
+            r#"
+            [contracts.coin_flip]
+            args = [{ type = "external", ref = "token" }]
+            "#,
+        );
+        assert_eq!(
+            res,
+            Err("Contract coin_flip references unknown external address token".to_string())
+        );
+
+        let res = Manifest::parse(
+            r#"
+            [contracts.coin_flip]
+            args = [{ type = "u32", value = 1 }]
+            params = ["a", "b"]
+            "#,
+        );
+        assert_eq!(res, Err("Contract coin_flip names 2 params for 1 args".to_string()));
+    }
+
+    #[test]
+    fn test_cycle_detected() {
+        let manifest = Manifest::parse(
+            r#"
+            [contracts.a]
+            depends_on = ["b"]
+
+            [contracts.b]
+            args = [{ type = "contract", ref = "c" }]
+
+            [contracts.c]
+            depends_on = ["a"]
+
+            [contracts.d]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.deployment_order(),
+            Err("Dependency cycle: a -> b -> c -> a".to_string())
+        );
+    }
+
+    #[test]
+    fn test_bundled_stack_manifest_is_acyclic() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("manifests/stellarcade.toml");
+        let manifest = Manifest::load(path).unwrap();
+        let order = manifest.deployment_order().unwrap();
+        let position = |name: &str| order.iter().position(|n| n == name).unwrap();
+        assert!(position("random_generator") < position("coin_flip"));
+        assert!(position("treasury") < position("settlement_queue"));
+        assert!(position("reward_distribution") < position("settlement_queue"));
+        assert!(position("fee_management") < position("tournament_system"));
+        assert!(manifest
+            .contracts
+            .values()
+            .all(|spec| spec.params.len() == spec.args.len()));
+    }
+}
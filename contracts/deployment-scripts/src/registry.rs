@@ -0,0 +1,109 @@
+use crate::manifest::ArgValue;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Manifest name of the `ContractAddressRegistry` contract
+pub const ADDRESS_REGISTRY: &str = "contract_address_registry";
+/// Manifest name of the `ContractMetadataRegistry` contract
+pub const METADATA_REGISTRY: &str = "contract_metadata_registry";
+
+/// Name a contract is registered under; the registries use kebab-case
+/// (`prize-pool`) while manifests use snake_case
+pub fn registry_name(name: &str) -> String {
+    name.replace('_', "-")
+}
+
+/// What was last written to the registries for one contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisteredContract {
+    pub version: u32,
+    pub address: String,
+    pub wasm_hash: String,
+}
+
+/// A registration that stopped partway: the record being written and the
+/// registries that already hold it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialRegistration {
+    pub record: RegisteredContract,
+    pub registries: Vec<String>,
+}
+
+/// A call into one of the registries, emitted for review and executed
+/// through the deploy backend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryCall {
+    /// Contract the call registers
+    pub contract: String,
+    /// Manifest name of the registry being called
+    pub registry: String,
+    pub function: String,
+    pub params: Vec<String>,
+    pub args: Vec<ArgValue>,
+}
+
+impl fmt::Display for RegistryCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<String> = self
+            .params
+            .iter()
+            .zip(&self.args)
+            .map(|(param, arg)| format!("{}={}", param, arg))
+            .collect();
+        write!(f, "{}.{}({})", self.registry, self.function, args.join(", "))
+    }
+}
+
+/// A problem flagged by `ContractAddressRegistry::validation_report`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryIssue {
+    pub contract_name: String,
+    pub issue_type: String,
+    pub details: String,
+}
+
+impl fmt::Display for RegistryIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.issue_type, self.contract_name, self.details)
+    }
+}
+
+/// Mirrors of the address registry's report types, used to decode
+/// `validation_report` results
+pub mod abi {
+    use soroban_sdk::{contracttype, String, Vec};
+
+    #[contracttype(export = false)]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum IssueType {
+        Missing = 1,
+        Duplicate = 2,
+        Placeholder = 3,
+    }
+
+    #[contracttype(export = false)]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct RegistryIssue {
+        pub contract_name: String,
+        pub issue_type: IssueType,
+        pub details: String,
+    }
+
+    #[contracttype(export = false)]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ValidationReport {
+        pub timestamp: u32,
+        pub issues: Vec<RegistryIssue>,
+        pub total_checked: u32,
+    }
+}
+
+/// Renders an `IssueType` discriminant the way the contract names it
+pub fn issue_type_name(issue_type: u32) -> String {
+    match issue_type {
+        1 => "Missing".to_string(),
+        2 => "Duplicate".to_string(),
+        3 => "Placeholder".to_string(),
+        other => format!("Issue({})", other),
+    }
+}
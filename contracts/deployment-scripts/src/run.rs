@@ -0,0 +1,149 @@
+use crate::registry::RegistryCall;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A unit of work the deployer performs for one contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Step {
+    Deploy,
+    Initialize,
+    /// Recording contracts in the address and metadata registries
+    Register,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Deploy => write!(f, "deploy"),
+            Step::Initialize => write!(f, "initialize"),
+            Step::Register => write!(f, "register"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StepOutcome {
+    Succeeded,
+    Failed(String),
+}
+
+/// One entry of the persisted run log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepAttempt {
+    /// Run that made the attempt; 0 for steps invoked outside `Deployer::run`
+    pub run: u32,
+    pub contract: String,
+    pub step: Step,
+    /// 1-based count of attempts at this step for this contract, across runs
+    pub attempt: u32,
+    pub outcome: StepOutcome,
+    pub timestamp: u64,
+}
+
+/// How `Deployer::run` treats leftovers from earlier runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunMode {
+    /// Refuses to start while any manifest contract is deployed but not yet initialized
+    Fresh,
+    /// Skips completed steps and retries `Pending`, `Failed` and uninitialized contracts
+    Resume,
+}
+
+/// A step that failed and stopped the run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepFailure {
+    pub contract: String,
+    pub step: Step,
+    pub error: String,
+}
+
+/// Outcome of a run. Soroban deployments cannot be undone, so on failure the
+/// report lists what this run changed and what a resumed run still has to do.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RunReport {
+    pub run: u32,
+    /// Contracts deployed and initialized during this run
+    pub completed: Vec<String>,
+    /// Contracts that were already initialized before this run
+    pub skipped: Vec<String>,
+    pub failure: Option<StepFailure>,
+    /// Contracts not reached because of the failure, in deployment order
+    pub remaining: Vec<String>,
+    /// Addresses of the manifest's deployed contracts, including earlier runs
+    pub addresses: BTreeMap<String, String>,
+    /// Registry updates made after all contracts were initialized
+    pub registry_calls: Vec<RegistryCall>,
+}
+
+impl RunReport {
+    pub fn is_success(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl fmt::Display for RunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Run {}: {} completed, {} skipped",
+            self.run,
+            self.completed.len(),
+            self.skipped.len()
+        )?;
+        for call in &self.registry_calls {
+            writeln!(f, "Registered: {}", call)?;
+        }
+        if let Some(failure) = &self.failure {
+            writeln!(
+                f,
+                "FAILED: {} {}: {}",
+                failure.step, failure.contract, failure.error
+            )?;
+            if let Some(address) = self.addresses.get(&failure.contract) {
+                writeln!(f, "{} is deployed at {} but not initialized", failure.contract, address)?;
+            }
+            writeln!(f, "Changed in this run: [{}]", self.completed.join(", "))?;
+            writeln!(
+                f,
+                "Not reached: [{}]; state kept, rerun in resume mode to continue",
+                self.remaining.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// What a run would do with one contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanAction {
+    /// Not in the state file yet
+    Deploy,
+    /// Deployed but not yet initialized
+    Initialize,
+    /// Left `Pending` or `Failed` by an earlier run
+    Retry(String),
+    /// Already initialized
+    UpToDate,
+    /// Present in the state file but not in the manifest
+    Untracked,
+}
+
+/// One line of the diff between a manifest and the persisted state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedStep {
+    pub contract: String,
+    pub action: PlanAction,
+}
+
+impl fmt::Display for PlannedStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.action {
+            PlanAction::Deploy => write!(f, "+ {} (deploy, initialize)", self.contract),
+            PlanAction::Initialize => write!(f, "~ {} (initialize)", self.contract),
+            PlanAction::Retry(reason) => write!(f, "! {} (retry: {})", self.contract, reason),
+            PlanAction::UpToDate => write!(f, "= {}", self.contract),
+            PlanAction::Untracked => write!(f, "? {} (not in manifest)", self.contract),
+        }
+    }
+}
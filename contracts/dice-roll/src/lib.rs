@@ -9,6 +9,9 @@
 //! 2. Oracle fulfills randomness on the RNG contract (off-chain step).
 //! 3. Anyone calls `resolve_roll` → reads RNG result, settles payout.
 //!
+//! If the RNG request expires before the oracle fulfills it, anyone can call
+//! `refund_expired` to return the wager to the player instead.
+//!
 //! ## Payout
 //! A correct prediction pays `6 * wager - fee`, where the fee is
 //! `wager * house_edge_bps / 10000` applied to the winnings portion
@@ -23,7 +26,7 @@
 
 use soroban_sdk::{
     contract, contracterror, contractevent, contractimpl, contracttype, token::TokenClient,
-    Address, Env,
+    Address, BytesN, Env,
 };
 
 use stellarcade_random_generator::RandomGeneratorClient;
@@ -64,6 +67,7 @@ pub enum Error {
     WagerTooHigh = 11,
     Overflow = 12,
     InvalidWagerRange = 13,
+    RngNotExpired = 14,
 }
 
 // ---------------------------------------------------------------------------
@@ -92,6 +96,7 @@ pub struct Roll {
     pub won: bool,
     pub result: u32,
     pub payout: i128,
+    pub refunded: bool,
 }
 
 #[contracttype]
@@ -126,6 +131,15 @@ pub struct RollResolved {
     pub payout: i128,
 }
 
+#[contractevent]
+pub struct RollRefunded {
+    #[topic]
+    pub game_id: u64,
+    #[topic]
+    pub player: Address,
+    pub amount: i128,
+}
+
 // ---------------------------------------------------------------------------
 // Contract
 // ---------------------------------------------------------------------------
@@ -170,12 +184,14 @@ impl DiceRoll {
     /// A randomness request is submitted to the RNG contract.
     ///
     /// `prediction`: the die face the player predicts (1–6).
+    /// `client_seed`: optional player entropy mixed into the RNG result.
     pub fn roll(
         env: Env,
         player: Address,
         prediction: u32,
         wager: i128,
         game_id: u64,
+        client_seed: Option<BytesN<32>>,
     ) -> Result<(), Error> {
         require_initialized(&env)?;
         player.require_auth();
@@ -211,6 +227,7 @@ impl DiceRoll {
             &env.current_contract_address(),
             &game_id,
             &DIE_SIDES,
+            &client_seed,
         );
 
         // Store game state
@@ -222,6 +239,7 @@ impl DiceRoll {
             won: false,
             result: 0,
             payout: 0,
+            refunded: false,
         };
         env.storage().persistent().set(&game_key, &roll);
         env.storage().persistent().extend_ttl(
@@ -336,6 +354,54 @@ impl DiceRoll {
         Ok(())
     }
 
+    /// Return the wager of a roll whose RNG request expired unfulfilled.
+    /// Anyone can call this; the wager only ever goes back to the player.
+    pub fn refund_expired(env: Env, game_id: u64) -> Result<(), Error> {
+        require_initialized(&env)?;
+
+        let game_key = DataKey::Game(game_id);
+        let mut roll: Roll = env
+            .storage()
+            .persistent()
+            .get(&game_key)
+            .ok_or(Error::GameNotFound)?;
+
+        if roll.resolved {
+            return Err(Error::GameAlreadyResolved);
+        }
+
+        let rng_addr: Address = env.storage().instance().get(&DataKey::RngContract).unwrap();
+        if !RandomGeneratorClient::new(&env, &rng_addr).is_expired(&game_id) {
+            return Err(Error::RngNotExpired);
+        }
+
+        // Update state before transfer (reentrancy-safe)
+        roll.resolved = true;
+        roll.refunded = true;
+        env.storage().persistent().set(&game_key, &roll);
+        env.storage().persistent().extend_ttl(
+            &game_key,
+            PERSISTENT_BUMP_LEDGERS,
+            PERSISTENT_BUMP_LEDGERS,
+        );
+
+        let token = get_token(&env);
+        TokenClient::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &roll.player,
+            &roll.wager,
+        );
+
+        RollRefunded {
+            game_id,
+            player: roll.player,
+            amount: roll.wager,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
     /// View a roll's state.
     pub fn get_roll(env: Env, game_id: u64) -> Result<Roll, Error> {
         env.storage()
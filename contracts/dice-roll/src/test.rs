@@ -2,9 +2,9 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::Address as _,
+    testutils::{Address as _, Ledger as _},
     token::{StellarAssetClient, TokenClient},
-    Address, BytesN, Env,
+    Address, Bytes, BytesN, Env,
 };
 use stellarcade_random_generator::{RandomGenerator, RandomGeneratorClient};
 
@@ -33,7 +33,18 @@ struct Setup<'a> {
     token_sac: StellarAssetClient<'a>,
 }
 
+/// `setup_without_chain` plus seed capacity for rolls that are never
+/// fulfilled. A chain can only be replaced once it is exhausted, so tests
+/// that fulfill start from `setup_without_chain` and commit their own seed
+/// with `commit_seed`.
 fn setup(env: &Env) -> Setup<'_> {
+    let s = setup_without_chain(env);
+    s.rng_client
+        .commit_seed_chain(&s.oracle, &seed(env, 0xFF), &64);
+    s
+}
+
+fn setup_without_chain(env: &Env) -> Setup<'_> {
     let admin = Address::generate(env);
     let oracle = Address::generate(env);
     let token_admin = Address::generate(env);
@@ -53,7 +64,6 @@ fn setup(env: &Env) -> Setup<'_> {
     // Init RNG and authorize dice roll as a caller
     rng_client.init(&admin, &oracle);
     rng_client.authorize(&admin, &dice_id);
-
     // Init DiceRoll: min=10, max=1000, house edge 250 bps (2.5%)
     dice_client.init(&admin, &rng_id, &token_addr, &10i128, &1000i128, &250i128);
 
@@ -70,13 +80,18 @@ fn setup(env: &Env) -> Setup<'_> {
     }
 }
 
+/// Commit a one-seed chain so the next roll is fulfilled with `server_seed`.
+fn commit_seed(env: &Env, s: &Setup, server_seed: &BytesN<32>) {
+    let tip: BytesN<32> = env.crypto().sha256(&Bytes::from(server_seed)).into();
+    s.rng_client.commit_seed_chain(&s.oracle, &tip, &1);
+}
+
 fn tc<'a>(env: &'a Env, token: &Address) -> TokenClient<'a> {
     TokenClient::new(env, token)
 }
 
 /// Reproduce the RNG derivation to find seeds that produce desired outcomes.
 fn derive_rng_result(env: &Env, server_seed: &BytesN<32>, request_id: u64, max: u64) -> u64 {
-    use soroban_sdk::Bytes;
     let mut preimage = [0u8; 40];
     preimage[..32].copy_from_slice(&server_seed.to_array());
     preimage[32..].copy_from_slice(&request_id.to_be_bytes());
@@ -137,7 +152,7 @@ fn test_roll_stores_game() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.dice_client.roll(&player, &3u32, &100, &1u64);
+    s.dice_client.roll(&player, &3u32, &100, &1u64, &None);
 
     let roll = s.dice_client.get_roll(&1u64);
     assert_eq!(roll.player, player);
@@ -158,7 +173,7 @@ fn test_roll_transfers_wager_from_player() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.dice_client.roll(&player, &1u32, &100, &1u64);
+    s.dice_client.roll(&player, &1u32, &100, &1u64, &None);
 
     assert_eq!(tc(&env, &s.token_addr).balance(&player), 400);
 }
@@ -170,7 +185,7 @@ fn test_roll_transfers_wager_from_player() {
 #[test]
 fn test_resolve_win() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -179,10 +194,12 @@ fn test_resolve_win() {
     let prediction = 4u32;
     let game_id = 1u64;
 
-    s.dice_client.roll(&player, &prediction, &100, &game_id);
-
     // Find a seed that produces die face = prediction
     let winning_seed = find_seed_for_face(&env, game_id, prediction);
+    commit_seed(&env, &s, &winning_seed);
+
+    s.dice_client
+        .roll(&player, &prediction, &100, &game_id, &None);
     s.rng_client
         .fulfill_random(&s.oracle, &game_id, &winning_seed);
     s.dice_client.resolve_roll(&game_id);
@@ -205,7 +222,7 @@ fn test_resolve_win() {
 #[test]
 fn test_resolve_loss() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -214,8 +231,6 @@ fn test_resolve_loss() {
     let prediction = 3u32;
     let game_id = 2u64;
 
-    s.dice_client.roll(&player, &prediction, &100, &game_id);
-
     // Find a seed that does NOT produce the predicted face
     let mut losing_seed = seed(&env, 0);
     for i in 0u8..=255 {
@@ -226,6 +241,10 @@ fn test_resolve_loss() {
             break;
         }
     }
+    commit_seed(&env, &s, &losing_seed);
+
+    s.dice_client
+        .roll(&player, &prediction, &100, &game_id, &None);
 
     s.rng_client
         .fulfill_random(&s.oracle, &game_id, &losing_seed);
@@ -255,7 +274,7 @@ fn test_all_faces_valid() {
 
     for face in MIN_FACE..=MAX_FACE {
         let game_id = face as u64;
-        s.dice_client.roll(&player, &face, &100, &game_id);
+        s.dice_client.roll(&player, &face, &100, &game_id, &None);
         let roll = s.dice_client.get_roll(&game_id);
         assert_eq!(roll.prediction, face);
     }
@@ -274,7 +293,7 @@ fn test_prediction_zero_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    let result = s.dice_client.try_roll(&player, &0u32, &100, &1u64);
+    let result = s.dice_client.try_roll(&player, &0u32, &100, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -287,7 +306,7 @@ fn test_prediction_seven_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    let result = s.dice_client.try_roll(&player, &7u32, &100, &1u64);
+    let result = s.dice_client.try_roll(&player, &7u32, &100, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -300,7 +319,7 @@ fn test_prediction_large_value_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    let result = s.dice_client.try_roll(&player, &255u32, &100, &1u64);
+    let result = s.dice_client.try_roll(&player, &255u32, &100, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -317,8 +336,8 @@ fn test_duplicate_game_id_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.dice_client.roll(&player, &1u32, &100, &1u64);
-    let result = s.dice_client.try_roll(&player, &5u32, &100, &1u64);
+    s.dice_client.roll(&player, &1u32, &100, &1u64, &None);
+    let result = s.dice_client.try_roll(&player, &5u32, &100, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -335,7 +354,7 @@ fn test_wager_too_low_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    let result = s.dice_client.try_roll(&player, &1u32, &5, &1u64); // min is 10
+    let result = s.dice_client.try_roll(&player, &1u32, &5, &1u64, &None); // min is 10
     assert!(result.is_err());
 }
 
@@ -348,7 +367,7 @@ fn test_wager_too_high_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &5000);
 
-    let result = s.dice_client.try_roll(&player, &1u32, &1001, &1u64); // max is 1000
+    let result = s.dice_client.try_roll(&player, &1u32, &1001, &1u64, &None); // max is 1000
     assert!(result.is_err());
 }
 
@@ -359,7 +378,7 @@ fn test_zero_wager_rejected() {
     env.mock_all_auths();
 
     let player = Address::generate(&env);
-    let result = s.dice_client.try_roll(&player, &1u32, &0, &1u64);
+    let result = s.dice_client.try_roll(&player, &1u32, &0, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -370,7 +389,9 @@ fn test_negative_wager_rejected() {
     env.mock_all_auths();
 
     let player = Address::generate(&env);
-    let result = s.dice_client.try_roll(&player, &1u32, &-50i128, &1u64);
+    let result = s
+        .dice_client
+        .try_roll(&player, &1u32, &-50i128, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -387,7 +408,7 @@ fn test_resolve_before_rng_rejected() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.dice_client.roll(&player, &1u32, &100, &1u64);
+    s.dice_client.roll(&player, &1u32, &100, &1u64, &None);
 
     let result = s.dice_client.try_resolve_roll(&1u64);
     assert!(result.is_err());
@@ -400,15 +421,16 @@ fn test_resolve_before_rng_rejected() {
 #[test]
 fn test_double_resolve_rejected() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.dice_client.roll(&player, &1u32, &100, &1u64);
-
     let rng_seed = seed(&env, 42);
+    commit_seed(&env, &s, &rng_seed);
+    s.dice_client.roll(&player, &1u32, &100, &1u64, &None);
+
     s.rng_client.fulfill_random(&s.oracle, &1u64, &rng_seed);
     s.dice_client.resolve_roll(&1u64);
 
@@ -453,8 +475,8 @@ fn test_multiple_games() {
     s.token_sac.mint(&player1, &500);
     s.token_sac.mint(&player2, &500);
 
-    s.dice_client.roll(&player1, &2u32, &100, &10u64);
-    s.dice_client.roll(&player2, &5u32, &200, &20u64);
+    s.dice_client.roll(&player1, &2u32, &100, &10u64, &None);
+    s.dice_client.roll(&player2, &5u32, &200, &20u64, &None);
 
     let r1 = s.dice_client.get_roll(&10u64);
     let r2 = s.dice_client.get_roll(&20u64);
@@ -473,7 +495,7 @@ fn test_multiple_games() {
 #[test]
 fn test_die_face_mapping() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -482,9 +504,11 @@ fn test_die_face_mapping() {
     // Test each possible RNG result maps to the correct face
     for target_face in 1u32..=6 {
         let game_id = (100 + target_face) as u64;
-        s.dice_client.roll(&player, &target_face, &10, &game_id);
-
         let winning_seed = find_seed_for_face(&env, game_id, target_face);
+        commit_seed(&env, &s, &winning_seed);
+        s.dice_client
+            .roll(&player, &target_face, &10, &game_id, &None);
+
         s.rng_client
             .fulfill_random(&s.oracle, &game_id, &winning_seed);
         s.dice_client.resolve_roll(&game_id);
@@ -502,7 +526,7 @@ fn test_die_face_mapping() {
 #[test]
 fn test_payout_math_250bps() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -512,9 +536,11 @@ fn test_payout_math_250bps() {
     let prediction = 2u32;
     let game_id = 50u64;
 
-    s.dice_client.roll(&player, &prediction, &wager, &game_id);
-
     let winning_seed = find_seed_for_face(&env, game_id, prediction);
+    commit_seed(&env, &s, &winning_seed);
+    s.dice_client
+        .roll(&player, &prediction, &wager, &game_id, &None);
+
     s.rng_client
         .fulfill_random(&s.oracle, &game_id, &winning_seed);
     s.dice_client.resolve_roll(&game_id);
@@ -541,7 +567,7 @@ fn test_exact_min_wager_accepted() {
     s.token_sac.mint(&player, &500);
 
     // min wager is 10
-    s.dice_client.roll(&player, &1u32, &10, &1u64);
+    s.dice_client.roll(&player, &1u32, &10, &1u64, &None);
     let roll = s.dice_client.get_roll(&1u64);
     assert_eq!(roll.wager, 10);
 }
@@ -556,7 +582,7 @@ fn test_exact_max_wager_accepted() {
     s.token_sac.mint(&player, &5000);
 
     // max wager is 1000
-    s.dice_client.roll(&player, &6u32, &1000, &1u64);
+    s.dice_client.roll(&player, &6u32, &1000, &1u64, &None);
     let roll = s.dice_client.get_roll(&1u64);
     assert_eq!(roll.wager, 1000);
 }
@@ -600,15 +626,15 @@ fn test_updated_wager_boundaries_enforced() {
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &1_000);
 
-    s.dice_client.roll(&player, &2u32, &25, &101u64);
-    s.dice_client.roll(&player, &3u32, &250, &102u64);
+    s.dice_client.roll(&player, &2u32, &25, &101u64, &None);
+    s.dice_client.roll(&player, &3u32, &250, &102u64, &None);
     assert!(s
         .dice_client
-        .try_roll(&player, &4u32, &24, &103u64)
+        .try_roll(&player, &4u32, &24, &103u64, &None)
         .is_err());
     assert!(s
         .dice_client
-        .try_roll(&player, &5u32, &251, &104u64)
+        .try_roll(&player, &5u32, &251, &104u64, &None)
         .is_err());
 }
 
@@ -619,7 +645,7 @@ fn test_updated_wager_boundaries_enforced() {
 #[test]
 fn test_win_then_loss() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -627,8 +653,10 @@ fn test_win_then_loss() {
 
     // Game 1: Win
     let prediction1 = 3u32;
-    s.dice_client.roll(&player, &prediction1, &100, &1u64);
     let win_seed = find_seed_for_face(&env, 1u64, prediction1);
+    commit_seed(&env, &s, &win_seed);
+    s.dice_client
+        .roll(&player, &prediction1, &100, &1u64, &None);
     s.rng_client.fulfill_random(&s.oracle, &1u64, &win_seed);
     s.dice_client.resolve_roll(&1u64);
 
@@ -637,7 +665,6 @@ fn test_win_then_loss() {
 
     // Game 2: Loss (predict something different from what the seed gives)
     let prediction2 = 2u32;
-    s.dice_client.roll(&player, &prediction2, &100, &2u64);
     // Find a seed that does NOT produce face 2
     let mut lose_seed = seed(&env, 0);
     for i in 0u8..=255 {
@@ -648,6 +675,9 @@ fn test_win_then_loss() {
             break;
         }
     }
+    commit_seed(&env, &s, &lose_seed);
+    s.dice_client
+        .roll(&player, &prediction2, &100, &2u64, &None);
     s.rng_client.fulfill_random(&s.oracle, &2u64, &lose_seed);
     s.dice_client.resolve_roll(&2u64);
 
@@ -662,14 +692,15 @@ fn test_win_then_loss() {
 #[test]
 fn test_predict_face_1_win() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.dice_client.roll(&player, &1u32, &100, &1u64);
     let winning_seed = find_seed_for_face(&env, 1u64, 1);
+    commit_seed(&env, &s, &winning_seed);
+    s.dice_client.roll(&player, &1u32, &100, &1u64, &None);
     s.rng_client.fulfill_random(&s.oracle, &1u64, &winning_seed);
     s.dice_client.resolve_roll(&1u64);
 
@@ -681,14 +712,15 @@ fn test_predict_face_1_win() {
 #[test]
 fn test_predict_face_6_win() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
-    s.dice_client.roll(&player, &6u32, &100, &1u64);
     let winning_seed = find_seed_for_face(&env, 1u64, 6);
+    commit_seed(&env, &s, &winning_seed);
+    s.dice_client.roll(&player, &6u32, &100, &1u64, &None);
     s.rng_client.fulfill_random(&s.oracle, &1u64, &winning_seed);
     s.dice_client.resolve_roll(&1u64);
 
@@ -704,7 +736,7 @@ fn test_predict_face_6_win() {
 #[test]
 fn test_loss_stores_actual_result() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -713,11 +745,13 @@ fn test_loss_stores_actual_result() {
     let prediction = 4u32;
     let game_id = 7u64;
 
-    s.dice_client.roll(&player, &prediction, &100, &game_id);
-
     // Pick a seed that gives a known face different from prediction
     let target_face = if prediction == 1 { 2u32 } else { 1u32 };
     let losing_seed = find_seed_for_face(&env, game_id, target_face);
+    commit_seed(&env, &s, &losing_seed);
+
+    s.dice_client
+        .roll(&player, &prediction, &100, &game_id, &None);
 
     s.rng_client
         .fulfill_random(&s.oracle, &game_id, &losing_seed);
@@ -729,3 +763,71 @@ fn test_loss_stores_actual_result() {
     assert_eq!(roll.result, target_face);
     assert_eq!(roll.payout, 0);
 }
+
+// ---------------------------------------------------------------------------
+// 19. Player client seed reaches the RNG request
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_client_seed_forwarded_to_rng() {
+    let env = Env::default();
+    let s = setup_without_chain(&env);
+    env.mock_all_auths();
+
+    let player = Address::generate(&env);
+    s.token_sac.mint(&player, &500);
+
+    let client_seed = seed(&env, 0x42);
+    let server_seed = seed(&env, 7);
+    commit_seed(&env, &s, &server_seed);
+    s.dice_client
+        .roll(&player, &3u32, &100, &1u64, &Some(client_seed.clone()));
+    s.rng_client.fulfill_random(&s.oracle, &1u64, &server_seed);
+
+    let entry = s.rng_client.get_result(&1u64);
+    assert_eq!(entry.client_seed, Some(client_seed));
+    assert!(s.rng_client.verify_result(&1u64));
+
+    s.dice_client.resolve_roll(&1u64);
+    assert_eq!(
+        s.dice_client.get_roll(&1u64).result,
+        entry.result as u32 + 1
+    );
+}
+
+// ---------------------------------------------------------------------------
+// 20. Expired RNG request refunds the wager
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_refund_expired() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let player = Address::generate(&env);
+    s.token_sac.mint(&player, &500);
+    s.dice_client.roll(&player, &3u32, &100, &1u64, &None);
+
+    assert_eq!(
+        s.dice_client.try_refund_expired(&1u64),
+        Err(Ok(Error::RngNotExpired))
+    );
+
+    let timeout = s.rng_client.get_request_timeout();
+    env.ledger().with_mut(|l| l.sequence_number += timeout);
+    s.dice_client.refund_expired(&1u64);
+
+    assert_eq!(tc(&env, &s.token_addr).balance(&player), 500);
+    let roll = s.dice_client.get_roll(&1u64);
+    assert!(roll.resolved);
+    assert!(roll.refunded);
+    assert_eq!(
+        s.dice_client.try_refund_expired(&1u64),
+        Err(Ok(Error::GameAlreadyResolved))
+    );
+    assert_eq!(
+        s.dice_client.try_resolve_roll(&1u64),
+        Err(Ok(Error::GameAlreadyResolved))
+    );
+}
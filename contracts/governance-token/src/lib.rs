@@ -13,6 +13,7 @@ pub enum Error {
     InsufficientBalance = 3,
     InvalidAmount = 4,
     Overflow = 5,
+    FutureLookup = 6,
 }
 
 #[contracttype]
@@ -24,6 +25,44 @@ pub enum DataKey {
     Decimals,
     Balance(Address),
     TotalSupply,
+    Delegate(Address),
+    /// `(delegate, index)`: one voting power checkpoint per entry.
+    Checkpoint(Address, u32),
+    NumCheckpoints(Address),
+    /// `index`: one total supply checkpoint per entry.
+    TotalSupplyCheckpoint(u32),
+    NumTotalSupplyCheckpoints,
+}
+
+/// Voting power of an account (or the total supply) as of `ledger`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub votes: i128,
+}
+
+/// A ledger-ordered series of checkpoints, stored one per persistent entry
+/// so no single entry grows with the history.
+enum Series {
+    Votes(Address),
+    TotalSupply,
+}
+
+impl Series {
+    fn count_key(&self) -> DataKey {
+        match self {
+            Series::Votes(account) => DataKey::NumCheckpoints(account.clone()),
+            Series::TotalSupply => DataKey::NumTotalSupplyCheckpoints,
+        }
+    }
+
+    fn entry_key(&self, index: u32) -> DataKey {
+        match self {
+            Series::Votes(account) => DataKey::Checkpoint(account.clone(), index),
+            Series::TotalSupply => DataKey::TotalSupplyCheckpoint(index),
+        }
+    }
 }
 
 // ── Events ────────────────────────────────────────────────────────
@@ -59,6 +98,22 @@ pub struct TokenTransferred {
     pub amount: i128,
 }
 
+#[contractevent]
+pub struct DelegateChanged {
+    #[topic]
+    pub delegator: Address,
+    pub from_delegate: Address,
+    pub to_delegate: Address,
+}
+
+#[contractevent]
+pub struct DelegateVotesChanged {
+    #[topic]
+    pub delegate: Address,
+    pub previous_votes: i128,
+    pub new_votes: i128,
+}
+
 #[contract]
 pub struct GovernanceToken;
 
@@ -113,6 +168,10 @@ impl GovernanceToken {
         let total_supply = Self::total_supply(env.clone());
         let new_total_supply = total_supply.checked_add(amount).ok_or(Error::Overflow)?;
         env.storage().instance().set(&DataKey::TotalSupply, &new_total_supply);
+        write_checkpoint(&env, &Series::TotalSupply, new_total_supply);
+
+        let to_delegate = Self::delegates(env.clone(), to.clone());
+        move_voting_power(&env, None, Some(&to_delegate), amount)?;
 
         TokenMinted { to, amount }.publish(&env);
         Ok(())
@@ -139,6 +198,10 @@ impl GovernanceToken {
         let total_supply = Self::total_supply(env.clone());
         let new_total_supply = total_supply.checked_sub(amount).ok_or(Error::Overflow)?;
         env.storage().instance().set(&DataKey::TotalSupply, &new_total_supply);
+        write_checkpoint(&env, &Series::TotalSupply, new_total_supply);
+
+        let from_delegate = Self::delegates(env.clone(), from.clone());
+        move_voting_power(&env, Some(&from_delegate), None, amount)?;
 
         TokenBurned { from, amount }.publish(&env);
         Ok(())
@@ -163,10 +226,77 @@ impl GovernanceToken {
         let new_balance_to = balance_to.checked_add(amount).ok_or(Error::Overflow)?;
         env.storage().persistent().set(&DataKey::Balance(to.clone()), &new_balance_to);
 
+        let from_delegate = Self::delegates(env.clone(), from.clone());
+        let to_delegate = Self::delegates(env.clone(), to.clone());
+        move_voting_power(&env, Some(&from_delegate), Some(&to_delegate), amount)?;
+
         TokenTransferred { from, to, amount }.publish(&env);
         Ok(())
     }
 
+    /// Delegates the voting power of `from` to `to`. Requires `from` authorization.
+    ///
+    /// Accounts that never delegated vote with their own balance, so delegating
+    /// back to oneself restores the default.
+    pub fn delegate(env: Env, from: Address, to: Address) -> Result<(), Error> {
+        from.require_auth();
+
+        let current = Self::delegates(env.clone(), from.clone());
+        if current == to {
+            return Ok(());
+        }
+
+        if to == from {
+            env.storage().persistent().remove(&DataKey::Delegate(from.clone()));
+        } else {
+            env.storage().persistent().set(&DataKey::Delegate(from.clone()), &to);
+        }
+
+        let balance = Self::balance(env.clone(), from.clone());
+        move_voting_power(&env, Some(&current), Some(&to), balance)?;
+
+        DelegateChanged {
+            delegator: from,
+            from_delegate: current,
+            to_delegate: to,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Returns the address `account` currently delegates to (itself by default).
+    pub fn delegates(env: Env, account: Address) -> Address {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Delegate(account.clone()))
+            .unwrap_or(account)
+    }
+
+    /// Returns the current voting power of `account`, including delegated votes.
+    pub fn get_votes(env: Env, account: Address) -> i128 {
+        latest_checkpoint(&env, &Series::Votes(account))
+    }
+
+    /// Returns the voting power `account` held at the end of `ledger`.
+    ///
+    /// `ledger` must already be closed: checkpoints for the current ledger
+    /// can still change, so looking it up fails with `FutureLookup`.
+    pub fn get_past_votes(env: Env, account: Address, ledger: u32) -> Result<i128, Error> {
+        if ledger >= env.ledger().sequence() {
+            return Err(Error::FutureLookup);
+        }
+        Ok(checkpoint_lookup(&env, &Series::Votes(account), ledger))
+    }
+
+    /// Returns the total supply at the end of `ledger`, which must already
+    /// be closed.
+    pub fn get_past_total_supply(env: Env, ledger: u32) -> Result<i128, Error> {
+        if ledger >= env.ledger().sequence() {
+            return Err(Error::FutureLookup);
+        }
+        Ok(checkpoint_lookup(&env, &Series::TotalSupply, ledger))
+    }
+
     pub fn balance(env: Env, id: Address) -> i128 {
         env.storage().persistent().get(&DataKey::Balance(id)).unwrap_or(0i128)
     }
@@ -188,10 +318,116 @@ impl GovernanceToken {
     }
 }
 
+// ── Checkpoint helpers ────────────────────────────────────────────
+
+fn checkpoint_count(env: &Env, series: &Series) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&series.count_key())
+        .unwrap_or(0)
+}
+
+fn read_checkpoint(env: &Env, series: &Series, index: u32) -> Checkpoint {
+    env.storage()
+        .persistent()
+        .get(&series.entry_key(index))
+        .unwrap()
+}
+
+fn latest_checkpoint(env: &Env, series: &Series) -> i128 {
+    match checkpoint_count(env, series) {
+        0 => 0,
+        count => read_checkpoint(env, series, count - 1).votes,
+    }
+}
+
+/// Binary search for the last checkpoint written at or before `ledger`.
+fn checkpoint_lookup(env: &Env, series: &Series, ledger: u32) -> i128 {
+    let mut low = 0u32;
+    let mut high = checkpoint_count(env, series);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if read_checkpoint(env, series, mid).ledger > ledger {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    if low == 0 {
+        0
+    } else {
+        read_checkpoint(env, series, low - 1).votes
+    }
+}
+
+/// Records `votes` for the current ledger, overwriting a checkpoint already
+/// written in the same ledger so each ledger has at most one entry.
+fn write_checkpoint(env: &Env, series: &Series, votes: i128) -> i128 {
+    let ledger = env.ledger().sequence();
+    let count = checkpoint_count(env, series);
+    let last = match count {
+        0 => None,
+        _ => Some(read_checkpoint(env, series, count - 1)),
+    };
+    let (index, previous) = match last {
+        Some(last) if last.ledger == ledger => (count - 1, last.votes),
+        Some(last) => (count, last.votes),
+        None => (0, 0),
+    };
+    env.storage()
+        .persistent()
+        .set(&series.entry_key(index), &Checkpoint { ledger, votes });
+    if index == count {
+        env.storage().persistent().set(&series.count_key(), &(count + 1));
+    }
+    previous
+}
+
+fn move_voting_power(
+    env: &Env,
+    from: Option<&Address>,
+    to: Option<&Address>,
+    amount: i128,
+) -> Result<(), Error> {
+    if from == to || amount == 0 {
+        return Ok(());
+    }
+
+    if let Some(from) = from {
+        let series = Series::Votes(from.clone());
+        let new_votes = latest_checkpoint(env, &series)
+            .checked_sub(amount)
+            .ok_or(Error::Overflow)?;
+        let previous_votes = write_checkpoint(env, &series, new_votes);
+        DelegateVotesChanged {
+            delegate: from.clone(),
+            previous_votes,
+            new_votes,
+        }
+        .publish(env);
+    }
+
+    if let Some(to) = to {
+        let series = Series::Votes(to.clone());
+        let new_votes = latest_checkpoint(env, &series)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        let previous_votes = write_checkpoint(env, &series, new_votes);
+        DelegateVotesChanged {
+            delegate: to.clone(),
+            previous_votes,
+            new_votes,
+        }
+        .publish(env);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+    use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
     use soroban_sdk::{IntoVal};
 
     #[test]
@@ -258,4 +494,125 @@ mod test {
 
         client.mint(&user, &1000);
     }
+
+    #[test]
+    fn test_checkpoints_and_delegation() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+
+        let contract_id = env.register(GovernanceToken, ());
+        let client = GovernanceTokenClient::new(&env, &contract_id);
+        client.init(
+            &admin,
+            &String::from_str(&env, "StellarCade Governance"),
+            &String::from_str(&env, "SCG"),
+            &18,
+        );
+
+        env.ledger().set_sequence_number(10);
+        client.mint(&alice, &1000);
+        client.mint(&bob, &500);
+
+        env.ledger().set_sequence_number(20);
+        client.transfer(&alice, &bob, &400);
+
+        env.ledger().set_sequence_number(30);
+        client.delegate(&bob, &carol);
+        assert_eq!(client.delegates(&bob), carol);
+        assert_eq!(client.get_votes(&bob), 0);
+        assert_eq!(client.get_votes(&carol), 900);
+
+        env.ledger().set_sequence_number(40);
+        client.burn(&alice, &100);
+
+        // Ledger 40 is still open, so it cannot be looked up yet.
+        assert_eq!(
+            client.try_get_past_votes(&alice, &40),
+            Err(Ok(Error::FutureLookup))
+        );
+        assert_eq!(
+            client.try_get_past_total_supply(&40),
+            Err(Ok(Error::FutureLookup))
+        );
+
+        env.ledger().set_sequence_number(41);
+
+        assert_eq!(client.get_past_votes(&alice, &5), 0);
+        assert_eq!(client.get_past_votes(&alice, &10), 1000);
+        assert_eq!(client.get_past_votes(&alice, &25), 600);
+        assert_eq!(client.get_past_votes(&alice, &40), 500);
+        assert_eq!(client.get_past_votes(&bob, &20), 900);
+        assert_eq!(client.get_past_votes(&bob, &30), 0);
+        assert_eq!(client.get_past_votes(&carol, &29), 0);
+        assert_eq!(client.get_past_votes(&carol, &30), 900);
+
+        assert_eq!(client.get_past_total_supply(&9), 0);
+        assert_eq!(client.get_past_total_supply(&10), 1500);
+        assert_eq!(client.get_past_total_supply(&40), 1400);
+
+        // Delegating back to self restores the default.
+        env.ledger().set_sequence_number(50);
+        client.delegate(&bob, &bob);
+        assert_eq!(client.delegates(&bob), bob);
+        assert_eq!(client.get_votes(&bob), 900);
+        assert_eq!(client.get_votes(&carol), 0);
+
+        assert_eq!(
+            client.try_get_past_votes(&alice, &51),
+            Err(Ok(Error::FutureLookup))
+        );
+        assert_eq!(
+            client.try_get_past_total_supply(&51),
+            Err(Ok(Error::FutureLookup))
+        );
+    }
+
+    #[test]
+    fn test_checkpoints_one_per_ledger() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+
+        let contract_id = env.register(GovernanceToken, ());
+        let client = GovernanceTokenClient::new(&env, &contract_id);
+        client.init(
+            &admin,
+            &String::from_str(&env, "StellarCade Governance"),
+            &String::from_str(&env, "SCG"),
+            &18,
+        );
+
+        for ledger in 1..=20u32 {
+            env.ledger().set_sequence_number(ledger);
+            client.mint(&alice, &10);
+            client.transfer(&alice, &bob, &5);
+        }
+        env.ledger().set_sequence_number(21);
+
+        env.as_contract(&contract_id, || {
+            let series = Series::Votes(alice.clone());
+            assert_eq!(checkpoint_count(&env, &series), 20);
+            assert_eq!(checkpoint_count(&env, &Series::TotalSupply), 20);
+            assert_eq!(
+                read_checkpoint(&env, &series, 19),
+                Checkpoint {
+                    ledger: 20,
+                    votes: 100
+                }
+            );
+        });
+        for ledger in 1..=20u32 {
+            assert_eq!(client.get_past_votes(&alice, &ledger), 5 * ledger as i128);
+            assert_eq!(client.get_past_votes(&bob, &ledger), 5 * ledger as i128);
+            assert_eq!(client.get_past_total_supply(&ledger), 10 * ledger as i128);
+        }
+    }
 }
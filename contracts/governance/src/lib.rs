@@ -4,31 +4,56 @@
 //! vote on proposals. Passed proposals enter a timelock queue before execution.
 //!
 //! ## Governance Flow
-//! 1. Proposer calls `propose` with proposal_id and payload_hash
-//! 2. Token holders call `vote` with support (for/against) weighted by holdings
+//! 1. Proposer calls `propose` with proposal_id and payload_hash, or
+//!    `propose_actions` with the on-chain actions to run (hash committed automatically)
+//! 2. Token holders call `vote` (for/against) or `cast_vote` (for/against/abstain,
+//!    with an optional reason) weighted by their checkpointed voting power at
+//!    the proposal's `start_ledger`; a relayer may submit ed25519-signed votes
+//!    on a voter's behalf through `vote_by_sig`
 //! 3. After voting period ends, if quorum + threshold met: anyone calls `queue`
 //! 4. After timelock delay: anyone calls `execute` with payload
-//! 5. Contract verifies payload matches hash and executes admin action
+//! 5. Contract verifies payload matches hash and invokes every committed action
+//!    via `invoke_contract`; a failing action reverts the whole execution
+//!
+//! Proposing can be gated by `set_proposal_config`: a minimum voting power,
+//! a refundable deposit in the governance token (slashed to the treasury if
+//! the proposal misses quorum, settled via `settle_deposit`) and a cap on
+//! concurrently active proposals per proposer.
 //!
 //! ## Security Model
 //! - Timelock prevents instant execution of malicious proposals
-//! - Quorum ensures minimum participation
+//! - Quorum ensures minimum participation (measured against the supply at `start_ledger`)
+//! - Snapshot voting power prevents re-voting the same tokens from another address
 //! - Vote threshold prevents 51% attacks (requires super-majority)
 //! - Payload hash commitment prevents bait-and-switch
 #![no_std]
 #![allow(unexpected_cfgs)]
 
 use soroban_sdk::{
-    contract, contracterror, contractevent, contractimpl, contracttype, token::TokenClient,
-    Address, BytesN, Env,
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype,
+    token::TokenClient, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Symbol, Val, Vec,
 };
 
+// ---------------------------------------------------------------------------
+// External contract clients
+// ---------------------------------------------------------------------------
+
+/// Checkpointed voting interface exposed by `stellarcade-governance-token`.
+#[contractclient(name = "VotesClient")]
+pub trait VotesContract {
+    fn get_past_votes(env: Env, account: Address, ledger: u32) -> i128;
+    fn get_past_total_supply(env: Env, ledger: u32) -> i128;
+}
+
 // ---------------------------------------------------------------------------
 // Constants
 // ---------------------------------------------------------------------------
 
 pub const PERSISTENT_BUMP_LEDGERS: u32 = 518_400;
 
+/// Upper bound on actions per proposal, keeping execution within budget.
+pub const MAX_PROPOSAL_ACTIONS: u32 = 10;
+
 // Proposal states
 pub const STATE_PENDING: u32 = 0;
 pub const STATE_ACTIVE: u32 = 1;
@@ -38,6 +63,14 @@ pub const STATE_QUEUED: u32 = 4;
 pub const STATE_EXECUTED: u32 = 5;
 pub const STATE_CANCELLED: u32 = 6;
 
+// Vote options for `cast_vote`
+pub const VOTE_AGAINST: u32 = 0;
+pub const VOTE_FOR: u32 = 1;
+pub const VOTE_ABSTAIN: u32 = 2;
+
+/// Maximum length in bytes of a vote reason.
+pub const MAX_VOTE_REASON_LEN: u32 = 256;
+
 // ---------------------------------------------------------------------------
 // Error types
 // ---------------------------------------------------------------------------
@@ -60,6 +93,16 @@ pub enum Error {
     AlreadyVoted = 12,
     InvalidPayload = 13,
     Overflow = 14,
+    InvalidActions = 15,
+    InvalidSupport = 16,
+    ReasonTooLong = 17,
+    VoteKeyNotRegistered = 18,
+    InvalidNonce = 19,
+    SignatureExpired = 20,
+    BelowProposalThreshold = 21,
+    TooManyActiveProposals = 22,
+    NoDeposit = 23,
+    InvalidConfig = 24,
 }
 
 // ---------------------------------------------------------------------------
@@ -74,9 +117,45 @@ pub enum DataKey {
     VotingPeriod,  // ledgers
     TimelockDelay, // ledgers
     QuorumBps,     // basis points of total supply
-    ThresholdBps,  // basis points of votes cast
+    ThresholdBps,  // basis points of for + against votes
+    VoteChangeWindow, // ledgers after a first vote during which it may be changed
     Proposal(u64),
     Vote(u64, Address), // (proposal_id, voter)
+    ProposalActions(u64),
+    VoteKey(Address),   // ed25519 public key authorised to sign votes for an address
+    VoteNonce(Address), // next unused signed-vote nonce for an address
+    ProposalConfig,
+    ProposalDeposit(u64), // Deposit held for a proposal
+    ActiveProposals(Address), // proposal ids a proposer may still have open
+}
+
+/// Anti-spam rules applied in `propose` and `propose_actions`.
+/// Zero values disable the corresponding check.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalConfig {
+    pub min_proposal_power: i128,
+    pub deposit_amount: i128,
+    pub max_active_per_proposer: u32,
+    pub treasury: Option<Address>, // receives slashed deposits; required if deposit_amount > 0
+}
+
+/// A deposit held for a proposal, with the treasury that receives it if
+/// slashed. Recorded at creation so later config changes do not affect it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deposit {
+    pub amount: i128,
+    pub treasury: Address,
+}
+
+/// A contract call executed when a proposal passes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalAction {
+    pub target: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
 }
 
 #[contracttype]
@@ -84,14 +163,49 @@ pub enum DataKey {
 pub struct Proposal {
     pub proposer: Address,
     pub payload_hash: BytesN<32>,
-    pub start_ledger: u32,
+    pub start_ledger: u32, // voting power snapshot: the last ledger closed before creation
     pub end_ledger: u32,
     pub for_votes: i128,
     pub against_votes: i128,
+    pub abstain_votes: i128,
     pub state: u32,
     pub eta: u32, // execution timestamp (ledger) after queueing
 }
 
+/// The payload a voter signs for `vote_by_sig`. The signature covers the XDR
+/// encoding of this struct, as returned by `vote_message`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteMessage {
+    pub governance: Address,
+    pub voter: Address,
+    pub proposal_id: u64,
+    pub support: u32,
+    pub nonce: u64,
+    pub expiry_ledger: u32,
+}
+
+/// A signed vote submitted by a relayer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedVote {
+    pub voter: Address,
+    pub proposal_id: u64,
+    pub support: u32,
+    pub nonce: u64,
+    pub expiry_ledger: u32,
+    pub signature: BytesN<64>,
+}
+
+/// A voter's recorded choice on a proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteReceipt {
+    pub support: u32,
+    pub weight: i128,
+    pub cast_ledger: u32, // ledger of the first vote; starts the change window
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProposalSummary {
@@ -100,6 +214,7 @@ pub struct ProposalSummary {
     pub state: u32,
     pub for_votes: i128,
     pub against_votes: i128,
+    pub abstain_votes: i128,
     pub total_votes: i128,
     pub quorum_votes_required: i128,
     pub quorum_votes_remaining: i128,
@@ -126,8 +241,42 @@ pub struct VoteCast {
     pub proposal_id: u64,
     #[topic]
     pub voter: Address,
-    pub support: bool,
+    pub support: u32,
     pub weight: i128,
+    pub reason: Option<String>,
+}
+
+#[contractevent]
+pub struct VoteChanged {
+    #[topic]
+    pub proposal_id: u64,
+    #[topic]
+    pub voter: Address,
+    pub previous_support: u32,
+    pub support: u32,
+}
+
+#[contractevent]
+pub struct VoteKeyRegistered {
+    #[topic]
+    pub voter: Address,
+    pub public_key: BytesN<32>,
+}
+
+#[contractevent]
+pub struct DepositRefunded {
+    #[topic]
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct DepositSlashed {
+    #[topic]
+    pub proposal_id: u64,
+    pub treasury: Address,
+    pub amount: i128,
 }
 
 #[contractevent]
@@ -143,6 +292,15 @@ pub struct ProposalExecuted {
     pub proposal_id: u64,
 }
 
+#[contractevent]
+pub struct ProposalActionExecuted {
+    #[topic]
+    pub proposal_id: u64,
+    pub index: u32,
+    pub target: Address,
+    pub function: Symbol,
+}
+
 #[contractevent]
 pub struct ProposalCancelled {
     #[topic]
@@ -197,7 +355,7 @@ impl Governance {
         Ok(())
     }
 
-    /// Create a new proposal. Anyone can propose.
+    /// Create a new proposal. Anyone meeting the `ProposalConfig` rules can propose.
     ///
     /// `payload_hash`: SHA-256 of the action to execute (verified at execution)
     pub fn propose(
@@ -209,131 +367,227 @@ impl Governance {
         require_initialized(&env)?;
         proposer.require_auth();
 
-        let key = DataKey::Proposal(proposal_id);
-        if env.storage().persistent().has(&key) {
-            return Err(Error::ProposalAlreadyExists);
-        }
+        create_proposal(&env, proposer, proposal_id, payload_hash)
+    }
 
-        let voting_period: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::VotingPeriod)
-            .unwrap();
-        let current_ledger = env.ledger().sequence();
-        let start_ledger = current_ledger;
-        let end_ledger = current_ledger
-            .checked_add(voting_period)
-            .ok_or(Error::Overflow)?;
+    /// Create a proposal that executes `actions` on-chain when it passes.
+    ///
+    /// The actions are stored with the proposal so voters can inspect them via
+    /// `get_proposal_actions`; `payload_hash` is set to `hash_actions(actions)`.
+    pub fn propose_actions(
+        env: Env,
+        proposer: Address,
+        proposal_id: u64,
+        actions: Vec<ProposalAction>,
+    ) -> Result<(), Error> {
+        require_initialized(&env)?;
+        proposer.require_auth();
 
-        let proposal = Proposal {
-            proposer: proposer.clone(),
-            payload_hash: payload_hash.clone(),
-            start_ledger,
-            end_ledger,
-            for_votes: 0,
-            against_votes: 0,
-            state: STATE_ACTIVE,
-            eta: 0,
-        };
+        if actions.is_empty() || actions.len() > MAX_PROPOSAL_ACTIONS {
+            return Err(Error::InvalidActions);
+        }
+
+        let payload_hash = Self::hash_actions(env.clone(), actions.clone());
+        create_proposal(&env, proposer, proposal_id, payload_hash)?;
 
-        env.storage().persistent().set(&key, &proposal);
+        let actions_key = DataKey::ProposalActions(proposal_id);
+        env.storage().persistent().set(&actions_key, &actions);
         env.storage().persistent().extend_ttl(
-            &key,
+            &actions_key,
             PERSISTENT_BUMP_LEDGERS,
             PERSISTENT_BUMP_LEDGERS,
         );
 
-        ProposalCreated {
-            proposal_id,
-            proposer,
-            payload_hash,
-        }
-        .publish(&env);
-
         Ok(())
     }
 
     /// Cast a vote on an active proposal.
     ///
     /// `support`: true = for, false = against
-    /// `weight`: voter's token balance at time of vote (verified on-chain)
+    /// `weight`: voter's voting power (own balance plus delegations) at the
+    /// proposal's `start_ledger`, read from the token's checkpoints
     pub fn vote(env: Env, proposal_id: u64, voter: Address, support: bool) -> Result<(), Error> {
         require_initialized(&env)?;
         voter.require_auth();
 
-        let proposal_key = DataKey::Proposal(proposal_id);
-        let mut proposal: Proposal = env
-            .storage()
-            .persistent()
-            .get(&proposal_key)
-            .ok_or(Error::ProposalNotFound)?;
+        let support = if support { VOTE_FOR } else { VOTE_AGAINST };
+        record_vote(&env, proposal_id, voter, support, None)
+    }
 
-        if proposal.state != STATE_ACTIVE {
-            return Err(Error::InvalidProposalState);
-        }
+    /// Cast a three-way vote with an optional reason.
+    ///
+    /// `support`: `VOTE_AGAINST`, `VOTE_FOR` or `VOTE_ABSTAIN`. Abstentions
+    /// count toward quorum but not toward the approval threshold.
+    ///
+    /// A voter who already voted may call this again to change their vote
+    /// while within `vote_change_window` ledgers of their first vote and
+    /// before `end_ledger`.
+    pub fn cast_vote(
+        env: Env,
+        proposal_id: u64,
+        voter: Address,
+        support: u32,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        require_initialized(&env)?;
+        voter.require_auth();
 
-        let current_ledger = env.ledger().sequence();
-        if current_ledger >= proposal.end_ledger {
-            return Err(Error::VotingPeriodEnded);
+        record_vote(&env, proposal_id, voter, support, reason)
+    }
+
+    /// Register the ed25519 public key allowed to sign votes for `voter`.
+    ///
+    /// Replacing the key does not reset the nonce, so signatures made with
+    /// an earlier key for an unused nonce remain invalid once that nonce is used.
+    pub fn register_vote_key(env: Env, voter: Address, public_key: BytesN<32>) -> Result<(), Error> {
+        require_initialized(&env)?;
+        voter.require_auth();
+
+        let key = DataKey::VoteKey(voter.clone());
+        env.storage().persistent().set(&key, &public_key);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_LEDGERS,
+            PERSISTENT_BUMP_LEDGERS,
+        );
+
+        VoteKeyRegistered { voter, public_key }.publish(&env);
+        Ok(())
+    }
+
+    /// Cast a vote signed off-chain by the voter's registered key. Anyone
+    /// (typically the backend relayer) may submit it and pay the fee.
+    ///
+    /// `nonce` must equal `get_vote_nonce(voter)` and the call must land
+    /// before `expiry_ledger`. An invalid signature aborts the call.
+    pub fn vote_by_sig(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        support: u32,
+        nonce: u64,
+        expiry_ledger: u32,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        require_initialized(&env)?;
+
+        apply_signed_vote(
+            &env,
+            SignedVote {
+                voter,
+                proposal_id,
+                support,
+                nonce,
+                expiry_ledger,
+                signature,
+            },
+        )
+    }
+
+    /// Submit several signed votes in one transaction. The batch is atomic:
+    /// if any vote is rejected none are recorded, so relayers should check
+    /// signatures and nonces off-chain first.
+    pub fn vote_by_sig_batch(env: Env, votes: Vec<SignedVote>) -> Result<(), Error> {
+        require_initialized(&env)?;
+
+        for vote in votes.iter() {
+            apply_signed_vote(&env, vote)?;
         }
+        Ok(())
+    }
 
-        // Check if already voted
-        let vote_key = DataKey::Vote(proposal_id, voter.clone());
-        if env.storage().persistent().has(&vote_key) {
-            return Err(Error::AlreadyVoted);
+    /// Configure proposal thresholds, deposits and the per-proposer active cap.
+    pub fn set_proposal_config(
+        env: Env,
+        admin: Address,
+        config: ProposalConfig,
+    ) -> Result<(), Error> {
+        require_initialized(&env)?;
+        require_admin(&env, &admin)?;
+
+        if config.min_proposal_power < 0
+            || config.deposit_amount < 0
+            || (config.deposit_amount > 0 && config.treasury.is_none())
+        {
+            return Err(Error::InvalidConfig);
         }
 
-        // Get voter's token balance as voting weight
-        let token: Address = env
-            .storage()
+        env.storage()
             .instance()
-            .get(&DataKey::GovernanceToken)
-            .unwrap();
-        let weight = TokenClient::new(&env, &token).balance(&voter);
+            .set(&DataKey::ProposalConfig, &config);
+        Ok(())
+    }
 
-        if weight <= 0 {
-            return Err(Error::NotAuthorized);
-        }
+    /// Return the deposit of a finished proposal. Anyone can call.
+    ///
+    /// Once voting has ended the deposit is refunded to the proposer if the
+    /// proposal reached quorum, or sent to the treasury if it did not.
+    /// Deposits of cancelled proposals are always refunded.
+    pub fn settle_deposit(env: Env, proposal_id: u64) -> Result<(), Error> {
+        require_initialized(&env)?;
 
-        // Record vote
-        if support {
-            proposal.for_votes = proposal
-                .for_votes
-                .checked_add(weight)
-                .ok_or(Error::Overflow)?;
-        } else {
-            proposal.against_votes = proposal
-                .against_votes
-                .checked_add(weight)
-                .ok_or(Error::Overflow)?;
-        }
+        let proposal: Proposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .ok_or(Error::ProposalNotFound)?;
 
-        env.storage().persistent().set(&proposal_key, &proposal);
-        env.storage().persistent().extend_ttl(
-            &proposal_key,
-            PERSISTENT_BUMP_LEDGERS,
-            PERSISTENT_BUMP_LEDGERS,
-        );
+        let deposit_key = DataKey::ProposalDeposit(proposal_id);
+        let deposit: Deposit = env
+            .storage()
+            .persistent()
+            .get(&deposit_key)
+            .ok_or(Error::NoDeposit)?;
+        let amount = deposit.amount;
 
-        // Mark voter as voted
-        env.storage().persistent().set(&vote_key, &true);
-        env.storage().persistent().extend_ttl(
-            &vote_key,
-            PERSISTENT_BUMP_LEDGERS,
-            PERSISTENT_BUMP_LEDGERS,
-        );
+        let reached_quorum = if proposal.state == STATE_CANCELLED {
+            true
+        } else {
+            if proposal.state == STATE_ACTIVE && env.ledger().sequence() < proposal.end_ledger {
+                return Err(Error::VotingPeriodActive);
+            }
+            let total_votes = proposal_total_votes(&proposal).ok_or(Error::Overflow)?;
+            total_votes > 0 && total_votes >= quorum_votes_required(&env, &proposal)
+        };
 
-        VoteCast {
-            proposal_id,
-            voter,
-            support,
-            weight,
+        env.storage().persistent().remove(&deposit_key);
+
+        let token = governance_token(&env);
+        let token_client = TokenClient::new(&env, &token);
+        if reached_quorum {
+            token_client.transfer(&env.current_contract_address(), &proposal.proposer, &amount);
+            DepositRefunded {
+                proposal_id,
+                proposer: proposal.proposer,
+                amount,
+            }
+            .publish(&env);
+        } else {
+            let treasury = deposit.treasury;
+            token_client.transfer(&env.current_contract_address(), &treasury, &amount);
+            DepositSlashed {
+                proposal_id,
+                treasury,
+                amount,
+            }
+            .publish(&env);
         }
-        .publish(&env);
 
         Ok(())
     }
 
+    /// Set how many ledgers after their first vote a voter may change it.
+    /// Zero (the default) disables vote changes.
+    pub fn set_vote_change_window(env: Env, admin: Address, ledgers: u32) -> Result<(), Error> {
+        require_initialized(&env)?;
+        require_admin(&env, &admin)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::VoteChangeWindow, &ledgers);
+        Ok(())
+    }
+
     /// Queue a succeeded proposal into the timelock. Anyone can call.
     ///
     /// Requirements: voting ended, quorum reached, threshold met
@@ -356,32 +610,15 @@ impl Governance {
             return Err(Error::VotingPeriodActive);
         }
 
-        // Check quorum: total votes >= quorum_bps of token supply
-        // Note: In production, query token.total_supply() and compare:
-        // (total_votes * 10000 / total_supply) >= quorum_bps
-        // For now, simplified: just require at least 1 vote
-        let total_votes = proposal
-            .for_votes
-            .checked_add(proposal.against_votes)
-            .ok_or(Error::Overflow)?;
+        // Check quorum: for + against + abstain >= quorum_bps of the supply at start_ledger
+        let total_votes = proposal_total_votes(&proposal).ok_or(Error::Overflow)?;
 
-        if total_votes == 0 {
+        if total_votes == 0 || total_votes < quorum_votes_required(&env, &proposal) {
             return Err(Error::QuorumNotReached);
         }
 
-        // Check threshold: for_votes / total_votes >= threshold_bps
-        let threshold_bps: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ThresholdBps)
-            .unwrap();
-        let for_votes_bps = proposal
-            .for_votes
-            .checked_mul(10_000)
-            .and_then(|v| v.checked_div(total_votes))
-            .ok_or(Error::Overflow)?;
-
-        if for_votes_bps < threshold_bps as i128 {
+        // Check threshold: for_votes / (for_votes + against_votes) >= threshold_bps
+        if !proposal_meets_threshold(&env, &proposal) {
             proposal.state = STATE_DEFEATED;
         } else {
             let timelock_delay: u32 = env
@@ -414,6 +651,9 @@ impl Governance {
     /// Execute a queued proposal after timelock. Anyone can call.
     ///
     /// `payload_hash_verify`: must match stored hash (prevents bait-and-switch)
+    ///
+    /// Proposals created with `propose_actions` invoke each action in order.
+    /// A failing action aborts the call, reverting the whole execution.
     pub fn execute(
         env: Env,
         proposal_id: u64,
@@ -441,6 +681,14 @@ impl Governance {
             return Err(Error::TimelockNotExpired);
         }
 
+        let actions = Self::get_proposal_actions(env.clone(), proposal_id);
+        if !actions.is_empty()
+            && Self::hash_actions(env.clone(), actions.clone()) != proposal.payload_hash
+        {
+            return Err(Error::InvalidPayload);
+        }
+
+        // Mark executed before invoking so a re-entrant call cannot run twice.
         proposal.state = STATE_EXECUTED;
         env.storage().persistent().set(&proposal_key, &proposal);
         env.storage().persistent().extend_ttl(
@@ -449,11 +697,19 @@ impl Governance {
             PERSISTENT_BUMP_LEDGERS,
         );
 
-        ProposalExecuted { proposal_id }.publish(&env);
+        for (index, action) in actions.iter().enumerate() {
+            env.invoke_contract::<Val>(&action.target, &action.function, action.args.clone());
+
+            ProposalActionExecuted {
+                proposal_id,
+                index: index as u32,
+                target: action.target,
+                function: action.function,
+            }
+            .publish(&env);
+        }
 
-        // In production, the payload would be decoded and executed here
-        // (e.g., call another contract, update config, transfer funds)
-        // For this implementation, we just mark as executed
+        ProposalExecuted { proposal_id }.publish(&env);
 
         Ok(())
     }
@@ -577,6 +833,7 @@ impl Governance {
                 state: STATE_PENDING,
                 for_votes: 0,
                 against_votes: 0,
+                abstain_votes: 0,
                 total_votes: 0,
                 quorum_votes_required: 0,
                 quorum_votes_remaining: 0,
@@ -586,11 +843,8 @@ impl Governance {
             };
         };
 
-        let total_votes = proposal
-            .for_votes
-            .checked_add(proposal.against_votes)
-            .unwrap_or(i128::MAX);
-        let quorum_votes_required = quorum_votes_required(&env);
+        let total_votes = proposal_total_votes(&proposal).unwrap_or(i128::MAX);
+        let quorum_votes_required = quorum_votes_required(&env, &proposal);
         let quorum_votes_remaining = if total_votes >= quorum_votes_required {
             0
         } else {
@@ -616,6 +870,7 @@ impl Governance {
             state: effective_proposal_state(&env, &proposal, total_votes),
             for_votes: proposal.for_votes,
             against_votes: proposal.against_votes,
+            abstain_votes: proposal.abstain_votes,
             total_votes,
             quorum_votes_required,
             quorum_votes_remaining,
@@ -625,18 +880,352 @@ impl Governance {
         }
     }
 
+    /// Get the actions committed by `propose_actions` (empty for hash-only proposals)
+    pub fn get_proposal_actions(env: Env, proposal_id: u64) -> Vec<ProposalAction> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProposalActions(proposal_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Compute the payload hash committed for `actions`: SHA-256 of their XDR encoding
+    pub fn hash_actions(env: Env, actions: Vec<ProposalAction>) -> BytesN<32> {
+        env.crypto().sha256(&actions.to_xdr(&env)).into()
+    }
+
+    /// Get the ed25519 key registered for signed votes, if any
+    pub fn get_vote_key(env: Env, voter: Address) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&DataKey::VoteKey(voter))
+    }
+
+    /// Get the nonce the next signed vote from `voter` must use
+    pub fn get_vote_nonce(env: Env, voter: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VoteNonce(voter))
+            .unwrap_or(0)
+    }
+
+    /// Build the exact bytes a voter signs for `vote_by_sig`
+    pub fn vote_message(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        support: u32,
+        nonce: u64,
+        expiry_ledger: u32,
+    ) -> Bytes {
+        VoteMessage {
+            governance: env.current_contract_address(),
+            voter,
+            proposal_id,
+            support,
+            nonce,
+            expiry_ledger,
+        }
+        .to_xdr(&env)
+    }
+
+    /// Get the proposal anti-spam configuration
+    pub fn get_proposal_config(env: Env) -> ProposalConfig {
+        proposal_config(&env)
+    }
+
+    /// Get the deposit still held for a proposal (0 once settled or if none)
+    pub fn get_proposal_deposit(env: Env, proposal_id: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, Deposit>(&DataKey::ProposalDeposit(proposal_id))
+            .map_or(0, |deposit| deposit.amount)
+    }
+
     /// Check if an address has voted on a proposal
     pub fn has_voted(env: Env, proposal_id: u64, voter: Address) -> bool {
         env.storage()
             .persistent()
             .has(&DataKey::Vote(proposal_id, voter))
     }
+
+    /// Get the recorded vote of an address on a proposal
+    pub fn get_vote(env: Env, proposal_id: u64, voter: Address) -> Option<VoteReceipt> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Vote(proposal_id, voter))
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Internal helpers
 // ---------------------------------------------------------------------------
 
+fn create_proposal(
+    env: &Env,
+    proposer: Address,
+    proposal_id: u64,
+    payload_hash: BytesN<32>,
+) -> Result<(), Error> {
+    let key = DataKey::Proposal(proposal_id);
+    if env.storage().persistent().has(&key) {
+        return Err(Error::ProposalAlreadyExists);
+    }
+
+    let config = proposal_config(env);
+    let current_ledger = env.ledger().sequence();
+
+    if config.min_proposal_power > 0 {
+        // Read the last closed ledger so tokens received in this one, e.g.
+        // borrowed for the transaction, do not count toward the threshold.
+        let snapshot = current_ledger.checked_sub(1).ok_or(Error::Overflow)?;
+        let power = votes_client(env).get_past_votes(&proposer, &snapshot);
+        if power < config.min_proposal_power {
+            return Err(Error::BelowProposalThreshold);
+        }
+    }
+
+    if config.max_active_per_proposer > 0 {
+        let active_key = DataKey::ActiveProposals(proposer.clone());
+        let mut active = Vec::new(env);
+        let tracked: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&active_key)
+            .unwrap_or_else(|| Vec::new(env));
+        for id in tracked.iter() {
+            if is_proposal_open(env, id) {
+                active.push_back(id);
+            }
+        }
+        if active.len() >= config.max_active_per_proposer {
+            return Err(Error::TooManyActiveProposals);
+        }
+        active.push_back(proposal_id);
+        env.storage().persistent().set(&active_key, &active);
+        env.storage().persistent().extend_ttl(
+            &active_key,
+            PERSISTENT_BUMP_LEDGERS,
+            PERSISTENT_BUMP_LEDGERS,
+        );
+    }
+
+    if config.deposit_amount > 0 {
+        let treasury = config.treasury.clone().ok_or(Error::InvalidConfig)?;
+        let token = governance_token(env);
+        TokenClient::new(env, &token).transfer(
+            &proposer,
+            env.current_contract_address(),
+            &config.deposit_amount,
+        );
+        let deposit_key = DataKey::ProposalDeposit(proposal_id);
+        let deposit = Deposit {
+            amount: config.deposit_amount,
+            treasury,
+        };
+        env.storage().persistent().set(&deposit_key, &deposit);
+        env.storage().persistent().extend_ttl(
+            &deposit_key,
+            PERSISTENT_BUMP_LEDGERS,
+            PERSISTENT_BUMP_LEDGERS,
+        );
+    }
+
+    let voting_period: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::VotingPeriod)
+        .unwrap();
+    // Snapshot the last closed ledger: token checkpoints for the current
+    // ledger can still change, so votes could be moved and counted twice.
+    let start_ledger = current_ledger.checked_sub(1).ok_or(Error::Overflow)?;
+    let end_ledger = current_ledger
+        .checked_add(voting_period)
+        .ok_or(Error::Overflow)?;
+
+    let proposal = Proposal {
+        proposer: proposer.clone(),
+        payload_hash: payload_hash.clone(),
+        start_ledger,
+        end_ledger,
+        for_votes: 0,
+        against_votes: 0,
+        abstain_votes: 0,
+        state: STATE_ACTIVE,
+        eta: 0,
+    };
+
+    env.storage().persistent().set(&key, &proposal);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_BUMP_LEDGERS,
+        PERSISTENT_BUMP_LEDGERS,
+    );
+
+    ProposalCreated {
+        proposal_id,
+        proposer,
+        payload_hash,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+fn record_vote(
+    env: &Env,
+    proposal_id: u64,
+    voter: Address,
+    support: u32,
+    reason: Option<String>,
+) -> Result<(), Error> {
+    if support > VOTE_ABSTAIN {
+        return Err(Error::InvalidSupport);
+    }
+    if let Some(reason) = &reason {
+        if reason.len() > MAX_VOTE_REASON_LEN {
+            return Err(Error::ReasonTooLong);
+        }
+    }
+
+    let proposal_key = DataKey::Proposal(proposal_id);
+    let mut proposal: Proposal = env
+        .storage()
+        .persistent()
+        .get(&proposal_key)
+        .ok_or(Error::ProposalNotFound)?;
+
+    if proposal.state != STATE_ACTIVE {
+        return Err(Error::InvalidProposalState);
+    }
+
+    let current_ledger = env.ledger().sequence();
+    if current_ledger >= proposal.end_ledger {
+        return Err(Error::VotingPeriodEnded);
+    }
+
+    // A previous vote may only be replaced inside the change window.
+    let vote_key = DataKey::Vote(proposal_id, voter.clone());
+    let previous: Option<VoteReceipt> = env.storage().persistent().get(&vote_key);
+    let cast_ledger = match &previous {
+        Some(receipt) => {
+            let window: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey::VoteChangeWindow)
+                .unwrap_or(0);
+            if window == 0 || current_ledger >= receipt.cast_ledger.saturating_add(window) {
+                return Err(Error::AlreadyVoted);
+            }
+            receipt.cast_ledger
+        }
+        None => current_ledger,
+    };
+
+    // Voting weight is snapshotted at proposal start so tokens moved
+    // after the proposal was created cannot be counted twice.
+    let weight = match &previous {
+        Some(receipt) => receipt.weight,
+        None => votes_client(env).get_past_votes(&voter, &proposal.start_ledger),
+    };
+
+    if weight <= 0 {
+        return Err(Error::NotAuthorized);
+    }
+
+    if let Some(receipt) = &previous {
+        let tally = tally_for(&mut proposal, receipt.support);
+        *tally = tally.checked_sub(receipt.weight).ok_or(Error::Overflow)?;
+    }
+    let tally = tally_for(&mut proposal, support);
+    *tally = tally.checked_add(weight).ok_or(Error::Overflow)?;
+
+    env.storage().persistent().set(&proposal_key, &proposal);
+    env.storage().persistent().extend_ttl(
+        &proposal_key,
+        PERSISTENT_BUMP_LEDGERS,
+        PERSISTENT_BUMP_LEDGERS,
+    );
+
+    let receipt = VoteReceipt {
+        support,
+        weight,
+        cast_ledger,
+    };
+    env.storage().persistent().set(&vote_key, &receipt);
+    env.storage().persistent().extend_ttl(
+        &vote_key,
+        PERSISTENT_BUMP_LEDGERS,
+        PERSISTENT_BUMP_LEDGERS,
+    );
+
+    if let Some(previous) = previous {
+        VoteChanged {
+            proposal_id,
+            voter: voter.clone(),
+            previous_support: previous.support,
+            support,
+        }
+        .publish(env);
+    }
+
+    VoteCast {
+        proposal_id,
+        voter,
+        support,
+        weight,
+        reason,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+fn apply_signed_vote(env: &Env, vote: SignedVote) -> Result<(), Error> {
+    if env.ledger().sequence() >= vote.expiry_ledger {
+        return Err(Error::SignatureExpired);
+    }
+
+    let public_key: BytesN<32> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::VoteKey(vote.voter.clone()))
+        .ok_or(Error::VoteKeyNotRegistered)?;
+
+    // Sequential nonces: each signature is usable once and in order.
+    let nonce_key = DataKey::VoteNonce(vote.voter.clone());
+    let expected_nonce: u64 = env.storage().persistent().get(&nonce_key).unwrap_or(0);
+    if vote.nonce != expected_nonce {
+        return Err(Error::InvalidNonce);
+    }
+
+    let message = Governance::vote_message(
+        env.clone(),
+        vote.voter.clone(),
+        vote.proposal_id,
+        vote.support,
+        vote.nonce,
+        vote.expiry_ledger,
+    );
+    env.crypto()
+        .ed25519_verify(&public_key, &message, &vote.signature);
+
+    let next_nonce = expected_nonce.checked_add(1).ok_or(Error::Overflow)?;
+    env.storage().persistent().set(&nonce_key, &next_nonce);
+    env.storage().persistent().extend_ttl(
+        &nonce_key,
+        PERSISTENT_BUMP_LEDGERS,
+        PERSISTENT_BUMP_LEDGERS,
+    );
+
+    record_vote(env, vote.proposal_id, vote.voter, vote.support, None)
+}
+
+fn tally_for(proposal: &mut Proposal, support: u32) -> &mut i128 {
+    match support {
+        VOTE_FOR => &mut proposal.for_votes,
+        VOTE_AGAINST => &mut proposal.against_votes,
+        _ => &mut proposal.abstain_votes,
+    }
+}
+
 fn require_initialized(env: &Env) -> Result<(), Error> {
     if !env.storage().instance().has(&DataKey::Admin) {
         return Err(Error::NotInitialized);
@@ -657,17 +1246,74 @@ fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
     Ok(())
 }
 
-fn quorum_votes_required(env: &Env) -> i128 {
+fn governance_token(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::GovernanceToken)
+        .unwrap()
+}
+
+fn votes_client(env: &Env) -> VotesClient<'_> {
+    VotesClient::new(env, &governance_token(env))
+}
+
+fn proposal_config(env: &Env) -> ProposalConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProposalConfig)
+        .unwrap_or(ProposalConfig {
+            min_proposal_power: 0,
+            deposit_amount: 0,
+            max_active_per_proposer: 0,
+            treasury: None,
+        })
+}
+
+/// A proposal counts against the active cap while its voting period is open.
+fn is_proposal_open(env: &Env, proposal_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, Proposal>(&DataKey::Proposal(proposal_id))
+        .map(|proposal| {
+            proposal.state == STATE_ACTIVE && env.ledger().sequence() < proposal.end_ledger
+        })
+        .unwrap_or(false)
+}
+
+/// Votes needed for quorum: `quorum_bps` of the supply at `start_ledger`,
+/// never less than one vote when a quorum is configured.
+fn quorum_votes_required(env: &Env, proposal: &Proposal) -> i128 {
     let quorum_bps: u32 = env.storage().instance().get(&DataKey::QuorumBps).unwrap();
     if quorum_bps == 0 {
-        0
-    } else {
+        return 0;
+    }
+
+    let past_supply = votes_client(env).get_past_total_supply(&proposal.start_ledger);
+    let required = past_supply
+        .checked_mul(quorum_bps as i128)
+        .map(|value| value / 10_000)
+        .unwrap_or(i128::MAX);
+    if required < 1 {
         1
+    } else {
+        required
     }
 }
 
-fn proposal_meets_threshold(env: &Env, proposal: &Proposal, total_votes: i128) -> bool {
-    if total_votes == 0 {
+/// All votes cast, including abstentions; this is what quorum is measured on.
+fn proposal_total_votes(proposal: &Proposal) -> Option<i128> {
+    proposal
+        .for_votes
+        .checked_add(proposal.against_votes)?
+        .checked_add(proposal.abstain_votes)
+}
+
+/// Approval is measured on decisive votes only; abstentions are ignored.
+fn proposal_meets_threshold(env: &Env, proposal: &Proposal) -> bool {
+    let Some(decisive_votes) = proposal.for_votes.checked_add(proposal.against_votes) else {
+        return false;
+    };
+    if decisive_votes == 0 {
         return false;
     }
 
@@ -679,7 +1325,7 @@ fn proposal_meets_threshold(env: &Env, proposal: &Proposal, total_votes: i128) -
     proposal
         .for_votes
         .checked_mul(10_000)
-        .and_then(|value| value.checked_div(total_votes))
+        .and_then(|value| value.checked_div(decisive_votes))
         .map(|approval_bps| approval_bps >= threshold_bps as i128)
         .unwrap_or(false)
 }
@@ -693,8 +1339,8 @@ fn effective_proposal_state(env: &Env, proposal: &Proposal, total_votes: i128) -
         return STATE_ACTIVE;
     }
 
-    let quorum_required = quorum_votes_required(env);
-    if total_votes < quorum_required || !proposal_meets_threshold(env, proposal, total_votes) {
+    let quorum_required = quorum_votes_required(env, proposal);
+    if total_votes < quorum_required || !proposal_meets_threshold(env, proposal) {
         STATE_DEFEATED
     } else {
         STATE_SUCCEEDED
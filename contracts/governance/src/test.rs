@@ -2,19 +2,53 @@
 
 use super::*;
 use soroban_sdk::{
+    contract, contractimpl, symbol_short,
     testutils::{Address as _, Ledger},
-    token::StellarAssetClient,
-    Address, Bytes, BytesN, Env,
+    vec, Address, Bytes, BytesN, Env, IntoVal, String,
 };
+use ed25519_dalek::{Signer, SigningKey};
+use stellarcade_governance_token::{GovernanceToken, GovernanceTokenClient};
 
 // -------------------------------------------------------------------
 // Helpers
 // -------------------------------------------------------------------
 
-fn create_token<'a>(env: &'a Env, admin: &Address) -> (Address, StellarAssetClient<'a>) {
-    let contract = env.register_stellar_asset_contract_v2(admin.clone());
-    let client = StellarAssetClient::new(env, &contract.address());
-    (contract.address(), client)
+fn create_token<'a>(env: &'a Env, admin: &Address) -> (Address, GovernanceTokenClient<'a>) {
+    let contract_id = env.register(GovernanceToken, ());
+    let client = GovernanceTokenClient::new(env, &contract_id);
+    env.mock_all_auths();
+    client.init(
+        admin,
+        &String::from_str(env, "StellarCade Governance"),
+        &String::from_str(env, "SCG"),
+        &7,
+    );
+    (contract_id, client)
+}
+
+/// Stand-in for a platform contract whose config is owned by governance.
+#[contract]
+pub struct MockFeeConfig;
+
+#[contractimpl]
+impl MockFeeConfig {
+    pub fn set_fee(env: Env, caller: Address, fee_bps: u32) {
+        caller.require_auth();
+        env.storage().instance().set(&symbol_short!("owner"), &caller);
+        env.storage().instance().set(&symbol_short!("fee"), &fee_bps);
+    }
+
+    pub fn fee(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("fee")).unwrap_or(0)
+    }
+
+    pub fn owner(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("owner"))
+    }
+
+    pub fn fail(_env: Env) {
+        panic!("action failed");
+    }
 }
 
 fn hash(env: &Env, data: &[u8]) -> BytesN<32> {
@@ -23,6 +57,7 @@ fn hash(env: &Env, data: &[u8]) -> BytesN<32> {
 
 struct Setup<'a> {
     gov_client: GovernanceClient<'a>,
+    token_client: GovernanceTokenClient<'a>,
     admin: Address,
     voter1: Address,
     voter2: Address,
@@ -34,7 +69,7 @@ fn setup(env: &Env) -> Setup<'_> {
     let voter1 = Address::generate(env);
     let voter2 = Address::generate(env);
 
-    let (token_addr, token_sac) = create_token(env, &token_admin);
+    let (token_addr, token_client) = create_token(env, &token_admin);
 
     let gov_id = env.register(Governance, ());
     let gov_client = GovernanceClient::new(env, &gov_id);
@@ -45,11 +80,16 @@ fn setup(env: &Env) -> Setup<'_> {
     gov_client.init(&admin, &token_addr, &100u32, &50u32, &1000u32, &6000u32);
 
     // Mint tokens to voters
-    token_sac.mint(&voter1, &1000);
-    token_sac.mint(&voter2, &500);
+    token_client.mint(&voter1, &1000);
+    token_client.mint(&voter2, &500);
+
+    // Close the minting ledger so proposals created next snapshot it.
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
 
     Setup {
         gov_client,
+        token_client,
         admin,
         voter1,
         voter2,
@@ -114,8 +154,9 @@ fn test_summary_for_active_proposal() {
     assert_eq!(summary.for_votes, 0);
     assert_eq!(summary.against_votes, 0);
     assert_eq!(summary.total_votes, 0);
-    assert_eq!(summary.quorum_votes_required, 1);
-    assert_eq!(summary.quorum_votes_remaining, 1);
+    // 10% of the 1500 supply at start_ledger
+    assert_eq!(summary.quorum_votes_required, 150);
+    assert_eq!(summary.quorum_votes_remaining, 150);
     assert_eq!(summary.quorum_progress_bps, 0);
     assert!(!summary.quorum_reached);
     assert_eq!(summary.execution_eta, proposal.end_ledger + 50);
@@ -143,7 +184,7 @@ fn test_summary_for_succeeded_proposal_before_queue() {
     assert!(summary.exists);
     assert_eq!(summary.state, STATE_SUCCEEDED);
     assert_eq!(summary.total_votes, 1500);
-    assert_eq!(summary.quorum_votes_required, 1);
+    assert_eq!(summary.quorum_votes_required, 150);
     assert_eq!(summary.quorum_votes_remaining, 0);
     assert_eq!(summary.quorum_progress_bps, 10_000);
     assert!(summary.quorum_reached);
@@ -294,6 +335,215 @@ fn test_vote_after_period_rejected() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_transferred_tokens_cannot_vote_twice() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    s.gov_client.vote(&1u64, &s.voter1, &true);
+
+    // Tokens moved after start_ledger carry no weight for this proposal.
+    let voter3 = Address::generate(&env);
+    s.token_client.transfer(&s.voter1, &voter3, &1000);
+    let result = s.gov_client.try_vote(&1u64, &voter3, &true);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    assert_eq!(s.gov_client.get_proposal(&1u64).for_votes, 1000);
+}
+
+#[test]
+fn test_tokens_moved_in_creation_ledger_cannot_vote_twice() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+    s.gov_client.vote(&1u64, &s.voter1, &true);
+
+    // Still the creation ledger: the snapshot is the ledger before it.
+    let voter3 = Address::generate(&env);
+    s.token_client.transfer(&s.voter1, &voter3, &1000);
+    let result = s.gov_client.try_vote(&1u64, &voter3, &true);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    let proposal = s.gov_client.get_proposal(&1u64);
+    assert_eq!(proposal.start_ledger, env.ledger().sequence() - 1);
+    assert_eq!(proposal.for_votes, 1000);
+}
+
+#[test]
+fn test_delegated_votes_counted_at_start_ledger() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let delegatee = Address::generate(&env);
+    s.token_client.delegate(&s.voter2, &delegatee);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    let result = s.gov_client.try_vote(&1u64, &s.voter2, &true);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    s.gov_client.vote(&1u64, &delegatee, &false);
+    assert_eq!(s.gov_client.get_proposal(&1u64).against_votes, 500);
+}
+
+#[test]
+fn test_queue_below_quorum_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let small_holder = Address::generate(&env);
+    s.token_client.mint(&small_holder, &100);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    // 100 of 1600 supply is below the 10% quorum.
+    s.gov_client.vote(&1u64, &small_holder, &true);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 101);
+    let result = s.gov_client.try_queue(&1u64);
+    assert_eq!(result, Err(Ok(Error::QuorumNotReached)));
+}
+
+#[test]
+fn test_abstain_counts_toward_quorum_not_threshold() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let small_holder = Address::generate(&env);
+    s.token_client.mint(&small_holder, &100);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    // 100 for + 1000 abstain: quorum (160 of 1600) met, approval is 100%.
+    s.gov_client.cast_vote(&1u64, &small_holder, &VOTE_FOR, &None);
+    s.gov_client.cast_vote(&1u64, &s.voter1, &VOTE_ABSTAIN, &None);
+
+    let summary = s.gov_client.get_proposal_summary(&1u64);
+    assert_eq!(summary.for_votes, 100);
+    assert_eq!(summary.abstain_votes, 1000);
+    assert_eq!(summary.total_votes, 1100);
+    assert!(summary.quorum_reached);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 101);
+    assert_eq!(s.gov_client.get_proposal_summary(&1u64).state, STATE_SUCCEEDED);
+    s.gov_client.queue(&1u64);
+    assert_eq!(s.gov_client.get_proposal(&1u64).state, STATE_QUEUED);
+}
+
+#[test]
+fn test_abstain_only_proposal_defeated() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+    s.gov_client.cast_vote(&1u64, &s.voter1, &VOTE_ABSTAIN, &None);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 101);
+    s.gov_client.queue(&1u64);
+    assert_eq!(s.gov_client.get_proposal(&1u64).state, STATE_DEFEATED);
+}
+
+#[test]
+fn test_cast_vote_with_reason_and_invalid_input() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    let result = s.gov_client.try_cast_vote(&1u64, &s.voter1, &3u32, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidSupport)));
+
+    let long_reason = String::from_bytes(&env, &[b'x'; 257]);
+    let result = s
+        .gov_client
+        .try_cast_vote(&1u64, &s.voter1, &VOTE_FOR, &Some(long_reason));
+    assert_eq!(result, Err(Ok(Error::ReasonTooLong)));
+
+    let reason = String::from_str(&env, "fees are too high");
+    s.gov_client.cast_vote(&1u64, &s.voter1, &VOTE_AGAINST, &Some(reason));
+    let receipt = s.gov_client.get_vote(&1u64, &s.voter1).unwrap();
+    assert_eq!(receipt.support, VOTE_AGAINST);
+    assert_eq!(receipt.weight, 1000);
+}
+
+#[test]
+fn test_vote_change_within_window() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    s.gov_client.set_vote_change_window(&s.admin, &10u32);
+
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    s.gov_client.vote(&1u64, &s.voter1, &true);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 5);
+    s.gov_client.cast_vote(&1u64, &s.voter1, &VOTE_ABSTAIN, &None);
+
+    let proposal = s.gov_client.get_proposal(&1u64);
+    assert_eq!(proposal.for_votes, 0);
+    assert_eq!(proposal.abstain_votes, 1000);
+
+    // The window runs from the first vote and is not reset by a change.
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 5);
+    let result = s.gov_client.try_cast_vote(&1u64, &s.voter1, &VOTE_FOR, &None);
+    assert_eq!(result, Err(Ok(Error::AlreadyVoted)));
+}
+
+#[test]
+fn test_vote_change_disabled_by_default() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    s.gov_client.cast_vote(&1u64, &s.voter1, &VOTE_FOR, &None);
+    let result = s.gov_client.try_cast_vote(&1u64, &s.voter1, &VOTE_AGAINST, &None);
+    assert_eq!(result, Err(Ok(Error::AlreadyVoted)));
+}
+
 // -------------------------------------------------------------------
 // 4. Queue
 // -------------------------------------------------------------------
@@ -704,3 +954,457 @@ fn test_full_governance_lifecycle() {
     s.gov_client.execute(&1u64, &payload);
     assert_eq!(s.gov_client.get_proposal(&1u64).state, STATE_EXECUTED);
 }
+
+// -------------------------------------------------------------------
+// 9. On-chain actions
+// -------------------------------------------------------------------
+
+fn set_fee_action(env: &Env, target: &Address, caller: &Address, fee_bps: u32) -> ProposalAction {
+    ProposalAction {
+        target: target.clone(),
+        function: symbol_short!("set_fee"),
+        args: vec![env, caller.into_val(env), fee_bps.into_val(env)],
+    }
+}
+
+fn pass_proposal(env: &Env, s: &Setup, proposal_id: u64) {
+    s.gov_client.vote(&proposal_id, &s.voter1, &true);
+    s.gov_client.vote(&proposal_id, &s.voter2, &true);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 101);
+    s.gov_client.queue(&proposal_id);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 51);
+}
+
+#[test]
+fn test_propose_actions_commits_hash() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let target = env.register(MockFeeConfig, ());
+    let actions = vec![&env, set_fee_action(&env, &target, &s.gov_client.address, 250)];
+
+    let proposer = Address::generate(&env);
+    s.gov_client.propose_actions(&proposer, &1u64, &actions);
+
+    let proposal = s.gov_client.get_proposal(&1u64);
+    assert_eq!(proposal.payload_hash, s.gov_client.hash_actions(&actions));
+    assert_eq!(s.gov_client.get_proposal_actions(&1u64), actions);
+}
+
+#[test]
+fn test_propose_actions_rejects_empty_or_oversized() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let proposer = Address::generate(&env);
+    let result = s
+        .gov_client
+        .try_propose_actions(&proposer, &1u64, &soroban_sdk::Vec::new(&env));
+    assert_eq!(result, Err(Ok(Error::InvalidActions)));
+
+    let target = env.register(MockFeeConfig, ());
+    let mut actions = soroban_sdk::Vec::new(&env);
+    for fee in 0..=MAX_PROPOSAL_ACTIONS {
+        actions.push_back(set_fee_action(&env, &target, &s.gov_client.address, fee));
+    }
+    let result = s.gov_client.try_propose_actions(&proposer, &2u64, &actions);
+    assert_eq!(result, Err(Ok(Error::InvalidActions)));
+}
+
+#[test]
+fn test_execute_invokes_actions_in_order() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let target = env.register(MockFeeConfig, ());
+    let target_client = MockFeeConfigClient::new(&env, &target);
+    let gov = s.gov_client.address.clone();
+    let actions = vec![
+        &env,
+        set_fee_action(&env, &target, &gov, 100),
+        set_fee_action(&env, &target, &gov, 300),
+    ];
+
+    let proposer = Address::generate(&env);
+    s.gov_client.propose_actions(&proposer, &1u64, &actions);
+    pass_proposal(&env, &s, 1);
+
+    let payload = s.gov_client.get_proposal(&1u64).payload_hash;
+    s.gov_client.execute(&1u64, &payload);
+
+    assert_eq!(s.gov_client.get_proposal(&1u64).state, STATE_EXECUTED);
+    assert_eq!(target_client.fee(), 300);
+    assert_eq!(target_client.owner(), Some(gov));
+}
+
+#[test]
+fn test_failing_action_reverts_execution() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let target = env.register(MockFeeConfig, ());
+    let target_client = MockFeeConfigClient::new(&env, &target);
+    let actions = vec![
+        &env,
+        set_fee_action(&env, &target, &s.gov_client.address, 100),
+        ProposalAction {
+            target: target.clone(),
+            function: symbol_short!("fail"),
+            args: soroban_sdk::Vec::new(&env),
+        },
+    ];
+
+    let proposer = Address::generate(&env);
+    s.gov_client.propose_actions(&proposer, &1u64, &actions);
+    pass_proposal(&env, &s, 1);
+
+    let payload = s.gov_client.get_proposal(&1u64).payload_hash;
+    assert!(s.gov_client.try_execute(&1u64, &payload).is_err());
+
+    assert_eq!(s.gov_client.get_proposal(&1u64).state, STATE_QUEUED);
+    assert_eq!(target_client.fee(), 0);
+}
+
+// -------------------------------------------------------------------
+// 10. Signed votes
+// -------------------------------------------------------------------
+
+fn register_signer(env: &Env, s: &Setup, voter: &Address, seed: u8) -> SigningKey {
+    let signing_key = SigningKey::from_bytes(&[seed; 32]);
+    let public_key = BytesN::from_array(env, signing_key.verifying_key().as_bytes());
+    s.gov_client.register_vote_key(voter, &public_key);
+    signing_key
+}
+
+fn sign_vote(
+    s: &Setup,
+    signing_key: &SigningKey,
+    voter: &Address,
+    proposal_id: u64,
+    support: u32,
+    nonce: u64,
+    expiry_ledger: u32,
+) -> SignedVote {
+    let message = s
+        .gov_client
+        .vote_message(voter, &proposal_id, &support, &nonce, &expiry_ledger);
+    let mut buf = [0u8; 256];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    let signature = signing_key.sign(&buf[..len]);
+    SignedVote {
+        voter: voter.clone(),
+        proposal_id,
+        support,
+        nonce,
+        expiry_ledger,
+        signature: BytesN::from_array(&s.gov_client.env, &signature.to_bytes()),
+    }
+}
+
+#[test]
+fn test_vote_by_sig_records_vote_and_bumps_nonce() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let signing_key = register_signer(&env, &s, &s.voter1, 1);
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    let vote = sign_vote(&s, &signing_key, &s.voter1, 1, VOTE_FOR, 0, 50);
+    s.gov_client.vote_by_sig(
+        &vote.voter,
+        &vote.proposal_id,
+        &vote.support,
+        &vote.nonce,
+        &vote.expiry_ledger,
+        &vote.signature,
+    );
+
+    assert_eq!(s.gov_client.get_proposal(&1u64).for_votes, 1000);
+    assert_eq!(s.gov_client.get_vote_nonce(&s.voter1), 1);
+
+    // Replaying the same signature fails on the consumed nonce.
+    let result = s.gov_client.try_vote_by_sig(
+        &vote.voter,
+        &vote.proposal_id,
+        &vote.support,
+        &vote.nonce,
+        &vote.expiry_ledger,
+        &vote.signature,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidNonce)));
+}
+
+#[test]
+fn test_vote_by_sig_batch() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let key1 = register_signer(&env, &s, &s.voter1, 1);
+    let key2 = register_signer(&env, &s, &s.voter2, 2);
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    let votes = vec![
+        &env,
+        sign_vote(&s, &key1, &s.voter1, 1, VOTE_FOR, 0, 50),
+        sign_vote(&s, &key2, &s.voter2, 1, VOTE_ABSTAIN, 0, 50),
+    ];
+    s.gov_client.vote_by_sig_batch(&votes);
+
+    let proposal = s.gov_client.get_proposal(&1u64);
+    assert_eq!(proposal.for_votes, 1000);
+    assert_eq!(proposal.abstain_votes, 500);
+}
+
+#[test]
+fn test_vote_by_sig_rejects_expired_and_unregistered() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    let signing_key = SigningKey::from_bytes(&[3; 32]);
+    let vote = sign_vote(&s, &signing_key, &s.voter2, 1, VOTE_FOR, 0, 50);
+    let result = s.gov_client.try_vote_by_sig_batch(&vec![&env, vote]);
+    assert_eq!(result, Err(Ok(Error::VoteKeyNotRegistered)));
+
+    let signing_key = register_signer(&env, &s, &s.voter1, 1);
+    let vote = sign_vote(&s, &signing_key, &s.voter1, 1, VOTE_FOR, 0, 10);
+    env.ledger().set_sequence_number(10);
+    let result = s.gov_client.try_vote_by_sig_batch(&vec![&env, vote]);
+    assert_eq!(result, Err(Ok(Error::SignatureExpired)));
+}
+
+#[test]
+fn test_vote_by_sig_rejects_wrong_signer() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    register_signer(&env, &s, &s.voter1, 1);
+    let proposer = Address::generate(&env);
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&proposer, &1u64, &payload);
+
+    let impostor = SigningKey::from_bytes(&[9; 32]);
+    let vote = sign_vote(&s, &impostor, &s.voter1, 1, VOTE_FOR, 0, 50);
+    assert!(s.gov_client.try_vote_by_sig_batch(&vec![&env, vote]).is_err());
+    assert!(!s.gov_client.has_voted(&1u64, &s.voter1));
+    assert_eq!(s.gov_client.get_vote_nonce(&s.voter1), 0);
+}
+
+// -------------------------------------------------------------------
+// 11. Proposal thresholds and deposits
+// -------------------------------------------------------------------
+
+fn proposal_config(
+    min_proposal_power: i128,
+    deposit_amount: i128,
+    max_active_per_proposer: u32,
+    treasury: Option<Address>,
+) -> ProposalConfig {
+    ProposalConfig {
+        min_proposal_power,
+        deposit_amount,
+        max_active_per_proposer,
+        treasury,
+    }
+}
+
+#[test]
+fn test_set_proposal_config_validates() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let result = s
+        .gov_client
+        .try_set_proposal_config(&s.admin, &proposal_config(0, 100, 0, None));
+    assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+
+    let result = s
+        .gov_client
+        .try_set_proposal_config(&s.voter1, &proposal_config(10, 0, 0, None));
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_propose_requires_min_power() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    s.gov_client
+        .set_proposal_config(&s.admin, &proposal_config(600, 0, 0, None));
+
+    // Power is read from the last closed ledger.
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    let payload = hash(&env, b"action:test");
+    let result = s.gov_client.try_propose(&s.voter2, &1u64, &payload);
+    assert_eq!(result, Err(Ok(Error::BelowProposalThreshold)));
+
+    s.gov_client.propose(&s.voter1, &1u64, &payload);
+    assert_eq!(s.gov_client.get_proposal(&1u64).proposer, s.voter1);
+}
+
+#[test]
+fn test_tokens_received_in_proposing_ledger_do_not_count() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    s.gov_client
+        .set_proposal_config(&s.admin, &proposal_config(600, 0, 0, None));
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    s.token_client.transfer(&s.voter1, &s.voter2, &1000);
+
+    let payload = hash(&env, b"action:test");
+    let result = s.gov_client.try_propose(&s.voter2, &1u64, &payload);
+    assert_eq!(result, Err(Ok(Error::BelowProposalThreshold)));
+}
+
+#[test]
+fn test_active_proposal_cap_per_proposer() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    s.gov_client
+        .set_proposal_config(&s.admin, &proposal_config(0, 0, 2, None));
+
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&s.voter1, &1u64, &payload);
+    s.gov_client.propose(&s.voter1, &2u64, &payload);
+    let result = s.gov_client.try_propose(&s.voter1, &3u64, &payload);
+    assert_eq!(result, Err(Ok(Error::TooManyActiveProposals)));
+
+    // Other proposers are unaffected.
+    s.gov_client.propose(&s.voter2, &3u64, &payload);
+
+    // Cancelled proposals free a slot.
+    s.gov_client.cancel(&s.admin, &1u64);
+    s.gov_client.propose(&s.voter1, &4u64, &payload);
+
+    // Proposals whose voting period ended free their slots too.
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 101);
+    s.gov_client.propose(&s.voter1, &5u64, &payload);
+    s.gov_client.propose(&s.voter1, &6u64, &payload);
+}
+
+#[test]
+fn test_deposit_refunded_when_quorum_reached() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let treasury = Address::generate(&env);
+    s.gov_client
+        .set_proposal_config(&s.admin, &proposal_config(0, 100, 0, Some(treasury.clone())));
+
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&s.voter2, &1u64, &payload);
+    assert_eq!(s.token_client.balance(&s.voter2), 400);
+    assert_eq!(s.gov_client.get_proposal_deposit(&1u64), 100);
+
+    s.gov_client.vote(&1u64, &s.voter1, &false);
+
+    let result = s.gov_client.try_settle_deposit(&1u64);
+    assert_eq!(result, Err(Ok(Error::VotingPeriodActive)));
+
+    // Defeated on threshold but quorum was met: deposit is returned.
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 101);
+    s.gov_client.settle_deposit(&1u64);
+    assert_eq!(s.token_client.balance(&s.voter2), 500);
+    assert_eq!(s.token_client.balance(&treasury), 0);
+    assert_eq!(s.gov_client.get_proposal_deposit(&1u64), 0);
+
+    let result = s.gov_client.try_settle_deposit(&1u64);
+    assert_eq!(result, Err(Ok(Error::NoDeposit)));
+}
+
+#[test]
+fn test_deposit_slashed_when_quorum_missed() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let treasury = Address::generate(&env);
+    s.gov_client
+        .set_proposal_config(&s.admin, &proposal_config(0, 100, 0, Some(treasury.clone())));
+
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&s.voter2, &1u64, &payload);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 101);
+    s.gov_client.settle_deposit(&1u64);
+    assert_eq!(s.token_client.balance(&s.voter2), 400);
+    assert_eq!(s.token_client.balance(&treasury), 100);
+}
+
+#[test]
+fn test_deposit_slashed_to_treasury_recorded_at_creation() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let treasury = Address::generate(&env);
+    s.gov_client
+        .set_proposal_config(&s.admin, &proposal_config(0, 100, 0, Some(treasury.clone())));
+
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&s.voter2, &1u64, &payload);
+    s.gov_client.propose(&s.voter2, &2u64, &payload);
+
+    // Switching deposits off or moving the treasury affects new proposals only.
+    s.gov_client
+        .set_proposal_config(&s.admin, &proposal_config(0, 0, 0, None));
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 101);
+    s.gov_client.settle_deposit(&1u64);
+
+    let new_treasury = Address::generate(&env);
+    s.gov_client
+        .set_proposal_config(&s.admin, &proposal_config(0, 100, 0, Some(new_treasury.clone())));
+    s.gov_client.settle_deposit(&2u64);
+
+    assert_eq!(s.token_client.balance(&treasury), 200);
+    assert_eq!(s.token_client.balance(&new_treasury), 0);
+}
+
+#[test]
+fn test_deposit_refunded_on_cancel() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let treasury = Address::generate(&env);
+    s.gov_client
+        .set_proposal_config(&s.admin, &proposal_config(0, 100, 0, Some(treasury)));
+
+    let payload = hash(&env, b"action:test");
+    s.gov_client.propose(&s.voter2, &1u64, &payload);
+    s.gov_client.cancel(&s.admin, &1u64);
+    s.gov_client.settle_deposit(&1u64);
+    assert_eq!(s.token_client.balance(&s.voter2), 500);
+}
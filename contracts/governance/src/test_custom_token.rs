@@ -50,6 +50,7 @@ fn test_governance_with_custom_governance_token() {
     let voter2 = Address::generate(&env);
     token_client.mint(&voter1, &1000);
     token_client.mint(&voter2, &500);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1);
 
     // 4. Propose
     let proposer = Address::generate(&env);
@@ -7,8 +7,9 @@
 
 use soroban_sdk::{
     contract, contractclient, contracterror, contractevent, contractimpl, contracttype,
-    symbol_short, Address, Env, Symbol,
+    symbol_short, Address, BytesN, Env, Symbol,
 };
+use stellarcade_random_generator::{RandomGeneratorClient, RequestState};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -17,20 +18,13 @@ use soroban_sdk::{
 pub const MIN_WAGER: i128 = 1;
 pub const MAX_WAGER: i128 = 1_000_000_000;
 pub const ANCHOR_VALUE: u32 = 50;
-/// Ledgers a round may stay unresolved before it is eligible for cleanup.
-/// ~24 hours at ~5 s/ledger.
-pub const ROUND_EXPIRY_LEDGERS: u32 = 17_280;
+/// Outcomes are requested from the RNG in `[0, OUTCOME_RANGE - 1]`, i.e. `0..=100`.
+pub const OUTCOME_RANGE: u64 = 101;
 
 // ---------------------------------------------------------------------------
 // External contract clients
 // ---------------------------------------------------------------------------
 
-#[contractclient(name = "RngClient")]
-pub trait RngContract {
-    fn is_ready(env: Env, game_id: u64) -> bool;
-    fn get_result(env: Env, game_id: u64) -> u32;
-}
-
 #[contractclient(name = "BalanceClient")]
 pub trait UserBalanceContract {
     fn debit(env: Env, game: Address, user: Address, amount: i128, reason: Symbol);
@@ -58,7 +52,7 @@ pub enum Error {
     InsufficientBalance = 10,
     HouseInsufficientFunds = 11,
     Overflow = 12,
-    /// Cleanup called before the expiry threshold has been reached.
+    /// Cleanup called before the round's RNG request has expired.
     NotExpired = 13,
     /// Attempt to resolve or interact with an already-expired game.
     GameExpired = 14,
@@ -163,12 +157,16 @@ impl HigherLower {
         Ok(())
     }
 
+    /// Escrow the wager and request the round's outcome from the RNG, using
+    /// `game_id` as the request id. `client_seed` is optional player entropy
+    /// mixed into the outcome.
     pub fn place_prediction(
         env: Env,
         player: Address,
         prediction: u32,
         wager: i128,
         game_id: u64,
+        client_seed: Option<BytesN<32>>,
     ) -> Result<(), Error> {
         require_initialized(&env)?;
         player.require_auth();
@@ -193,6 +191,14 @@ impl HigherLower {
         balance_client.debit(&game_addr, &player, &wager, &symbol_short!("wager"));
         balance_client.credit(&game_addr, &game_addr, &wager, &symbol_short!("escrow"));
 
+        let rng_contract = get_rng_contract(&env)?;
+        RandomGeneratorClient::new(&env, &rng_contract).request_random(
+            &game_addr,
+            &game_id,
+            &OUTCOME_RANGE,
+            &client_seed,
+        );
+
         let game = GameData {
             player: player.clone(),
             prediction,
@@ -236,11 +242,11 @@ impl HigherLower {
         }
 
         let rng_contract = get_rng_contract(&env)?;
-        let rng_client = RngClient::new(&env, &rng_contract);
-        if !rng_client.is_ready(&game_id) {
+        let rng_client = RandomGeneratorClient::new(&env, &rng_contract);
+        if rng_client.get_request_status(&game_id).state != RequestState::Fulfilled {
             return Err(Error::RngNotReady);
         }
-        let outcome = rng_client.get_result(&game_id);
+        let outcome = rng_client.get_result(&game_id).result as u32;
 
         let win = match game.prediction {
             Prediction::Higher => outcome > ANCHOR_VALUE,
@@ -284,13 +290,15 @@ impl HigherLower {
         Ok(())
     }
 
-    /// Expires a stale round that has not been resolved within `ROUND_EXPIRY_LEDGERS`.
+    /// Expires a stale round whose RNG request expired before it was fulfilled.
     ///
     /// Callable by anyone. On success the wager is refunded to the player and
     /// the round is transitioned to the terminal `expired` state.
     ///
     /// # Expiry Model
-    /// - Threshold: `ROUND_EXPIRY_LEDGERS = 17_280` ledgers (≈24 h at 5 s/ledger).
+    /// - Follows the random-generator's request expiry (`is_expired`), the same
+    ///   rule as `refund_expired` in the other RNG games. A fulfilled request
+    ///   never expires, so a round whose outcome is known can only be resolved.
     /// - A `RoundExpired` event is emitted on success, recording `game_id`, `player`, and `refund` amount.
     /// - Resolved or already-expired rounds are never re-targeted.
     ///
@@ -299,7 +307,7 @@ impl HigherLower {
     /// * `GameNotFound`   - No round stored under this ID.
     /// * `AlreadyResolved` - Round was already properly resolved.
     /// * `GameExpired`   - Round was already cleaned up via `expire_round`.
-    /// * `NotExpired`    - Threshold not yet reached; round is still active.
+    /// * `NotExpired`    - The RNG request is fulfilled or still pending.
     pub fn expire_round(env: Env, game_id: u64) -> Result<(), Error> {
         require_initialized(&env)?;
 
@@ -318,8 +326,8 @@ impl HigherLower {
             return Err(Error::GameExpired);
         }
 
-        let current_ledger = env.ledger().sequence();
-        if current_ledger < game.created_at.saturating_add(ROUND_EXPIRY_LEDGERS) {
+        let rng_contract = get_rng_contract(&env)?;
+        if !RandomGeneratorClient::new(&env, &rng_contract).is_expired(&game_id) {
             return Err(Error::NotExpired);
         }
 
@@ -3,8 +3,9 @@ use soroban_sdk::{
     contract, contractimpl, contracttype,
     testutils::{Address as _, Ledger},
     token::StellarAssetClient,
-    Address, Env,
+    Address, Bytes, BytesN, Env,
 };
+use stellarcade_random_generator::{RandomGenerator, RandomGeneratorClient};
 
 // -----------------------------
 // Mock Balance contract
@@ -52,38 +53,45 @@ impl MockBalance {
 }
 
 // -----------------------------
-// Mock RNG contract
+// Random Generator
 // -----------------------------
 
-#[contract]
-pub struct MockRng;
+struct Rng<'a> {
+    client: RandomGeneratorClient<'a>,
+    oracle: Address,
+}
 
-#[contracttype]
-pub enum RngKey {
-    Result(u64),
-    Ready(u64),
+fn seed(env: &Env, byte: u8) -> BytesN<32> {
+    let mut arr = [0u8; 32];
+    arr[31] = byte;
+    BytesN::from_array(env, &arr)
 }
 
-#[contractimpl]
-impl MockRng {
-    pub fn set_result(env: Env, game_id: u64, result: u32) {
-        env.storage().persistent().set(&RngKey::Result(game_id), &result);
-        env.storage().persistent().set(&RngKey::Ready(game_id), &true);
-    }
+/// The outcome the RNG derives for `game_id` from `server_seed` without a
+/// client seed. Draws are only rejected with negligible probability for
+/// `OUTCOME_RANGE`, so the first draw decides.
+fn derive_outcome(env: &Env, server_seed: &BytesN<32>, game_id: u64) -> u32 {
+    let mut preimage = [0u8; 40];
+    preimage[..32].copy_from_slice(&server_seed.to_array());
+    preimage[32..].copy_from_slice(&game_id.to_be_bytes());
+    let digest: BytesN<32> = env.crypto().sha256(&Bytes::from_slice(env, &preimage)).into();
+    let arr = digest.to_array();
+    let raw = u64::from_be_bytes([arr[0], arr[1], arr[2], arr[3], arr[4], arr[5], arr[6], arr[7]]);
+    (raw % OUTCOME_RANGE) as u32
+}
 
-    pub fn is_ready(env: Env, game_id: u64) -> bool {
-        env.storage()
-            .persistent()
-            .get(&RngKey::Ready(game_id))
-            .unwrap_or(false)
-    }
+/// Finds a server seed whose outcome for `game_id` satisfies `wanted`.
+fn seed_for(env: &Env, game_id: u64, wanted: impl Fn(u32) -> bool) -> BytesN<32> {
+    (0u8..=255)
+        .map(|byte| seed(env, byte))
+        .find(|server_seed| wanted(derive_outcome(env, server_seed, game_id)))
+        .unwrap()
+}
 
-    pub fn get_result(env: Env, game_id: u64) -> u32 {
-        env.storage()
-            .persistent()
-            .get(&RngKey::Result(game_id))
-            .unwrap_or(0)
-    }
+/// Commit a one-seed chain so the next prediction is fulfilled with `server_seed`.
+fn commit_seed(env: &Env, rng: &Rng, server_seed: &BytesN<32>) {
+    let tip: BytesN<32> = env.crypto().sha256(&Bytes::from(server_seed)).into();
+    rng.client.commit_seed_chain(&rng.oracle, &tip, &1);
 }
 
 fn create_token<'a>(env: &'a Env, token_admin: &Address) -> (Address, StellarAssetClient<'a>) {
@@ -100,7 +108,25 @@ fn setup(
     Address, // player
     Address, // house
     MockBalanceClient<'_>,
-    MockRngClient<'_>,
+    Rng<'_>,
+) {
+    let (client, admin, player, house, balance, rng) = setup_without_chain(env);
+    // Seed capacity for predictions that are never fulfilled. A chain can only
+    // be replaced once it is exhausted, so tests that fulfill start from
+    // `setup_without_chain` and commit their own seed with `commit_seed`.
+    rng.client.commit_seed_chain(&rng.oracle, &seed(env, 0xFF), &64);
+    (client, admin, player, house, balance, rng)
+}
+
+fn setup_without_chain(
+    env: &Env,
+) -> (
+    HigherLowerClient<'_>,
+    Address, // admin
+    Address, // player
+    Address, // house
+    MockBalanceClient<'_>,
+    Rng<'_>,
 ) {
     env.mock_all_auths();
 
@@ -114,8 +140,10 @@ fn setup(
     let balance_client = MockBalanceClient::new(env, &balance_id);
     balance_client.init(&admin, &token_addr);
 
-    let rng_id = env.register(MockRng, ());
-    let rng_client = MockRngClient::new(env, &rng_id);
+    let oracle = Address::generate(env);
+    let rng_id = env.register(RandomGenerator, ());
+    let rng_client = RandomGeneratorClient::new(env, &rng_id);
+    rng_client.init(&admin, &oracle);
 
     let higher_lower_id = env.register(HigherLower, ());
     let higher_lower_client = HigherLowerClient::new(env, &higher_lower_id);
@@ -125,6 +153,7 @@ fn setup(
     higher_lower_client.init(&admin, &rng_id, &Address::generate(env), &balance_id);
 
     balance_client.authorize_game(&admin, &higher_lower_id);
+    rng_client.authorize(&admin, &higher_lower_id);
 
     token_sac.mint(&player, &1_000);
     token_sac.mint(&house, &5_000);
@@ -138,7 +167,10 @@ fn setup(
         player,
         house,
         balance_client,
-        rng_client,
+        Rng {
+            client: rng_client,
+            oracle,
+        },
     )
 }
 
@@ -147,7 +179,7 @@ fn test_place_prediction_happy_path() {
     let env = Env::default();
     let (client, _admin, player, house, balance, _rng) = setup(&env);
 
-    client.place_prediction(&player, &0, &100, &1);
+    client.place_prediction(&player, &0, &100, &1, &None);
 
     let game = client.get_game(&1).unwrap();
     assert_eq!(game.player, player);
@@ -162,16 +194,19 @@ fn test_place_prediction_happy_path() {
 #[test]
 fn test_win_resolution_path() {
     let env = Env::default();
-    let (client, _admin, player, house, balance, rng) = setup(&env);
+    let (client, _admin, player, house, balance, rng) = setup_without_chain(&env);
 
-    client.place_prediction(&player, &0, &100, &2);
+    let server_seed = seed_for(&env, 2, |outcome| outcome > ANCHOR_VALUE);
+    commit_seed(&env, &rng, &server_seed);
+    client.place_prediction(&player, &0, &100, &2, &None);
 
-    rng.set_result(&2, &80);
+    rng.client.fulfill_random(&rng.oracle, &2, &server_seed);
     client.resolve_game(&2);
 
     let game = client.get_game(&2).unwrap();
     assert!(game.resolved);
     assert!(game.win);
+    assert_eq!(game.outcome as u64, rng.client.get_result(&2).result);
     assert_eq!(game.payout, 200);
 
     assert_eq!(balance.balance_of(&player), 1_100);
@@ -181,16 +216,19 @@ fn test_win_resolution_path() {
 #[test]
 fn test_loss_resolution_path() {
     let env = Env::default();
-    let (client, _admin, player, house, balance, rng) = setup(&env);
+    let (client, _admin, player, house, balance, rng) = setup_without_chain(&env);
 
-    client.place_prediction(&player, &0, &100, &3);
+    let server_seed = seed_for(&env, 3, |outcome| outcome < ANCHOR_VALUE);
+    commit_seed(&env, &rng, &server_seed);
+    client.place_prediction(&player, &0, &100, &3, &None);
 
-    rng.set_result(&3, &20);
+    rng.client.fulfill_random(&rng.oracle, &3, &server_seed);
     client.resolve_game(&3);
 
     let game = client.get_game(&3).unwrap();
     assert!(game.resolved);
     assert!(!game.win);
+    assert_eq!(game.outcome, derive_outcome(&env, &server_seed, 3));
     assert_eq!(game.payout, 0);
 
     assert_eq!(balance.balance_of(&player), 900);
@@ -202,7 +240,7 @@ fn test_invalid_prediction_rejected() {
     let env = Env::default();
     let (client, _admin, player, _house, _balance, _rng) = setup(&env);
 
-    let result = client.try_place_prediction(&player, &2, &100, &4);
+    let result = client.try_place_prediction(&player, &2, &100, &4, &None);
     assert!(result.is_err());
 }
 
@@ -213,20 +251,22 @@ fn test_insufficient_balance_rejected() {
 
     balance.withdraw(&player, &1_000);
 
-    let result = client.try_place_prediction(&player, &0, &100, &5);
+    let result = client.try_place_prediction(&player, &0, &100, &5, &None);
     assert!(result.is_err());
 }
 
 #[test]
 fn test_duplicate_and_double_resolution_blocked() {
     let env = Env::default();
-    let (client, _admin, player, _house, _balance, rng) = setup(&env);
+    let (client, _admin, player, _house, _balance, rng) = setup_without_chain(&env);
 
-    client.place_prediction(&player, &1, &100, &6);
-    let dup = client.try_place_prediction(&player, &1, &100, &6);
+    let server_seed = seed(&env, 6);
+    commit_seed(&env, &rng, &server_seed);
+    client.place_prediction(&player, &1, &100, &6, &None);
+    let dup = client.try_place_prediction(&player, &1, &100, &6, &None);
     assert!(dup.is_err());
 
-    rng.set_result(&6, &20);
+    rng.client.fulfill_random(&rng.oracle, &6, &server_seed);
     client.resolve_game(&6);
     let again = client.try_resolve_game(&6);
     assert!(again.is_err());
@@ -237,9 +277,9 @@ fn test_resolve_before_rng_ready_rejected() {
     let env = Env::default();
     let (client, _admin, player, _house, _balance, _rng) = setup(&env);
 
-    client.place_prediction(&player, &1, &100, &7);
+    client.place_prediction(&player, &1, &100, &7, &None);
     let result = client.try_resolve_game(&7);
-    assert!(result.is_err());
+    assert_eq!(result, Err(Ok(Error::RngNotReady)));
 }
 
 // ── Stale Round Cleanup Tests ──────────────────────────────────────────
@@ -247,31 +287,33 @@ fn test_resolve_before_rng_ready_rejected() {
 #[test]
 fn test_cleanup_rejected_before_expiry() {
     let env = Env::default();
-    let (client, _admin, player, _house, _balance, _rng) = setup(&env);
+    let (client, _admin, player, _house, _balance, rng) = setup(&env);
 
-    // Round placed at ledger 0 (default).  Threshold is ROUND_EXPIRY_LEDGERS = 17_280.
-    client.place_prediction(&player, &0, &100, &10);
+    // Round placed at ledger 0 (default); its RNG request expires after the timeout.
+    client.place_prediction(&player, &0, &100, &10, &None);
 
     // Advance to just below the threshold.
-    env.ledger().with_mut(|l| l.sequence_number = ROUND_EXPIRY_LEDGERS - 1);
+    let timeout = rng.client.get_request_timeout();
+    env.ledger().with_mut(|l| l.sequence_number = timeout - 1);
 
     let result = client.try_expire_round(&10);
-    assert!(result.is_err());
+    assert_eq!(result, Err(Ok(Error::NotExpired)));
 }
 
 #[test]
 fn test_stale_round_cleanup_success() {
     let env = Env::default();
-    let (client, _admin, player, house, balance, _rng) = setup(&env);
+    let (client, _admin, player, house, balance, rng) = setup(&env);
 
-    client.place_prediction(&player, &0, &100, &11);
+    client.place_prediction(&player, &0, &100, &11, &None);
 
     // Player's wager is escrowed: player 900, house 5100.
     assert_eq!(balance.balance_of(&player), 900);
     assert_eq!(balance.balance_of(&house), 5_100);
 
-    // Advance past expiry threshold.
-    env.ledger().with_mut(|l| l.sequence_number = ROUND_EXPIRY_LEDGERS + 1);
+    // Advance until the RNG request expires.
+    let timeout = rng.client.get_request_timeout();
+    env.ledger().with_mut(|l| l.sequence_number = timeout);
 
     client.expire_round(&11);
 
@@ -287,11 +329,12 @@ fn test_stale_round_cleanup_success() {
 #[test]
 fn test_repeat_cleanup_rejected() {
     let env = Env::default();
-    let (client, _admin, player, _house, _balance, _rng) = setup(&env);
+    let (client, _admin, player, _house, _balance, rng) = setup(&env);
 
-    client.place_prediction(&player, &0, &100, &12);
+    client.place_prediction(&player, &0, &100, &12, &None);
 
-    env.ledger().with_mut(|l| l.sequence_number = ROUND_EXPIRY_LEDGERS + 1);
+    let timeout = rng.client.get_request_timeout();
+    env.ledger().with_mut(|l| l.sequence_number = timeout);
 
     client.expire_round(&12);
 
@@ -299,3 +342,44 @@ fn test_repeat_cleanup_rejected() {
     let result = client.try_expire_round(&12);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_fulfilled_round_cannot_be_expired() {
+    let env = Env::default();
+    let (client, _admin, player, house, balance, rng) = setup_without_chain(&env);
+
+    // The outcome loses for a Higher prediction and is public once fulfilled.
+    let server_seed = seed_for(&env, 15, |outcome| outcome <= ANCHOR_VALUE);
+    commit_seed(&env, &rng, &server_seed);
+    client.place_prediction(&player, &0, &100, &15, &None);
+    rng.client.fulfill_random(&rng.oracle, &15, &server_seed);
+
+    // Nobody resolves it, but the player cannot take the wager back.
+    let timeout = rng.client.get_request_timeout();
+    env.ledger().with_mut(|l| l.sequence_number = timeout + 1);
+    assert_eq!(client.try_expire_round(&15), Err(Ok(Error::NotExpired)));
+    assert_eq!(balance.balance_of(&player), 900);
+
+    client.resolve_game(&15);
+    let game = client.get_game(&15).unwrap();
+    assert!(game.resolved);
+    assert!(!game.win);
+    assert!(!game.expired);
+    assert_eq!(balance.balance_of(&house), 5_100);
+}
+
+#[test]
+fn test_client_seed_forwarded_to_rng() {
+    let env = Env::default();
+    let (client, _, player, _, _, rng) = setup(&env);
+
+    let client_seed = BytesN::from_array(&env, &[7u8; 32]);
+    client.place_prediction(&player, &0, &100, &13, &Some(client_seed.clone()));
+    client.place_prediction(&player, &1, &100, &14, &None);
+
+    assert_eq!(
+        rng.client.get_request_status(&13).client_seed,
+        Some(client_seed)
+    );
+    assert_eq!(rng.client.get_request_status(&14).client_seed, None);
+}
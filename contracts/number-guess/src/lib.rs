@@ -14,6 +14,9 @@
 //! 4. Anyone calls `resolve_game` → reads RNG result, derives secret number,
 //!    settles payout if guess matches.
 //!
+//! If the RNG request expires before the oracle fulfills it, anyone can call
+//! `refund_expired` to return the wager to the player instead.
+//!
 //! ## Fairness Model
 //! The player's guess is committed to storage **before** the oracle reveals the
 //! server seed, so the oracle cannot select a seed after observing the guess.
@@ -41,7 +44,7 @@
 
 use soroban_sdk::{
     contract, contracterror, contractevent, contractimpl, contracttype, token::TokenClient,
-    Address, Env,
+    Address, BytesN, Env,
 };
 
 use stellarcade_random_generator::RandomGeneratorClient;
@@ -89,6 +92,8 @@ pub enum Error {
     WagerTooLow = 14,
     WagerTooHigh = 15,
     Overflow = 16,
+    /// `refund_expired` requires the RNG request to have expired.
+    RngNotExpired = 17,
 }
 
 // ---------------------------------------------------------------------------
@@ -101,6 +106,7 @@ pub enum Error {
 /// `Guessed`  — `submit_guess` called; waiting for RNG fulfillment and resolution.
 /// `Won`      — Resolved; guess matched the secret number.
 /// `Lost`     — Resolved; guess did not match.
+/// `Refunded` — RNG request expired unfulfilled; wager returned to the player.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum GameStatus {
@@ -108,6 +114,7 @@ pub enum GameStatus {
     Guessed = 1,
     Won = 2,
     Lost = 3,
+    Refunded = 4,
 }
 
 /// Full state for a single number-guess game.
@@ -180,6 +187,15 @@ pub struct GameResolved {
     pub payout: i128,
 }
 
+#[contractevent]
+pub struct GameRefunded {
+    #[topic]
+    pub game_id: u64,
+    #[topic]
+    pub player: Address,
+    pub amount: i128,
+}
+
 // ---------------------------------------------------------------------------
 // Contract
 // ---------------------------------------------------------------------------
@@ -256,7 +272,8 @@ impl NumberGuess {
     /// unique across the RNG contract's request space.
     ///
     /// `game_id` is caller-provided and must not collide with any existing
-    /// pending or fulfilled RNG request.
+    /// pending or fulfilled RNG request. `client_seed` is optional player
+    /// entropy forwarded to the RNG request and mixed into the secret.
     pub fn start_game(
         env: Env,
         player: Address,
@@ -264,6 +281,7 @@ impl NumberGuess {
         max: u32,
         wager: i128,
         game_id: u64,
+        client_seed: Option<BytesN<32>>,
     ) -> Result<(), Error> {
         require_initialized(&env)?;
         player.require_auth();
@@ -310,6 +328,7 @@ impl NumberGuess {
             &env.current_contract_address(),
             &game_id,
             &(range_size as u64),
+            &client_seed,
         );
 
         // Persist initial game state.
@@ -421,7 +440,9 @@ impl NumberGuess {
 
         match game.status {
             GameStatus::Open => return Err(Error::GuessNotSubmitted),
-            GameStatus::Won | GameStatus::Lost => return Err(Error::GameAlreadyResolved),
+            GameStatus::Won | GameStatus::Lost | GameStatus::Refunded => {
+                return Err(Error::GameAlreadyResolved)
+            }
             GameStatus::Guessed => {}
         }
 
@@ -499,6 +520,64 @@ impl NumberGuess {
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // refund_expired
+    // -----------------------------------------------------------------------
+
+    /// Return the wager of a game whose RNG request expired unfulfilled.
+    ///
+    /// No authorization required — the wager can only go back to the player.
+    /// Allowed from `Open` or `Guessed`, since the RNG request may expire
+    /// before the player submits a guess.
+    pub fn refund_expired(env: Env, game_id: u64) -> Result<(), Error> {
+        require_initialized(&env)?;
+
+        let game_key = DataKey::Game(game_id);
+        let mut game: Game = env
+            .storage()
+            .persistent()
+            .get(&game_key)
+            .ok_or(Error::GameNotFound)?;
+
+        if !matches!(game.status, GameStatus::Open | GameStatus::Guessed) {
+            return Err(Error::GameAlreadyResolved);
+        }
+
+        let rng_addr: Address = env.storage().instance().get(&DataKey::RngContract).unwrap();
+        if !RandomGeneratorClient::new(&env, &rng_addr).is_expired(&game_id) {
+            return Err(Error::RngNotExpired);
+        }
+
+        // Update state before any external transfer (reentrancy guard).
+        game.status = GameStatus::Refunded;
+        env.storage().persistent().set(&game_key, &game);
+        env.storage().persistent().extend_ttl(
+            &game_key,
+            PERSISTENT_BUMP_LEDGERS,
+            PERSISTENT_BUMP_LEDGERS,
+        );
+
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BalanceContract)
+            .unwrap();
+        TokenClient::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &game.player,
+            &game.wager,
+        );
+
+        GameRefunded {
+            game_id,
+            player: game.player,
+            amount: game.wager,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // get_game
     // -----------------------------------------------------------------------
@@ -2,9 +2,9 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::Address as _,
+    testutils::{Address as _, Ledger as _},
     token::{StellarAssetClient, TokenClient},
-    Address, BytesN, Env,
+    Address, Bytes, BytesN, Env,
 };
 use stellarcade_random_generator::{RandomGenerator, RandomGeneratorClient};
 
@@ -27,7 +27,6 @@ fn make_seed(env: &Env, byte: u8) -> BytesN<32> {
 /// Re-derive the RNG result the same way the Random Generator contract does,
 /// so tests can select seeds that produce a specific secret number.
 fn derive_rng_result(env: &Env, server_seed: &BytesN<32>, request_id: u64, max: u64) -> u64 {
-    use soroban_sdk::Bytes;
     let mut preimage = [0u8; 40];
     preimage[..32].copy_from_slice(&server_seed.to_array());
     preimage[32..].copy_from_slice(&request_id.to_be_bytes());
@@ -68,7 +67,18 @@ struct Setup<'a> {
     token_sac: StellarAssetClient<'a>,
 }
 
+/// `setup_without_chain` plus seed capacity for games that are never
+/// fulfilled. A chain can only be replaced once it is exhausted, so tests
+/// that fulfill start from `setup_without_chain` and commit their own seed
+/// with `commit_seed`.
 fn setup(env: &Env) -> Setup<'_> {
+    let s = setup_without_chain(env);
+    s.rng_client
+        .commit_seed_chain(&s.oracle, &make_seed(env, 0xFF), &64);
+    s
+}
+
+fn setup_without_chain(env: &Env) -> Setup<'_> {
     let admin = Address::generate(env);
     let oracle = Address::generate(env);
     let token_admin = Address::generate(env);
@@ -88,7 +98,6 @@ fn setup(env: &Env) -> Setup<'_> {
     // Init RNG; authorize the NumberGuess contract as a caller
     rng_client.init(&admin, &oracle);
     rng_client.authorize(&admin, &ng_id);
-
     let prize_pool = Address::generate(env);
 
     // Init NumberGuess: min_wager=10, max_wager=10_000, house_edge=250 bps (2.5%)
@@ -115,6 +124,12 @@ fn setup(env: &Env) -> Setup<'_> {
     }
 }
 
+/// Commit a one-seed chain so the next game is fulfilled with `server_seed`.
+fn commit_seed(env: &Env, s: &Setup, server_seed: &BytesN<32>) {
+    let tip: BytesN<32> = env.crypto().sha256(&Bytes::from(server_seed)).into();
+    s.rng_client.commit_seed_chain(&s.oracle, &tip, &1);
+}
+
 fn tc<'a>(env: &'a Env, token: &Address) -> TokenClient<'a> {
     TokenClient::new(env, token)
 }
@@ -152,7 +167,7 @@ fn test_start_game_stores_game() {
     s.token_sac.mint(&player, &500);
 
     s.ng_client
-        .start_game(&player, &1u32, &10u32, &100i128, &1u64);
+        .start_game(&player, &1u32, &10u32, &100i128, &1u64, &None);
 
     let game = s.ng_client.get_game(&1u64);
     assert_eq!(game.player, player);
@@ -179,7 +194,7 @@ fn test_submit_guess_transitions_to_guessed() {
     s.token_sac.mint(&player, &500);
 
     s.ng_client
-        .start_game(&player, &1u32, &10u32, &100i128, &1u64);
+        .start_game(&player, &1u32, &10u32, &100i128, &1u64, &None);
     s.ng_client.submit_guess(&1u64, &5u32);
 
     let game = s.ng_client.get_game(&1u64);
@@ -194,7 +209,7 @@ fn test_submit_guess_transitions_to_guessed() {
 #[test]
 fn test_win_path() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -206,11 +221,13 @@ fn test_win_path() {
     let wager: i128 = 100;
     let target = 7u32; // player's guess that we'll engineer to win
 
+    let winning_seed = find_seed_for_target(&env, game_id, min, max, target);
+    commit_seed(&env, &s, &winning_seed);
+
     s.ng_client
-        .start_game(&player, &min, &max, &wager, &game_id);
+        .start_game(&player, &min, &max, &wager, &game_id, &None);
     s.ng_client.submit_guess(&game_id, &target);
 
-    let winning_seed = find_seed_for_target(&env, game_id, min, max, target);
     s.rng_client
         .fulfill_random(&s.oracle, &game_id, &winning_seed);
     s.ng_client.resolve_game(&game_id);
@@ -234,7 +251,7 @@ fn test_win_path() {
 #[test]
 fn test_loss_path() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -246,10 +263,6 @@ fn test_loss_path() {
     let wager: i128 = 100;
     let guess = 3u32;
 
-    s.ng_client
-        .start_game(&player, &min, &max, &wager, &game_id);
-    s.ng_client.submit_guess(&game_id, &guess);
-
     // Find a seed whose outcome is NOT 3
     let range_size = (max - min + 1) as u64;
     let mut losing_seed = make_seed(&env, 0);
@@ -262,6 +275,11 @@ fn test_loss_path() {
             break;
         }
     }
+    commit_seed(&env, &s, &losing_seed);
+
+    s.ng_client
+        .start_game(&player, &min, &max, &wager, &game_id, &None);
+    s.ng_client.submit_guess(&game_id, &guess);
 
     s.rng_client
         .fulfill_random(&s.oracle, &game_id, &losing_seed);
@@ -289,10 +307,10 @@ fn test_duplicate_game_id_rejected() {
     s.token_sac.mint(&player, &1_000);
 
     s.ng_client
-        .start_game(&player, &1u32, &10u32, &100i128, &1u64);
+        .start_game(&player, &1u32, &10u32, &100i128, &1u64, &None);
     let result = s
         .ng_client
-        .try_start_game(&player, &1u32, &10u32, &100i128, &1u64);
+        .try_start_game(&player, &1u32, &10u32, &100i128, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -311,7 +329,7 @@ fn test_invalid_range_min_equals_max() {
 
     let result = s
         .ng_client
-        .try_start_game(&player, &5u32, &5u32, &100i128, &1u64);
+        .try_start_game(&player, &5u32, &5u32, &100i128, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -326,7 +344,7 @@ fn test_invalid_range_min_greater_than_max() {
 
     let result = s
         .ng_client
-        .try_start_game(&player, &10u32, &5u32, &100i128, &1u64);
+        .try_start_game(&player, &10u32, &5u32, &100i128, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -346,7 +364,7 @@ fn test_wager_too_low_rejected() {
     // min_wager = 10
     let result = s
         .ng_client
-        .try_start_game(&player, &1u32, &10u32, &5i128, &1u64);
+        .try_start_game(&player, &1u32, &10u32, &5i128, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -362,7 +380,7 @@ fn test_wager_too_high_rejected() {
     // max_wager = 10_000
     let result = s
         .ng_client
-        .try_start_game(&player, &1u32, &10u32, &10_001i128, &1u64);
+        .try_start_game(&player, &1u32, &10u32, &10_001i128, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -375,7 +393,7 @@ fn test_zero_wager_rejected() {
     let player = Address::generate(&env);
     let result = s
         .ng_client
-        .try_start_game(&player, &1u32, &10u32, &0i128, &1u64);
+        .try_start_game(&player, &1u32, &10u32, &0i128, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -393,7 +411,7 @@ fn test_guess_below_min_rejected() {
     s.token_sac.mint(&player, &500);
 
     s.ng_client
-        .start_game(&player, &5u32, &15u32, &100i128, &1u64);
+        .start_game(&player, &5u32, &15u32, &100i128, &1u64, &None);
     // Guess of 4 is below min=5
     let result = s.ng_client.try_submit_guess(&1u64, &4u32);
     assert!(result.is_err());
@@ -409,7 +427,7 @@ fn test_guess_above_max_rejected() {
     s.token_sac.mint(&player, &500);
 
     s.ng_client
-        .start_game(&player, &5u32, &15u32, &100i128, &1u64);
+        .start_game(&player, &5u32, &15u32, &100i128, &1u64, &None);
     // Guess of 16 is above max=15
     let result = s.ng_client.try_submit_guess(&1u64, &16u32);
     assert!(result.is_err());
@@ -429,7 +447,7 @@ fn test_double_guess_rejected() {
     s.token_sac.mint(&player, &500);
 
     s.ng_client
-        .start_game(&player, &1u32, &10u32, &100i128, &1u64);
+        .start_game(&player, &1u32, &10u32, &100i128, &1u64, &None);
     s.ng_client.submit_guess(&1u64, &5u32);
 
     let result = s.ng_client.try_submit_guess(&1u64, &7u32);
@@ -443,18 +461,19 @@ fn test_double_guess_rejected() {
 #[test]
 fn test_double_resolve_rejected() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
     let game_id: u64 = 1;
+    let seed = make_seed(&env, 42);
+    commit_seed(&env, &s, &seed);
     s.ng_client
-        .start_game(&player, &1u32, &10u32, &100i128, &game_id);
+        .start_game(&player, &1u32, &10u32, &100i128, &game_id, &None);
     s.ng_client.submit_guess(&game_id, &5u32);
 
-    let seed = make_seed(&env, 42);
     s.rng_client.fulfill_random(&s.oracle, &game_id, &seed);
     s.ng_client.resolve_game(&game_id);
 
@@ -469,18 +488,19 @@ fn test_double_resolve_rejected() {
 #[test]
 fn test_resolve_before_guess_rejected() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
     s.token_sac.mint(&player, &500);
 
     let game_id: u64 = 1;
+    let seed = make_seed(&env, 1);
+    commit_seed(&env, &s, &seed);
     s.ng_client
-        .start_game(&player, &1u32, &10u32, &100i128, &game_id);
+        .start_game(&player, &1u32, &10u32, &100i128, &game_id, &None);
     // No submit_guess call
 
-    let seed = make_seed(&env, 1);
     s.rng_client.fulfill_random(&s.oracle, &game_id, &seed);
 
     let result = s.ng_client.try_resolve_game(&game_id);
@@ -502,7 +522,7 @@ fn test_resolve_before_rng_rejected() {
 
     let game_id: u64 = 1;
     s.ng_client
-        .start_game(&player, &1u32, &10u32, &100i128, &game_id);
+        .start_game(&player, &1u32, &10u32, &100i128, &game_id, &None);
     s.ng_client.submit_guess(&game_id, &5u32);
     // No RNG fulfillment
 
@@ -538,9 +558,10 @@ fn test_multiple_games_independent() {
     s.token_sac.mint(&p1, &1_000);
     s.token_sac.mint(&p2, &1_000);
 
-    s.ng_client.start_game(&p1, &1u32, &10u32, &100i128, &10u64);
     s.ng_client
-        .start_game(&p2, &50u32, &100u32, &200i128, &20u64);
+        .start_game(&p1, &1u32, &10u32, &100i128, &10u64, &None);
+    s.ng_client
+        .start_game(&p2, &50u32, &100u32, &200i128, &20u64, &None);
 
     let g1 = s.ng_client.get_game(&10u64);
     let g2 = s.ng_client.get_game(&20u64);
@@ -562,7 +583,7 @@ fn test_multiple_games_independent() {
 #[test]
 fn test_payout_formula_range_two() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -575,11 +596,13 @@ fn test_payout_formula_range_two() {
     let wager: i128 = 1_000;
     let target = 1u32;
 
+    let winning_seed = find_seed_for_target(&env, game_id, min, max, target);
+    commit_seed(&env, &s, &winning_seed);
+
     s.ng_client
-        .start_game(&player, &min, &max, &wager, &game_id);
+        .start_game(&player, &min, &max, &wager, &game_id, &None);
     s.ng_client.submit_guess(&game_id, &target);
 
-    let winning_seed = find_seed_for_target(&env, game_id, min, max, target);
     s.rng_client
         .fulfill_random(&s.oracle, &game_id, &winning_seed);
     s.ng_client.resolve_game(&game_id);
@@ -604,7 +627,7 @@ fn test_boundary_guess_at_min() {
     s.token_sac.mint(&player, &500);
 
     s.ng_client
-        .start_game(&player, &1u32, &100u32, &100i128, &1u64);
+        .start_game(&player, &1u32, &100u32, &100i128, &1u64, &None);
     s.ng_client.submit_guess(&1u64, &1u32); // guess == min
 
     let game = s.ng_client.get_game(&1u64);
@@ -626,7 +649,7 @@ fn test_boundary_guess_at_max() {
     s.token_sac.mint(&player, &500);
 
     s.ng_client
-        .start_game(&player, &1u32, &100u32, &100i128, &1u64);
+        .start_game(&player, &1u32, &100u32, &100i128, &1u64, &None);
     s.ng_client.submit_guess(&1u64, &100u32); // guess == max
 
     let game = s.ng_client.get_game(&1u64);
@@ -649,9 +672,9 @@ fn test_range_size_cap_enforced() {
 
     // Range size = MAX_RANGE_SIZE + 1 → must be rejected
     let oversized_max = MAX_RANGE_SIZE; // min=0+1, max=MAX_RANGE_SIZE → size = MAX_RANGE_SIZE
-    let result = s
-        .ng_client
-        .try_start_game(&player, &1u32, &(oversized_max + 1), &100i128, &1u64);
+    let result =
+        s.ng_client
+            .try_start_game(&player, &1u32, &(oversized_max + 1), &100i128, &1u64, &None);
     assert!(result.is_err());
 }
 
@@ -662,7 +685,7 @@ fn test_range_size_cap_enforced() {
 #[test]
 fn test_secret_always_in_range() {
     let env = Env::default();
-    let s = setup(&env);
+    let s = setup_without_chain(&env);
     env.mock_all_auths();
 
     let player = Address::generate(&env);
@@ -676,11 +699,13 @@ fn test_secret_always_in_range() {
         let wager: i128 = 10;
         let game_id = 1000 + i;
 
+        let seed = make_seed(&env, i as u8);
+        commit_seed(&env, &s, &seed);
+
         s.ng_client
-            .start_game(&player, &min, &max, &wager, &game_id);
+            .start_game(&player, &min, &max, &wager, &game_id, &None);
         s.ng_client.submit_guess(&game_id, &min); // always guess min
 
-        let seed = make_seed(&env, i as u8);
         s.rng_client.fulfill_random(&s.oracle, &game_id, &seed);
         s.ng_client.resolve_game(&game_id);
 
@@ -700,3 +725,75 @@ fn test_secret_always_in_range() {
         assert_eq!(game.secret, expected_secret);
     }
 }
+
+// ---------------------------------------------------------------------------
+// 21. Player client seed reaches the RNG request
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_client_seed_forwarded_to_rng() {
+    let env = Env::default();
+    let s = setup_without_chain(&env);
+    env.mock_all_auths();
+
+    let player = Address::generate(&env);
+    s.token_sac.mint(&player, &500);
+
+    let client_seed = make_seed(&env, 0x42);
+    let server_seed = make_seed(&env, 7);
+    commit_seed(&env, &s, &server_seed);
+    s.ng_client.start_game(
+        &player,
+        &1u32,
+        &10u32,
+        &100i128,
+        &1u64,
+        &Some(client_seed.clone()),
+    );
+    s.ng_client.submit_guess(&1u64, &5u32);
+    s.rng_client.fulfill_random(&s.oracle, &1u64, &server_seed);
+
+    let entry = s.rng_client.get_result(&1u64);
+    assert_eq!(entry.client_seed, Some(client_seed));
+    assert!(s.rng_client.verify_result(&1u64));
+
+    s.ng_client.resolve_game(&1u64);
+    assert_eq!(s.ng_client.get_game(&1u64).secret, 1 + entry.result as u32);
+}
+
+// ---------------------------------------------------------------------------
+// 22. Expired RNG request refunds the wager
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_refund_expired() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths();
+
+    let player = Address::generate(&env);
+    s.token_sac.mint(&player, &500);
+    s.ng_client
+        .start_game(&player, &1u32, &10u32, &100i128, &1u64, &None);
+    s.ng_client.submit_guess(&1u64, &5u32);
+
+    assert_eq!(
+        s.ng_client.try_refund_expired(&1u64),
+        Err(Ok(Error::RngNotExpired))
+    );
+
+    let timeout = s.rng_client.get_request_timeout();
+    env.ledger().with_mut(|l| l.sequence_number += timeout);
+    s.ng_client.refund_expired(&1u64);
+
+    assert_eq!(tc(&env, &s.token_addr).balance(&player), 500);
+    assert_eq!(s.ng_client.get_game(&1u64).status, GameStatus::Refunded);
+    assert_eq!(
+        s.ng_client.try_refund_expired(&1u64),
+        Err(Ok(Error::GameAlreadyResolved))
+    );
+    assert_eq!(
+        s.ng_client.try_resolve_game(&1u64),
+        Err(Ok(Error::GameAlreadyResolved))
+    );
+}
@@ -4,34 +4,83 @@
 //! two-phase request/fulfill model:
 //!
 //! 1. An authorized game contract calls `request_random`, registering a
-//!    pending request with a caller address and an upper bound (`max`).
+//!    pending request with a caller address, an upper bound (`max`) and an
+//!    optional player-supplied `client_seed`.
 //! 2. The designated oracle calls `fulfill_random` with a `server_seed`.
-//!    The result is computed deterministically as:
+//!    The result is computed deterministically from
 //!
-//!      `sha256(server_seed || request_id_be_bytes)[0..8] % max`
+//!      `draw = sha256(server_seed || client_seed || request_id_be_bytes)[0..8]`
 //!
-//!    and stored on-chain alongside the server seed so anyone can verify.
+//!    (without `client_seed` when none was supplied) and stored on-chain
+//!    alongside the seeds so anyone can verify. See "Derivation" below for
+//!    how `draw` is reduced into `[0, max)`.
 //!
-//! ## Fairness Model
-//! The oracle must publish `sha256(server_seed)` **before** a game round
-//! begins (off-chain commitment). Once a request is submitted, the server
-//! seed is fixed — the oracle cannot choose a seed after seeing the
-//! request without breaking the pre-published commitment. After fulfillment,
-//! any party can re-derive and verify the result using the stored seed:
+//! ## Derivation
+//! Each request records the `Derivation` in force when it was made, taken
+//! from the admin-set `EntropySourceMetadata`:
+//! - `Modulo` (v1): `draw % max`. Biased towards low values whenever `max`
+//!   does not divide 2^64.
+//! - `RejectionSampling` (v2, the default): `draw` is accepted only below the
+//!   largest multiple of `max` that fits in 64 bits, `2^64 - (2^64 % max)`.
+//!   Otherwise the preimage is re-hashed with a 4-byte big-endian counter
+//!   appended (1, 2, ...) until a draw is accepted; the result is that draw
+//!   `% max`. Accepted draws agree with `Modulo`, so the two only differ when
+//!   a draw is rejected.
+//!
+//! Results keep the derivation they were produced with, so switching the
+//! metadata never changes how earlier results verify.
+//!
+//! ## Batches and Shuffles
+//! `request_random_batch` and `request_shuffle` draw several values from the
+//! one seed a request is fulfilled with, in counter mode: draw `i` (from 0)
+//! hashes the request's preimage with `i` appended as 4 big-endian bytes and
+//! is reduced with the request's derivation (whose retries append their own
+//! counter after it). A batch of `count` values in `[0, max)` takes draws
+//! `0..count`. A shuffle of `n` is a Fisher-Yates shuffle of `[0, n)`: step
+//! `i` swaps position `n - 1 - i` with draw `i` reduced into `[0, n - i)`.
+//! `get_batch_result` returns the values with the fulfilled entry.
 //!
-//!   `sha256(stored_server_seed || request_id_be)[0..8] % max == stored_result`
+//! ## Expiry
+//! Every request expires `RequestTimeout` ledgers after it is made (admin
+//! configurable, `DEFAULT_REQUEST_TIMEOUT_LEDGERS` until set). An expired
+//! request can no longer be fulfilled, and `is_expired` lets game contracts
+//! refund its wager. To keep the chain moving, the oracle closes an expired
+//! request with `close_expired`, revealing its seed without producing a
+//! result. Expired requests also count as settled when the oracle rotates to
+//! a new chain (see below).
+//!
+//! ## Fairness Model
+//! Server seeds come from a reverse hash chain the oracle commits to on-chain
+//! with `commit_seed_chain`: it picks a secret `s_n`, computes
+//! `s_{k-1} = sha256(s_k)` down to the tip `s_0`, and registers `s_0` and `n`.
+//! Each request is assigned the next chain position when it is submitted, and
+//! `fulfill_random` must reveal exactly that preimage: `sha256(s_k)` has to
+//! equal the last revealed link. The seed for every request is therefore
+//! fixed before the request exists, so the oracle cannot grind seeds at
+//! fulfillment time. The oracle can predict the next result, though, so it
+//! must not be able to move the next request onto another seed: a new chain
+//! can only be committed once every seed of the current one is assigned and
+//! each of those requests is fulfilled or expired. Because the oracle knows
+//! its chain in advance, a player can also pass a `client_seed` the oracle
+//! cannot know when committing the chain; the result then depends on both
+//! parties' entropy. After fulfillment, any party can re-derive the result
+//! from the stored seeds and derivation and check the seed against the chain
+//! with `sha256^k(seed) == tip`. `verify_result` performs both checks
+//! on-chain.
 //!
 //! ## Storage Strategy
-//! - `instance()`: Admin, Oracle. Fixed contract-level config.
+//! - `instance()`: Admin, Oracle, entropy metadata, the current seed chain
+//!   id. Fixed contract-level config.
 //! - `persistent()`: AuthorizedCaller entries, PendingRequest entries,
-//!   FulfilledRequest entries — each a separate ledger entry with TTL
-//!   bumped on every write so active requests never expire mid-game.
+//!   FulfilledRequest entries, SeedChain entries — each a separate ledger
+//!   entry with TTL bumped on every write so active requests never expire
+//!   mid-game.
 #![no_std]
 #![allow(unexpected_cfgs)]
 
 use soroban_sdk::{
     contract, contracterror, contractevent, contractimpl, contracttype, Address, Bytes, BytesN,
-    Env, String,
+    Env, String, Vec,
 };
 
 // ---------------------------------------------------------------------------
@@ -42,6 +91,13 @@ use soroban_sdk::{
 /// Bumped on every persistent write so no request expires mid-game.
 pub const PERSISTENT_BUMP_LEDGERS: u32 = 518_400;
 
+/// Largest batch `count` or shuffle size. Bounds the hashing one
+/// fulfillment performs.
+pub const MAX_BATCH_SIZE: u32 = 256;
+
+/// Request timeout used until the admin sets one (~24 hours at 5 s/ledger).
+pub const DEFAULT_REQUEST_TIMEOUT_LEDGERS: u32 = 17_280;
+
 // ---------------------------------------------------------------------------
 // Error Types
 // ---------------------------------------------------------------------------
@@ -62,6 +118,28 @@ pub enum Error {
     AlreadyFulfilled = 7,
     /// The `caller` passed to `request_random` is not in the whitelist.
     UnauthorizedCaller = 8,
+    /// The oracle has not committed a seed chain yet.
+    SeedChainNotSet = 9,
+    /// Every seed of the current chain is assigned to a request.
+    SeedChainExhausted = 10,
+    /// A new chain was committed before every seed of the current one was
+    /// assigned and every request on it fulfilled or expired.
+    SeedChainInUse = 11,
+    /// `sha256(server_seed)` is not the last revealed link of the chain.
+    InvalidSeed = 12,
+    /// An earlier request must be fulfilled first.
+    SeedOutOfOrder = 13,
+    /// A seed chain must hold at least one seed.
+    InvalidChainLength = 14,
+    /// Batch `count` or shuffle size outside `1..=MAX_BATCH_SIZE`
+    /// (`2..=MAX_BATCH_SIZE` for shuffles).
+    InvalidBatchSize = 15,
+    /// The request passed its expiry ledger before it was fulfilled.
+    RequestExpired = 16,
+    /// `close_expired` called on a request that has not expired yet.
+    NotExpired = 17,
+    /// A request timeout must be at least one ledger.
+    InvalidTimeout = 18,
 }
 
 // ---------------------------------------------------------------------------
@@ -78,6 +156,10 @@ pub enum DataKey {
     Admin,
     Oracle,
     EntropyMetadata,
+    /// Id of the seed chain requests are assigned to.
+    CurrentSeedChain,
+    /// Ledgers a request stays fulfillable.
+    RequestTimeout,
     // --- persistent() ---
     /// Presence flag for whitelisted game contract addresses.
     AuthorizedCaller(Address),
@@ -85,6 +167,34 @@ pub enum DataKey {
     PendingRequest(u64),
     /// A fulfilled request with its result and seed stored for verification.
     FulfilledRequest(u64),
+    /// Values drawn for a fulfilled batch or shuffle request.
+    BatchResult(u64),
+    /// An expired request closed by `close_expired`.
+    ExpiredRequest(u64),
+    /// A committed seed chain, kept after rotation for auditing.
+    SeedChain(u32),
+}
+
+/// How a hash draw is reduced into `[0, max)`. See the module docs.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Derivation {
+    /// v1: `draw % max`.
+    Modulo,
+    /// v2: redraw until `draw` is below the largest multiple of `max`.
+    RejectionSampling,
+}
+
+/// What a request draws from its seed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RequestKind {
+    /// One value in `[0, max)`.
+    Single,
+    /// The given number of values in `[0, max)`.
+    Batch(u32),
+    /// A permutation of `[0, max)`.
+    Shuffle,
 }
 
 #[contracttype]
@@ -93,6 +203,7 @@ pub enum RequestState {
     Missing = 0,
     Pending = 1,
     Fulfilled = 2,
+    Expired = 3,
 }
 
 /// A pending randomness request registered by an authorized game contract.
@@ -101,6 +212,16 @@ pub enum RequestState {
 pub struct PendingEntry {
     pub caller: Address,
     pub max: u64,
+    pub kind: RequestKind,
+    /// Player-supplied entropy mixed into the result, if any.
+    pub client_seed: Option<BytesN<32>>,
+    /// Derivation in force when the request was made.
+    pub derivation: Derivation,
+    /// Seed chain and position whose seed will fulfill the request.
+    pub chain_id: u32,
+    pub seed_index: u32,
+    /// First ledger at which the request can no longer be fulfilled.
+    pub expires_at: u32,
 }
 
 /// A fulfilled request with its deterministic result and the oracle seed.
@@ -112,10 +233,49 @@ pub struct PendingEntry {
 pub struct FulfilledEntry {
     pub caller: Address,
     pub max: u64,
+    pub kind: RequestKind,
     /// Oracle-provided seed; stored to allow on-chain result verification.
     pub server_seed: BytesN<32>,
-    /// `sha256(server_seed || request_id_be)[0..8] % max`; always in `[0, max)`.
+    /// Player-supplied entropy mixed into the result, if any.
+    pub client_seed: Option<BytesN<32>>,
+    /// Derived from `sha256(server_seed || client_seed || request_id_be)`
+    /// with `derivation`; always in `[0, max)`. The first value for batch and
+    /// shuffle requests.
     pub result: u64,
+    pub derivation: Derivation,
+    /// `server_seed` is link `seed_index` of chain `chain_id`:
+    /// `sha256^seed_index(server_seed) == tip`.
+    pub chain_id: u32,
+    pub seed_index: u32,
+}
+
+/// A fulfilled batch or shuffle request with all of its values.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchResult {
+    pub entry: FulfilledEntry,
+    pub values: Vec<u64>,
+}
+
+/// A reverse hash chain of server seeds committed by the oracle.
+///
+/// Link `k` is the seed for the `k`-th request assigned to the chain, and
+/// `sha256` of link `k` is link `k - 1`; link 0 is the committed `tip`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedChain {
+    pub chain_id: u32,
+    pub tip: BytesN<32>,
+    /// Number of seeds in the chain, excluding the tip.
+    pub length: u32,
+    /// Seeds assigned to requests so far.
+    pub assigned: u32,
+    /// Seeds revealed by `fulfill_random` so far.
+    pub revealed: u32,
+    /// Last revealed link; `tip` before the first reveal.
+    pub head: BytesN<32>,
+    /// Latest `expires_at` of the requests assigned to the chain.
+    pub last_expiry: u32,
 }
 
 #[contracttype]
@@ -127,12 +287,17 @@ pub struct RequestStatus {
     pub max: Option<u64>,
     pub result: Option<u64>,
     pub server_seed: Option<BytesN<32>>,
+    pub client_seed: Option<BytesN<32>>,
+    pub chain_id: Option<u32>,
+    pub seed_index: Option<u32>,
+    pub expires_at: Option<u32>,
 }
 
 /// Describes the entropy source used by this contract.
 ///
 /// Stored in instance storage so it lives as long as the contract itself.
-/// Version metadata is informational — it does not alter randomness output.
+/// `derivation` applies to requests made after the metadata is set; the other
+/// fields are informational and do not alter randomness output.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EntropySourceMetadata {
@@ -144,6 +309,8 @@ pub struct EntropySourceMetadata {
     pub hash_algorithm: String,
     /// Number of bytes from the hash digest used for the random value.
     pub output_bytes: u32,
+    /// Derivation used for new requests.
+    pub derivation: Derivation,
 }
 
 // ---------------------------------------------------------------------------
@@ -157,6 +324,8 @@ pub struct RandomRequested {
     #[topic]
     pub caller: Address,
     pub max: u64,
+    pub kind: RequestKind,
+    pub client_seed: Option<BytesN<32>>,
 }
 
 /// Emits the server_seed so off-chain verifiers do not need a `get_result` call.
@@ -168,6 +337,21 @@ pub struct RandomFulfilled {
     pub server_seed: BytesN<32>,
 }
 
+#[contractevent]
+pub struct SeedChainCommitted {
+    #[topic]
+    pub chain_id: u32,
+    pub tip: BytesN<32>,
+    pub length: u32,
+}
+
+#[contractevent]
+pub struct RandomRequestExpired {
+    #[topic]
+    pub request_id: u64,
+    pub seed_index: u32,
+}
+
 #[contractevent]
 pub struct RandomFulfillmentRejected {
     #[topic]
@@ -238,6 +422,65 @@ impl RandomGenerator {
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // commit_seed_chain
+    // -----------------------------------------------------------------------
+
+    /// Commit a new reverse hash chain of server seeds. Oracle only.
+    ///
+    /// `tip` is `sha256^length(secret)`. Later requests are assigned the
+    /// chain's seeds in order, starting with the preimage of `tip`. Rejected
+    /// until every seed of the current chain is assigned and every request on
+    /// it is fulfilled or expired, so the oracle can neither swap seeds under
+    /// a live request nor steer the next request away from a seed whose
+    /// result it dislikes.
+    pub fn commit_seed_chain(
+        env: Env,
+        oracle: Address,
+        tip: BytesN<32>,
+        length: u32,
+    ) -> Result<u32, Error> {
+        require_initialized(&env)?;
+        require_oracle(&env, &oracle)?;
+
+        if length == 0 {
+            return Err(Error::InvalidChainLength);
+        }
+
+        let previous = current_seed_chain(&env);
+        if let Some(chain) = &previous {
+            let settled =
+                chain.assigned == chain.revealed || env.ledger().sequence() >= chain.last_expiry;
+            if chain.assigned < chain.length || !settled {
+                return Err(Error::SeedChainInUse);
+            }
+        }
+
+        let chain_id = previous.map_or(1, |chain| chain.chain_id + 1);
+        let chain = SeedChain {
+            chain_id,
+            tip: tip.clone(),
+            length,
+            assigned: 0,
+            revealed: 0,
+            head: tip.clone(),
+            last_expiry: 0,
+        };
+        write_seed_chain(&env, &chain);
+        env.storage()
+            .instance()
+            .set(&DataKey::CurrentSeedChain, &chain_id);
+
+        SeedChainCommitted {
+            chain_id,
+            tip,
+            length,
+        }
+        .publish(&env);
+
+        Ok(chain_id)
+    }
+
     // -----------------------------------------------------------------------
     // request_random
     // -----------------------------------------------------------------------
@@ -246,12 +489,15 @@ impl RandomGenerator {
     ///
     /// `max` must be >= 2. The fulfilled result will be in `[0, max - 1]`.
     /// `request_id` must be globally unique — rejected if a pending or
-    /// fulfilled entry for the same ID already exists.
+    /// fulfilled entry for the same ID already exists. The request is assigned
+    /// the next seed of the current seed chain. `client_seed`, when given, is
+    /// mixed into the result so it does not depend on the oracle's seed alone.
     pub fn request_random(
         env: Env,
         caller: Address,
         request_id: u64,
         max: u64,
+        client_seed: Option<BytesN<32>>,
     ) -> Result<(), Error> {
         require_initialized(&env)?;
 
@@ -259,50 +505,70 @@ impl RandomGenerator {
             return Err(Error::InvalidBound);
         }
 
-        caller.require_auth();
+        register_request(
+            &env,
+            caller,
+            request_id,
+            max,
+            RequestKind::Single,
+            client_seed,
+        )
+    }
 
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::AuthorizedCaller(caller.clone()))
-        {
-            return Err(Error::UnauthorizedCaller);
-        }
+    /// Submit a request for `count` values in `[0, max - 1]`, drawn from one
+    /// seed in counter mode. Same rules as `request_random`; `count` must be
+    /// in `1..=MAX_BATCH_SIZE`. Values are read with `get_batch_result`.
+    pub fn request_random_batch(
+        env: Env,
+        caller: Address,
+        request_id: u64,
+        max: u64,
+        count: u32,
+        client_seed: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        require_initialized(&env)?;
 
-        // Block reuse of any request_id, pending or fulfilled, to prevent
-        // a game contract from submitting a duplicate after its first result.
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingRequest(request_id))
-            || env
-                .storage()
-                .persistent()
-                .has(&DataKey::FulfilledRequest(request_id))
-        {
-            return Err(Error::DuplicateRequestId);
+        if max < 2 {
+            return Err(Error::InvalidBound);
+        }
+        if count == 0 || count > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
         }
 
-        let entry = PendingEntry {
-            caller: caller.clone(),
-            max,
-        };
-        let key = DataKey::PendingRequest(request_id);
-        env.storage().persistent().set(&key, &entry);
-        env.storage().persistent().extend_ttl(
-            &key,
-            PERSISTENT_BUMP_LEDGERS,
-            PERSISTENT_BUMP_LEDGERS,
-        );
-
-        RandomRequested {
-            request_id,
+        register_request(
+            &env,
             caller,
+            request_id,
             max,
+            RequestKind::Batch(count),
+            client_seed,
+        )
+    }
+
+    /// Submit a request for a uniformly random permutation of `[0, n - 1]`.
+    /// Same rules as `request_random`; `n` must be in `2..=MAX_BATCH_SIZE`.
+    /// The permutation is read with `get_batch_result`.
+    pub fn request_shuffle(
+        env: Env,
+        caller: Address,
+        request_id: u64,
+        n: u32,
+        client_seed: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        require_initialized(&env)?;
+
+        if !(2..=MAX_BATCH_SIZE).contains(&n) {
+            return Err(Error::InvalidBatchSize);
         }
-        .publish(&env);
 
-        Ok(())
+        register_request(
+            &env,
+            caller,
+            request_id,
+            n as u64,
+            RequestKind::Shuffle,
+            client_seed,
+        )
     }
 
     // -----------------------------------------------------------------------
@@ -311,12 +577,15 @@ impl RandomGenerator {
 
     /// Fulfill a pending randomness request. Oracle only.
     ///
-    /// The result is derived as:
-    ///   `sha256(server_seed || request_id_be_bytes)[0..8] % max`
+    /// The result is derived from:
+    ///   `sha256(server_seed || client_seed || request_id_be_bytes)[0..8]`
     ///
-    /// Both `server_seed` and `result` are persisted for on-chain verification.
-    /// Fairness holds when the oracle published `sha256(server_seed)` before
-    /// the corresponding `request_random` call was submitted.
+    /// with `client_seed` omitted when the request has none; batch and shuffle
+    /// requests derive all of their values in counter mode. The seeds and
+    /// results are persisted for on-chain verification.
+    /// `server_seed` must be the seed assigned to the request: the next link of
+    /// its chain, with `sha256(server_seed)` equal to the last revealed link.
+    /// Requests are therefore fulfilled in the order they were submitted.
     pub fn fulfill_random(
         env: Env,
         oracle: Address,
@@ -354,16 +623,48 @@ impl RandomGenerator {
                     Error::RequestNotFound
                 })?;
 
-        let result = derive_result(&env, &server_seed, request_id, pending.max);
+        if env.ledger().sequence() >= pending.expires_at {
+            RandomFulfillmentRejected {
+                request_id,
+                reason: Error::RequestExpired as u32,
+            }
+            .publish(&env);
+            return Err(Error::RequestExpired);
+        }
+        reveal_seed(&env, request_id, &pending, &server_seed)?;
+
+        let preimage = seed_preimage(&env, &server_seed, pending.client_seed.as_ref(), request_id);
+        let values = derive_values(
+            &env,
+            &preimage,
+            pending.max,
+            &pending.kind,
+            pending.derivation,
+        );
+        let result = values.get_unchecked(0);
 
         // Remove the pending entry; write the fulfilled entry.
         env.storage().persistent().remove(&pending_key);
+        if pending.kind != RequestKind::Single {
+            let batch_key = DataKey::BatchResult(request_id);
+            env.storage().persistent().set(&batch_key, &values);
+            env.storage().persistent().extend_ttl(
+                &batch_key,
+                PERSISTENT_BUMP_LEDGERS,
+                PERSISTENT_BUMP_LEDGERS,
+            );
+        }
 
         let fulfilled = FulfilledEntry {
             caller: pending.caller,
             max: pending.max,
+            kind: pending.kind,
             server_seed: server_seed.clone(),
+            client_seed: pending.client_seed,
             result,
+            derivation: pending.derivation,
+            chain_id: pending.chain_id,
+            seed_index: pending.seed_index,
         };
         let fulfilled_key = DataKey::FulfilledRequest(request_id);
         env.storage().persistent().set(&fulfilled_key, &fulfilled);
@@ -384,16 +685,101 @@ impl RandomGenerator {
     }
 
     // -----------------------------------------------------------------------
-    // get_result
+    // expiry
     // -----------------------------------------------------------------------
 
+    /// Close an expired request by revealing its seed. Oracle only.
+    ///
+    /// No result is derived; the seed only advances the chain so the requests
+    /// behind it can be fulfilled. The same ordering and seed checks as
+    /// `fulfill_random` apply.
+    pub fn close_expired(
+        env: Env,
+        oracle: Address,
+        request_id: u64,
+        server_seed: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_initialized(&env)?;
+        require_oracle(&env, &oracle)?;
+
+        let pending_key = DataKey::PendingRequest(request_id);
+        let pending: PendingEntry = env
+            .storage()
+            .persistent()
+            .get(&pending_key)
+            .ok_or(Error::RequestNotFound)?;
+        if env.ledger().sequence() < pending.expires_at {
+            return Err(Error::NotExpired);
+        }
+        reveal_seed(&env, request_id, &pending, &server_seed)?;
+
+        env.storage().persistent().remove(&pending_key);
+        let expired_key = DataKey::ExpiredRequest(request_id);
+        env.storage().persistent().set(&expired_key, &pending);
+        env.storage().persistent().extend_ttl(
+            &expired_key,
+            PERSISTENT_BUMP_LEDGERS,
+            PERSISTENT_BUMP_LEDGERS,
+        );
+
+        RandomRequestExpired {
+            request_id,
+            seed_index: pending.seed_index,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Set how many ledgers new requests stay fulfillable. Admin only.
+    /// Pending requests keep the expiry they were given.
+    pub fn set_request_timeout(env: Env, admin: Address, ledgers: u32) -> Result<(), Error> {
+        require_initialized(&env)?;
+        require_admin(&env, &admin)?;
+
+        if ledgers == 0 {
+            return Err(Error::InvalidTimeout);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::RequestTimeout, &ledgers);
+
+        Ok(())
+    }
+
+    /// Return the timeout applied to new requests, in ledgers.
+    pub fn get_request_timeout(env: Env) -> Result<u32, Error> {
+        require_initialized(&env)?;
+        Ok(request_timeout(&env))
+    }
+
+    /// Whether a request expired before it was fulfilled, so the game that
+    /// made it can refund the wager. `false` for missing requests.
+    pub fn is_expired(env: Env, request_id: u64) -> Result<bool, Error> {
+        require_initialized(&env)?;
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::ExpiredRequest(request_id))
+        {
+            return Ok(true);
+        }
+        Ok(env
+            .storage()
+            .persistent()
+            .get::<DataKey, PendingEntry>(&DataKey::PendingRequest(request_id))
+            .is_some_and(|entry| env.ledger().sequence() >= entry.expires_at))
+    }
+
     // -----------------------------------------------------------------------
     // entropy metadata
     // -----------------------------------------------------------------------
 
     /// Set entropy source version metadata. Admin only.
     ///
-    /// Metadata is informational and does not affect randomness output.
+    /// `metadata.derivation` selects the derivation for requests made from
+    /// now on; pending and fulfilled requests keep the one they recorded.
     pub fn set_entropy_metadata(
         env: Env,
         admin: Address,
@@ -435,6 +821,81 @@ impl RandomGenerator {
             .ok_or(Error::RequestNotFound)
     }
 
+    /// Return the fulfilled entry for a `request_id` with all of its values.
+    ///
+    /// `values` holds the batch or the permutation; for a single-value
+    /// request it is just `result`. Returns `RequestNotFound` if the request
+    /// is still pending or never existed.
+    pub fn get_batch_result(env: Env, request_id: u64) -> Result<BatchResult, Error> {
+        require_initialized(&env)?;
+
+        let entry: FulfilledEntry = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FulfilledRequest(request_id))
+            .ok_or(Error::RequestNotFound)?;
+        let values = read_values(&env, request_id, &entry);
+        Ok(BatchResult { entry, values })
+    }
+
+    /// Recompute a fulfilled request on-chain.
+    ///
+    /// Returns `true` when the stored values match the stored seeds under the
+    /// request's recorded derivation and `server_seed` hashes to the tip of
+    /// its seed chain in `seed_index` steps. Costs one hash per chain position
+    /// and per value, so large batches or late positions of a long chain are
+    /// best verified off-chain.
+    pub fn verify_result(env: Env, request_id: u64) -> Result<bool, Error> {
+        require_initialized(&env)?;
+
+        let entry: FulfilledEntry = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FulfilledRequest(request_id))
+            .ok_or(Error::RequestNotFound)?;
+        let chain: SeedChain = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SeedChain(entry.chain_id))
+            .ok_or(Error::SeedChainNotSet)?;
+
+        let preimage = seed_preimage(
+            &env,
+            &entry.server_seed,
+            entry.client_seed.as_ref(),
+            request_id,
+        );
+        let expected = derive_values(&env, &preimage, entry.max, &entry.kind, entry.derivation);
+        if expected != read_values(&env, request_id, &entry)
+            || expected.get_unchecked(0) != entry.result
+        {
+            return Ok(false);
+        }
+
+        let mut link = entry.server_seed;
+        for _ in 0..entry.seed_index {
+            link = env.crypto().sha256(&Bytes::from(&link)).into();
+        }
+        Ok(link == chain.tip)
+    }
+
+    /// Return the seed chain requests are currently assigned to.
+    pub fn get_current_seed_chain(env: Env) -> Result<SeedChain, Error> {
+        require_initialized(&env)?;
+
+        current_seed_chain(&env).ok_or(Error::SeedChainNotSet)
+    }
+
+    /// Return a committed seed chain by id, including rotated-out chains.
+    pub fn get_seed_chain(env: Env, chain_id: u32) -> Result<SeedChain, Error> {
+        require_initialized(&env)?;
+
+        env.storage()
+            .persistent()
+            .get(&DataKey::SeedChain(chain_id))
+            .ok_or(Error::SeedChainNotSet)
+    }
+
     /// Return the lifecycle status for a request id.
     pub fn get_request_status(env: Env, request_id: u64) -> Result<RequestStatus, Error> {
         require_initialized(&env)?;
@@ -451,21 +912,38 @@ impl RandomGenerator {
                 max: Some(entry.max),
                 result: Some(entry.result),
                 server_seed: Some(entry.server_seed),
+                client_seed: entry.client_seed,
+                chain_id: Some(entry.chain_id),
+                seed_index: Some(entry.seed_index),
+                expires_at: None,
             });
         }
 
-        if let Some(entry) = env
+        let expired = env
             .storage()
             .persistent()
-            .get::<DataKey, PendingEntry>(&DataKey::PendingRequest(request_id))
-        {
+            .get::<DataKey, PendingEntry>(&DataKey::ExpiredRequest(request_id));
+        let pending = env
+            .storage()
+            .persistent()
+            .get::<DataKey, PendingEntry>(&DataKey::PendingRequest(request_id));
+        if let Some(entry) = expired.or(pending) {
+            let state = if env.ledger().sequence() >= entry.expires_at {
+                RequestState::Expired
+            } else {
+                RequestState::Pending
+            };
             return Ok(RequestStatus {
                 request_id,
-                state: RequestState::Pending,
+                state,
                 caller: Some(entry.caller),
                 max: Some(entry.max),
                 result: None,
                 server_seed: None,
+                client_seed: entry.client_seed,
+                chain_id: Some(entry.chain_id),
+                seed_index: Some(entry.seed_index),
+                expires_at: Some(entry.expires_at),
             });
         }
 
@@ -476,6 +954,10 @@ impl RandomGenerator {
             max: None,
             result: None,
             server_seed: None,
+            client_seed: None,
+            chain_id: None,
+            seed_index: None,
+            expires_at: None,
         })
     }
 }
@@ -517,30 +999,266 @@ fn require_oracle(env: &Env, caller: &Address) -> Result<(), Error> {
     Ok(())
 }
 
-/// Derive a bounded random result from `server_seed` and `request_id`.
+/// Validate the caller and request id, assign the next seed of the current
+/// chain, and store the pending request.
+fn register_request(
+    env: &Env,
+    caller: Address,
+    request_id: u64,
+    max: u64,
+    kind: RequestKind,
+    client_seed: Option<BytesN<32>>,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::AuthorizedCaller(caller.clone()))
+    {
+        return Err(Error::UnauthorizedCaller);
+    }
+
+    // Block reuse of any request_id, pending, fulfilled or expired, to
+    // prevent a game contract from submitting a duplicate after its first
+    // result.
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::PendingRequest(request_id))
+        || env
+            .storage()
+            .persistent()
+            .has(&DataKey::FulfilledRequest(request_id))
+        || env
+            .storage()
+            .persistent()
+            .has(&DataKey::ExpiredRequest(request_id))
+    {
+        return Err(Error::DuplicateRequestId);
+    }
+
+    let mut chain = current_seed_chain(env).ok_or(Error::SeedChainNotSet)?;
+    if chain.assigned >= chain.length {
+        return Err(Error::SeedChainExhausted);
+    }
+    let expires_at = env.ledger().sequence().saturating_add(request_timeout(env));
+    chain.assigned += 1;
+    chain.last_expiry = chain.last_expiry.max(expires_at);
+    write_seed_chain(env, &chain);
+
+    let entry = PendingEntry {
+        caller: caller.clone(),
+        max,
+        kind: kind.clone(),
+        client_seed: client_seed.clone(),
+        derivation: current_derivation(env),
+        chain_id: chain.chain_id,
+        seed_index: chain.assigned,
+        expires_at,
+    };
+    let key = DataKey::PendingRequest(request_id);
+    env.storage().persistent().set(&key, &entry);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_BUMP_LEDGERS, PERSISTENT_BUMP_LEDGERS);
+
+    RandomRequested {
+        request_id,
+        caller,
+        max,
+        kind,
+        client_seed,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Check that `server_seed` is the next link of the request's chain and
+/// advance the chain past it.
+fn reveal_seed(
+    env: &Env,
+    request_id: u64,
+    pending: &PendingEntry,
+    server_seed: &BytesN<32>,
+) -> Result<(), Error> {
+    let mut chain: SeedChain = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SeedChain(pending.chain_id))
+        .ok_or(Error::SeedChainNotSet)?;
+    if pending.seed_index != chain.revealed + 1 {
+        RandomFulfillmentRejected {
+            request_id,
+            reason: Error::SeedOutOfOrder as u32,
+        }
+        .publish(env);
+        return Err(Error::SeedOutOfOrder);
+    }
+    let link: BytesN<32> = env.crypto().sha256(&Bytes::from(server_seed)).into();
+    if link != chain.head {
+        RandomFulfillmentRejected {
+            request_id,
+            reason: Error::InvalidSeed as u32,
+        }
+        .publish(env);
+        return Err(Error::InvalidSeed);
+    }
+    chain.revealed += 1;
+    chain.head = server_seed.clone();
+    write_seed_chain(env, &chain);
+    Ok(())
+}
+
+fn request_timeout(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequestTimeout)
+        .unwrap_or(DEFAULT_REQUEST_TIMEOUT_LEDGERS)
+}
+
+fn current_seed_chain(env: &Env) -> Option<SeedChain> {
+    let chain_id: u32 = env.storage().instance().get(&DataKey::CurrentSeedChain)?;
+    env.storage()
+        .persistent()
+        .get(&DataKey::SeedChain(chain_id))
+}
+
+fn write_seed_chain(env: &Env, chain: &SeedChain) {
+    let key = DataKey::SeedChain(chain.chain_id);
+    env.storage().persistent().set(&key, chain);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_BUMP_LEDGERS, PERSISTENT_BUMP_LEDGERS);
+}
+
+/// Derivation for new requests: the metadata's, or `RejectionSampling` if
+/// no metadata is set.
+fn current_derivation(env: &Env) -> Derivation {
+    env.storage()
+        .instance()
+        .get::<DataKey, EntropySourceMetadata>(&DataKey::EntropyMetadata)
+        .map_or(Derivation::RejectionSampling, |metadata| {
+            metadata.derivation
+        })
+}
+
+/// Build the preimage every draw of a request starts from.
 ///
-/// Constructs a 40-byte preimage:  server_seed (32 bytes) || request_id (8 bytes BE)
-/// Takes SHA-256, interprets the first 8 bytes as a big-endian u64, and reduces
-/// modulo `max`. Produces a value in `[0, max - 1]`.
+/// Constructs:
+///   server_seed (32 bytes) || client_seed (32 bytes, if any) || request_id (8 bytes BE)
 ///
 /// Combining `server_seed` with `request_id` in the preimage ensures that
 /// different requests fulfilled with the same seed produce different outputs,
 /// preventing the oracle from reusing a single seed commitment across rounds.
-fn derive_result(env: &Env, server_seed: &BytesN<32>, request_id: u64, max: u64) -> u64 {
-    // Build preimage on the stack to avoid heap allocation.
-    let mut preimage = [0u8; 40];
+/// Requests without a `client_seed` keep the original 40-byte preimage.
+fn seed_preimage(
+    env: &Env,
+    server_seed: &BytesN<32>,
+    client_seed: Option<&BytesN<32>>,
+    request_id: u64,
+) -> Bytes {
+    // Build preimage on the stack before copying it into host bytes.
+    let mut preimage = [0u8; 72];
     preimage[..32].copy_from_slice(&server_seed.to_array());
-    preimage[32..].copy_from_slice(&request_id.to_be_bytes());
+    let len = match client_seed {
+        Some(client_seed) => {
+            preimage[32..64].copy_from_slice(&client_seed.to_array());
+            preimage[64..].copy_from_slice(&request_id.to_be_bytes());
+            72
+        }
+        None => {
+            preimage[32..40].copy_from_slice(&request_id.to_be_bytes());
+            40
+        }
+    };
+    Bytes::from_slice(env, &preimage[..len])
+}
+
+/// Derive every value of a request from its seed preimage.
+///
+/// A single value is the preimage reduced into `[0, max - 1]`. Batches and
+/// shuffles use counter mode: draw `i` reduces `preimage || i_be (4 bytes)`.
+fn derive_values(
+    env: &Env,
+    preimage: &Bytes,
+    max: u64,
+    kind: &RequestKind,
+    derivation: Derivation,
+) -> Vec<u64> {
+    let counter_draw = |i: u32, bound: u64| {
+        let mut input = preimage.clone();
+        input.extend_from_array(&i.to_be_bytes());
+        reduce(env, &input, bound, derivation)
+    };
+
+    let mut values = Vec::new(env);
+    match kind {
+        RequestKind::Single => values.push_back(reduce(env, preimage, max, derivation)),
+        RequestKind::Batch(count) => {
+            for i in 0..*count {
+                values.push_back(counter_draw(i, max));
+            }
+        }
+        RequestKind::Shuffle => {
+            // Fisher-Yates: fill position `pos` from the `pos + 1` values
+            // not yet placed, working down from the end.
+            for value in 0..max {
+                values.push_back(value);
+            }
+            let n = max as u32;
+            for i in 0..n - 1 {
+                let pos = n - 1 - i;
+                let pick = counter_draw(i, pos as u64 + 1) as u32;
+                let picked = values.get_unchecked(pick);
+                values.set(pick, values.get_unchecked(pos));
+                values.set(pos, picked);
+            }
+        }
+    }
+    values
+}
+
+/// Stored values of a fulfilled request; `[result]` for single requests.
+fn read_values(env: &Env, request_id: u64, entry: &FulfilledEntry) -> Vec<u64> {
+    if entry.kind == RequestKind::Single {
+        return Vec::from_array(env, [entry.result]);
+    }
+    env.storage()
+        .persistent()
+        .get(&DataKey::BatchResult(request_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Reduce the draw from `preimage` into `[0, max - 1]` with `derivation`.
+fn reduce(env: &Env, preimage: &Bytes, max: u64, derivation: Derivation) -> u64 {
+    let raw = draw(env, preimage);
+    if derivation == Derivation::Modulo {
+        return raw % max;
+    }
+
+    // 2^64 % max: the draws at the top of the range that would favour low
+    // results. Anything below 2^64 - rejected maps onto [0, max) uniformly.
+    let rejected = max.wrapping_neg() % max;
+    let mut raw = raw;
+    let mut counter: u32 = 0;
+    while rejected != 0 && raw >= rejected.wrapping_neg() {
+        counter += 1;
+        let mut retry = preimage.clone();
+        retry.extend_from_array(&counter.to_be_bytes());
+        raw = draw(env, &retry);
+    }
+    raw % max
+}
 
-    let digest: BytesN<32> = env
-        .crypto()
-        .sha256(&Bytes::from_slice(env, &preimage))
-        .into();
+/// First 8 bytes of `sha256(preimage)` as a big-endian u64.
+fn draw(env: &Env, preimage: &Bytes) -> u64 {
+    let digest: BytesN<32> = env.crypto().sha256(preimage).into();
     let arr = digest.to_array();
-    let raw = u64::from_be_bytes([
+    u64::from_be_bytes([
         arr[0], arr[1], arr[2], arr[3], arr[4], arr[5], arr[6], arr[7],
-    ]);
-    raw % max
+    ])
 }
 
 // ---------------------------------------------------------------------------
@@ -550,7 +1268,10 @@ fn derive_result(env: &Env, server_seed: &BytesN<32>, request_id: u64, max: u64)
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Bytes, BytesN, Env, String};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger as _},
+        Bytes, BytesN, Env, String, Vec,
+    };
 
     // ------------------------------------------------------------------
     // Test helpers
@@ -568,12 +1289,32 @@ mod test {
         env.mock_all_auths();
         client.init(&admin, &oracle);
         client.authorize(&admin, &game);
+        client.commit_seed_chain(&oracle, &link(env, 0), &CHAIN_LENGTH);
 
         (client, admin, oracle, game)
     }
 
-    /// Re-derive the expected result using the same logic as `derive_result`,
-    /// so the test is an independent cross-check of the on-chain computation.
+    /// Length of the seed chain `setup` commits.
+    const CHAIN_LENGTH: u32 = 32;
+
+    /// Link `k` of the chain `setup` commits: `sha256^(CHAIN_LENGTH - k)` of
+    /// a fixed secret. Link `k` is the seed for the `k`-th request.
+    fn link(env: &Env, k: u32) -> BytesN<32> {
+        chain_link(env, &seed(env, 0xC0), CHAIN_LENGTH, k)
+    }
+
+    fn chain_link(env: &Env, secret: &BytesN<32>, length: u32, k: u32) -> BytesN<32> {
+        let mut node = secret.clone();
+        for _ in k..length {
+            node = env.crypto().sha256(&Bytes::from(&node)).into();
+        }
+        node
+    }
+
+    /// Re-derive the expected result using the same logic as `reduce`
+    /// with `Derivation::Modulo`, so the test is an independent cross-check of
+    /// the on-chain computation. Matches `RejectionSampling` unless the draw
+    /// is rejected, which is vanishingly rare for small `max`.
     fn expected_result(env: &Env, server_seed: &BytesN<32>, request_id: u64, max: u64) -> u64 {
         let mut preimage = [0u8; 40];
         preimage[..32].copy_from_slice(&server_seed.to_array());
@@ -589,6 +1330,62 @@ mod test {
         raw % max
     }
 
+    /// `expected_result` for a request carrying a `client_seed`.
+    fn expected_result_with_client(
+        env: &Env,
+        server_seed: &BytesN<32>,
+        client_seed: &BytesN<32>,
+        request_id: u64,
+        max: u64,
+    ) -> u64 {
+        let mut preimage = Bytes::from(server_seed);
+        preimage.append(&Bytes::from(client_seed));
+        preimage.extend_from_array(&request_id.to_be_bytes());
+        let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+        let arr = digest.to_array();
+        let raw = u64::from_be_bytes([
+            arr[0], arr[1], arr[2], arr[3], arr[4], arr[5], arr[6], arr[7],
+        ]);
+        raw % max
+    }
+
+    /// First 8 hash bytes of `server_seed || request_id_be || suffix`.
+    fn raw_draw(env: &Env, server_seed: &BytesN<32>, request_id: u64, suffix: &[u8]) -> u64 {
+        let mut preimage = Bytes::from(server_seed);
+        preimage.extend_from_array(&request_id.to_be_bytes());
+        preimage.extend_from_slice(suffix);
+        let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+        let arr = digest.to_array();
+        u64::from_be_bytes([
+            arr[0], arr[1], arr[2], arr[3], arr[4], arr[5], arr[6], arr[7],
+        ])
+    }
+
+    /// `Derivation::RejectionSampling` computed with u128 arithmetic.
+    fn expected_rejection_result(
+        env: &Env,
+        server_seed: &BytesN<32>,
+        request_id: u64,
+        max: u64,
+    ) -> u64 {
+        let limit = (1u128 << 64) - (1u128 << 64) % max as u128;
+        let mut raw = raw_draw(env, server_seed, request_id, &[]);
+        let mut counter = 0u32;
+        while raw as u128 >= limit {
+            counter += 1;
+            raw = raw_draw(env, server_seed, request_id, &counter.to_be_bytes());
+        }
+        raw % max
+    }
+
+    /// A request id whose first draw with `server_seed` is rejected for `max`.
+    fn rejected_request_id(env: &Env, server_seed: &BytesN<32>, max: u64, from: u64) -> u64 {
+        let limit = (1u128 << 64) - (1u128 << 64) % max as u128;
+        (from..)
+            .find(|id| raw_draw(env, server_seed, *id, &[]) as u128 >= limit)
+            .unwrap()
+    }
+
     fn seed(env: &Env, byte: u8) -> BytesN<32> {
         let mut arr = [0u8; 32];
         arr[31] = byte;
@@ -605,7 +1402,7 @@ mod test {
         let (client, _, _, game) = setup(&env);
         env.mock_all_auths();
 
-        client.request_random(&game, &1u64, &6u64);
+        client.request_random(&game, &1u64, &6u64, &None);
 
         // After request, result is not yet available.
         let result = client.try_get_result(&1u64);
@@ -629,9 +1426,9 @@ mod test {
 
         let max = 6u64;
         let request_id = 42u64;
-        let server_seed = seed(&env, 0xAB);
+        let server_seed = link(&env, 1);
 
-        client.request_random(&game, &request_id, &max);
+        client.request_random(&game, &request_id, &max, &None);
         client.fulfill_random(&oracle, &request_id, &server_seed);
 
         let entry = client.get_result(&request_id);
@@ -652,9 +1449,9 @@ mod test {
         let (client, _, _, game) = setup(&env);
         env.mock_all_auths();
 
-        client.request_random(&game, &1u64, &2u64);
+        client.request_random(&game, &1u64, &2u64, &None);
 
-        let result = client.try_request_random(&game, &1u64, &2u64);
+        let result = client.try_request_random(&game, &1u64, &2u64, &None);
         assert!(result.is_err());
     }
 
@@ -668,12 +1465,12 @@ mod test {
         let (client, _, oracle, game) = setup(&env);
         env.mock_all_auths();
 
-        let s = seed(&env, 1);
-        client.request_random(&game, &1u64, &2u64);
+        let s = link(&env, 1);
+        client.request_random(&game, &1u64, &2u64, &None);
         client.fulfill_random(&oracle, &1u64, &s);
 
         // Same ID cannot be requested again after fulfillment.
-        let result = client.try_request_random(&game, &1u64, &2u64);
+        let result = client.try_request_random(&game, &1u64, &2u64, &None);
         assert!(result.is_err());
     }
 
@@ -687,8 +1484,8 @@ mod test {
         let (client, _, oracle, game) = setup(&env);
         env.mock_all_auths();
 
-        let s = seed(&env, 2);
-        client.request_random(&game, &1u64, &2u64);
+        let s = link(&env, 1);
+        client.request_random(&game, &1u64, &2u64, &None);
         client.fulfill_random(&oracle, &1u64, &s);
 
         // Second fulfill on the same request_id must fail.
@@ -704,9 +1501,9 @@ mod test {
 
         let request_id = 77u64;
         let max = 9u64;
-        let server_seed = seed(&env, 7);
+        let server_seed = link(&env, 1);
 
-        client.request_random(&game, &request_id, &max);
+        client.request_random(&game, &request_id, &max, &None);
         client.fulfill_random(&oracle, &request_id, &server_seed);
 
         let status = client.get_request_status(&request_id);
@@ -744,7 +1541,7 @@ mod test {
         env.mock_all_auths();
 
         let stranger = Address::generate(&env);
-        let result = client.try_request_random(&stranger, &1u64, &2u64);
+        let result = client.try_request_random(&stranger, &1u64, &2u64, &None);
         assert!(result.is_err());
     }
 
@@ -758,8 +1555,8 @@ mod test {
         let (client, _, _, game) = setup(&env);
         env.mock_all_auths();
 
-        let s = seed(&env, 3);
-        client.request_random(&game, &1u64, &2u64);
+        let s = link(&env, 1);
+        client.request_random(&game, &1u64, &2u64, &None);
 
         let impostor = Address::generate(&env);
         let result = client.try_fulfill_random(&impostor, &1u64, &s);
@@ -779,8 +1576,8 @@ mod test {
         let max = 6u64; // simulates a six-sided die
 
         for i in 0u64..20 {
-            let s = seed(&env, i as u8);
-            client.request_random(&game, &i, &max);
+            let s = link(&env, i as u32 + 1);
+            client.request_random(&game, &i, &max, &None);
             client.fulfill_random(&oracle, &i, &s);
             let entry = client.get_result(&i);
             assert!(
@@ -806,8 +1603,8 @@ mod test {
         let mut results = [0u64; 8];
 
         for i in 0u64..8 {
-            let s = seed(&env, (i * 37) as u8); // spread out seed bytes
-            client.request_random(&game, &i, &max);
+            let s = link(&env, i as u32 + 1);
+            client.request_random(&game, &i, &max, &None);
             client.fulfill_random(&oracle, &i, &s);
             results[i as usize] = client.get_result(&i).result;
         }
@@ -845,8 +1642,12 @@ mod test {
         let (client, _, _, game) = setup(&env);
         env.mock_all_auths();
 
-        assert!(client.try_request_random(&game, &1u64, &0u64).is_err());
-        assert!(client.try_request_random(&game, &2u64, &1u64).is_err());
+        assert!(client
+            .try_request_random(&game, &1u64, &0u64, &None)
+            .is_err());
+        assert!(client
+            .try_request_random(&game, &2u64, &1u64, &None)
+            .is_err());
     }
 
     // ------------------------------------------------------------------
@@ -861,7 +1662,7 @@ mod test {
 
         client.revoke(&admin, &game);
 
-        let result = client.try_request_random(&game, &1u64, &2u64);
+        let result = client.try_request_random(&game, &1u64, &2u64, &None);
         assert!(result.is_err());
     }
 
@@ -875,7 +1676,7 @@ mod test {
         let (client, _, _, game) = setup(&env);
         env.mock_all_auths();
 
-        client.request_random(&game, &1u64, &4u64);
+        client.request_random(&game, &1u64, &4u64, &None);
 
         // Pending, not yet fulfilled — must return RequestNotFound.
         let result = client.try_get_result(&1u64);
@@ -908,11 +1709,11 @@ mod test {
 
         let max_a = 2u64;
         let max_b = 100u64;
-        let seed_a = seed(&env, 0x11);
-        let seed_b = seed(&env, 0x22);
+        let seed_a = link(&env, 1);
+        let seed_b = link(&env, 2);
 
-        client.request_random(&game, &10u64, &max_a);
-        client.request_random(&game, &20u64, &max_b);
+        client.request_random(&game, &10u64, &max_a, &None);
+        client.request_random(&game, &20u64, &max_b, &None);
 
         client.fulfill_random(&oracle, &10u64, &seed_a);
         client.fulfill_random(&oracle, &20u64, &seed_b);
@@ -941,6 +1742,7 @@ mod test {
             source_type: String::from_str(&env, "oracle-committed-seed"),
             hash_algorithm: String::from_str(&env, "sha256"),
             output_bytes: 8,
+            derivation: Derivation::RejectionSampling,
         };
 
         client.set_entropy_metadata(&admin, &metadata);
@@ -970,6 +1772,7 @@ mod test {
             source_type: String::from_str(&env, "oracle-committed-seed"),
             hash_algorithm: String::from_str(&env, "sha256"),
             output_bytes: 8,
+            derivation: Derivation::RejectionSampling,
         };
 
         let result = client.try_set_entropy_metadata(&game, &metadata);
@@ -1002,9 +1805,8 @@ mod test {
 
         // Fulfill a request before setting metadata
         let max = 100u64;
-        let s = seed(&env, 0x42);
-        client.request_random(&game, &1u64, &max);
-        client.fulfill_random(&oracle, &1u64, &s);
+        client.request_random(&game, &1u64, &max, &None);
+        client.fulfill_random(&oracle, &1u64, &link(&env, 1));
         let result_before = client.get_result(&1u64).result;
 
         // Set metadata
@@ -1013,15 +1815,16 @@ mod test {
             source_type: String::from_str(&env, "oracle-committed-seed"),
             hash_algorithm: String::from_str(&env, "sha256"),
             output_bytes: 8,
+            derivation: Derivation::RejectionSampling,
         };
         client.set_entropy_metadata(&admin, &metadata);
 
-        // Fulfill another request with same seed and max at a different ID
-        client.request_random(&game, &2u64, &max);
-        client.fulfill_random(&oracle, &2u64, &s);
+        // Fulfill another request with the same max at a different ID
+        client.request_random(&game, &2u64, &max, &None);
+        client.fulfill_random(&oracle, &2u64, &link(&env, 2));
         let result_after = client.get_result(&2u64).result;
 
-        // Results differ because request_id differs, but both are valid
+        // Both results are valid
         assert!(result_before < max);
         assert!(result_after < max);
 
@@ -1044,6 +1847,7 @@ mod test {
             source_type: String::from_str(&env, "oracle-committed-seed"),
             hash_algorithm: String::from_str(&env, "sha256"),
             output_bytes: 8,
+            derivation: Derivation::RejectionSampling,
         };
         client.set_entropy_metadata(&admin, &v1);
         assert_eq!(
@@ -1056,6 +1860,7 @@ mod test {
             source_type: String::from_str(&env, "oracle-committed-seed-v2"),
             hash_algorithm: String::from_str(&env, "sha256"),
             output_bytes: 8,
+            derivation: Derivation::RejectionSampling,
         };
         client.set_entropy_metadata(&admin, &v2);
         assert_eq!(
@@ -1063,4 +1868,482 @@ mod test {
             String::from_str(&env, "2.0.0")
         );
     }
+
+    // ------------------------------------------------------------------
+    // 21. Seed chain
+    // ------------------------------------------------------------------
+
+    #[test]
+    fn test_seed_chain_assigns_and_verifies_seeds() {
+        let env = Env::default();
+        let (client, _, oracle, game) = setup(&env);
+        env.mock_all_auths();
+
+        client.request_random(&game, &1u64, &6u64, &None);
+        client.request_random(&game, &2u64, &6u64, &None);
+        let status = client.get_request_status(&2u64);
+        assert_eq!(status.chain_id, Some(1));
+        assert_eq!(status.seed_index, Some(2));
+
+        // Requests are fulfilled in order, each with its own link
+        assert_eq!(
+            client.try_fulfill_random(&oracle, &2u64, &link(&env, 2)),
+            Err(Ok(Error::SeedOutOfOrder))
+        );
+        assert_eq!(
+            client.try_fulfill_random(&oracle, &1u64, &link(&env, 2)),
+            Err(Ok(Error::InvalidSeed))
+        );
+        assert_eq!(
+            client.try_fulfill_random(&oracle, &1u64, &seed(&env, 9)),
+            Err(Ok(Error::InvalidSeed))
+        );
+        client.fulfill_random(&oracle, &1u64, &link(&env, 1));
+        client.fulfill_random(&oracle, &2u64, &link(&env, 2));
+
+        let chain = client.get_current_seed_chain();
+        assert_eq!(chain.tip, link(&env, 0));
+        assert_eq!(chain.assigned, 2);
+        assert_eq!(chain.revealed, 2);
+        assert_eq!(chain.head, link(&env, 2));
+
+        let status = client.get_request_status(&2u64);
+        assert_eq!(status.state, RequestState::Fulfilled);
+        assert_eq!(status.seed_index, Some(2));
+        assert_eq!(status.server_seed, Some(link(&env, 2)));
+    }
+
+    #[test]
+    fn test_seed_chain_rotation() {
+        let env = Env::default();
+        let (client, _, oracle, game) = setup(&env);
+        env.mock_all_auths();
+
+        let secret = seed(&env, 0xD0);
+        let tip = chain_link(&env, &secret, 1, 0);
+        assert_eq!(
+            client.try_commit_seed_chain(&oracle, &tip, &0),
+            Err(Ok(Error::InvalidChainLength))
+        );
+
+        // No rotation while a request waits on the current chain
+        client.request_random(&game, &1u64, &2u64, &None);
+        assert_eq!(
+            client.try_commit_seed_chain(&oracle, &tip, &1),
+            Err(Ok(Error::SeedChainInUse))
+        );
+        client.fulfill_random(&oracle, &1u64, &link(&env, 1));
+
+        // Nor while seeds remain: the oracle knows the next one and could
+        // otherwise move the next request onto a seed of its choosing
+        assert_eq!(
+            client.try_commit_seed_chain(&oracle, &tip, &1),
+            Err(Ok(Error::SeedChainInUse))
+        );
+        for k in 2..CHAIN_LENGTH {
+            client.request_random(&game, &(k as u64), &2u64, &None);
+            client.fulfill_random(&oracle, &(k as u64), &link(&env, k));
+        }
+        client.request_random(&game, &(CHAIN_LENGTH as u64), &2u64, &None);
+        assert_eq!(
+            client.try_commit_seed_chain(&oracle, &tip, &1),
+            Err(Ok(Error::SeedChainInUse))
+        );
+        client.fulfill_random(&oracle, &(CHAIN_LENGTH as u64), &link(&env, CHAIN_LENGTH));
+
+        assert_eq!(client.commit_seed_chain(&oracle, &tip, &1), 2);
+        client.request_random(&game, &100u64, &2u64, &None);
+        assert_eq!(
+            client.try_request_random(&game, &101u64, &2u64, &None),
+            Err(Ok(Error::SeedChainExhausted))
+        );
+        client.fulfill_random(&oracle, &100u64, &secret);
+        assert_eq!(client.get_result(&100u64).chain_id, 2);
+
+        // The rotated-out chain stays readable for audits
+        let old = client.get_seed_chain(&1);
+        assert_eq!(old.revealed, CHAIN_LENGTH);
+        assert_eq!(old.head, link(&env, CHAIN_LENGTH));
+    }
+
+    #[test]
+    fn test_request_requires_seed_chain() {
+        let env = Env::default();
+        let admin = Address::generate(&env);
+        let oracle = Address::generate(&env);
+        let game = Address::generate(&env);
+        let client = RandomGeneratorClient::new(&env, &env.register(RandomGenerator, ()));
+        env.mock_all_auths();
+        client.init(&admin, &oracle);
+        client.authorize(&admin, &game);
+
+        assert_eq!(
+            client.try_request_random(&game, &1u64, &2u64, &None),
+            Err(Ok(Error::SeedChainNotSet))
+        );
+        let impostor = Address::generate(&env);
+        assert_eq!(
+            client.try_commit_seed_chain(&impostor, &link(&env, 0), &1),
+            Err(Ok(Error::NotAuthorized))
+        );
+    }
+
+    // ------------------------------------------------------------------
+    // 22. Client seed and on-chain verification
+    // ------------------------------------------------------------------
+
+    #[test]
+    fn test_client_seed_mixed_into_result() {
+        let env = Env::default();
+        let (client, _, oracle, game) = setup(&env);
+        env.mock_all_auths();
+
+        let max = 1_000_000u64;
+        let client_seed = seed(&env, 0x5A);
+        client.request_random(&game, &1u64, &max, &Some(client_seed.clone()));
+        assert_eq!(
+            client.get_request_status(&1u64).client_seed,
+            Some(client_seed.clone())
+        );
+        client.fulfill_random(&oracle, &1u64, &link(&env, 1));
+
+        let entry = client.get_result(&1u64);
+        assert_eq!(entry.client_seed, Some(client_seed.clone()));
+        assert_eq!(
+            entry.result,
+            expected_result_with_client(&env, &link(&env, 1), &client_seed, 1, max)
+        );
+        assert_ne!(entry.result, expected_result(&env, &link(&env, 1), 1, max));
+    }
+
+    #[test]
+    fn test_verify_result() {
+        let env = Env::default();
+        let (client, _, oracle, game) = setup(&env);
+        env.mock_all_auths();
+
+        client.request_random(&game, &1u64, &6u64, &None);
+        client.request_random(&game, &2u64, &6u64, &Some(seed(&env, 7)));
+        assert_eq!(
+            client.try_verify_result(&2u64),
+            Err(Ok(Error::RequestNotFound))
+        );
+        client.fulfill_random(&oracle, &1u64, &link(&env, 1));
+        client.fulfill_random(&oracle, &2u64, &link(&env, 2));
+        assert!(client.verify_result(&1u64));
+        assert!(client.verify_result(&2u64));
+
+        // A tampered result no longer verifies
+        env.as_contract(&client.address, || {
+            let key = DataKey::FulfilledRequest(2);
+            let mut entry: FulfilledEntry = env.storage().persistent().get(&key).unwrap();
+            entry.result = (entry.result + 1) % 6;
+            env.storage().persistent().set(&key, &entry);
+        });
+        assert!(!client.verify_result(&2u64));
+    }
+
+    // ------------------------------------------------------------------
+    // 23. Rejection sampling
+    // ------------------------------------------------------------------
+
+    /// Half of all draws fall in the biased tail for this bound.
+    const BIASED_MAX: u64 = (1 << 63) + 1;
+
+    #[test]
+    fn test_rejection_sampling_redraws_biased_values() {
+        let env = Env::default();
+        let (client, _, oracle, game) = setup(&env);
+        env.mock_all_auths();
+
+        let request_id = rejected_request_id(&env, &link(&env, 1), BIASED_MAX, 1);
+        client.request_random(&game, &request_id, &BIASED_MAX, &None);
+        client.fulfill_random(&oracle, &request_id, &link(&env, 1));
+
+        let entry = client.get_result(&request_id);
+        assert_eq!(entry.derivation, Derivation::RejectionSampling);
+        assert_eq!(
+            entry.result,
+            expected_rejection_result(&env, &link(&env, 1), request_id, BIASED_MAX)
+        );
+        assert_ne!(
+            entry.result,
+            expected_result(&env, &link(&env, 1), request_id, BIASED_MAX)
+        );
+        assert!(client.verify_result(&request_id));
+    }
+
+    #[test]
+    fn test_derivation_is_fixed_at_request_time() {
+        let env = Env::default();
+        let (client, admin, oracle, game) = setup(&env);
+        env.mock_all_auths();
+
+        let mut metadata = EntropySourceMetadata {
+            version: String::from_str(&env, "1.0.0"),
+            source_type: String::from_str(&env, "oracle-committed-seed"),
+            hash_algorithm: String::from_str(&env, "sha256"),
+            output_bytes: 8,
+            derivation: Derivation::Modulo,
+        };
+        client.set_entropy_metadata(&admin, &metadata);
+        let legacy_id = rejected_request_id(&env, &link(&env, 1), BIASED_MAX, 1);
+        client.request_random(&game, &legacy_id, &BIASED_MAX, &None);
+
+        // Switching derivation does not touch the pending request
+        metadata.version = String::from_str(&env, "2.0.0");
+        metadata.derivation = Derivation::RejectionSampling;
+        client.set_entropy_metadata(&admin, &metadata);
+        let new_id = rejected_request_id(&env, &link(&env, 2), BIASED_MAX, legacy_id + 1);
+        client.request_random(&game, &new_id, &BIASED_MAX, &None);
+
+        client.fulfill_random(&oracle, &legacy_id, &link(&env, 1));
+        client.fulfill_random(&oracle, &new_id, &link(&env, 2));
+
+        let legacy = client.get_result(&legacy_id);
+        assert_eq!(legacy.derivation, Derivation::Modulo);
+        assert_eq!(
+            legacy.result,
+            expected_result(&env, &link(&env, 1), legacy_id, BIASED_MAX)
+        );
+        assert_eq!(
+            client.get_result(&new_id).result,
+            expected_rejection_result(&env, &link(&env, 2), new_id, BIASED_MAX)
+        );
+        assert!(client.verify_result(&legacy_id));
+        assert!(client.verify_result(&new_id));
+    }
+
+    // ------------------------------------------------------------------
+    // 24. Batches and shuffles
+    // ------------------------------------------------------------------
+
+    #[test]
+    fn test_batch_request_draws_counter_mode_values() {
+        let env = Env::default();
+        let (client, _, oracle, game) = setup(&env);
+        env.mock_all_auths();
+
+        client.request_random_batch(&game, &1u64, &10u64, &5u32, &None);
+        client.fulfill_random(&oracle, &1u64, &link(&env, 1));
+
+        let batch = client.get_batch_result(&1u64);
+        assert_eq!(batch.entry.kind, RequestKind::Batch(5));
+        assert_eq!(batch.values.len(), 5);
+        for i in 0..5u32 {
+            let expected = raw_draw(&env, &link(&env, 1), 1, &i.to_be_bytes()) % 10;
+            assert_eq!(batch.values.get(i), Some(expected));
+        }
+        assert_eq!(batch.entry.result, batch.values.get_unchecked(0));
+        assert!(client.verify_result(&1u64));
+
+        // Single requests report their one value
+        client.request_random(&game, &2u64, &10u64, &None);
+        client.fulfill_random(&oracle, &2u64, &link(&env, 2));
+        let single = client.get_batch_result(&2u64);
+        assert_eq!(single.values, Vec::from_array(&env, [single.entry.result]));
+    }
+
+    #[test]
+    fn test_shuffle_returns_permutation() {
+        let env = Env::default();
+        let (client, _, oracle, game) = setup(&env);
+        env.mock_all_auths();
+
+        let n = 52u32;
+        client.request_shuffle(&game, &1u64, &n, &Some(seed(&env, 3)));
+        client.fulfill_random(&oracle, &1u64, &link(&env, 1));
+
+        let deck = client.get_batch_result(&1u64).values;
+        assert_eq!(deck.len(), n);
+        let mut seen = [false; 52];
+        for card in deck.iter() {
+            assert!(!seen[card as usize]);
+            seen[card as usize] = true;
+        }
+        assert!(client.verify_result(&1u64));
+
+        // Tampering with one position breaks verification
+        env.as_contract(&client.address, || {
+            let key = DataKey::BatchResult(1);
+            let mut values: Vec<u64> = env.storage().persistent().get(&key).unwrap();
+            let first = values.get_unchecked(0);
+            values.set(0, values.get_unchecked(1));
+            values.set(1, first);
+            env.storage().persistent().set(&key, &values);
+        });
+        assert!(!client.verify_result(&1u64));
+    }
+
+    #[test]
+    fn test_batch_and_shuffle_bounds() {
+        let env = Env::default();
+        let (client, _, _, game) = setup(&env);
+        env.mock_all_auths();
+
+        assert_eq!(
+            client.try_request_random_batch(&game, &1u64, &10u64, &0u32, &None),
+            Err(Ok(Error::InvalidBatchSize))
+        );
+        assert_eq!(
+            client.try_request_random_batch(&game, &1u64, &10u64, &(MAX_BATCH_SIZE + 1), &None),
+            Err(Ok(Error::InvalidBatchSize))
+        );
+        assert_eq!(
+            client.try_request_random_batch(&game, &1u64, &1u64, &3u32, &None),
+            Err(Ok(Error::InvalidBound))
+        );
+        assert_eq!(
+            client.try_request_shuffle(&game, &1u64, &1u32, &None),
+            Err(Ok(Error::InvalidBatchSize))
+        );
+        client.request_shuffle(&game, &1u64, &MAX_BATCH_SIZE, &None);
+        assert_eq!(
+            client.try_request_random_batch(&game, &1u64, &10u64, &2u32, &None),
+            Err(Ok(Error::DuplicateRequestId))
+        );
+    }
+
+    // ------------------------------------------------------------------
+    // 25. Request expiry
+    // ------------------------------------------------------------------
+
+    fn advance_ledgers(env: &Env, ledgers: u32) {
+        env.ledger().with_mut(|l| l.sequence_number += ledgers);
+    }
+
+    #[test]
+    fn test_request_timeout_config() {
+        let env = Env::default();
+        let (client, admin, _, game) = setup(&env);
+        env.mock_all_auths();
+
+        assert_eq!(
+            client.get_request_timeout(),
+            DEFAULT_REQUEST_TIMEOUT_LEDGERS
+        );
+        assert_eq!(
+            client.try_set_request_timeout(&admin, &0u32),
+            Err(Ok(Error::InvalidTimeout))
+        );
+        assert_eq!(
+            client.try_set_request_timeout(&game, &10u32),
+            Err(Ok(Error::NotAuthorized))
+        );
+        client.set_request_timeout(&admin, &10u32);
+        assert_eq!(client.get_request_timeout(), 10);
+
+        let start = env.ledger().sequence();
+        client.request_random(&game, &1u64, &6u64, &None);
+        let status = client.get_request_status(&1u64);
+        assert_eq!(status.expires_at, Some(start + 10));
+    }
+
+    #[test]
+    fn test_expired_request_cannot_be_fulfilled() {
+        let env = Env::default();
+        let (client, admin, oracle, game) = setup(&env);
+        env.mock_all_auths();
+        client.set_request_timeout(&admin, &10u32);
+
+        client.request_random(&game, &1u64, &6u64, &None);
+        advance_ledgers(&env, 9);
+        assert!(!client.is_expired(&1u64));
+        assert_eq!(
+            client.get_request_status(&1u64).state,
+            RequestState::Pending
+        );
+
+        advance_ledgers(&env, 1);
+        assert!(client.is_expired(&1u64));
+        assert_eq!(
+            client.get_request_status(&1u64).state,
+            RequestState::Expired
+        );
+        assert_eq!(
+            client.try_fulfill_random(&oracle, &1u64, &link(&env, 1)),
+            Err(Ok(Error::RequestExpired))
+        );
+        assert_eq!(
+            client.try_get_result(&1u64),
+            Err(Ok(Error::RequestNotFound))
+        );
+    }
+
+    #[test]
+    fn test_close_expired_unblocks_chain() {
+        let env = Env::default();
+        let (client, admin, oracle, game) = setup(&env);
+        env.mock_all_auths();
+        client.set_request_timeout(&admin, &10u32);
+
+        client.request_random(&game, &1u64, &6u64, &None);
+        advance_ledgers(&env, 5);
+        client.request_random(&game, &2u64, &6u64, &None);
+
+        // Request 2 is live, but waits on request 1's seed.
+        assert_eq!(
+            client.try_close_expired(&oracle, &1u64, &link(&env, 1)),
+            Err(Ok(Error::NotExpired))
+        );
+        advance_ledgers(&env, 5);
+        assert_eq!(
+            client.try_fulfill_random(&oracle, &2u64, &link(&env, 2)),
+            Err(Ok(Error::SeedOutOfOrder))
+        );
+
+        assert_eq!(
+            client.try_close_expired(&game, &1u64, &link(&env, 1)),
+            Err(Ok(Error::NotAuthorized))
+        );
+        assert_eq!(
+            client.try_close_expired(&oracle, &1u64, &seed(&env, 9)),
+            Err(Ok(Error::InvalidSeed))
+        );
+        client.close_expired(&oracle, &1u64, &link(&env, 1));
+        client.fulfill_random(&oracle, &2u64, &link(&env, 2));
+
+        assert!(client.is_expired(&1u64));
+        assert!(!client.is_expired(&2u64));
+        let status = client.get_request_status(&1u64);
+        assert_eq!(status.state, RequestState::Expired);
+        assert_eq!(status.seed_index, Some(1));
+        assert_eq!(status.result, None);
+        assert_eq!(client.get_seed_chain(&1u32).revealed, 2);
+
+        // Closed ids stay reserved
+        assert_eq!(
+            client.try_request_random(&game, &1u64, &6u64, &None),
+            Err(Ok(Error::DuplicateRequestId))
+        );
+        assert_eq!(
+            client.try_close_expired(&oracle, &1u64, &link(&env, 1)),
+            Err(Ok(Error::RequestNotFound))
+        );
+    }
+
+    #[test]
+    fn test_new_chain_after_pending_requests_expire() {
+        let env = Env::default();
+        let (client, admin, oracle, game) = setup(&env);
+        env.mock_all_auths();
+        client.set_request_timeout(&admin, &10u32);
+
+        for id in 1..=CHAIN_LENGTH as u64 {
+            client.request_random(&game, &id, &6u64, &None);
+        }
+        let secret = seed(&env, 0xD0);
+        let tip = chain_link(&env, &secret, 4, 0);
+        assert_eq!(
+            client.try_commit_seed_chain(&oracle, &tip, &4u32),
+            Err(Ok(Error::SeedChainInUse))
+        );
+
+        advance_ledgers(&env, 10);
+        let chain_id = client.commit_seed_chain(&oracle, &tip, &4u32);
+        client.request_random(&game, &100u64, &6u64, &None);
+        client.fulfill_random(&oracle, &100u64, &chain_link(&env, &secret, 4, 1));
+        assert_eq!(client.get_request_status(&100u64).chain_id, Some(chain_id));
+        assert!(client.is_expired(&1u64));
+    }
 }
@@ -1,15 +1,30 @@
 //! Stellarcade Speed Trivia Contract
 //!
 //! Players compete to answer a question as quickly as possible.
-//! Rewards are shared among correct answers submitted before the deadline.
-//! The speed of submission (captured via timestamp) can be used to rank or reward players.
+//! Rewards are shared among correct answers submitted before the deadline,
+//! split according to the round's [`PayoutCurve`]. Answers are ranked by the
+//! ledger timestamp of their commitment, ties broken by player address.
+//!
+//! ## Commit-Reveal
+//! Answers are submitted in two phases so nobody can copy a correct answer
+//! from pending transactions:
+//! 1. Until `deadline`, players call `commit_answer` with
+//!    `sha256(answer || salt || player)`, where `player` is the address's XDR
+//!    encoding (see [`answer_commitment`]).
+//! 2. After `deadline` and until `reveal_deadline`, players call
+//!    `reveal_answer` with the plaintext answer and salt. Correctness is
+//!    evaluated at reveal; speed is still measured at commit time.
+//!
+//! Commitments that are not revealed in time are forfeited: they never count
+//! as correct and cannot claim a reward. The round can only be finalized once
+//! the reveal window has closed.
 
 #![no_std]
 #![allow(unexpected_cfgs)]
 
 use soroban_sdk::{
     contract, contractclient, contracterror, contractevent, contractimpl, contracttype,
-    symbol_short, Address, Bytes, BytesN, Env, Symbol,
+    symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol,
 };
 
 // ---------------------------------------------------------------------------
@@ -52,8 +67,17 @@ pub enum Error {
     Overflow = 12,
     InvalidDeadline = 13,
     PastDeadline = 14,
+    RevealNotOpen = 15,
+    RevealClosed = 16,
+    CommitmentNotFound = 17,
+    AlreadyRevealed = 18,
+    CommitmentMismatch = 19,
+    RevealInProgress = 20,
+    InvalidPayoutCurve = 21,
 }
 
+const BASIS_POINTS_DIVISOR: i128 = 10_000;
+
 // ---------------------------------------------------------------------------
 // Types
 // ---------------------------------------------------------------------------
@@ -65,15 +89,41 @@ pub enum RoundStatus {
     Finalized = 1,
 }
 
+/// How a round's `reward_amount` is split among correct answers, in
+/// leaderboard order. Amounts nobody qualifies for, including rounding dust,
+/// are released back to the prize pool at finalization.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutCurve {
+    /// Every correct answer gets the same share
+    Equal,
+    /// Shares proportional to `deadline - timestamp + 1`, decaying linearly
+    /// from an answer at `opened_at` to one at `deadline`
+    LinearDecay,
+    /// The i-th fastest correct answer gets `tiers[i]` basis points; slower
+    /// answers get nothing. Tiers must sum to at most 10 000.
+    Tiers(soroban_sdk::Vec<u32>),
+    /// The fastest correct answer gets this many basis points; the rest is
+    /// split equally among the other correct answers
+    FirstCorrect(u32),
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct RoundData {
     pub answer_commitment: BytesN<32>,
     pub reward_amount: i128,
+    pub payout_curve: PayoutCurve,
+    /// Share of each winner under `PayoutCurve::Equal`, 0 for other curves
     pub payout_per_winner: i128,
+    /// Sum of all winners' shares, set at finalization
+    pub total_payout: i128,
     pub winner_count: u32,
     pub status: RoundStatus,
+    /// Last timestamp at which answers can be committed
     pub deadline: u64,
+    /// Last timestamp at which commitments can be revealed
+    pub reveal_deadline: u64,
     pub opened_at: u64,
 }
 
@@ -92,11 +142,15 @@ pub struct RoundSnapshot {
     pub round_id: u64,
     pub opened_at: u64,
     pub deadline: u64,
+    pub reveal_deadline: u64,
     pub now: u64,
     pub answer_window_open: bool,
+    pub reveal_window_open: bool,
     pub reward_amount: i128,
+    pub payout_curve: PayoutCurve,
     pub winner_count: u32,
     pub payout_per_winner: i128,
+    pub total_payout: i128,
 }
 
 #[contracttype]
@@ -105,6 +159,9 @@ pub struct LeaderboardEntry {
     pub player: Address,
     pub correct: bool,
     pub timestamp: u64,
+    /// Reward under the round's payout curve given the answers revealed so
+    /// far; final once the round is finalized
+    pub share: i128,
 }
 
 #[contracttype]
@@ -118,10 +175,15 @@ pub struct LeaderboardSnapshot {
 #[contracttype]
 #[derive(Clone)]
 pub struct Submission {
-    pub answer_hash: BytesN<32>,
+    /// `sha256(answer || salt || player)`, see [`answer_commitment`]
+    pub commitment: BytesN<32>,
+    pub revealed: bool,
+    /// Only meaningful once `revealed`
     pub correct: bool,
     pub claimed: bool,
     pub timestamp: u64,
+    /// Reward owed, set at finalization
+    pub payout: i128,
 }
 
 #[contracttype]
@@ -148,7 +210,15 @@ pub struct QuestionOpened {
 }
 
 #[contractevent]
-pub struct AnswerSubmitted {
+pub struct AnswerCommitted {
+    #[topic]
+    pub round_id: u64,
+    pub player: Address,
+    pub timestamp: u64,
+}
+
+#[contractevent]
+pub struct AnswerRevealed {
     #[topic]
     pub round_id: u64,
     pub player: Address,
@@ -162,6 +232,7 @@ pub struct RoundFinalized {
     pub round_id: u64,
     pub winners: u32,
     pub payout_per_winner: i128,
+    pub total_payout: i128,
 }
 
 #[contractevent]
@@ -204,20 +275,25 @@ impl SpeedTrivia {
 
     /// Open a new trivia question.
     /// Added `reward_amount` to facilitate prize pool reservation.
+    /// Answers are committed until `deadline` and revealed until `reveal_deadline`.
+    /// `payout_curve` decides how the reward is split among correct answers.
     pub fn open_question(
         env: Env,
         round_id: u64,
         answer_commitment: BytesN<32>,
         deadline: u64,
+        reveal_deadline: u64,
         reward_amount: i128,
+        payout_curve: PayoutCurve,
     ) -> Result<(), Error> {
         let admin = require_admin(&env)?;
         require_positive(reward_amount)?;
 
         let now = env.ledger().timestamp();
-        if deadline <= now {
+        if deadline <= now || reveal_deadline <= deadline {
             return Err(Error::InvalidDeadline);
         }
+        validate_payout_curve(&payout_curve, reward_amount, deadline - now)?;
 
         let key = DataKey::Round(round_id);
         if env.storage().persistent().has(&key) {
@@ -231,10 +307,13 @@ impl SpeedTrivia {
         let round = RoundData {
             answer_commitment,
             reward_amount,
+            payout_curve,
             payout_per_winner: 0,
+            total_payout: 0,
             winner_count: 0,
             status: RoundStatus::Open,
             deadline,
+            reveal_deadline,
             opened_at: now,
         };
         env.storage().persistent().set(&key, &round);
@@ -249,62 +328,109 @@ impl SpeedTrivia {
         Ok(())
     }
 
-    /// Submit an answer for a specific round.
-    /// `timestamp` is provide by the caller, verified to be within ledger bounds.
-    pub fn submit_answer(
+    /// Commit to an answer for a specific round.
+    /// `commitment` is `sha256(answer || salt || player)`; see [`answer_commitment`].
+    /// The ledger timestamp of the commitment is what the answer is ranked by.
+    pub fn commit_answer(
         env: Env,
         player: Address,
         round_id: u64,
-        answer: Bytes,
-        timestamp: u64,
+        commitment: BytesN<32>,
     ) -> Result<(), Error> {
         require_initialized(&env)?;
         player.require_auth();
 
+        let round: RoundData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Round(round_id))
+            .ok_or(Error::RoundNotFound)?;
+        if round.status != RoundStatus::Open {
+            return Err(Error::RoundClosed);
+        }
+
+        let timestamp = env.ledger().timestamp();
+        if timestamp > round.deadline {
+            return Err(Error::PastDeadline);
+        }
+
+        let submission_key = DataKey::Submission(round_id, player.clone());
+        if env.storage().persistent().has(&submission_key) {
+            return Err(Error::AlreadySubmitted);
+        }
+
+        let submission = Submission {
+            commitment,
+            revealed: false,
+            correct: false,
+            claimed: false,
+            timestamp,
+            payout: 0,
+        };
+        env.storage().persistent().set(&submission_key, &submission);
+
+        AnswerCommitted {
+            round_id,
+            player,
+            timestamp,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Reveal a committed answer after the answer deadline.
+    /// The answer is correct when `sha256(answer)` matches the round's `answer_commitment`.
+    pub fn reveal_answer(
+        env: Env,
+        player: Address,
+        round_id: u64,
+        answer: Bytes,
+        salt: BytesN<32>,
+    ) -> Result<bool, Error> {
+        require_initialized(&env)?;
+        player.require_auth();
+
         let key = DataKey::Round(round_id);
         let mut round: RoundData = env
             .storage()
             .persistent()
             .get(&key)
             .ok_or(Error::RoundNotFound)?;
-
         if round.status != RoundStatus::Open {
             return Err(Error::RoundClosed);
         }
 
         let now = env.ledger().timestamp();
-        if now > round.deadline {
-            return Err(Error::PastDeadline);
+        if now <= round.deadline {
+            return Err(Error::RevealNotOpen);
         }
-
-        // Validate timestamp: shouldn't be too far in the future or drastically in the past
-        // For simplicity, we ensure it's not beyond current ledger time.
-        if timestamp > now {
-            return Err(Error::InvalidAmount); // Or a more specific error
+        if now > round.reveal_deadline {
+            return Err(Error::RevealClosed);
         }
 
         let submission_key = DataKey::Submission(round_id, player.clone());
-        if env.storage().persistent().has(&submission_key) {
-            return Err(Error::AlreadySubmitted);
+        let mut submission: Submission = env
+            .storage()
+            .persistent()
+            .get(&submission_key)
+            .ok_or(Error::CommitmentNotFound)?;
+        if submission.revealed {
+            return Err(Error::AlreadyRevealed);
+        }
+        if answer_commitment(&env, &answer, &salt, &player) != submission.commitment {
+            return Err(Error::CommitmentMismatch);
         }
 
         let answer_hash: BytesN<32> = env.crypto().sha256(&answer).into();
         let correct = answer_hash == round.answer_commitment;
 
         if correct {
-            round.winner_count = round
-                .winner_count
-                .checked_add(1)
-                .ok_or(Error::Overflow)?;
+            round.winner_count = round.winner_count.checked_add(1).ok_or(Error::Overflow)?;
             env.storage().persistent().set(&key, &round);
         }
 
-        let submission = Submission {
-            answer_hash,
-            correct,
-            claimed: false,
-            timestamp,
-        };
+        submission.revealed = true;
+        submission.correct = correct;
         env.storage().persistent().set(&submission_key, &submission);
 
         let mut leaderboard: soroban_sdk::Vec<LeaderboardEntry> = env
@@ -315,20 +441,21 @@ impl SpeedTrivia {
         leaderboard.push_back(LeaderboardEntry {
             player: player.clone(),
             correct,
-            timestamp,
+            timestamp: submission.timestamp,
+            share: 0,
         });
         env.storage()
             .persistent()
             .set(&DataKey::Leaderboard(round_id), &leaderboard);
 
-        AnswerSubmitted {
+        AnswerRevealed {
             round_id,
             player,
             correct,
-            timestamp,
+            timestamp: submission.timestamp,
         }
         .publish(&env);
-        Ok(())
+        Ok(correct)
     }
 
     /// Finalize the round, closing it and calculating the payout per winner.
@@ -345,10 +472,14 @@ impl SpeedTrivia {
             return Err(Error::RoundClosed);
         }
 
-        // Allow finalize even before deadline if admin chooses, or wait until after.
-        // Usually finalize happens after deadline.
+        // Unrevealed commitments are forfeited, so every player must have had
+        // the whole reveal window.
+        if env.ledger().timestamp() <= round.reveal_deadline {
+            return Err(Error::RevealInProgress);
+        }
 
-        let payout_per_winner = if round.winner_count == 0 {
+        let equal_split = round.payout_curve == PayoutCurve::Equal;
+        let payout_per_winner = if round.winner_count == 0 || !equal_split {
             0
         } else {
             round
@@ -357,14 +488,35 @@ impl SpeedTrivia {
                 .ok_or(Error::Overflow)?
         };
 
-        if round.winner_count == 0 {
+        let mut total_payout: i128 = 0;
+        for entry in ranked_leaderboard(&env, round_id, &round)?.iter() {
+            if entry.share <= 0 {
+                continue;
+            }
+            let submission_key = DataKey::Submission(round_id, entry.player);
+            let mut submission: Submission = env
+                .storage()
+                .persistent()
+                .get(&submission_key)
+                .ok_or(Error::CommitmentNotFound)?;
+            submission.payout = entry.share;
+            env.storage().persistent().set(&submission_key, &submission);
+            total_payout = total_payout.checked_add(entry.share).ok_or(Error::Overflow)?;
+        }
+
+        let unallocated = round
+            .reward_amount
+            .checked_sub(total_payout)
+            .ok_or(Error::Overflow)?;
+        if unallocated > 0 {
             let prize_pool = get_prize_pool(&env)?;
             let pool_client = PrizePoolClient::new(&env, &prize_pool);
-            pool_client.release(&admin, &round_id, &round.reward_amount);
+            pool_client.release(&admin, &round_id, &unallocated);
         }
 
         round.status = RoundStatus::Finalized;
         round.payout_per_winner = payout_per_winner;
+        round.total_payout = total_payout;
         env.storage().persistent().set(&key, &round);
         env.storage().instance().set(&DataKey::LatestRoundId, &round_id);
 
@@ -372,6 +524,7 @@ impl SpeedTrivia {
             round_id,
             winners: round.winner_count,
             payout_per_winner,
+            total_payout,
         }
         .publish(&env);
         Ok(())
@@ -403,14 +556,14 @@ impl SpeedTrivia {
             return Err(Error::AlreadyClaimed);
         }
 
-        if !submission.correct || round.payout_per_winner <= 0 {
+        if !submission.correct || submission.payout <= 0 {
             return Err(Error::NoRewardAvailable);
         }
 
         let prize_pool = get_prize_pool(&env)?;
         let pool_client = PrizePoolClient::new(&env, &prize_pool);
         let admin = get_admin(&env)?;
-        pool_client.payout(&admin, &player, &round_id, &round.payout_per_winner);
+        pool_client.payout(&admin, &player, &round_id, &submission.payout);
 
         let balance_contract = get_balance_contract(&env)?;
         let balance_client = BalanceClient::new(&env, &balance_contract);
@@ -420,13 +573,13 @@ impl SpeedTrivia {
         balance_client.debit(
             &contract_addr,
             &contract_addr,
-            &round.payout_per_winner,
+            &submission.payout,
             &symbol_short!("payout"),
         );
         balance_client.credit(
             &contract_addr,
             &player,
-            &round.payout_per_winner,
+            &submission.payout,
             &symbol_short!("win"),
         );
 
@@ -436,10 +589,10 @@ impl SpeedTrivia {
         RewardClaimed {
             round_id,
             player,
-            amount: round.payout_per_winner,
+            amount: submission.payout,
         }
         .publish(&env);
-        Ok(round.payout_per_winner)
+        Ok(submission.payout)
     }
 
     /// Get round data.
@@ -456,11 +609,15 @@ impl SpeedTrivia {
                 round_id: 0,
                 opened_at: 0,
                 deadline: 0,
+                reveal_deadline: 0,
                 now,
                 answer_window_open: false,
+                reveal_window_open: false,
                 reward_amount: 0,
+                payout_curve: PayoutCurve::Equal,
                 winner_count: 0,
                 payout_per_winner: 0,
+                total_payout: 0,
             };
         };
 
@@ -474,11 +631,15 @@ impl SpeedTrivia {
                 round_id,
                 opened_at: 0,
                 deadline: 0,
+                reveal_deadline: 0,
                 now,
                 answer_window_open: false,
+                reveal_window_open: false,
                 reward_amount: 0,
+                payout_curve: PayoutCurve::Equal,
                 winner_count: 0,
                 payout_per_winner: 0,
+                total_payout: 0,
             };
         };
 
@@ -488,20 +649,28 @@ impl SpeedTrivia {
         };
 
         let answer_window_open = round.status == RoundStatus::Open && now <= round.deadline;
+        let reveal_window_open = round.status == RoundStatus::Open
+            && now > round.deadline
+            && now <= round.reveal_deadline;
 
         RoundSnapshot {
             status,
             round_id,
             opened_at: round.opened_at,
             deadline: round.deadline,
+            reveal_deadline: round.reveal_deadline,
             now,
             answer_window_open,
+            reveal_window_open,
             reward_amount: round.reward_amount,
+            payout_curve: round.payout_curve,
             winner_count: round.winner_count,
             payout_per_winner: round.payout_per_winner,
+            total_payout: round.total_payout,
         }
     }
 
+    /// Revealed answers in ranking order with each player's share of the reward.
     pub fn get_leaderboard_snapshot(env: Env) -> LeaderboardSnapshot {
         let Some(round_id) = env.storage().instance().get::<DataKey, u64>(&DataKey::LatestRoundId) else {
             return LeaderboardSnapshot {
@@ -528,7 +697,9 @@ impl SpeedTrivia {
             RoundStatus::Finalized => RoundSnapshotStatus::Finalized,
         };
 
-        let entries = read_leaderboard(&env, round_id);
+        // Open rounds validate their curve, so ranking cannot overflow
+        let entries = ranked_leaderboard(&env, round_id, &round)
+            .unwrap_or_else(|_| read_leaderboard(&env, round_id));
         LeaderboardSnapshot {
             status,
             round_id,
@@ -541,6 +712,21 @@ impl SpeedTrivia {
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// The value a player commits to: `sha256(answer || salt || player)`, with
+/// `player` encoded as its `ScVal` XDR. Binding the player stops others from
+/// replaying a commitment seen in a pending transaction.
+pub fn answer_commitment(
+    env: &Env,
+    answer: &Bytes,
+    salt: &BytesN<32>,
+    player: &Address,
+) -> BytesN<32> {
+    let mut preimage = answer.clone();
+    preimage.append(&Bytes::from(salt));
+    preimage.append(&player.clone().to_xdr(env));
+    env.crypto().sha256(&preimage).into()
+}
+
 fn require_initialized(env: &Env) -> Result<(), Error> {
     if !env.storage().instance().has(&DataKey::Admin) {
         return Err(Error::NotInitialized);
@@ -593,7 +779,7 @@ fn read_leaderboard(env: &Env, round_id: u64) -> soroban_sdk::Vec<LeaderboardEnt
     // Order by timestamp asc, then by player address (stable tie-break).
     let mut working = raw;
     let mut sorted: soroban_sdk::Vec<LeaderboardEntry> = soroban_sdk::Vec::new(env);
-    while working.len() > 0 {
+    while !working.is_empty() {
         let mut best_idx: u32 = 0;
         let mut best = working.get(0).unwrap();
 
@@ -618,6 +804,97 @@ fn read_leaderboard(env: &Env, round_id: u64) -> soroban_sdk::Vec<LeaderboardEnt
     sorted
 }
 
+/// Rejects malformed curves and rewards too large to split under them
+/// without overflowing. `answer_window` is `deadline - opened_at`.
+fn validate_payout_curve(
+    curve: &PayoutCurve,
+    reward_amount: i128,
+    answer_window: u64,
+) -> Result<(), Error> {
+    let scale = match curve {
+        PayoutCurve::Equal => return Ok(()),
+        PayoutCurve::LinearDecay => answer_window as i128 + 1,
+        PayoutCurve::Tiers(tiers) => {
+            let mut total: u32 = 0;
+            for bps in tiers.iter() {
+                total = total.checked_add(bps).ok_or(Error::InvalidPayoutCurve)?;
+            }
+            if tiers.is_empty() || total as i128 > BASIS_POINTS_DIVISOR {
+                return Err(Error::InvalidPayoutCurve);
+            }
+            BASIS_POINTS_DIVISOR
+        }
+        PayoutCurve::FirstCorrect(bps) => {
+            if *bps as i128 > BASIS_POINTS_DIVISOR {
+                return Err(Error::InvalidPayoutCurve);
+            }
+            BASIS_POINTS_DIVISOR
+        }
+    };
+    reward_amount.checked_mul(scale).ok_or(Error::InvalidAmount)?;
+    Ok(())
+}
+
+/// The leaderboard with each correct answer's share under the round's curve
+fn ranked_leaderboard(
+    env: &Env,
+    round_id: u64,
+    round: &RoundData,
+) -> Result<soroban_sdk::Vec<LeaderboardEntry>, Error> {
+    let entries = read_leaderboard(env, round_id);
+    let reward = round.reward_amount;
+    let winners = round.winner_count as i128;
+    let decay_weight = |timestamp: u64| round.deadline.saturating_sub(timestamp) as i128 + 1;
+
+    let mut total_weight: i128 = 0;
+    if round.payout_curve == PayoutCurve::LinearDecay {
+        for entry in entries.iter().filter(|e| e.correct) {
+            total_weight = total_weight
+                .checked_add(decay_weight(entry.timestamp))
+                .ok_or(Error::Overflow)?;
+        }
+    }
+
+    let mut ranked = soroban_sdk::Vec::new(env);
+    let mut rank: u32 = 0;
+    for mut entry in entries.iter() {
+        if entry.correct {
+            entry.share = match &round.payout_curve {
+                PayoutCurve::Equal => reward.checked_div(winners).ok_or(Error::Overflow)?,
+                PayoutCurve::LinearDecay => reward
+                    .checked_mul(decay_weight(entry.timestamp))
+                    .and_then(|v| v.checked_div(total_weight))
+                    .ok_or(Error::Overflow)?,
+                PayoutCurve::Tiers(tiers) => match tiers.get(rank) {
+                    Some(bps) => basis_points_of(reward, bps)?,
+                    None => 0,
+                },
+                PayoutCurve::FirstCorrect(bps) => {
+                    let first = basis_points_of(reward, *bps)?;
+                    if rank == 0 {
+                        first
+                    } else {
+                        reward
+                            .checked_sub(first)
+                            .and_then(|rest| rest.checked_div(winners - 1))
+                            .ok_or(Error::Overflow)?
+                    }
+                }
+            };
+            rank += 1;
+        }
+        ranked.push_back(entry);
+    }
+    Ok(ranked)
+}
+
+fn basis_points_of(amount: i128, bps: u32) -> Result<i128, Error> {
+    amount
+        .checked_mul(bps as i128)
+        .and_then(|v| v.checked_div(BASIS_POINTS_DIVISOR))
+        .ok_or(Error::Overflow)
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -724,6 +1001,19 @@ mod test {
         env.crypto().sha256(payload).into()
     }
 
+    fn commit(
+        env: &Env,
+        client: &SpeedTriviaClient,
+        player: &Address,
+        round_id: u64,
+        payload: &Bytes,
+    ) -> BytesN<32> {
+        let salt = BytesN::from_array(env, &[7; 32]);
+        let commitment = answer_commitment(env, payload, &salt, player);
+        client.commit_answer(player, &round_id, &commitment);
+        salt
+    }
+
     #[test]
     fn test_lifecycle() {
         let env = Env::default();
@@ -733,10 +1023,21 @@ mod test {
         let payload = Bytes::from_array(&env, &[1, 2, 3]);
         let commitment = hash_answer(&env, &payload);
 
-        client.open_question(&1, &commitment, &deadline, &1000);
-        
-        client.submit_answer(&player, &1, &payload, &env.ledger().timestamp());
-        
+        client.open_question(
+            &1,
+            &commitment,
+            &deadline,
+            &(deadline + 100),
+            &1000,
+            &PayoutCurve::Equal,
+        );
+
+        let salt = commit(&env, &client, &player, 1, &payload);
+
+        env.ledger().set_timestamp(deadline + 1);
+        assert!(client.reveal_answer(&player, &1, &payload, &salt));
+
+        env.ledger().set_timestamp(deadline + 101);
         client.finalize_round(&1);
         
         let reward = client.claim_reward(&player, &1);
@@ -753,12 +1054,37 @@ mod test {
         let payload = Bytes::from_array(&env, &[1, 2, 3]);
         let commitment = hash_answer(&env, &payload);
 
-        client.open_question(&1, &commitment, &deadline, &1000);
+        client.open_question(
+            &1,
+            &commitment,
+            &deadline,
+            &(deadline + 10),
+            &1000,
+            &PayoutCurve::Equal,
+        );
         
         env.ledger().set_timestamp(deadline + 1);
         
-        let result = client.try_submit_answer(&player, &1, &payload, &env.ledger().timestamp());
-        assert!(result.is_err());
+        let result = client.try_commit_answer(&player, &1, &commitment);
+        assert_eq!(result, Err(Ok(Error::PastDeadline)));
+    }
+
+    #[test]
+    fn test_reveal_deadline_must_follow_deadline() {
+        let env = Env::default();
+        let (client, _admin, _player, _trivia_id, _balance) = setup(&env);
+
+        let deadline = env.ledger().timestamp() + 10;
+        let commitment = hash_answer(&env, &Bytes::from_array(&env, &[1]));
+        let result = client.try_open_question(
+            &1,
+            &commitment,
+            &deadline,
+            &deadline,
+            &1000,
+            &PayoutCurve::Equal,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidDeadline)));
     }
 
     #[test]
@@ -775,11 +1101,19 @@ mod test {
             invoke: &soroban_sdk::testutils::MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "open_question",
-                args: (1u64, commitment.clone(), 1000u64, 100i128).into_val(&env),
+                args: (1u64, commitment.clone(), 1000u64, 2000u64, 100i128, PayoutCurve::Equal)
+                    .into_val(&env),
                 sub_invokes: &[],
             },
         }]);
-        let result = client.try_open_question(&1, &commitment, &1000, &100);
+        let result = client.try_open_question(
+            &1,
+            &commitment,
+            &1000,
+            &2000,
+            &100,
+            &PayoutCurve::Equal,
+        );
         assert!(result.is_err());
     }
 
@@ -790,11 +1124,125 @@ mod test {
 
         let payload = Bytes::from_array(&env, &[1]);
         let commitment = hash_answer(&env, &payload);
-        client.open_question(&1, &commitment, &(env.ledger().timestamp() + 100), &100);
+        let deadline = env.ledger().timestamp() + 100;
+        client.open_question(
+            &1,
+            &commitment,
+            &deadline,
+            &(deadline + 100),
+            &100,
+            &PayoutCurve::Equal,
+        );
+
+        let salt = commit(&env, &client, &player, 1, &payload);
+        let result = client.try_commit_answer(&player, &1, &commitment);
+        assert_eq!(result, Err(Ok(Error::AlreadySubmitted)));
+
+        env.ledger().set_timestamp(deadline + 1);
+        client.reveal_answer(&player, &1, &payload, &salt);
+        let result = client.try_reveal_answer(&player, &1, &payload, &salt);
+        assert_eq!(result, Err(Ok(Error::AlreadyRevealed)));
+    }
+
+    #[test]
+    fn test_reveal_window_enforced() {
+        let env = Env::default();
+        let (client, _admin, player, _trivia_id, _balance) = setup(&env);
+        let late = Address::generate(&env);
+
+        let payload = Bytes::from_array(&env, &[1]);
+        let commitment = hash_answer(&env, &payload);
+        let deadline = env.ledger().timestamp() + 100;
+        client.open_question(
+            &1,
+            &commitment,
+            &deadline,
+            &(deadline + 100),
+            &100,
+            &PayoutCurve::Equal,
+        );
 
-        client.submit_answer(&player, &1, &payload, &env.ledger().timestamp());
-        let result = client.try_submit_answer(&player, &1, &payload, &env.ledger().timestamp());
-        assert!(result.is_err());
+        let salt = commit(&env, &client, &player, 1, &payload);
+        let late_salt = commit(&env, &client, &late, 1, &payload);
+
+        // Revealing during the answer window would leak the answer
+        let result = client.try_reveal_answer(&player, &1, &payload, &salt);
+        assert_eq!(result, Err(Ok(Error::RevealNotOpen)));
+
+        env.ledger().set_timestamp(deadline + 1);
+        let other_salt = BytesN::from_array(&env, &[8; 32]);
+        let result = client.try_reveal_answer(&player, &1, &payload, &other_salt);
+        assert_eq!(result, Err(Ok(Error::CommitmentMismatch)));
+
+        let stranger = Address::generate(&env);
+        let result = client.try_reveal_answer(&stranger, &1, &payload, &salt);
+        assert_eq!(result, Err(Ok(Error::CommitmentNotFound)));
+
+        env.ledger().set_timestamp(deadline + 101);
+        let result = client.try_reveal_answer(&late, &1, &payload, &late_salt);
+        assert_eq!(result, Err(Ok(Error::RevealClosed)));
+    }
+
+    #[test]
+    fn test_unrevealed_commitment_forfeited() {
+        let env = Env::default();
+        let (client, _admin, player, _trivia_id, _balance) = setup(&env);
+        let silent = Address::generate(&env);
+
+        let payload = Bytes::from_array(&env, &[1]);
+        let commitment = hash_answer(&env, &payload);
+        let deadline = env.ledger().timestamp() + 100;
+        client.open_question(
+            &1,
+            &commitment,
+            &deadline,
+            &(deadline + 100),
+            &100,
+            &PayoutCurve::Equal,
+        );
+
+        let salt = commit(&env, &client, &player, 1, &payload);
+        commit(&env, &client, &silent, 1, &payload);
+
+        env.ledger().set_timestamp(deadline + 1);
+        client.reveal_answer(&player, &1, &payload, &salt);
+        assert_eq!(client.try_finalize_round(&1), Err(Ok(Error::RevealInProgress)));
+
+        env.ledger().set_timestamp(deadline + 101);
+        client.finalize_round(&1);
+        let round = client.get_round(&1).unwrap();
+        assert_eq!(round.winner_count, 1);
+        assert_eq!(round.payout_per_winner, 100);
+
+        assert_eq!(client.try_claim_reward(&silent, &1), Err(Ok(Error::NoRewardAvailable)));
+        assert_eq!(client.claim_reward(&player, &1), 100);
+    }
+
+    #[test]
+    fn test_wrong_answer_revealed_not_rewarded() {
+        let env = Env::default();
+        let (client, _admin, player, _trivia_id, _balance) = setup(&env);
+
+        let commitment = hash_answer(&env, &Bytes::from_array(&env, &[1]));
+        let deadline = env.ledger().timestamp() + 100;
+        client.open_question(
+            &1,
+            &commitment,
+            &deadline,
+            &(deadline + 100),
+            &100,
+            &PayoutCurve::Equal,
+        );
+
+        let wrong = Bytes::from_array(&env, &[2]);
+        let salt = commit(&env, &client, &player, 1, &wrong);
+        env.ledger().set_timestamp(deadline + 1);
+        assert!(!client.reveal_answer(&player, &1, &wrong, &salt));
+
+        env.ledger().set_timestamp(deadline + 101);
+        client.finalize_round(&1);
+        assert_eq!(client.get_round(&1).unwrap().winner_count, 0);
+        assert_eq!(client.try_claim_reward(&player, &1), Err(Ok(Error::NoRewardAvailable)));
     }
 
     #[test]
@@ -804,8 +1252,16 @@ mod test {
 
         let payload = Bytes::from_array(&env, &[1]);
         let commitment = hash_answer(&env, &payload);
-        client.open_question(&1, &commitment, &(env.ledger().timestamp() + 100), &100);
-        client.submit_answer(&player, &1, &payload, &env.ledger().timestamp());
+        let deadline = env.ledger().timestamp() + 100;
+        client.open_question(
+            &1,
+            &commitment,
+            &deadline,
+            &(deadline + 100),
+            &100,
+            &PayoutCurve::Equal,
+        );
+        commit(&env, &client, &player, 1, &payload);
 
         let result = client.try_claim_reward(&player, &1);
         assert!(result.is_err());
@@ -835,25 +1291,39 @@ mod test {
         let deadline = env.ledger().timestamp() + 100;
         let payload = Bytes::from_array(&env, &[9, 9]);
         let commitment = hash_answer(&env, &payload);
-        client.open_question(&7, &commitment, &deadline, &100);
+        client.open_question(
+            &7,
+            &commitment,
+            &deadline,
+            &(deadline + 100),
+            &100,
+            &PayoutCurve::Equal,
+        );
 
         let snap = client.get_round_snapshot();
         assert_eq!(snap.status, RoundSnapshotStatus::Open);
         assert_eq!(snap.round_id, 7);
-        assert_eq!(snap.answer_window_open, true);
+        assert_eq!(snap.reveal_deadline, deadline + 100);
+        assert!(snap.answer_window_open);
+        assert!(!snap.reveal_window_open);
+
+        env.ledger().set_timestamp(deadline);
+        let salt = commit(&env, &client, &player, 7, &payload);
 
-        // move past deadline -> window closed but round still open
+        // move past deadline -> answer window closed, reveal window open
         env.ledger().set_timestamp(deadline + 1);
         let snap2 = client.get_round_snapshot();
         assert_eq!(snap2.status, RoundSnapshotStatus::Open);
-        assert_eq!(snap2.answer_window_open, false);
+        assert!(!snap2.answer_window_open);
+        assert!(snap2.reveal_window_open);
 
-        // ensure leaderboard has an entry after a submission
-        env.ledger().set_timestamp(deadline);
-        client.submit_answer(&player, &7, &payload, &env.ledger().timestamp());
+        // leaderboard only lists revealed answers
+        assert_eq!(client.get_leaderboard_snapshot().entries.len(), 0);
+        client.reveal_answer(&player, &7, &payload, &salt);
         let lb = client.get_leaderboard_snapshot();
         assert_eq!(lb.round_id, 7);
         assert_eq!(lb.entries.len(), 1);
+        assert_eq!(lb.entries.get(0).unwrap().timestamp, deadline);
     }
 
     #[test]
@@ -867,12 +1337,22 @@ mod test {
         let deadline = env.ledger().timestamp() + 1000;
         let payload = Bytes::from_array(&env, &[1]);
         let commitment = hash_answer(&env, &payload);
-        client.open_question(&2, &commitment, &deadline, &100);
+        client.open_question(
+            &2,
+            &commitment,
+            &deadline,
+            &(deadline + 100),
+            &100,
+            &PayoutCurve::Equal,
+        );
 
         // submit same timestamp for both to force tie-break
         let ts = env.ledger().timestamp();
-        client.submit_answer(&p1, &2, &payload, &ts);
-        client.submit_answer(&p2, &2, &payload, &ts);
+        let s1 = commit(&env, &client, &p1, 2, &payload);
+        let s2 = commit(&env, &client, &p2, 2, &payload);
+        env.ledger().set_timestamp(deadline + 1);
+        client.reveal_answer(&p2, &2, &payload, &s2);
+        client.reveal_answer(&p1, &2, &payload, &s1);
 
         let lb = client.get_leaderboard_snapshot();
         assert_eq!(lb.entries.len(), 2);
@@ -887,4 +1367,139 @@ mod test {
         let s1 = e1.player.to_string();
         assert!(s0 <= s1);
     }
+
+    /// Opens round 3 with `curve` and a deadline 100s out, then for each
+    /// `(offset, correct)` has a new player commit at that offset and reveal.
+    /// Leaves the ledger after the reveal window.
+    fn play_round(
+        env: &Env,
+        client: &SpeedTriviaClient,
+        curve: PayoutCurve,
+        answers: &[(u64, bool)],
+    ) -> soroban_sdk::Vec<Address> {
+        let payload = Bytes::from_array(env, &[1]);
+        let wrong = Bytes::from_array(env, &[2]);
+        let start = env.ledger().timestamp();
+        client.open_question(
+            &3,
+            &hash_answer(env, &payload),
+            &(start + 100),
+            &(start + 200),
+            &1000,
+            &curve,
+        );
+
+        let mut players = soroban_sdk::Vec::new(env);
+        let mut salts = soroban_sdk::Vec::new(env);
+        for (offset, correct) in answers {
+            let player = Address::generate(env);
+            env.ledger().set_timestamp(start + offset);
+            let answer = if *correct { &payload } else { &wrong };
+            salts.push_back(commit(env, client, &player, 3, answer));
+            players.push_back(player);
+        }
+        env.ledger().set_timestamp(start + 101);
+        for (i, (_, correct)) in answers.iter().enumerate() {
+            let answer = if *correct { &payload } else { &wrong };
+            let player = players.get(i as u32).unwrap();
+            client.reveal_answer(&player, &3, answer, &salts.get(i as u32).unwrap());
+        }
+        env.ledger().set_timestamp(start + 201);
+        players
+    }
+
+    #[test]
+    fn test_linear_decay_rewards_speed() {
+        let env = Env::default();
+        let (client, _admin, _player, _trivia_id, _balance) = setup(&env);
+
+        let players = play_round(
+            &env,
+            &client,
+            PayoutCurve::LinearDecay,
+            &[(0, true), (50, true), (100, true)],
+        );
+        client.finalize_round(&3);
+
+        // Weights 101, 51 and 1 out of 153; the unit of dust is released
+        assert_eq!(client.claim_reward(&players.get(0).unwrap(), &3), 660);
+        assert_eq!(client.claim_reward(&players.get(1).unwrap(), &3), 333);
+        assert_eq!(client.claim_reward(&players.get(2).unwrap(), &3), 6);
+        let round = client.get_round(&3).unwrap();
+        assert_eq!(round.total_payout, 999);
+        assert_eq!(round.payout_per_winner, 0);
+    }
+
+    #[test]
+    fn test_tiers_pay_fastest_correct_answers() {
+        let env = Env::default();
+        let (client, _admin, _player, _trivia_id, _balance) = setup(&env);
+
+        let tiers = soroban_sdk::vec![&env, 5_000u32, 3_000u32];
+        let players = play_round(
+            &env,
+            &client,
+            PayoutCurve::Tiers(tiers),
+            &[(10, true), (20, false), (30, true), (40, true)],
+        );
+
+        // Shares are projected before finalization; wrong answers take no tier
+        let entries = client.get_leaderboard_snapshot().entries;
+        let shares: [i128; 4] = core::array::from_fn(|i| entries.get(i as u32).unwrap().share);
+        assert_eq!(shares, [500, 0, 300, 0]);
+
+        client.finalize_round(&3);
+        assert_eq!(client.get_round(&3).unwrap().total_payout, 800);
+        assert_eq!(client.claim_reward(&players.get(0).unwrap(), &3), 500);
+        assert_eq!(client.claim_reward(&players.get(2).unwrap(), &3), 300);
+        assert_eq!(
+            client.try_claim_reward(&players.get(3).unwrap(), &3),
+            Err(Ok(Error::NoRewardAvailable))
+        );
+    }
+
+    #[test]
+    fn test_first_correct_takes_percentage() {
+        let env = Env::default();
+        let (client, _admin, _player, _trivia_id, _balance) = setup(&env);
+
+        let players = play_round(
+            &env,
+            &client,
+            PayoutCurve::FirstCorrect(6_000),
+            &[(30, true), (10, true), (20, true)],
+        );
+        client.finalize_round(&3);
+
+        assert_eq!(client.claim_reward(&players.get(1).unwrap(), &3), 600);
+        assert_eq!(client.claim_reward(&players.get(0).unwrap(), &3), 200);
+        assert_eq!(client.claim_reward(&players.get(2).unwrap(), &3), 200);
+    }
+
+    #[test]
+    fn test_invalid_payout_curves_rejected() {
+        let env = Env::default();
+        let (client, _admin, _player, _trivia_id, _balance) = setup(&env);
+
+        let commitment = hash_answer(&env, &Bytes::from_array(&env, &[1]));
+        let invalid = [
+            PayoutCurve::Tiers(soroban_sdk::vec![&env]),
+            PayoutCurve::Tiers(soroban_sdk::vec![&env, 6_000u32, 5_000u32]),
+            PayoutCurve::FirstCorrect(10_001),
+        ];
+        for curve in invalid {
+            let result = client.try_open_question(&1, &commitment, &100, &200, &1000, &curve);
+            assert_eq!(result, Err(Ok(Error::InvalidPayoutCurve)));
+        }
+
+        let result = client.try_open_question(
+            &1,
+            &commitment,
+            &100,
+            &200,
+            &i128::MAX,
+            &PayoutCurve::LinearDecay,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    }
 }
@@ -1,11 +1,65 @@
 //! Stellarcade Daily Trivia Game Contract
 //!
-//! Supports lightweight round configuration so clients can inspect the
-//! currently active round and its question-set metadata.
+//! Runs multi-question trivia rounds. Clients can inspect the currently
+//! active round and its question-set metadata.
+//!
+//! ## Round Flow
+//! 1. The admin calls `configure_round` with the Merkle root of the round's
+//!    answers; the reward is reserved in the prize pool.
+//! 2. The admin activates the round. Between `starts_at` and `ends_at`,
+//!    players call `submit_answer` once per question with a commitment to
+//!    their answer, `sha256(answer || salt || player)` (see
+//!    [`answer_commitment`]), so nobody can copy an answer from the ledger.
+//! 3. After `ends_at` the admin calls `close_round`, and until
+//!    `reveal_ends_at` players open their commitments with
+//!    `reveal_submission`. Each opened answer increments a counter for its
+//!    `(question, answer_hash)` pair.
+//! 4. After `reveal_ends_at` anyone reveals each question's answer with
+//!    `reveal_answer`, proving it against the committed root. The counter of
+//!    the correct answer is that question's number of correct players, so a
+//!    reveal costs the same however many players answered. Once every
+//!    question is revealed the round is settled. If some answers are still
+//!    unrevealed [`ANSWER_REVEAL_TIMEOUT`] seconds after `reveal_ends_at`,
+//!    anyone can settle it with `expire_round` instead.
+//! 5. Players call `claim_reward`, which scores their opened answers against
+//!    the revealed ones, and are paid `reward_amount * score / total_score`
+//!    through the prize pool. Unopened commitments score nothing.
+//!
+//! ## Answer Commitment
+//! Leaves are `sha256(question_id_be || salt || answer)` (see
+//! [`answer_leaf`]), with `question_id` as 4 big-endian bytes, a secret 32-byte
+//! `salt` per question and `answer` as its raw UTF-8 bytes; answers must match
+//! byte for byte. The salt keeps the answers from being brute-forced from the
+//! root while the round is open, and is published with `reveal_answer`. The tree has `2^depth` leaves with
+//! `depth = ceil(log2(question_count))`, and question `q` sits at index
+//! `q - 1`; unused leaves may hold any 32 bytes. Interior nodes are
+//! `sha256(left || right)`, and the bits of the leaf index pick the side at
+//! each level, so the root holds exactly one answer per question.
 #![no_std]
 #![allow(unexpected_cfgs)]
+// `configure_round` takes the full round configuration in one call; the lint
+// also fires on macro-generated client stubs.
+#![allow(clippy::too_many_arguments)]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, String, Vec,
+};
+
+/// Largest `question_count`. Players are scored lazily, one question at a
+/// time, so this bounds the entries `claim_reward` and `player_score` read.
+pub const MAX_QUESTIONS: u32 = 32;
 
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, String};
+/// Seconds after `reveal_ends_at` that the answers have to be revealed
+/// before `expire_round` can settle the round without them.
+pub const ANSWER_REVEAL_TIMEOUT: u64 = 86_400;
+
+#[contractclient(name = "PrizePoolClient")]
+pub trait PrizePoolContract {
+    fn reserve(env: Env, admin: Address, game_id: u64, amount: i128);
+    fn release(env: Env, admin: Address, game_id: u64, amount: i128);
+    fn payout(env: Env, admin: Address, to: Address, game_id: u64, amount: i128);
+}
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -17,15 +71,39 @@ pub enum Error {
     RoundAlreadyExists = 4,
     RoundNotFound = 5,
     InvalidConfig = 6,
+    NoActiveRound = 7,
+    AnswersClosed = 8,
+    InvalidQuestion = 9,
+    AlreadyAnswered = 10,
+    InvalidRoundStatus = 11,
+    RoundInProgress = 12,
+    AlreadyRevealed = 13,
+    InvalidProof = 14,
+    NoRewardAvailable = 15,
+    AlreadyClaimed = 16,
+    Overflow = 17,
+    RevealNotOpen = 18,
+    RevealClosed = 19,
+    CommitmentNotFound = 20,
+    CommitmentMismatch = 21,
+    RevealInProgress = 22,
+    RoundNotExpired = 23,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     Admin,
+    PrizePoolContract,
     ActiveRoundId,
     Round(u64),
     Participation(u64, Address),
+    /// A player's committed answer to a question
+    Submission(u64, u32, Address),
+    /// Opened submissions per `(round, question, answer_hash)`
+    AnswerCount(u64, u32, BytesN<32>),
+    /// [`answer_hash`] of a question's revealed answer
+    Revealed(u64, u32),
 }
 
 #[contracttype]
@@ -34,6 +112,8 @@ pub enum RoundStatus {
     Configured = 0,
     Active = 1,
     Closed = 2,
+    /// Every answer revealed; rewards can be claimed
+    Settled = 3,
 }
 
 #[contracttype]
@@ -45,9 +125,35 @@ pub struct RoundData {
     pub difficulty: u32,
     pub starts_at: u64,
     pub ends_at: u64,
+    /// Last timestamp at which players can open their commitments
+    pub reveal_ends_at: u64,
     pub participant_count: u32,
     pub submission_count: u32,
     pub status: RoundStatus,
+    /// Merkle root of the round's answer leaves
+    pub answers_root: BytesN<32>,
+    pub reward_amount: i128,
+    pub revealed_count: u32,
+    /// Sum of all players' scores over the revealed questions
+    pub total_score: u32,
+}
+
+/// A player's progress in one round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Participation {
+    pub answered: u32,
+    pub claimed: bool,
+}
+
+/// A player's answer to one question
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Submission {
+    /// `sha256(answer || salt || player)`, see [`answer_commitment`]
+    pub commitment: BytesN<32>,
+    /// [`answer_hash`] of the opened answer; `None` until opened
+    pub answer_hash: Option<BytesN<32>>,
 }
 
 #[contracttype]
@@ -60,6 +166,7 @@ pub struct QuestionSetMetadata {
     pub difficulty: u32,
     pub starts_at: u64,
     pub ends_at: u64,
+    pub reveal_ends_at: u64,
     pub status: RoundStatus,
 }
 
@@ -77,23 +184,91 @@ pub struct ActiveRoundSnapshot {
     pub is_accepting_answers: bool,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerScore {
+    pub round_id: u64,
+    pub answered: u32,
+    /// Opened answers matching the revealed ones
+    pub score: u32,
+    /// Reward owed once the round is settled, 0 before
+    pub reward: i128,
+    pub claimed: bool,
+}
+
+#[contractevent]
+pub struct AnswerSubmitted {
+    #[topic]
+    pub round_id: u64,
+    pub player: Address,
+    pub question_id: u32,
+}
+
+#[contractevent]
+pub struct SubmissionRevealed {
+    #[topic]
+    pub round_id: u64,
+    pub player: Address,
+    pub question_id: u32,
+    pub answer: String,
+}
+
+#[contractevent]
+pub struct RoundClosed {
+    #[topic]
+    pub round_id: u64,
+    pub participant_count: u32,
+    pub submission_count: u32,
+}
+
+#[contractevent]
+pub struct AnswerRevealed {
+    #[topic]
+    pub round_id: u64,
+    pub question_id: u32,
+    pub answer: String,
+    pub correct_count: u32,
+}
+
+#[contractevent]
+pub struct RoundSettled {
+    #[topic]
+    pub round_id: u64,
+    pub total_score: u32,
+}
+
+#[contractevent]
+pub struct RewardClaimed {
+    #[topic]
+    pub round_id: u64,
+    pub player: Address,
+    pub score: u32,
+    pub amount: i128,
+}
+
 #[contract]
 pub struct TriviaGame;
 
 #[contractimpl]
 impl TriviaGame {
-    /// Initialize the contract once with an admin who can configure rounds.
-    pub fn init(env: Env, admin: Address) -> Result<(), Error> {
+    /// Initialize the contract once with an admin who can configure rounds
+    /// and the prize pool that funds them.
+    pub fn init(env: Env, admin: Address, prize_pool_contract: Address) -> Result<(), Error> {
         if env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::AlreadyInitialized);
         }
 
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::PrizePoolContract, &prize_pool_contract);
         Ok(())
     }
 
-    /// Configure a round and its question-set metadata.
+    /// Configure a round, its question-set metadata and the Merkle root of
+    /// its answers, reserving `reward_amount` in the prize pool. Answers are
+    /// committed until `ends_at` and opened until `reveal_ends_at`.
     pub fn configure_round(
         env: Env,
         admin: Address,
@@ -104,10 +279,20 @@ impl TriviaGame {
         difficulty: u32,
         starts_at: u64,
         ends_at: u64,
+        reveal_ends_at: u64,
+        answers_root: BytesN<32>,
+        reward_amount: i128,
     ) -> Result<(), Error> {
         require_admin(&env, &admin)?;
 
-        if round_id == 0 || question_set_id == 0 || question_count == 0 || ends_at <= starts_at {
+        if round_id == 0
+            || question_set_id == 0
+            || question_count == 0
+            || question_count > MAX_QUESTIONS
+            || ends_at <= starts_at
+            || reveal_ends_at <= ends_at
+            || reward_amount <= 0
+        {
             return Err(Error::InvalidConfig);
         }
 
@@ -116,6 +301,10 @@ impl TriviaGame {
             return Err(Error::RoundAlreadyExists);
         }
 
+        let prize_pool = get_prize_pool(&env)?;
+        let pool_client = PrizePoolClient::new(&env, &prize_pool);
+        pool_client.reserve(&admin, &round_id, &reward_amount);
+
         env.storage().persistent().set(
             &key,
             &RoundData {
@@ -125,9 +314,14 @@ impl TriviaGame {
                 difficulty,
                 starts_at,
                 ends_at,
+                reveal_ends_at,
                 participant_count: 0,
                 submission_count: 0,
                 status: RoundStatus::Configured,
+                answers_root,
+                reward_amount,
+                revealed_count: 0,
+                total_score: 0,
             },
         );
         Ok(())
@@ -144,6 +338,10 @@ impl TriviaGame {
             .get(&key)
             .ok_or(Error::RoundNotFound)?;
 
+        if round.status == RoundStatus::Closed || round.status == RoundStatus::Settled {
+            return Err(Error::InvalidRoundStatus);
+        }
+
         if let Some(previous_round_id) = env
             .storage()
             .instance()
@@ -174,56 +372,354 @@ impl TriviaGame {
         Ok(())
     }
 
-    /// Submit an answer for the current active round.
+    /// Commit to an answer to one question of the active round.
     ///
-    /// The method records participation metadata only; answer validation
-    /// remains out of scope for this contract stub.
-    pub fn submit_answer(env: Env, player: Address, question_id: u32, answer: String) {
-        let _ = (question_id, answer);
+    /// Questions are numbered from 1 to `question_count` and each can be
+    /// answered once per player. `commitment` is
+    /// `sha256(answer || salt || player)` (see [`answer_commitment`]); the
+    /// answer is opened with `reveal_submission` after the round ends.
+    pub fn submit_answer(
+        env: Env,
+        player: Address,
+        question_id: u32,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_initialized(&env)?;
         player.require_auth();
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return;
-        }
-
-        let Some(round_id) = env
+        let round_id = env
             .storage()
             .instance()
             .get::<DataKey, u64>(&DataKey::ActiveRoundId)
-        else {
-            return;
-        };
+            .ok_or(Error::NoActiveRound)?;
 
         let round_key = DataKey::Round(round_id);
-        let Some(mut round) = env
+        let mut round: RoundData = env
             .storage()
             .persistent()
-            .get::<DataKey, RoundData>(&round_key)
-        else {
-            return;
-        };
+            .get(&round_key)
+            .ok_or(Error::RoundNotFound)?;
 
         let now = env.ledger().timestamp();
         if round.status != RoundStatus::Active || now < round.starts_at || now > round.ends_at {
-            return;
+            return Err(Error::AnswersClosed);
+        }
+        if question_id == 0 || question_id > round.question_count {
+            return Err(Error::InvalidQuestion);
         }
 
-        round.submission_count = round.submission_count.saturating_add(1);
-
-        let participation_key = DataKey::Participation(round_id, player);
-        if !env.storage().persistent().has(&participation_key) {
-            env.storage().persistent().set(&participation_key, &true);
-            round.participant_count = round.participant_count.saturating_add(1);
+        let submission_key = DataKey::Submission(round_id, question_id, player.clone());
+        if env.storage().persistent().has(&submission_key) {
+            return Err(Error::AlreadyAnswered);
         }
+        env.storage().persistent().set(
+            &submission_key,
+            &Submission {
+                commitment,
+                answer_hash: None,
+            },
+        );
+
+        round.submission_count = round
+            .submission_count
+            .checked_add(1)
+            .ok_or(Error::Overflow)?;
+
+        let participation_key = DataKey::Participation(round_id, player.clone());
+        let mut participation = match env
+            .storage()
+            .persistent()
+            .get::<DataKey, Participation>(&participation_key)
+        {
+            Some(participation) => participation,
+            None => {
+                round.participant_count = round
+                    .participant_count
+                    .checked_add(1)
+                    .ok_or(Error::Overflow)?;
+                Participation {
+                    answered: 0,
+                    claimed: false,
+                }
+            }
+        };
+        participation.answered += 1;
+        env.storage()
+            .persistent()
+            .set(&participation_key, &participation);
 
         env.storage().persistent().set(&round_key, &round);
+
+        AnswerSubmitted {
+            round_id,
+            player,
+            question_id,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    /// Claim rewards for a correct answer.
-    pub fn claim_reward(_env: Env, player: Address, _game_id: u32) {
+    /// Open a committed answer after `ends_at` and until `reveal_ends_at`.
+    ///
+    /// `answer` and `salt` must hash to the submission's commitment. The
+    /// opened answer is counted under its [`answer_hash`] so `reveal_answer`
+    /// can read the number of correct players without visiting them.
+    pub fn reveal_submission(
+        env: Env,
+        player: Address,
+        round_id: u64,
+        question_id: u32,
+        answer: String,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_initialized(&env)?;
         player.require_auth();
-        // TODO: Verify winner status
-        // TODO: Call PrizePool for payout
+
+        let round: RoundData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Round(round_id))
+            .ok_or(Error::RoundNotFound)?;
+
+        let now = env.ledger().timestamp();
+        if now <= round.ends_at {
+            return Err(Error::RevealNotOpen);
+        }
+        if now > round.reveal_ends_at {
+            return Err(Error::RevealClosed);
+        }
+
+        let submission_key = DataKey::Submission(round_id, question_id, player.clone());
+        let mut submission: Submission = env
+            .storage()
+            .persistent()
+            .get(&submission_key)
+            .ok_or(Error::CommitmentNotFound)?;
+        if submission.answer_hash.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if answer_commitment(&env, &answer, &salt, &player) != submission.commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+
+        let hash = answer_hash(&env, &answer);
+        let count_key = DataKey::AnswerCount(round_id, question_id, hash.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&count_key, &count.checked_add(1).ok_or(Error::Overflow)?);
+
+        submission.answer_hash = Some(hash);
+        env.storage().persistent().set(&submission_key, &submission);
+
+        SubmissionRevealed {
+            round_id,
+            player,
+            question_id,
+            answer,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Stop accepting answers once the round has ended so its answers can be
+    /// revealed.
+    pub fn close_round(env: Env, admin: Address, round_id: u64) -> Result<(), Error> {
+        require_admin(&env, &admin)?;
+
+        let key = DataKey::Round(round_id);
+        let mut round: RoundData = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::RoundNotFound)?;
+
+        if round.status != RoundStatus::Active {
+            return Err(Error::InvalidRoundStatus);
+        }
+        if env.ledger().timestamp() <= round.ends_at {
+            return Err(Error::RoundInProgress);
+        }
+
+        round.status = RoundStatus::Closed;
+        env.storage().persistent().set(&key, &round);
+
+        RoundClosed {
+            round_id,
+            participant_count: round.participant_count,
+            submission_count: round.submission_count,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Reveal the answer to one question of a closed round once players can
+    /// no longer open their submissions, proving it against the committed
+    /// `answers_root`. Revealing the last question settles the round.
+    ///
+    /// Anyone holding the answer and its proof may reveal it. Returns the
+    /// number of players whose opened answer is correct, read from the
+    /// answer's counter.
+    pub fn reveal_answer(
+        env: Env,
+        round_id: u64,
+        question_id: u32,
+        answer: String,
+        salt: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        require_initialized(&env)?;
+
+        let key = DataKey::Round(round_id);
+        let mut round: RoundData = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::RoundNotFound)?;
+
+        if round.status != RoundStatus::Closed {
+            return Err(Error::InvalidRoundStatus);
+        }
+        if env.ledger().timestamp() <= round.reveal_ends_at {
+            return Err(Error::RevealInProgress);
+        }
+        if question_id == 0 || question_id > round.question_count {
+            return Err(Error::InvalidQuestion);
+        }
+        let revealed_key = DataKey::Revealed(round_id, question_id);
+        if env.storage().persistent().has(&revealed_key) {
+            return Err(Error::AlreadyRevealed);
+        }
+
+        let leaf = answer_leaf(&env, question_id, &salt, &answer);
+        let hash = answer_hash(&env, &answer);
+        if proof.len() != merkle_depth(round.question_count)
+            || !verify_merkle_proof(&env, &leaf, question_id - 1, &proof, &round.answers_root)
+        {
+            return Err(Error::InvalidProof);
+        }
+        let correct_count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AnswerCount(round_id, question_id, hash.clone()))
+            .unwrap_or(0);
+        env.storage().persistent().set(&revealed_key, &hash);
+
+        round.total_score = round
+            .total_score
+            .checked_add(correct_count)
+            .ok_or(Error::Overflow)?;
+        round.revealed_count += 1;
+        let settled = round.revealed_count == round.question_count;
+        if settled {
+            settle(&env, round_id, &mut round)?;
+        }
+        env.storage().persistent().set(&key, &round);
+
+        AnswerRevealed {
+            round_id,
+            question_id,
+            answer,
+            correct_count,
+        }
+        .publish(&env);
+        if settled {
+            RoundSettled {
+                round_id,
+                total_score: round.total_score,
+            }
+            .publish(&env);
+        }
+        Ok(correct_count)
+    }
+
+    /// Settle a round whose answers were not all revealed within
+    /// [`ANSWER_REVEAL_TIMEOUT`] seconds of `reveal_ends_at`, so its reward
+    /// cannot stay reserved for good.
+    ///
+    /// Anyone may call it. Players are scored on the questions revealed so
+    /// far; if nobody scored, the reward is released back to the prize pool.
+    pub fn expire_round(env: Env, round_id: u64) -> Result<(), Error> {
+        require_initialized(&env)?;
+
+        let key = DataKey::Round(round_id);
+        let mut round: RoundData = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::RoundNotFound)?;
+
+        if round.status != RoundStatus::Active && round.status != RoundStatus::Closed {
+            return Err(Error::InvalidRoundStatus);
+        }
+        let deadline = round
+            .reveal_ends_at
+            .checked_add(ANSWER_REVEAL_TIMEOUT)
+            .ok_or(Error::Overflow)?;
+        if env.ledger().timestamp() <= deadline {
+            return Err(Error::RoundNotExpired);
+        }
+
+        settle(&env, round_id, &mut round)?;
+        env.storage().persistent().set(&key, &round);
+
+        RoundSettled {
+            round_id,
+            total_score: round.total_score,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Claim a settled round's reward in proportion to the player's score,
+    /// which is computed here from their opened answers.
+    pub fn claim_reward(env: Env, player: Address, round_id: u64) -> Result<i128, Error> {
+        require_initialized(&env)?;
+        player.require_auth();
+
+        let round: RoundData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Round(round_id))
+            .ok_or(Error::RoundNotFound)?;
+        if round.status != RoundStatus::Settled {
+            return Err(Error::InvalidRoundStatus);
+        }
+
+        let participation_key = DataKey::Participation(round_id, player.clone());
+        let mut participation: Participation = env
+            .storage()
+            .persistent()
+            .get(&participation_key)
+            .ok_or(Error::NoRewardAvailable)?;
+        if participation.claimed {
+            return Err(Error::AlreadyClaimed);
+        }
+
+        let score = player_points(&env, round_id, &round, &player);
+        let amount = score_reward(&round, score)?;
+        if amount <= 0 {
+            return Err(Error::NoRewardAvailable);
+        }
+
+        participation.claimed = true;
+        env.storage()
+            .persistent()
+            .set(&participation_key, &participation);
+
+        let admin = get_admin(&env)?;
+        let prize_pool = get_prize_pool(&env)?;
+        let pool_client = PrizePoolClient::new(&env, &prize_pool);
+        pool_client.payout(&admin, &player, &round_id, &amount);
+
+        RewardClaimed {
+            round_id,
+            player,
+            score,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
     }
 
     /// Return the display-safe question-set metadata for a configured round.
@@ -244,10 +740,48 @@ impl TriviaGame {
             difficulty: round.difficulty,
             starts_at: round.starts_at,
             ends_at: round.ends_at,
+            reveal_ends_at: round.reveal_ends_at,
             status: round.status,
         })
     }
 
+    /// Return a player's answers, score and reward for a round.
+    ///
+    /// The score only counts revealed questions; `reward` stays 0 until the
+    /// round is settled.
+    pub fn player_score(env: Env, round_id: u64, player: Address) -> Result<PlayerScore, Error> {
+        require_initialized(&env)?;
+
+        let round: RoundData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Round(round_id))
+            .ok_or(Error::RoundNotFound)?;
+        let participation = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Participation>(&DataKey::Participation(round_id, player.clone()))
+            .unwrap_or(Participation {
+                answered: 0,
+                claimed: false,
+            });
+
+        let score = player_points(&env, round_id, &round, &player);
+        let reward = if round.status == RoundStatus::Settled {
+            score_reward(&round, score)?
+        } else {
+            0
+        };
+
+        Ok(PlayerScore {
+            round_id,
+            answered: participation.answered,
+            score,
+            reward,
+            claimed: participation.claimed,
+        })
+    }
+
     /// Return a deterministic snapshot of the active round, if one exists.
     ///
     /// When no round is active the snapshot is zeroed with
@@ -293,6 +827,124 @@ impl TriviaGame {
     }
 }
 
+/// The Merkle leaf committing to `answer` as the answer of `question_id`:
+/// `sha256(question_id_be || salt || answer)`.
+pub fn answer_leaf(env: &Env, question_id: u32, salt: &BytesN<32>, answer: &String) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &question_id.to_be_bytes());
+    preimage.append(&Bytes::from(salt));
+    preimage.append(&answer.to_bytes());
+    env.crypto().sha256(&preimage).into()
+}
+
+/// `sha256(answer)`, the key opened answers are counted and scored under. It
+/// is only stored once answers are public, after the round ends.
+pub fn answer_hash(env: &Env, answer: &String) -> BytesN<32> {
+    env.crypto().sha256(&answer.to_bytes()).into()
+}
+
+/// The value a player commits to: `sha256(answer || salt || player)`, with
+/// `answer` as its UTF-8 bytes and `player` encoded as its `ScVal` XDR so a
+/// commitment cannot be replayed by another player.
+pub fn answer_commitment(
+    env: &Env,
+    answer: &String,
+    salt: &BytesN<32>,
+    player: &Address,
+) -> BytesN<32> {
+    let mut preimage = answer.to_bytes();
+    preimage.append(&Bytes::from(salt));
+    preimage.append(&player.clone().to_xdr(env));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Depth of the answer tree for `question_count` questions,
+/// `ceil(log2(question_count))`; every proof has exactly this many siblings.
+pub fn merkle_depth(question_count: u32) -> u32 {
+    match question_count {
+        0 | 1 => 0,
+        count => u32::BITS - (count - 1).leading_zeros(),
+    }
+}
+
+/// Folds `proof` into the leaf at `index`, taking the node as the left child
+/// when the index bit for that level is 0, and compares the result with
+/// `root`.
+pub fn verify_merkle_proof(
+    env: &Env,
+    leaf: &BytesN<32>,
+    index: u32,
+    proof: &Vec<BytesN<32>>,
+    root: &BytesN<32>,
+) -> bool {
+    let mut node = leaf.clone();
+    let mut index = index;
+    for sibling in proof.iter() {
+        node = if index & 1 == 0 {
+            hash_pair(env, &node, &sibling)
+        } else {
+            hash_pair(env, &sibling, &node)
+        };
+        index >>= 1;
+    }
+    &node == root
+}
+
+fn hash_pair(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from(left);
+    preimage.append(&Bytes::from(right));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Questions revealed so far whose answer matches the player's opened one
+fn player_points(env: &Env, round_id: u64, round: &RoundData, player: &Address) -> u32 {
+    let mut points = 0;
+    for question_id in 1..=round.question_count {
+        let Some(correct) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BytesN<32>>(&DataKey::Revealed(round_id, question_id))
+        else {
+            continue;
+        };
+        let submission =
+            env.storage()
+                .persistent()
+                .get::<DataKey, Submission>(&DataKey::Submission(
+                    round_id,
+                    question_id,
+                    player.clone(),
+                ));
+        if submission.and_then(|s| s.answer_hash) == Some(correct) {
+            points += 1;
+        }
+    }
+    points
+}
+
+/// Marks `round` settled, releasing its reward if nobody scored
+fn settle(env: &Env, round_id: u64, round: &mut RoundData) -> Result<(), Error> {
+    round.status = RoundStatus::Settled;
+    if round.total_score == 0 {
+        let admin = get_admin(env)?;
+        let prize_pool = get_prize_pool(env)?;
+        let pool_client = PrizePoolClient::new(env, &prize_pool);
+        pool_client.release(&admin, &round_id, &round.reward_amount);
+    }
+    Ok(())
+}
+
+/// `reward_amount * score / total_score`, rounded down
+fn score_reward(round: &RoundData, score: u32) -> Result<i128, Error> {
+    if round.total_score == 0 {
+        return Ok(0);
+    }
+    round
+        .reward_amount
+        .checked_mul(score as i128)
+        .and_then(|v| v.checked_div(round.total_score as i128))
+        .ok_or(Error::Overflow)
+}
+
 fn require_initialized(env: &Env) -> Result<(), Error> {
     if !env.storage().instance().has(&DataKey::Admin) {
         return Err(Error::NotInitialized);
@@ -301,14 +953,24 @@ fn require_initialized(env: &Env) -> Result<(), Error> {
     Ok(())
 }
 
+fn get_admin(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::NotInitialized)
+}
+
+fn get_prize_pool(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PrizePoolContract)
+        .ok_or(Error::NotInitialized)
+}
+
 fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
     require_initialized(env)?;
 
-    let stored_admin: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::Admin)
-        .ok_or(Error::NotInitialized)?;
+    let stored_admin = get_admin(env)?;
     admin.require_auth();
 
     if admin != &stored_admin {
@@ -336,17 +998,64 @@ fn empty_snapshot(now: u64) -> ActiveRoundSnapshot {
 mod test {
     use super::*;
     use soroban_sdk::{
+        contract, contractimpl,
         testutils::{Address as _, Ledger},
-        Address, Env, String,
+        vec, Address, Env, String,
     };
 
-    fn setup(env: &Env) -> (TriviaGameClient<'_>, Address) {
+    #[contract]
+    pub struct MockPrizePool;
+
+    #[contracttype]
+    pub enum PoolKey {
+        Reserved(u64),
+        Paid(u64),
+        Released(u64),
+    }
+
+    #[contractimpl]
+    impl MockPrizePool {
+        pub fn reserve(env: Env, _admin: Address, game_id: u64, amount: i128) {
+            env.storage()
+                .persistent()
+                .set(&PoolKey::Reserved(game_id), &amount);
+        }
+
+        pub fn release(env: Env, _admin: Address, game_id: u64, amount: i128) {
+            env.storage()
+                .persistent()
+                .set(&PoolKey::Released(game_id), &amount);
+        }
+
+        pub fn payout(env: Env, _admin: Address, _to: Address, game_id: u64, amount: i128) {
+            let paid: i128 = env
+                .storage()
+                .persistent()
+                .get(&PoolKey::Paid(game_id))
+                .unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&PoolKey::Paid(game_id), &(paid + amount));
+        }
+
+        pub fn amounts(env: Env, game_id: u64) -> (i128, i128, i128) {
+            let get = |key: PoolKey| env.storage().persistent().get(&key).unwrap_or(0);
+            (
+                get(PoolKey::Reserved(game_id)),
+                get(PoolKey::Paid(game_id)),
+                get(PoolKey::Released(game_id)),
+            )
+        }
+    }
+
+    fn setup(env: &Env) -> (TriviaGameClient<'_>, Address, MockPrizePoolClient<'_>) {
         let admin = Address::generate(env);
+        let pool_id = env.register(MockPrizePool, ());
         let contract_id = env.register(TriviaGame, ());
         let client = TriviaGameClient::new(env, &contract_id);
         env.mock_all_auths();
-        client.init(&admin);
-        (client, admin)
+        client.init(&admin, &pool_id);
+        (client, admin, MockPrizePoolClient::new(env, &pool_id))
     }
 
     fn set_time(env: &Env, timestamp: u64) {
@@ -355,10 +1064,116 @@ mod test {
         });
     }
 
+    /// Answers to a four-question round with the tree's root and each
+    /// answer's proof
+    struct AnswerKey {
+        answers: [String; 4],
+        salts: [BytesN<32>; 4],
+        root: BytesN<32>,
+        proofs: [Vec<BytesN<32>>; 4],
+    }
+
+    /// Root of a tree over `leaves` (a power of two of them) and the proof
+    /// of the leaf at `index`
+    fn merkle_proof(
+        env: &Env,
+        leaves: &Vec<BytesN<32>>,
+        index: u32,
+    ) -> (BytesN<32>, Vec<BytesN<32>>) {
+        let mut level = leaves.clone();
+        let mut index = index;
+        let mut proof = Vec::new(env);
+        while level.len() > 1 {
+            proof.push_back(level.get_unchecked(index ^ 1));
+            let mut parents = Vec::new(env);
+            for i in (0..level.len()).step_by(2) {
+                parents.push_back(hash_pair(
+                    env,
+                    &level.get_unchecked(i),
+                    &level.get_unchecked(i + 1),
+                ));
+            }
+            level = parents;
+            index >>= 1;
+        }
+        (level.get_unchecked(0), proof)
+    }
+
+    fn answer_key(env: &Env) -> AnswerKey {
+        let answers = ["Paris", "42", "blue", "Stellar"].map(|a| String::from_str(env, a));
+        let salts: [BytesN<32>; 4] =
+            core::array::from_fn(|i| BytesN::from_array(env, &[0xa0 + i as u8; 32]));
+        let mut leaves = Vec::new(env);
+        for i in 0..4 {
+            leaves.push_back(answer_leaf(env, i as u32 + 1, &salts[i], &answers[i]));
+        }
+        AnswerKey {
+            root: merkle_proof(env, &leaves, 0).0,
+            proofs: core::array::from_fn(|i| merkle_proof(env, &leaves, i as u32).1),
+            answers,
+            salts,
+        }
+    }
+
+    fn configure(env: &Env, client: &TriviaGameClient, admin: &Address, key: &AnswerKey) {
+        let category = String::from_str(env, "general");
+        client.configure_round(
+            admin, &1u64, &10u64, &4u32, &category, &1u32, &1_000u64, &2_000u64, &2_500u64,
+            &key.root, &1_000i128,
+        );
+        client.activate_round(admin, &1u64);
+        set_time(env, 1_500);
+    }
+
+    fn salt(env: &Env, question_id: u32) -> BytesN<32> {
+        BytesN::from_array(env, &[question_id as u8; 32])
+    }
+
+    /// Commits `player` to `answer` for a question of round 1
+    fn submit(
+        env: &Env,
+        client: &TriviaGameClient,
+        player: &Address,
+        question_id: u32,
+        answer: &String,
+    ) {
+        let commitment = answer_commitment(env, answer, &salt(env, question_id), player);
+        client.submit_answer(player, &question_id, &commitment);
+    }
+
+    /// Opens a commitment made with [`submit`]
+    fn open(
+        env: &Env,
+        client: &TriviaGameClient,
+        player: &Address,
+        question_id: u32,
+        answer: &String,
+    ) {
+        client.reveal_submission(player, &1u64, &question_id, answer, &salt(env, question_id));
+    }
+
+    fn close(env: &Env, client: &TriviaGameClient, admin: &Address) {
+        set_time(env, 2_001);
+        client.close_round(admin, &1u64);
+    }
+
+    fn reveal_all(env: &Env, client: &TriviaGameClient, key: &AnswerKey) {
+        set_time(env, 2_501);
+        for i in 0..4 {
+            client.reveal_answer(
+                &1u64,
+                &(i as u32 + 1),
+                &key.answers[i],
+                &key.salts[i],
+                &key.proofs[i],
+            );
+        }
+    }
+
     #[test]
     fn test_active_round_snapshot_inactive_state() {
         let env = Env::default();
-        let (client, _) = setup(&env);
+        let (client, _, _) = setup(&env);
 
         set_time(&env, 1_000);
         let snapshot = client.active_round_snapshot();
@@ -373,12 +1188,14 @@ mod test {
     #[test]
     fn test_question_set_metadata_for_configured_round() {
         let env = Env::default();
-        let (client, admin) = setup(&env);
+        let (client, admin, pool) = setup(&env);
         env.mock_all_auths();
 
         let category = String::from_str(&env, "general");
+        let root = BytesN::from_array(&env, &[1; 32]);
         client.configure_round(
-            &admin, &7u64, &42u64, &12u32, &category, &3u32, &1_000u64, &2_000u64,
+            &admin, &7u64, &42u64, &12u32, &category, &3u32, &1_000u64, &2_000u64, &2_500u64,
+            &root, &500i128,
         );
 
         let metadata = client.question_set_metadata(&7u64);
@@ -389,29 +1206,33 @@ mod test {
         assert_eq!(metadata.difficulty, 3);
         assert_eq!(metadata.starts_at, 1_000);
         assert_eq!(metadata.ends_at, 2_000);
+        assert_eq!(metadata.reveal_ends_at, 2_500);
         assert_eq!(metadata.status, RoundStatus::Configured);
+        assert_eq!(pool.amounts(&7u64), (500, 0, 0));
     }
 
     #[test]
     fn test_active_round_snapshot_tracks_timing_and_participation() {
         let env = Env::default();
-        let (client, admin) = setup(&env);
+        let (client, admin, _) = setup(&env);
         env.mock_all_auths();
 
         let category = String::from_str(&env, "arcade");
+        let root = BytesN::from_array(&env, &[1; 32]);
         client.configure_round(
-            &admin, &9u64, &77u64, &8u32, &category, &2u32, &1_000u64, &2_000u64,
+            &admin, &9u64, &77u64, &8u32, &category, &2u32, &1_000u64, &2_000u64, &2_500u64, &root,
+            &100i128,
         );
         client.activate_round(&admin, &9u64);
 
         set_time(&env, 1_500);
         let player_a = Address::generate(&env);
         let player_b = Address::generate(&env);
-        let answer = String::from_str(&env, "A");
+        let commitment = BytesN::from_array(&env, &[2; 32]);
 
-        client.submit_answer(&player_a, &1u32, &answer);
-        client.submit_answer(&player_a, &2u32, &answer);
-        client.submit_answer(&player_b, &3u32, &answer);
+        client.submit_answer(&player_a, &1u32, &commitment);
+        client.submit_answer(&player_a, &2u32, &commitment);
+        client.submit_answer(&player_b, &3u32, &commitment);
 
         let snapshot = client.active_round_snapshot();
         assert!(snapshot.has_active_round);
@@ -427,4 +1248,374 @@ mod test {
         let repeated_read = client.active_round_snapshot();
         assert_eq!(snapshot, repeated_read);
     }
+
+    #[test]
+    fn test_submit_answer_validation() {
+        let env = Env::default();
+        let (client, admin, _) = setup(&env);
+        let key = answer_key(&env);
+        let player = Address::generate(&env);
+        let commitment = BytesN::from_array(&env, &[2; 32]);
+
+        let result = client.try_submit_answer(&player, &1u32, &commitment);
+        assert_eq!(result, Err(Ok(Error::NoActiveRound)));
+
+        configure(&env, &client, &admin, &key);
+        client.submit_answer(&player, &1u32, &commitment);
+        let result = client.try_submit_answer(&player, &1u32, &commitment);
+        assert_eq!(result, Err(Ok(Error::AlreadyAnswered)));
+        for question_id in [0u32, 5] {
+            let result = client.try_submit_answer(&player, &question_id, &commitment);
+            assert_eq!(result, Err(Ok(Error::InvalidQuestion)));
+        }
+
+        set_time(&env, 2_001);
+        let result = client.try_submit_answer(&player, &2u32, &commitment);
+        assert_eq!(result, Err(Ok(Error::AnswersClosed)));
+    }
+
+    #[test]
+    fn test_configure_round_validates_reveal_window_and_size() {
+        let env = Env::default();
+        let (client, admin, _) = setup(&env);
+        let category = String::from_str(&env, "general");
+        let root = BytesN::from_array(&env, &[1; 32]);
+
+        let result = client.try_configure_round(
+            &admin, &1u64, &10u64, &4u32, &category, &1u32, &1_000u64, &2_000u64, &2_000u64, &root,
+            &1_000i128,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+        let result = client.try_configure_round(
+            &admin,
+            &1u64,
+            &10u64,
+            &(MAX_QUESTIONS + 1),
+            &category,
+            &1u32,
+            &1_000u64,
+            &2_000u64,
+            &2_500u64,
+            &root,
+            &1_000i128,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+
+    #[test]
+    fn test_reveal_submission_rules() {
+        let env = Env::default();
+        let (client, admin, _) = setup(&env);
+        let key = answer_key(&env);
+        configure(&env, &client, &admin, &key);
+
+        let alice = Address::generate(&env);
+        let mallory = Address::generate(&env);
+        submit(&env, &client, &alice, 1, &key.answers[0]);
+
+        // Nothing can be opened while answers are still accepted
+        let result =
+            client.try_reveal_submission(&alice, &1u64, &1u32, &key.answers[0], &salt(&env, 1));
+        assert_eq!(result, Err(Ok(Error::RevealNotOpen)));
+
+        // Copying alice's commitment does not let mallory open it as their own
+        let commitment = answer_commitment(&env, &key.answers[0], &salt(&env, 1), &alice);
+        client.submit_answer(&mallory, &1u32, &commitment);
+
+        close(&env, &client, &admin);
+        let wrong = String::from_str(&env, "London");
+        let result = client.try_reveal_submission(&alice, &1u64, &1u32, &wrong, &salt(&env, 1));
+        assert_eq!(result, Err(Ok(Error::CommitmentMismatch)));
+        let result =
+            client.try_reveal_submission(&alice, &1u64, &1u32, &key.answers[0], &salt(&env, 2));
+        assert_eq!(result, Err(Ok(Error::CommitmentMismatch)));
+        let result =
+            client.try_reveal_submission(&mallory, &1u64, &1u32, &key.answers[0], &salt(&env, 1));
+        assert_eq!(result, Err(Ok(Error::CommitmentMismatch)));
+        let result =
+            client.try_reveal_submission(&alice, &1u64, &2u32, &key.answers[1], &salt(&env, 2));
+        assert_eq!(result, Err(Ok(Error::CommitmentNotFound)));
+
+        open(&env, &client, &alice, 1, &key.answers[0]);
+        let result =
+            client.try_reveal_submission(&alice, &1u64, &1u32, &key.answers[0], &salt(&env, 1));
+        assert_eq!(result, Err(Ok(Error::AlreadyRevealed)));
+
+        // The official answer waits for the reveal window to end
+        let result =
+            client.try_reveal_answer(&1u64, &1u32, &key.answers[0], &key.salts[0], &key.proofs[0]);
+        assert_eq!(result, Err(Ok(Error::RevealInProgress)));
+
+        set_time(&env, 2_501);
+        let result =
+            client.try_reveal_submission(&mallory, &1u64, &1u32, &key.answers[0], &salt(&env, 1));
+        assert_eq!(result, Err(Ok(Error::RevealClosed)));
+        assert_eq!(
+            client.reveal_answer(&1u64, &1u32, &key.answers[0], &key.salts[0], &key.proofs[0]),
+            1
+        );
+    }
+
+    #[test]
+    fn test_unopened_submissions_do_not_score() {
+        let env = Env::default();
+        let (client, admin, pool) = setup(&env);
+        let key = answer_key(&env);
+        configure(&env, &client, &admin, &key);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        submit(&env, &client, &alice, 1, &key.answers[0]);
+        submit(&env, &client, &bob, 1, &key.answers[0]);
+        submit(&env, &client, &bob, 2, &key.answers[1]);
+
+        close(&env, &client, &admin);
+        open(&env, &client, &alice, 1, &key.answers[0]);
+        open(&env, &client, &bob, 1, &key.answers[0]);
+        reveal_all(&env, &client, &key);
+
+        let score = client.player_score(&1u64, &bob);
+        assert_eq!((score.answered, score.score, score.reward), (2, 1, 500));
+        assert_eq!(client.claim_reward(&alice, &1u64), 500);
+        assert_eq!(client.claim_reward(&bob, &1u64), 500);
+        assert_eq!(pool.amounts(&1u64), (1_000, 1_000, 0));
+    }
+
+    #[test]
+    fn test_scores_and_proportional_claims() {
+        let env = Env::default();
+        let (client, admin, pool) = setup(&env);
+        let key = answer_key(&env);
+        configure(&env, &client, &admin, &key);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let wrong = String::from_str(&env, "wrong");
+        let answers = [
+            (&alice, 1, &key.answers[0]),
+            (&alice, 2, &key.answers[1]),
+            (&alice, 3, &key.answers[2]),
+            (&alice, 4, &wrong),
+            (&bob, 2, &key.answers[1]),
+            (&bob, 3, &wrong),
+        ];
+        for (player, question_id, answer) in answers {
+            submit(&env, &client, player, question_id, answer);
+        }
+
+        assert_eq!(
+            client.try_close_round(&admin, &1u64),
+            Err(Ok(Error::RoundInProgress))
+        );
+        close(&env, &client, &admin);
+        for (player, question_id, answer) in answers {
+            open(&env, &client, player, question_id, answer);
+        }
+        reveal_all(&env, &client, &key);
+
+        assert_eq!(
+            client.question_set_metadata(&1u64).status,
+            RoundStatus::Settled
+        );
+        let score = client.player_score(&1u64, &alice);
+        assert_eq!((score.answered, score.score, score.reward), (4, 3, 750));
+        let score = client.player_score(&1u64, &bob);
+        assert_eq!((score.answered, score.score, score.reward), (2, 1, 250));
+
+        assert_eq!(client.claim_reward(&alice, &1u64), 750);
+        assert_eq!(client.claim_reward(&bob, &1u64), 250);
+        assert_eq!(
+            client.try_claim_reward(&bob, &1u64),
+            Err(Ok(Error::AlreadyClaimed))
+        );
+        assert!(client.player_score(&1u64, &bob).claimed);
+        assert_eq!(pool.amounts(&1u64), (1_000, 1_000, 0));
+    }
+
+    #[test]
+    fn test_reveal_requires_valid_proof_once() {
+        let env = Env::default();
+        let (client, admin, _) = setup(&env);
+        let key = answer_key(&env);
+        configure(&env, &client, &admin, &key);
+
+        let player = Address::generate(&env);
+        submit(&env, &client, &player, 1, &key.answers[0]);
+
+        let result =
+            client.try_reveal_answer(&1u64, &1u32, &key.answers[0], &key.salts[0], &key.proofs[0]);
+        assert_eq!(result, Err(Ok(Error::InvalidRoundStatus)));
+
+        close(&env, &client, &admin);
+        open(&env, &client, &player, 1, &key.answers[0]);
+        set_time(&env, 2_501);
+
+        // A different answer, the right answer under another question, or the
+        // wrong salt fails
+        let wrong = String::from_str(&env, "London");
+        let result = client.try_reveal_answer(&1u64, &1u32, &wrong, &key.salts[0], &key.proofs[0]);
+        assert_eq!(result, Err(Ok(Error::InvalidProof)));
+        let result =
+            client.try_reveal_answer(&1u64, &2u32, &key.answers[0], &key.salts[0], &key.proofs[0]);
+        assert_eq!(result, Err(Ok(Error::InvalidProof)));
+        let result =
+            client.try_reveal_answer(&1u64, &1u32, &key.answers[0], &key.salts[1], &key.proofs[0]);
+        assert_eq!(result, Err(Ok(Error::InvalidProof)));
+
+        assert_eq!(
+            client.reveal_answer(&1u64, &1u32, &key.answers[0], &key.salts[0], &key.proofs[0]),
+            1
+        );
+        let result =
+            client.try_reveal_answer(&1u64, &1u32, &key.answers[0], &key.salts[0], &key.proofs[0]);
+        assert_eq!(result, Err(Ok(Error::AlreadyRevealed)));
+
+        // Claims wait for every question to be revealed
+        let result = client.try_claim_reward(&player, &1u64);
+        assert_eq!(result, Err(Ok(Error::InvalidRoundStatus)));
+    }
+
+    #[test]
+    fn test_root_with_two_answers_for_one_question_is_rejected() {
+        let env = Env::default();
+        let (client, admin, _) = setup(&env);
+        let key = answer_key(&env);
+        let paris = String::from_str(&env, "Paris");
+        let london = String::from_str(&env, "London");
+        let category = String::from_str(&env, "general");
+
+        // Question 1 answered "London" in the slot of question 3
+        let leaves = vec![
+            &env,
+            answer_leaf(&env, 1, &key.salts[0], &paris),
+            answer_leaf(&env, 2, &key.salts[1], &key.answers[1]),
+            answer_leaf(&env, 1, &key.salts[0], &london),
+            answer_leaf(&env, 4, &key.salts[3], &key.answers[3]),
+        ];
+        let (root, _) = merkle_proof(&env, &leaves, 0);
+        // The same four answers with an extra "London" leaf in a deeper tree
+        let mut deep_leaves = leaves.clone();
+        deep_leaves.set(2, answer_leaf(&env, 3, &key.salts[2], &key.answers[2]));
+        deep_leaves.push_back(answer_leaf(&env, 1, &key.salts[0], &london));
+        for _ in 5..8 {
+            deep_leaves.push_back(BytesN::from_array(&env, &[0; 32]));
+        }
+        let (deep_root, _) = merkle_proof(&env, &deep_leaves, 0);
+
+        // Only the leaf at question 1's index proves, and only at the round's
+        // depth, so neither tree lets "London" be revealed instead of "Paris"
+        let cases = [
+            (1u64, root, leaves, 2, Ok(Ok(0))),
+            (2, deep_root, deep_leaves, 4, Err(Ok(Error::InvalidProof))),
+        ];
+        for (round_id, root, leaves, index, paris_result) in cases {
+            client.configure_round(
+                &admin, &round_id, &10u64, &4u32, &category, &1u32, &1_000u64, &2_000u64,
+                &2_500u64, &root, &1_000i128,
+            );
+            set_time(&env, 1_000);
+            client.activate_round(&admin, &round_id);
+            set_time(&env, 2_001);
+            client.close_round(&admin, &round_id);
+            set_time(&env, 2_501);
+
+            let (_, proof) = merkle_proof(&env, &leaves, index);
+            let result = client.try_reveal_answer(&round_id, &1u32, &london, &key.salts[0], &proof);
+            assert_eq!(result, Err(Ok(Error::InvalidProof)));
+            let (_, proof) = merkle_proof(&env, &leaves, 0);
+            let result = client.try_reveal_answer(&round_id, &1u32, &paris, &key.salts[0], &proof);
+            assert_eq!(result, paris_result);
+        }
+    }
+
+    #[test]
+    fn test_expire_round_settles_unrevealed_answers() {
+        let env = Env::default();
+        let (client, admin, pool) = setup(&env);
+        let key = answer_key(&env);
+        configure(&env, &client, &admin, &key);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        submit(&env, &client, &alice, 1, &key.answers[0]);
+        submit(&env, &client, &alice, 2, &key.answers[1]);
+        submit(&env, &client, &bob, 1, &key.answers[0]);
+        close(&env, &client, &admin);
+        open(&env, &client, &alice, 1, &key.answers[0]);
+        open(&env, &client, &alice, 2, &key.answers[1]);
+        open(&env, &client, &bob, 1, &key.answers[0]);
+
+        // Only question 1 is ever revealed
+        set_time(&env, 2_501);
+        client.reveal_answer(&1u64, &1u32, &key.answers[0], &key.salts[0], &key.proofs[0]);
+        set_time(&env, 2_500 + ANSWER_REVEAL_TIMEOUT);
+        assert_eq!(
+            client.try_expire_round(&1u64),
+            Err(Ok(Error::RoundNotExpired))
+        );
+
+        set_time(&env, 2_501 + ANSWER_REVEAL_TIMEOUT);
+        client.expire_round(&1u64);
+        assert_eq!(
+            client.question_set_metadata(&1u64).status,
+            RoundStatus::Settled
+        );
+        assert_eq!(
+            client.try_expire_round(&1u64),
+            Err(Ok(Error::InvalidRoundStatus))
+        );
+        assert_eq!(
+            client.try_reveal_answer(&1u64, &2u32, &key.answers[1], &key.salts[1], &key.proofs[1]),
+            Err(Ok(Error::InvalidRoundStatus))
+        );
+
+        assert_eq!(client.claim_reward(&alice, &1u64), 500);
+        assert_eq!(client.claim_reward(&bob, &1u64), 500);
+        assert_eq!(pool.amounts(&1u64), (1_000, 1_000, 0));
+    }
+
+    #[test]
+    fn test_expire_round_releases_reward_when_nothing_revealed() {
+        let env = Env::default();
+        let (client, admin, pool) = setup(&env);
+        let key = answer_key(&env);
+        configure(&env, &client, &admin, &key);
+
+        // The admin never even closes the round
+        let player = Address::generate(&env);
+        submit(&env, &client, &player, 1, &key.answers[0]);
+        set_time(&env, 2_501 + ANSWER_REVEAL_TIMEOUT);
+        client.expire_round(&1u64);
+
+        assert_eq!(
+            client.try_claim_reward(&player, &1u64),
+            Err(Ok(Error::NoRewardAvailable))
+        );
+        assert_eq!(pool.amounts(&1u64), (1_000, 0, 1_000));
+    }
+
+    #[test]
+    fn test_round_without_correct_answers_releases_reward() {
+        let env = Env::default();
+        let (client, admin, pool) = setup(&env);
+        let key = answer_key(&env);
+        configure(&env, &client, &admin, &key);
+
+        let player = Address::generate(&env);
+        let rome = String::from_str(&env, "Rome");
+        submit(&env, &client, &player, 1, &rome);
+        close(&env, &client, &admin);
+        open(&env, &client, &player, 1, &rome);
+        reveal_all(&env, &client, &key);
+
+        assert_eq!(
+            client.try_claim_reward(&player, &1u64),
+            Err(Ok(Error::NoRewardAvailable))
+        );
+        assert_eq!(pool.amounts(&1u64), (1_000, 0, 1_000));
+        assert_eq!(
+            client.try_activate_round(&admin, &1u64),
+            Err(Ok(Error::InvalidRoundStatus))
+        );
+    }
 }
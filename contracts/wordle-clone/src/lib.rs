@@ -22,6 +22,27 @@
 //! first, then remaining answer characters are consumed for PRESENT matches,
 //! so each answer character accounts for at most one PRESENT mark.
 //!
+//! ## Oracle Scoring
+//! A puzzle may name a scorer who knows the answer. After each attempt the
+//! scorer calls `post_scores` with the scores, signing them against the
+//! puzzle's `answer_commitment`, and the player sees them before guessing
+//! again. A scorer that does not score an attempt within
+//! `SCORE_TIMEOUT_LEDGERS` no longer holds the player up: the next guess is
+//! accepted without it. `finalize_result` rescores every attempt against the
+//! revealed answer; a single posted score that differs invalidates the
+//! puzzle. An invalidated puzzle records no winners or disqualifications and
+//! keeps every attempt with the scores as posted, so the bad score stays on
+//! record.
+//!
+//! ## Puzzle Rules
+//! - A puzzle may commit to a dictionary: the Merkle root of its valid words
+//!   (see [`word_leaf`]). Every guess must then carry a membership proof.
+//! - In hard mode every guess must reuse the hints of the player's earlier
+//!   guesses (see [`follows_hints`]). With a scorer this is checked as each
+//!   guess is submitted; otherwise guesses are only scored once the answer is
+//!   revealed, and a player who ignored a hint is disqualified at
+//!   finalization.
+//!
 //! ## Storage Strategy
 //! - `instance()` storage: contract-level config (Admin, PrizePoolContract,
 //!   BalanceContract). Small, bounded, stored in a single ledger entry.
@@ -60,6 +81,10 @@ pub const MAX_PLAYERS_PER_PUZZLE: u32 = 1_000;
 /// Persistent storage TTL (~30 days at 5 s/ledger).
 pub const PERSISTENT_BUMP_LEDGERS: u32 = 518_400;
 
+/// Ledgers the scorer has to score an attempt before the player may guess
+/// again without its scores (~1 hour at 5 s/ledger).
+pub const SCORE_TIMEOUT_LEDGERS: u32 = 720;
+
 // ---------------------------------------------------------------------------
 // Errors
 // ---------------------------------------------------------------------------
@@ -81,6 +106,13 @@ pub enum Error {
     Overflow = 11,
     PuzzleFull = 12,
     AnswerNotRevealed = 13,
+    NotInDictionary = 14,
+    NoScorer = 15,
+    AwaitingScore = 16,
+    AlreadyScored = 17,
+    AttemptNotFound = 18,
+    InvalidScores = 19,
+    HardModeViolation = 20,
 }
 
 // ---------------------------------------------------------------------------
@@ -108,6 +140,10 @@ pub enum PuzzleStatus {
     Revealed = 1,
     /// All attempts scored; puzzle complete.
     Finalized = 2,
+    /// A score posted by the scorer did not match the revealed answer; no
+    /// winners or disqualifications are recorded and attempts keep their
+    /// posted scores.
+    Invalidated = 3,
 }
 
 /// Puzzle metadata and result summary.
@@ -123,6 +159,15 @@ pub struct PuzzleData {
     pub winner_count: u32,
     /// Number of distinct players who submitted at least one attempt.
     pub player_count: u32,
+    /// Merkle root of the valid guesses; any 5 bytes are accepted when unset.
+    pub dictionary_root: Option<BytesN<32>>,
+    /// Guesses must reuse the hints of earlier guesses.
+    pub hard_mode: bool,
+    /// Number of players disqualified for breaking hard mode.
+    pub disqualified_count: u32,
+    /// Posts scores after each attempt; attempts are only scored at
+    /// finalization when unset.
+    pub scorer: Option<Address>,
 }
 
 /// A single scored guess.
@@ -132,8 +177,10 @@ pub struct Attempt {
     /// The 5-letter guess submitted by the player.
     pub guess: Bytes,
     /// Per-character scores: Vec of SCORE_* constants, length == WORD_LENGTH.
-    /// Empty until the puzzle is finalized.
+    /// Empty until the scorer posts them or the puzzle is finalized.
     pub scores: Vec<u32>,
+    /// Ledger sequence the guess was submitted at.
+    pub submitted_at: u32,
 }
 
 #[contracttype]
@@ -142,6 +189,7 @@ pub enum PuzzleSnapshotState {
     Missing = 0,
     Active = 1,
     Completed = 2,
+    Invalidated = 3,
 }
 
 #[contracttype]
@@ -177,6 +225,8 @@ pub enum DataKey {
     Attempts(u64, Address),
     /// Set to `true` when a player solves the puzzle.
     Winner(u64, Address),
+    /// Set to `true` when a player broke hard mode.
+    Disqualified(u64, Address),
 }
 
 // ---------------------------------------------------------------------------
@@ -188,6 +238,9 @@ pub struct PuzzleCreated {
     #[topic]
     pub puzzle_id: u64,
     pub answer_commitment: BytesN<32>,
+    pub dictionary_root: Option<BytesN<32>>,
+    pub hard_mode: bool,
+    pub scorer: Option<Address>,
 }
 
 #[contractevent]
@@ -201,6 +254,17 @@ pub struct AttemptSubmitted {
     pub guess: Bytes,
 }
 
+#[contractevent]
+pub struct ScoresPosted {
+    #[topic]
+    pub puzzle_id: u64,
+    #[topic]
+    pub player: Address,
+    /// Attempt number (1-indexed).
+    pub attempt_number: u32,
+    pub scores: Vec<u32>,
+}
+
 #[contractevent]
 pub struct AnswerRevealed {
     #[topic]
@@ -215,6 +279,15 @@ pub struct PuzzleFinalized {
     pub winner_count: u32,
 }
 
+#[contractevent]
+pub struct PuzzleInvalidated {
+    #[topic]
+    pub puzzle_id: u64,
+    /// First attempt found with a posted score that does not match the answer.
+    pub player: Address,
+    pub attempt_number: u32,
+}
+
 // ---------------------------------------------------------------------------
 // Contract
 // ---------------------------------------------------------------------------
@@ -265,11 +338,19 @@ impl WordleClone {
     /// computed off-chain. The plaintext answer is never stored until the admin
     /// calls `reveal_answer`.
     ///
+    /// `dictionary_root`, when set, is the Merkle root of the words accepted
+    /// as guesses; it should include the answer. `hard_mode` requires every
+    /// guess to reuse the hints of the player's earlier guesses. `scorer`,
+    /// when set, posts the scores of each attempt with `post_scores`.
+    ///
     /// Emits `PuzzleCreated`.
     pub fn create_daily_puzzle(
         env: Env,
         puzzle_id: u64,
         answer_commitment: BytesN<32>,
+        dictionary_root: Option<BytesN<32>>,
+        hard_mode: bool,
+        scorer: Option<Address>,
     ) -> Result<(), Error> {
         let admin = get_admin(&env)?;
         admin.require_auth();
@@ -284,6 +365,10 @@ impl WordleClone {
             answer: Bytes::new(&env),
             winner_count: 0,
             player_count: 0,
+            dictionary_root: dictionary_root.clone(),
+            hard_mode,
+            disqualified_count: 0,
+            scorer: scorer.clone(),
         };
 
         persist_set(&env, DataKey::Puzzle(puzzle_id), &puzzle);
@@ -296,6 +381,9 @@ impl WordleClone {
         PuzzleCreated {
             puzzle_id,
             answer_commitment,
+            dictionary_root,
+            hard_mode,
+            scorer,
         }
         .publish(&env);
 
@@ -312,12 +400,20 @@ impl WordleClone {
     /// exactly `WORD_LENGTH` (5) bytes. Scores are computed after finalization;
     /// the `scores` field is empty until then.
     ///
+    /// With a scorer, the previous attempt must be scored before the next is
+    /// accepted, unless `SCORE_TIMEOUT_LEDGERS` have passed since it was
+    /// submitted. In hard mode the guess must follow the posted hints.
+    ///
+    /// If the puzzle has a dictionary, `proof` is the Merkle proof of the
+    /// guess's [`word_leaf`]; otherwise it is ignored.
+    ///
     /// Emits `AttemptSubmitted`.
     pub fn submit_attempt(
         env: Env,
         player: Address,
         puzzle_id: u64,
         attempt: Bytes,
+        proof: Vec<BytesN<32>>,
     ) -> Result<(), Error> {
         player.require_auth();
 
@@ -335,6 +431,12 @@ impl WordleClone {
             return Err(Error::PuzzleNotOpen);
         }
 
+        if let Some(root) = &puzzle.dictionary_root {
+            if !verify_merkle_proof(&env, &word_leaf(&env, &attempt), &proof, root) {
+                return Err(Error::NotInDictionary);
+            }
+        }
+
         let mut attempts: Vec<Attempt> = env
             .storage()
             .persistent()
@@ -346,6 +448,22 @@ impl WordleClone {
             return Err(Error::TooManyAttempts);
         }
 
+        if puzzle.scorer.is_some() {
+            if let Some(last) = attempts.last() {
+                let deadline = last.submitted_at.saturating_add(SCORE_TIMEOUT_LEDGERS);
+                if last.scores.is_empty() && env.ledger().sequence() < deadline {
+                    return Err(Error::AwaitingScore);
+                }
+            }
+            if puzzle.hard_mode
+                && !attempts
+                    .iter()
+                    .all(|earlier| follows_hints(&attempt, &earlier.guess, &earlier.scores))
+            {
+                return Err(Error::HardModeViolation);
+            }
+        }
+
         // Register new player in PlayerList on their first attempt.
         if attempt_number == 0 {
             if puzzle.player_count >= MAX_PLAYERS_PER_PUZZLE {
@@ -366,6 +484,7 @@ impl WordleClone {
         attempts.push_back(Attempt {
             guess: attempt.clone(),
             scores: Vec::new(&env),
+            submitted_at: env.ledger().sequence(),
         });
         persist_set(
             &env,
@@ -384,6 +503,75 @@ impl WordleClone {
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // post_scores
+    // -----------------------------------------------------------------------
+
+    /// Post the scores of a player's attempt. Scorer only.
+    ///
+    /// `answer_commitment` must be the puzzle's: the scorer's authorization
+    /// covers every argument, so each call is a signed statement that `scores`
+    /// are what `score_guess` gives for the guess against that commitment.
+    /// `finalize_result` holds the scorer to it. Accepted until the puzzle is
+    /// finalized; each attempt is scored once.
+    ///
+    /// Emits `ScoresPosted`.
+    pub fn post_scores(
+        env: Env,
+        puzzle_id: u64,
+        player: Address,
+        attempt_number: u32,
+        answer_commitment: BytesN<32>,
+        scores: Vec<u32>,
+    ) -> Result<(), Error> {
+        let puzzle: PuzzleData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Puzzle(puzzle_id))
+            .ok_or(Error::PuzzleNotFound)?;
+
+        let scorer = puzzle.scorer.ok_or(Error::NoScorer)?;
+        scorer.require_auth();
+
+        if puzzle.status != PuzzleStatus::Open && puzzle.status != PuzzleStatus::Revealed {
+            return Err(Error::PuzzleAlreadyFinalized);
+        }
+        if answer_commitment != puzzle.answer_commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+        if scores.len() != WORD_LENGTH || scores.iter().any(|s| s > SCORE_CORRECT) {
+            return Err(Error::InvalidScores);
+        }
+
+        let key = DataKey::Attempts(puzzle_id, player.clone());
+        let mut attempts: Vec<Attempt> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env));
+        let index = attempt_number
+            .checked_sub(1)
+            .ok_or(Error::AttemptNotFound)?;
+        let mut attempt = attempts.get(index).ok_or(Error::AttemptNotFound)?;
+        if !attempt.scores.is_empty() {
+            return Err(Error::AlreadyScored);
+        }
+
+        attempt.scores = scores.clone();
+        attempts.set(index, attempt);
+        persist_set(&env, key, &attempts);
+
+        ScoresPosted {
+            puzzle_id,
+            player,
+            attempt_number,
+            scores,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // reveal_answer  (admin prerequisite before finalize_result)
     // -----------------------------------------------------------------------
@@ -437,6 +625,15 @@ impl WordleClone {
     ///
     /// Iterates all submissions (bounded by `MAX_PLAYERS_PER_PUZZLE × MAX_ATTEMPTS`).
     /// A player is a winner if any of their attempts matches the answer exactly.
+    /// In hard mode without a scorer, a player with an attempt that ignores
+    /// the hints of an earlier one is disqualified instead. With a scorer,
+    /// hard mode was already enforced against the posted hints as guesses
+    /// came in.
+    ///
+    /// Scores posted by the scorer are checked first. If any differs from
+    /// `score_guess`, the puzzle is marked `Invalidated` and
+    /// `PuzzleInvalidated` is emitted instead: attempts are left with their
+    /// posted scores and no winners or disqualifications are recorded.
     ///
     /// Emits `PuzzleFinalized`.
     pub fn finalize_result(env: Env, player: Address, puzzle_id: u64) -> Result<(), Error> {
@@ -449,7 +646,7 @@ impl WordleClone {
             .get(&DataKey::Puzzle(puzzle_id))
             .ok_or(Error::PuzzleNotFound)?;
 
-        if puzzle.status == PuzzleStatus::Finalized {
+        if puzzle.status == PuzzleStatus::Finalized || puzzle.status == PuzzleStatus::Invalidated {
             return Err(Error::PuzzleAlreadyFinalized);
         }
         if puzzle.status != PuzzleStatus::Revealed {
@@ -468,7 +665,26 @@ impl WordleClone {
             .get(&DataKey::PlayerList(puzzle_id))
             .unwrap_or_else(|| Vec::new(&env));
 
+        if puzzle.scorer.is_some() {
+            if let Some((cheated, attempt_number)) =
+                find_score_mismatch(&env, puzzle_id, &players, &answer)
+            {
+                puzzle.status = PuzzleStatus::Invalidated;
+                persist_set(&env, DataKey::Puzzle(puzzle_id), &puzzle);
+
+                PuzzleInvalidated {
+                    puzzle_id,
+                    player: cheated,
+                    attempt_number,
+                }
+                .publish(&env);
+
+                return Ok(());
+            }
+        }
+
         let mut winner_count: u32 = 0;
+        let mut disqualified_count: u32 = 0;
 
         for p in players.iter() {
             let attempts: Vec<Attempt> = env
@@ -480,9 +696,20 @@ impl WordleClone {
             let len = attempts.len();
             let mut scored: Vec<Attempt> = Vec::new(&env);
             let mut player_won = false;
+            let mut disqualified = false;
 
             for i in 0..len {
                 let att = attempts.get(i).unwrap();
+                // Scorer puzzles checked hard mode at submission; a guess made
+                // after a score timed out owes nothing to hints never shown
+                if puzzle.hard_mode
+                    && puzzle.scorer.is_none()
+                    && !scored
+                        .iter()
+                        .all(|earlier| follows_hints(&att.guess, &earlier.guess, &earlier.scores))
+                {
+                    disqualified = true;
+                }
                 let scores = score_guess(&env, &att.guess, &answer);
                 let solved = is_all_correct(&scores);
                 if solved {
@@ -491,12 +718,16 @@ impl WordleClone {
                 scored.push_back(Attempt {
                     guess: att.guess,
                     scores,
+                    submitted_at: att.submitted_at,
                 });
             }
 
             persist_set(&env, DataKey::Attempts(puzzle_id, p.clone()), &scored);
 
-            if player_won {
+            if disqualified {
+                persist_set(&env, DataKey::Disqualified(puzzle_id, p.clone()), &true);
+                disqualified_count = disqualified_count.checked_add(1).ok_or(Error::Overflow)?;
+            } else if player_won {
                 persist_set(&env, DataKey::Winner(puzzle_id, p.clone()), &true);
                 winner_count = winner_count.checked_add(1).ok_or(Error::Overflow)?;
             }
@@ -504,6 +735,7 @@ impl WordleClone {
 
         puzzle.status = PuzzleStatus::Finalized;
         puzzle.winner_count = winner_count;
+        puzzle.disqualified_count = disqualified_count;
         persist_set(&env, DataKey::Puzzle(puzzle_id), &puzzle);
 
         PuzzleFinalized {
@@ -545,6 +777,14 @@ impl WordleClone {
             .unwrap_or(false)
     }
 
+    /// Returns `true` if the player was disqualified for breaking hard mode.
+    pub fn is_disqualified(env: Env, puzzle_id: u64, player: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Disqualified(puzzle_id, player))
+            .unwrap_or(false)
+    }
+
     /// Return a compact puzzle snapshot for restoring in-progress or completed play.
     pub fn get_puzzle_snapshot(env: Env, player: Address, puzzle_id: u64) -> PuzzleSnapshot {
         let attempts: Vec<Attempt> = env
@@ -558,6 +798,13 @@ impl WordleClone {
             env.storage().persistent().get(&DataKey::Puzzle(puzzle_id));
 
         match puzzle {
+            Some(puzzle) if puzzle.status == PuzzleStatus::Invalidated => PuzzleSnapshot {
+                state: PuzzleSnapshotState::Invalidated,
+                guesses: attempts,
+                answer_revealed: true,
+                answer: puzzle.answer,
+                remaining_attempts,
+            },
             Some(puzzle) if puzzle.status == PuzzleStatus::Finalized => PuzzleSnapshot {
                 state: PuzzleSnapshotState::Completed,
                 guesses: attempts,
@@ -632,6 +879,87 @@ pub fn score_guess(env: &Env, guess: &Bytes, answer: &Bytes) -> Vec<u32> {
     result
 }
 
+/// Returns `true` when `guess` reuses the hints `previous_scores` gave for
+/// `previous_guess`, as hard mode requires:
+///
+/// 1. every CORRECT letter stays in its position;
+/// 2. every letter marked CORRECT or PRESENT appears at least as many times
+///    as it was marked.
+pub fn follows_hints(guess: &Bytes, previous_guess: &Bytes, previous_scores: &Vec<u32>) -> bool {
+    for i in 0..WORD_LENGTH {
+        let score = previous_scores.get(i).unwrap_or(SCORE_ABSENT);
+        if score == SCORE_CORRECT && guess.get(i) != previous_guess.get(i) {
+            return false;
+        }
+        if score == SCORE_ABSENT {
+            continue;
+        }
+        let letter = previous_guess.get(i);
+        let marked = (0..WORD_LENGTH)
+            .filter(|&j| {
+                previous_guess.get(j) == letter
+                    && previous_scores.get(j).unwrap_or(SCORE_ABSENT) != SCORE_ABSENT
+            })
+            .count();
+        let used = (0..WORD_LENGTH).filter(|&j| guess.get(j) == letter).count();
+        if used < marked {
+            return false;
+        }
+    }
+    true
+}
+
+/// Dictionary leaf for a word: `sha256(word)`.
+pub fn word_leaf(env: &Env, word: &Bytes) -> BytesN<32> {
+    env.crypto().sha256(word).into()
+}
+
+/// Folds `proof` into `leaf`, hashing each pair of nodes in sorted order, and
+/// compares the result with `root`.
+pub fn verify_merkle_proof(
+    env: &Env,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+    root: &BytesN<32>,
+) -> bool {
+    let mut node = leaf.clone();
+    for sibling in proof.iter() {
+        node = hash_pair(env, &node, &sibling);
+    }
+    &node == root
+}
+
+fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut preimage = Bytes::from(first);
+    preimage.append(&Bytes::from(second));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Returns the first attempt, as `(player, attempt_number)`, whose posted
+/// scores differ from `score_guess` against `answer`. Unscored attempts are
+/// skipped.
+fn find_score_mismatch(
+    env: &Env,
+    puzzle_id: u64,
+    players: &Vec<Address>,
+    answer: &Bytes,
+) -> Option<(Address, u32)> {
+    for p in players.iter() {
+        let attempts: Vec<Attempt> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Attempts(puzzle_id, p.clone()))
+            .unwrap_or_else(|| Vec::new(env));
+        for (i, att) in attempts.iter().enumerate() {
+            if !att.scores.is_empty() && att.scores != score_guess(env, &att.guess, answer) {
+                return Some((p, i as u32 + 1));
+            }
+        }
+    }
+    None
+}
+
 /// Returns `true` when every score in the vec is `SCORE_CORRECT`.
 fn is_all_correct(scores: &Vec<u32>) -> bool {
     for i in 0..scores.len() {
@@ -664,7 +992,10 @@ fn get_admin(env: &Env) -> Result<Address, Error> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Bytes, BytesN, Env, IntoVal};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger as _},
+        Bytes, BytesN, Env, IntoVal,
+    };
 
     fn sha256_of(env: &Env, data: &[u8]) -> BytesN<32> {
         let b = Bytes::from_slice(env, data);
@@ -698,13 +1029,13 @@ mod test {
 
         let answer: [u8; 5] = *b"CRANE";
         let commitment = sha256_of(&env, &answer);
-        client.create_daily_puzzle(&1u64, &commitment);
+        client.create_daily_puzzle(&1u64, &commitment, &None, &false, &None);
 
         let winner = Address::generate(&env);
         let loser = Address::generate(&env);
 
-        client.submit_attempt(&winner, &1u64, &bytes5(&env, b"CRANE"));
-        client.submit_attempt(&loser, &1u64, &bytes5(&env, b"STALE"));
+        client.submit_attempt(&winner, &1u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
+        client.submit_attempt(&loser, &1u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
 
         client.reveal_answer(&1u64, &bytes5(&env, b"CRANE"));
         client.finalize_result(&winner, &1u64);
@@ -728,10 +1059,10 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"PIANO");
-        client.create_daily_puzzle(&2u64, &commitment);
+        client.create_daily_puzzle(&2u64, &commitment, &None, &false, &None);
 
         let player = Address::generate(&env);
-        client.submit_attempt(&player, &2u64, &bytes5(&env, b"PIANO"));
+        client.submit_attempt(&player, &2u64, &bytes5(&env, b"PIANO"), &Vec::new(&env));
 
         client.reveal_answer(&2u64, &bytes5(&env, b"PIANO"));
         client.finalize_result(&player, &2u64);
@@ -756,10 +1087,10 @@ mod test {
 
         // answer: CRANE, guess: NACRE — every letter is in the answer
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&3u64, &commitment);
+        client.create_daily_puzzle(&3u64, &commitment, &None, &false, &None);
 
         let player = Address::generate(&env);
-        client.submit_attempt(&player, &3u64, &bytes5(&env, b"NACRE"));
+        client.submit_attempt(&player, &3u64, &bytes5(&env, b"NACRE"), &Vec::new(&env));
 
         client.reveal_answer(&3u64, &bytes5(&env, b"CRANE"));
         client.finalize_result(&player, &3u64);
@@ -784,14 +1115,15 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&4u64, &commitment);
+        client.create_daily_puzzle(&4u64, &commitment, &None, &false, &None);
 
         let player = Address::generate(&env);
         for _ in 0..MAX_ATTEMPTS {
-            client.submit_attempt(&player, &4u64, &bytes5(&env, b"STALE"));
+            client.submit_attempt(&player, &4u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
         }
 
-        let result = client.try_submit_attempt(&player, &4u64, &bytes5(&env, b"STALE"));
+        let result =
+            client.try_submit_attempt(&player, &4u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
         assert!(result.is_err());
     }
 
@@ -806,11 +1138,11 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&5u64, &commitment);
+        client.create_daily_puzzle(&5u64, &commitment, &None, &false, &None);
 
         let player = Address::generate(&env);
         let short = Bytes::from_slice(&env, b"HI");
-        let result = client.try_submit_attempt(&player, &5u64, &short);
+        let result = client.try_submit_attempt(&player, &5u64, &short, &Vec::new(&env));
         assert!(result.is_err());
     }
 
@@ -825,7 +1157,8 @@ mod test {
         env.mock_all_auths();
 
         let player = Address::generate(&env);
-        let result = client.try_submit_attempt(&player, &99u64, &bytes5(&env, b"CRANE"));
+        let result =
+            client.try_submit_attempt(&player, &99u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
         assert!(result.is_err());
     }
 
@@ -840,7 +1173,7 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&6u64, &commitment);
+        client.create_daily_puzzle(&6u64, &commitment, &None, &false, &None);
 
         let result = client.try_reveal_answer(&6u64, &bytes5(&env, b"STALE"));
         assert!(result.is_err());
@@ -872,12 +1205,15 @@ mod test {
                     &env,
                     7u64.into_val(&env),
                     commitment.clone().into_val(&env),
+                    Option::<BytesN<32>>::None.into_val(&env),
+                    false.into_val(&env),
+                    Option::<Address>::None.into_val(&env),
                 ],
                 sub_invokes: &[],
             },
         }]);
 
-        let result = client2.try_create_daily_puzzle(&7u64, &commitment);
+        let result = client2.try_create_daily_puzzle(&7u64, &commitment, &None, &false, &None);
         assert!(result.is_err());
 
         let _ = client;
@@ -898,7 +1234,7 @@ mod test {
         client2.init(&admin, &prize_pool, &balance);
 
         let commitment = sha256_of(&env, b"CRANE");
-        client2.create_daily_puzzle(&8u64, &commitment);
+        client2.create_daily_puzzle(&8u64, &commitment, &None, &false, &None);
         client2.reveal_answer(&8u64, &bytes5(&env, b"CRANE"));
 
         let imposter = Address::generate(&env);
@@ -949,9 +1285,9 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&10u64, &commitment);
+        client.create_daily_puzzle(&10u64, &commitment, &None, &false, &None);
 
-        let result = client.try_create_daily_puzzle(&10u64, &commitment);
+        let result = client.try_create_daily_puzzle(&10u64, &commitment, &None, &false, &None);
         assert!(result.is_err());
     }
 
@@ -966,11 +1302,12 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&11u64, &commitment);
+        client.create_daily_puzzle(&11u64, &commitment, &None, &false, &None);
         client.reveal_answer(&11u64, &bytes5(&env, b"CRANE"));
 
         let late = Address::generate(&env);
-        let result = client.try_submit_attempt(&late, &11u64, &bytes5(&env, b"CRANE"));
+        let result =
+            client.try_submit_attempt(&late, &11u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
         assert!(result.is_err());
     }
 
@@ -985,14 +1322,15 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&20u64, &commitment);
+        client.create_daily_puzzle(&20u64, &commitment, &None, &false, &None);
         client.reveal_answer(&20u64, &bytes5(&env, b"CRANE"));
 
         let dummy = Address::generate(&env);
         client.finalize_result(&dummy, &20u64);
 
         let late = Address::generate(&env);
-        let result = client.try_submit_attempt(&late, &20u64, &bytes5(&env, b"CRANE"));
+        let result =
+            client.try_submit_attempt(&late, &20u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
         assert!(result.is_err());
     }
 
@@ -1007,7 +1345,7 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&12u64, &commitment);
+        client.create_daily_puzzle(&12u64, &commitment, &None, &false, &None);
         client.reveal_answer(&12u64, &bytes5(&env, b"CRANE"));
 
         let dummy = Address::generate(&env);
@@ -1028,13 +1366,13 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&13u64, &commitment);
+        client.create_daily_puzzle(&13u64, &commitment, &None, &false, &None);
 
         let player = Address::generate(&env);
         for _ in 0..(MAX_ATTEMPTS - 1) {
-            client.submit_attempt(&player, &13u64, &bytes5(&env, b"STALE"));
+            client.submit_attempt(&player, &13u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
         }
-        client.submit_attempt(&player, &13u64, &bytes5(&env, b"CRANE"));
+        client.submit_attempt(&player, &13u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
 
         client.reveal_answer(&13u64, &bytes5(&env, b"CRANE"));
         client.finalize_result(&player, &13u64);
@@ -1053,7 +1391,7 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&14u64, &commitment);
+        client.create_daily_puzzle(&14u64, &commitment, &None, &false, &None);
 
         let stranger = Address::generate(&env);
         let attempts = client.get_attempts(&stranger, &14u64);
@@ -1071,7 +1409,7 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&15u64, &commitment);
+        client.create_daily_puzzle(&15u64, &commitment, &None, &false, &None);
 
         let dummy = Address::generate(&env);
         let result = client.try_finalize_result(&dummy, &15u64);
@@ -1120,15 +1458,15 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&16u64, &commitment);
+        client.create_daily_puzzle(&16u64, &commitment, &None, &false, &None);
 
         let w1 = Address::generate(&env);
         let w2 = Address::generate(&env);
         let loser = Address::generate(&env);
 
-        client.submit_attempt(&w1, &16u64, &bytes5(&env, b"CRANE"));
-        client.submit_attempt(&w2, &16u64, &bytes5(&env, b"CRANE"));
-        client.submit_attempt(&loser, &16u64, &bytes5(&env, b"STALE"));
+        client.submit_attempt(&w1, &16u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
+        client.submit_attempt(&w2, &16u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
+        client.submit_attempt(&loser, &16u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
 
         client.reveal_answer(&16u64, &bytes5(&env, b"CRANE"));
         client.finalize_result(&w1, &16u64);
@@ -1147,11 +1485,11 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&17u64, &commitment);
+        client.create_daily_puzzle(&17u64, &commitment, &None, &false, &None);
 
         let player = Address::generate(&env);
-        client.submit_attempt(&player, &17u64, &bytes5(&env, b"STALE"));
-        client.submit_attempt(&player, &17u64, &bytes5(&env, b"CRATE"));
+        client.submit_attempt(&player, &17u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
+        client.submit_attempt(&player, &17u64, &bytes5(&env, b"CRATE"), &Vec::new(&env));
 
         let snapshot = client.get_puzzle_snapshot(&player, &17u64);
         assert_eq!(snapshot.state, PuzzleSnapshotState::Active);
@@ -1176,10 +1514,10 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&18u64, &commitment);
+        client.create_daily_puzzle(&18u64, &commitment, &None, &false, &None);
 
         let player = Address::generate(&env);
-        client.submit_attempt(&player, &18u64, &bytes5(&env, b"CRANE"));
+        client.submit_attempt(&player, &18u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
         client.reveal_answer(&18u64, &bytes5(&env, b"CRANE"));
         client.finalize_result(&player, &18u64);
 
@@ -1198,10 +1536,10 @@ mod test {
         env.mock_all_auths();
 
         let commitment = sha256_of(&env, b"CRANE");
-        client.create_daily_puzzle(&19u64, &commitment);
+        client.create_daily_puzzle(&19u64, &commitment, &None, &false, &None);
 
         let player = Address::generate(&env);
-        client.submit_attempt(&player, &19u64, &bytes5(&env, b"STALE"));
+        client.submit_attempt(&player, &19u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
         client.reveal_answer(&19u64, &bytes5(&env, b"CRANE"));
 
         let snapshot = client.get_puzzle_snapshot(&player, &19u64);
@@ -1211,4 +1549,319 @@ mod test {
         assert_eq!(snapshot.guesses.len(), 1);
         assert_eq!(snapshot.guesses.get(0).unwrap().scores.len(), 0);
     }
+
+    // ------------------------------------------------------------------
+    // Dictionary and hard mode
+    // ------------------------------------------------------------------
+
+    /// Dictionary of four words: root and the proof for each, in order.
+    fn dictionary(env: &Env, words: [&[u8; 5]; 4]) -> (BytesN<32>, [Vec<BytesN<32>>; 4]) {
+        let leaves = words.map(|w| word_leaf(env, &bytes5(env, w)));
+        let left = hash_pair(env, &leaves[0], &leaves[1]);
+        let right = hash_pair(env, &leaves[2], &leaves[3]);
+        let root = hash_pair(env, &left, &right);
+        let proofs = [
+            soroban_sdk::vec![env, leaves[1].clone(), right.clone()],
+            soroban_sdk::vec![env, leaves[0].clone(), right.clone()],
+            soroban_sdk::vec![env, leaves[3].clone(), left.clone()],
+            soroban_sdk::vec![env, leaves[2].clone(), left.clone()],
+        ];
+        (root, proofs)
+    }
+
+    #[test]
+    fn test_dictionary_requires_membership_proof() {
+        let env = Env::default();
+        let (client, _, _, _) = setup(&env);
+        env.mock_all_auths();
+
+        let (root, proofs) = dictionary(&env, [b"CRANE", b"STALE", b"CRATE", b"SLATE"]);
+        let commitment = sha256_of(&env, b"CRANE");
+        client.create_daily_puzzle(&21u64, &commitment, &Some(root.clone()), &false, &None);
+        assert_eq!(
+            client.get_puzzle(&21u64).unwrap().dictionary_root,
+            Some(root)
+        );
+
+        let player = Address::generate(&env);
+        client.submit_attempt(&player, &21u64, &bytes5(&env, b"STALE"), &proofs[1]);
+
+        // A real word with another word's proof, and a word outside the dictionary
+        let result =
+            client.try_submit_attempt(&player, &21u64, &bytes5(&env, b"SLATE"), &proofs[2]);
+        assert_eq!(result, Err(Ok(Error::NotInDictionary)));
+        let result =
+            client.try_submit_attempt(&player, &21u64, &bytes5(&env, b"ZZZZZ"), &proofs[0]);
+        assert_eq!(result, Err(Ok(Error::NotInDictionary)));
+
+        client.submit_attempt(&player, &21u64, &bytes5(&env, b"CRANE"), &proofs[0]);
+        assert_eq!(client.get_attempts(&player, &21u64).len(), 2);
+    }
+
+    #[test]
+    fn test_hard_mode_disqualifies_ignored_hints() {
+        let env = Env::default();
+        let (client, _, _, _) = setup(&env);
+        env.mock_all_auths();
+
+        let commitment = sha256_of(&env, b"CRANE");
+        client.create_daily_puzzle(&22u64, &commitment, &None, &true, &None);
+        assert!(client.get_puzzle(&22u64).unwrap().hard_mode);
+
+        // STALE reveals A in place and E in place; CRATE keeps both
+        let fair = Address::generate(&env);
+        client.submit_attempt(&fair, &22u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
+        client.submit_attempt(&fair, &22u64, &bytes5(&env, b"CRATE"), &Vec::new(&env));
+        client.submit_attempt(&fair, &22u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
+
+        // PIOUS drops the hints of STALE, so the later solve does not count
+        let cheat = Address::generate(&env);
+        client.submit_attempt(&cheat, &22u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
+        client.submit_attempt(&cheat, &22u64, &bytes5(&env, b"PIOUS"), &Vec::new(&env));
+        client.submit_attempt(&cheat, &22u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
+
+        client.reveal_answer(&22u64, &bytes5(&env, b"CRANE"));
+        client.finalize_result(&fair, &22u64);
+
+        let puzzle = client.get_puzzle(&22u64).unwrap();
+        assert_eq!(puzzle.winner_count, 1);
+        assert_eq!(puzzle.disqualified_count, 1);
+        assert!(client.is_winner(&22u64, &fair));
+        assert!(!client.is_winner(&22u64, &cheat));
+        assert!(client.is_disqualified(&22u64, &cheat));
+        assert!(!client.is_disqualified(&22u64, &fair));
+    }
+
+    #[test]
+    fn test_follows_hints() {
+        let env = Env::default();
+        let answer = Bytes::from_slice(&env, b"CRANE");
+        let previous = Bytes::from_slice(&env, b"NACRE");
+        let scores = score_guess(&env, &previous, &answer);
+
+        // NACRE: E correct, N, A, C, R present
+        assert!(follows_hints(&bytes5(&env, b"CRANE"), &previous, &scores));
+        assert!(follows_hints(&bytes5(&env, b"RANCE"), &previous, &scores));
+        // E moved
+        assert!(!follows_hints(&bytes5(&env, b"CRAEN"), &previous, &scores));
+        // R dropped
+        assert!(!follows_hints(&bytes5(&env, b"CLANE"), &previous, &scores));
+
+        // EERIE against SPEED marks two E's present, so two must be reused
+        let answer = Bytes::from_slice(&env, b"SPEED");
+        let previous = Bytes::from_slice(&env, b"EERIE");
+        let scores = score_guess(&env, &previous, &answer);
+        assert!(follows_hints(&bytes5(&env, b"STEEP"), &previous, &scores));
+        assert!(!follows_hints(&bytes5(&env, b"STEAK"), &previous, &scores));
+    }
+
+    // ------------------------------------------------------------------
+    // Oracle scoring
+    // ------------------------------------------------------------------
+
+    fn scores_of(env: &Env, guess: &[u8; 5], answer: &[u8; 5]) -> Vec<u32> {
+        score_guess(env, &bytes5(env, guess), &bytes5(env, answer))
+    }
+
+    #[test]
+    fn test_oracle_scores_each_guess() {
+        let env = Env::default();
+        let (client, _, _, _) = setup(&env);
+        env.mock_all_auths();
+
+        let scorer = Address::generate(&env);
+        let commitment = sha256_of(&env, b"CRANE");
+        client.create_daily_puzzle(&23u64, &commitment, &None, &true, &Some(scorer));
+
+        let player = Address::generate(&env);
+        let none = Vec::new(&env);
+        client.submit_attempt(&player, &23u64, &bytes5(&env, b"STALE"), &none);
+        let result = client.try_submit_attempt(&player, &23u64, &bytes5(&env, b"CRATE"), &none);
+        assert_eq!(result, Err(Ok(Error::AwaitingScore)));
+
+        let stale = scores_of(&env, b"STALE", b"CRANE");
+        let wrong_commitment = sha256_of(&env, b"STALE");
+        let result = client.try_post_scores(&23u64, &player, &1, &wrong_commitment, &stale);
+        assert_eq!(result, Err(Ok(Error::CommitmentMismatch)));
+        let result = client.try_post_scores(
+            &23u64,
+            &player,
+            &1,
+            &commitment,
+            &soroban_sdk::vec![&env, 3, 0, 0, 0, 0],
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidScores)));
+        let result = client.try_post_scores(&23u64, &player, &2, &commitment, &stale);
+        assert_eq!(result, Err(Ok(Error::AttemptNotFound)));
+
+        client.post_scores(&23u64, &player, &1, &commitment, &stale);
+        assert_eq!(
+            client.get_attempts(&player, &23u64).get(0).unwrap().scores,
+            stale
+        );
+        let result = client.try_post_scores(&23u64, &player, &1, &commitment, &stale);
+        assert_eq!(result, Err(Ok(Error::AlreadyScored)));
+
+        // Hard mode is checked against the posted hints as guesses come in
+        let result = client.try_submit_attempt(&player, &23u64, &bytes5(&env, b"PIOUS"), &none);
+        assert_eq!(result, Err(Ok(Error::HardModeViolation)));
+        client.submit_attempt(&player, &23u64, &bytes5(&env, b"CRANE"), &none);
+        client.post_scores(
+            &23u64,
+            &player,
+            &2,
+            &commitment,
+            &scores_of(&env, b"CRANE", b"CRANE"),
+        );
+
+        client.reveal_answer(&23u64, &bytes5(&env, b"CRANE"));
+        client.finalize_result(&player, &23u64);
+        let puzzle = client.get_puzzle(&23u64).unwrap();
+        assert_eq!(puzzle.status, PuzzleStatus::Finalized);
+        assert!(client.is_winner(&23u64, &player));
+    }
+
+    #[test]
+    fn test_post_scores_requires_scorer() {
+        let env = Env::default();
+        let (client, _, _, _) = setup(&env);
+        env.mock_all_auths();
+
+        let commitment = sha256_of(&env, b"CRANE");
+        client.create_daily_puzzle(&24u64, &commitment, &None, &false, &None);
+
+        let player = Address::generate(&env);
+        client.submit_attempt(&player, &24u64, &bytes5(&env, b"STALE"), &Vec::new(&env));
+        let scores = scores_of(&env, b"STALE", b"CRANE");
+        let result = client.try_post_scores(&24u64, &player, &1, &commitment, &scores);
+        assert_eq!(result, Err(Ok(Error::NoScorer)));
+    }
+
+    #[test]
+    fn test_wrong_posted_score_invalidates_puzzle() {
+        let env = Env::default();
+        let (client, _, _, _) = setup(&env);
+        env.mock_all_auths();
+
+        let scorer = Address::generate(&env);
+        let commitment = sha256_of(&env, b"CRANE");
+        client.create_daily_puzzle(&25u64, &commitment, &None, &false, &Some(scorer));
+
+        let honest = Address::generate(&env);
+        client.submit_attempt(&honest, &25u64, &bytes5(&env, b"CRANE"), &Vec::new(&env));
+        client.post_scores(
+            &25u64,
+            &honest,
+            &1,
+            &commitment,
+            &scores_of(&env, b"CRANE", b"CRANE"),
+        );
+
+        // The scorer steers this player away with a false hint
+        let misled = Address::generate(&env);
+        client.submit_attempt(&misled, &25u64, &bytes5(&env, b"CRATE"), &Vec::new(&env));
+        client.post_scores(
+            &25u64,
+            &misled,
+            &1,
+            &commitment,
+            &soroban_sdk::vec![&env, 0, 0, 0, 0, 0],
+        );
+
+        client.reveal_answer(&25u64, &bytes5(&env, b"CRANE"));
+        client.finalize_result(&honest, &25u64);
+
+        let puzzle = client.get_puzzle(&25u64).unwrap();
+        assert_eq!(puzzle.status, PuzzleStatus::Invalidated);
+        assert_eq!(puzzle.winner_count, 0);
+        assert!(!client.is_winner(&25u64, &honest));
+        assert_eq!(
+            client.get_puzzle_snapshot(&honest, &25u64).state,
+            PuzzleSnapshotState::Invalidated
+        );
+        assert_eq!(
+            client.try_finalize_result(&honest, &25u64),
+            Err(Ok(Error::PuzzleAlreadyFinalized))
+        );
+    }
+
+    #[test]
+    fn test_wrong_posted_score_forces_hard_mode_violation() {
+        let env = Env::default();
+        let (client, _, _, _) = setup(&env);
+        env.mock_all_auths();
+
+        let scorer = Address::generate(&env);
+        let commitment = sha256_of(&env, b"CRANE");
+        client.create_daily_puzzle(&26u64, &commitment, &None, &true, &Some(scorer));
+
+        // The scorer claims CRATE solved the puzzle, so hard mode pins all
+        // five letters and the real answer is refused
+        let player = Address::generate(&env);
+        let none = Vec::new(&env);
+        client.submit_attempt(&player, &26u64, &bytes5(&env, b"CRATE"), &none);
+        let false_hint = soroban_sdk::vec![&env, 2, 2, 2, 2, 2];
+        client.post_scores(&26u64, &player, &1, &commitment, &false_hint);
+        let result = client.try_submit_attempt(&player, &26u64, &bytes5(&env, b"CRANE"), &none);
+        assert_eq!(result, Err(Ok(Error::HardModeViolation)));
+
+        client.reveal_answer(&26u64, &bytes5(&env, b"CRANE"));
+        client.finalize_result(&player, &26u64);
+
+        let puzzle = client.get_puzzle(&26u64).unwrap();
+        assert_eq!(puzzle.status, PuzzleStatus::Invalidated);
+        assert_eq!(puzzle.disqualified_count, 0);
+        assert!(!client.is_disqualified(&26u64, &player));
+        // The false hint stays on record
+        let attempts = client.get_attempts(&player, &26u64);
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts.get(0).unwrap().scores, false_hint);
+    }
+
+    #[test]
+    fn test_score_timeout_unblocks_player() {
+        let env = Env::default();
+        let (client, _, _, _) = setup(&env);
+        env.mock_all_auths();
+
+        let scorer = Address::generate(&env);
+        let commitment = sha256_of(&env, b"CRANE");
+        client.create_daily_puzzle(&27u64, &commitment, &None, &true, &Some(scorer));
+
+        let player = Address::generate(&env);
+        let none = Vec::new(&env);
+        client.submit_attempt(&player, &27u64, &bytes5(&env, b"STALE"), &none);
+        let submitted_at = client
+            .get_attempts(&player, &27u64)
+            .get(0)
+            .unwrap()
+            .submitted_at;
+
+        // The scorer goes quiet
+        env.ledger()
+            .set_sequence_number(submitted_at + SCORE_TIMEOUT_LEDGERS - 1);
+        let result = client.try_submit_attempt(&player, &27u64, &bytes5(&env, b"PIOUS"), &none);
+        assert_eq!(result, Err(Ok(Error::AwaitingScore)));
+
+        // Past the deadline the next guess goes through, free of the hints
+        // STALE would have given
+        env.ledger()
+            .set_sequence_number(submitted_at + SCORE_TIMEOUT_LEDGERS);
+        client.submit_attempt(&player, &27u64, &bytes5(&env, b"PIOUS"), &none);
+        client.post_scores(
+            &27u64,
+            &player,
+            &2,
+            &commitment,
+            &scores_of(&env, b"PIOUS", b"CRANE"),
+        );
+        client.submit_attempt(&player, &27u64, &bytes5(&env, b"CRANE"), &none);
+
+        client.reveal_answer(&27u64, &bytes5(&env, b"CRANE"));
+        client.finalize_result(&player, &27u64);
+        let puzzle = client.get_puzzle(&27u64).unwrap();
+        assert_eq!(puzzle.status, PuzzleStatus::Finalized);
+        assert!(client.is_winner(&27u64, &player));
+        assert!(!client.is_disqualified(&27u64, &player));
+    }
 }
@@ -0,0 +1,6594 @@
+// Generated by stellarcade-contract-doc-generator from the contract sources.
+// Do not edit; rerun the generator instead.
+
+export declare namespace AccessControl {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Role"; values: readonly [string, string] }
+    | { tag: "RoleMembers"; values: readonly [string] };
+  export interface RoleGrantedEvent {
+    topics: readonly ["role_granted"];
+    data: { role: string; account: string };
+  }
+  export interface RoleRevokedEvent {
+    topics: readonly ["role_revoked"];
+    data: { role: string; account: string };
+  }
+  export type ContractEvent = RoleGrantedEvent | RoleRevokedEvent;
+  export interface Methods {
+    /**
+     * Initializes the contract with a super admin.
+     * This admin will have the power to grant and revoke any roles.
+     */
+    init: { args: { admin: string }; returns: void };
+    /** Grants a role to an account. Only accounts with ADMIN role (or the super admin) can call this. */
+    grant_role: { args: { role: string; account: string }; returns: void };
+    /** Revokes a role from an account. Only accounts with ADMIN role can call this. */
+    revoke_role: { args: { role: string; account: string }; returns: void };
+    /** Checks if an account has a specific role. */
+    has_role: { args: { role: string; account: string }; returns: boolean };
+    /** Retrieves the current super admin address. */
+    get_admin: { args: Record<string, never>; returns: string };
+    /**
+     * Returns the number of accounts that currently hold the given role.
+     * Returns 0 for roles that have never been granted.
+     */
+    role_member_count: { args: { role: string }; returns: number };
+    /**
+     * Returns a bounded, deterministically-ordered slice of accounts that hold
+     * the given role.  Members are ordered by grant time (oldest first).
+     * Returns an empty vec for roles that have never been granted.
+     */
+    list_role_members: { args: { role: string; start: number; limit: number }; returns: Array<string> };
+  }
+}
+
+/**
+ * Stellarcade Achievement/Badge Contract
+ *
+ * Manages the definition, evaluation, and awarding of achievement badges to
+ * players on the StellarCade platform. Badges are defined by an admin with a
+ * criteria hash (off-chain criteria commitment) and an optional on-chain reward
+ * routed through the reward contract. The admin evaluates and awards badges;
+ * badge holders are tracked per user.
+ *
+ * ## Storage Strategy
+ * - `instance()`: Admin and RewardContract address. Small, fixed config shared
+ *   across all entries in one ledger entry with a single TTL.
+ * - `persistent()`: BadgeDefinition per badge_id, UserBadges per user.
+ *   Each is a separate ledger entry with its own TTL, bumped on every write.
+ *
+ * ## Invariants
+ * - A badge_id can only be defined once (`define_badge` is idempotent-guarded).
+ * - A user can only hold each badge once (duplicate awards are rejected).
+ * - `award_badge` requires the badge to be defined and the user not to already
+ *   hold it, in that order, with no TOCTOU gap.
+ */
+export declare namespace AchievementBadge {
+  /**
+   * Discriminants for all storage keys.
+   *
+   * Instance keys (Admin, RewardContract): contract config, one ledger entry.
+   * Persistent keys (Badge, UserBadges): per-badge definitions and per-user
+   * badge lists, each with their own TTL.
+   */
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "RewardContract"; values: void }
+    | { tag: "Badge"; values: readonly [bigint] }
+    | { tag: "UserBadges"; values: readonly [string] }
+    | { tag: "BadgeMeta"; values: readonly [bigint] };
+  /**
+   * Definition of a badge, stored on-chain.
+   *
+   * `criteria_hash` is a 32-byte SHA-256 hash of the off-chain criteria
+   * document, providing a tamper-evident commitment without on-chain verbosity.
+   * `reward` is an optional i128 amount to disburse via the reward contract
+   * when the badge is awarded; 0 means no reward.
+   */
+  export interface BadgeDefinition {
+    /** SHA-256 hash of the off-chain criteria specification (32 bytes). */
+    criteria_hash: Buffer;
+    /** Token amount paid via `reward_contract` when badge is awarded. 0 = none. */
+    reward: bigint;
+  }
+  /**
+   * Human-readable metadata attached to a badge.
+   *
+   * Stored separately from `BadgeDefinition` to allow metadata updates
+   * without touching the immutable criteria commitment, and to remain
+   * compatible with future metadata expansion.
+   */
+  export interface BadgeMetaEntry {
+    /** Display name shown on the badge card (e.g. "First Win"). */
+    title: string;
+    /** Short description of the achievement. */
+    description: string;
+    /** Plain-language explanation of the award rules / criteria. */
+    award_rules: string;
+  }
+  /**
+   * A single-call snapshot combining definition and metadata, suitable for
+   * rendering a complete badge card without additional reads.
+   *
+   * `found` is `false` when the `badge_id` is unknown; all other fields
+   * will be zero-values in that case.
+   */
+  export interface BadgeSummary {
+    /** `false` means the badge_id does not exist; all other fields are empty. */
+    found: boolean;
+    badge_id: bigint;
+    criteria_hash: Buffer;
+    reward: bigint;
+    title: string;
+    description: string;
+    award_rules: string;
+  }
+  /**
+   * Per-user claim-status snapshot for a single badge.
+   *
+   * `badge_found` is `false` when the badge_id is not defined.
+   * `claimed` is `false` both when the badge is unknown and when the user
+   * has not yet been awarded it.
+   */
+  export interface ClaimStatusSnapshot {
+    badge_id: bigint;
+    claimed: boolean;
+    badge_found: boolean;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    BadgeNotFound = 4,
+    BadgeAlreadyExists = 5,
+    BadgeAlreadyAwarded = 6,
+    InvalidInput = 7,
+  }
+  export interface BadgeDefinedEvent {
+    topics: readonly ["badge_defined", bigint];
+    data: { criteria_hash: Buffer; reward: bigint };
+  }
+  export interface UserEvaluatedEvent {
+    topics: readonly ["user_evaluated", string, bigint];
+    data: Record<string, never>;
+  }
+  export interface BadgeAwardedEvent {
+    topics: readonly ["badge_awarded", string, bigint];
+    data: { reward: bigint };
+  }
+  export type ContractEvent = BadgeDefinedEvent | UserEvaluatedEvent | BadgeAwardedEvent;
+  export interface Methods {
+    /**
+     * Initialize the contract. May only be called once.
+     *
+     * `admin` is the only address authorized to define badges, evaluate users,
+     * and award badges. `reward_contract` is the address of the downstream
+     * contract that handles token payouts (e.g., PrizePool). It is stored for
+     * future integration but is not called directly in this contract.
+     */
+    init: { args: { admin: string; reward_contract: string }; returns: void };
+    /**
+     * Define a new achievement badge. Admin only.
+     *
+     * `badge_id` must be unique; re-defining an existing badge returns
+     * `BadgeAlreadyExists`. `criteria_hash` is the 32-byte SHA-256 hash of
+     * the off-chain criteria document. `reward` is the token amount awarded
+     * through the reward contract on badge issuance; use 0 for no reward.
+     */
+    define_badge: { args: { admin: string; badge_id: bigint; criteria_hash: Buffer; reward: bigint }; returns: void };
+    /**
+     * Signal that a user has been evaluated against a badge's criteria.
+     * Admin only.
+     *
+     * This is an administrative action that emits an auditable event. It does
+     * not award the badge; call `award_badge` separately if the evaluation
+     * determines the user qualifies. The badge must exist.
+     */
+    evaluate_user: { args: { admin: string; user: string; badge_id: bigint }; returns: void };
+    /**
+     * Award `badge_id` to `user`. Admin only.
+     *
+     * The badge must be defined. Each badge can only be awarded once per user;
+     * duplicate awards return `BadgeAlreadyAwarded`. The badge is appended to
+     * the user's persistent badge list, which is created on first award.
+     *
+     * If `badge.reward > 0`, a `BadgeAwarded` event is emitted with the
+     * reward amount so off-chain services can trigger the downstream payout
+     * via the reward contract.
+     */
+    award_badge: { args: { admin: string; user: string; badge_id: bigint }; returns: void };
+    /**
+     * Return the list of badge IDs awarded to `user`.
+     *
+     * Returns an empty list if the user has not been awarded any badges.
+     * Does not require initialization — a user with no badges trivially has
+     * an empty list regardless of contract state.
+     */
+    badges_of: { args: { user: string }; returns: Array<bigint> };
+    /**
+     * Attach human-readable metadata to an existing badge. Admin only.
+     *
+     * The badge must already be defined via `define_badge`. Metadata may be
+     * updated by calling this again; each write extends the TTL.
+     *
+     * Keeping metadata separate from the immutable `BadgeDefinition` allows
+     * copy edits and future metadata field additions without touching the
+     * on-chain criteria commitment.
+     */
+    set_badge_metadata: { args: { admin: string; badge_id: bigint; title: string; description: string; award_rules: string }; returns: void };
+    /**
+     * Return a combined badge definition + metadata snapshot for `badge_id`.
+     *
+     * This is designed for single-call badge-card rendering — no additional
+     * reads are required. When `badge_id` is unknown `found` is `false` and
+     * all other fields carry zero/empty values. Missing metadata fields
+     * (metadata not yet set) are returned as empty strings.
+     */
+    get_badge_summary: { args: { badge_id: bigint }; returns: BadgeSummary };
+    /**
+     * Return the claim-status snapshot for `(user, badge_id)`.
+     *
+     * `badge_found` is `false` and `claimed` is `false` when the badge does
+     * not exist. `claimed` is `false` when the badge exists but has not been
+     * awarded to this user. Both fields are deterministic for all inputs.
+     */
+    get_claim_status: { args: { user: string; badge_id: bigint }; returns: ClaimStatusSnapshot };
+  }
+}
+
+export declare namespace AiGeneratedGame {
+  /**
+   * Lifecycle visibility state for a session snapshot.
+   * Missing = game_id not found; Active = in-flight; Completed = resolved.
+   */
+  export const enum SnapshotStatus {
+    /** No session exists for the requested game_id. */
+    Missing = 0,
+    /** Session exists but has not yet been resolved (Created or InProgress). */
+    Active = 1,
+    /** Session has been resolved by the oracle. */
+    Completed = 2,
+  }
+  /**
+   * Read model returned by `get_session_snapshot`.
+   *
+   * Exposes enough state for a client to resume an in-progress session without
+   * leaking sensitive prompt internals. The `prompt_hash` field is the
+   * SHA-256 commitment stored at game creation — it is safe to expose for
+   * verification purposes but does NOT reveal the underlying prompt content.
+   *
+   * Fields intentionally omitted (redacted):
+   * - Raw prompt / config payload (never stored on-chain; only the hash is kept)
+   * - Oracle result payload (stored off-chain; not part of on-chain state)
+   * - Internal reward-claim flags (private accounting detail)
+   */
+  export interface SessionSnapshot {
+    /** Requested game identifier. */
+    game_id: bigint;
+    /** Lifecycle visibility state. */
+    status: SnapshotStatus;
+    /**
+     * SHA-256 hash of the game configuration / prompt committed at creation.
+     * Zero-filled when status is Missing.
+     */
+    prompt_hash: Buffer;
+    /** Address of the winner once the session is Completed; None otherwise. */
+    winner: string | undefined;
+    /** True when a winner has been designated (convenience flag for clients). */
+    has_winner: boolean;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "ModelOracle"; values: void }
+    | { tag: "RewardContract"; values: void }
+    | { tag: "Game"; values: readonly [bigint] }
+    | { tag: "Reward"; values: readonly [bigint, string] };
+  export type GameStatus =
+    | { tag: "Created"; values: void }
+    | { tag: "InProgress"; values: void }
+    | { tag: "Resolved"; values: void };
+  export interface AIGameState {
+    config_hash: Buffer;
+    status: GameStatus;
+    winner: string | undefined;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    GameAlreadyExists = 4,
+    GameNotFound = 5,
+    InvalidStatus = 6,
+    RewardAlreadyClaimed = 7,
+    NoReward = 8,
+  }
+  export interface ContractInitializedEvent {
+    topics: readonly ["contract_initialized"];
+    data: { admin: string; model_oracle: string; reward_contract: string };
+  }
+  export interface GameCreatedEvent {
+    topics: readonly ["game_created", bigint];
+    data: { config_hash: Buffer };
+  }
+  export interface MovePlayedEvent {
+    topics: readonly ["move_played", bigint, string];
+    data: { move_payload: string };
+  }
+  export interface GameResolvedEvent {
+    topics: readonly ["game_resolved", bigint, string];
+    data: { result_payload: string; winner: string | undefined };
+  }
+  export interface RewardClaimedEvent {
+    topics: readonly ["reward_claimed", bigint, string];
+    data: Record<string, never>;
+  }
+  export type ContractEvent = ContractInitializedEvent | GameCreatedEvent | MovePlayedEvent | GameResolvedEvent | RewardClaimedEvent;
+  export interface Methods {
+    /** Initialize the contract with the admin, AI model oracle address, and reward system address. */
+    init: { args: { admin: string; model_oracle: string; reward_contract: string }; returns: void };
+    /** Setup a new AI-generated game layout. */
+    create_ai_game: { args: { admin: string; game_id: bigint; config_hash: Buffer }; returns: void };
+    /** Player submitting a move towards an active AI game. */
+    submit_ai_move: { args: { player: string; game_id: bigint; move_payload: string }; returns: void };
+    /** Oracle node resolves the game securely mapping outputs and winners systematically. */
+    resolve_ai_game: { args: { oracle: string; game_id: bigint; result_payload: string; winner: string | undefined }; returns: void };
+    /**
+     * Returns a stable read-only snapshot of a session for client resume flows.
+     *
+     * Safe to call without authentication — no sensitive internals are exposed.
+     * Returns a deterministic `Missing` snapshot when the game_id is unknown,
+     * so callers never need to handle a hard error for a simple lookup.
+     */
+    get_session_snapshot: { args: { game_id: bigint }; returns: SessionSnapshot };
+    /** Authorizes player to claim rewards mapped after oracle validation finishes. */
+    claim_ai_reward: { args: { player: string; game_id: bigint }; returns: void };
+  }
+}
+
+/** Stellarcade User Balance Management Contract */
+export declare namespace BalanceManagement {
+  export type DataKey =
+    | { tag: "Account"; values: readonly [string] };
+  export interface AccountState {
+    balance: bigint;
+    reserved: bigint;
+    last_update: number;
+  }
+  export interface AccountSummary {
+    /** True if the account has state persisted in this contract. */
+    exists: boolean;
+    /** Spendable balance. */
+    balance: bigint;
+    /** Amount currently reserved/locked. */
+    reserved: bigint;
+    /** Ledger sequence of the latest state mutation affecting the account. */
+    last_update: number;
+  }
+  export interface Methods {
+    /** Update user balance (Internal use by other contracts). */
+    update_balance: { args: { user: string; amount: bigint; is_add: boolean }; returns: void };
+    /** View user balance. */
+    get_balance: { args: { user: string }; returns: bigint };
+    /**
+     * Returns a stable account snapshot for backend consumers.
+     *
+     * If an account has never been written, `exists` is false and numeric
+     * fields are zeroed so unknown and zero-balance-known accounts are
+     * distinguishable.
+     */
+    get_account_summary: { args: { user: string }; returns: AccountSummary };
+  }
+}
+
+/**
+ * Stellarcade Coin Flip Contract
+ *
+ * A 50/50 betting game integrated with the Random Generator contract.
+ * Players pick Heads (0) or Tails (1), place a wager, and an oracle
+ * resolves the outcome via the RNG contract's request/fulfill model.
+ *
+ * ## Game Flow
+ * 1. Player calls `place_bet` → tokens transfer in, RNG requested, game stored.
+ * 2. Oracle fulfills randomness on the RNG contract (off-chain step).
+ * 3. Anyone calls `resolve_bet` → reads RNG result, settles payout.
+ *
+ * If the RNG request expires before the oracle fulfills it, anyone can call
+ * `refund_expired` to return the wager to the player instead.
+ *
+ * ## House Edge
+ * Configured at init via `house_edge_bps` (basis points). A 250 bps edge
+ * means a winning bet on a 100-token wager pays 195 tokens (2x minus 5%).
+ * The remaining 5 tokens stay in the contract as house profit.
+ */
+export declare namespace CoinFlip {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Token"; values: void }
+    | { tag: "RngContract"; values: void }
+    | { tag: "MinWager"; values: void }
+    | { tag: "MaxWager"; values: void }
+    | { tag: "HouseEdgeBps"; values: void }
+    | { tag: "Game"; values: readonly [bigint] }
+    | { tag: "PlayerRecentGames"; values: readonly [string] };
+  export interface Game {
+    player: string;
+    side: number;
+    wager: bigint;
+    resolved: boolean;
+    won: boolean;
+    payout: bigint;
+    refunded: boolean;
+  }
+  export interface PlayerGameHistoryPage {
+    total: number;
+    start: number;
+    limit: number;
+    game_ids: Array<bigint>;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    InvalidSide = 5,
+    GameAlreadyExists = 6,
+    GameNotFound = 7,
+    GameAlreadyResolved = 8,
+    RngNotFulfilled = 9,
+    WagerTooLow = 10,
+    WagerTooHigh = 11,
+    Overflow = 12,
+    RngNotExpired = 13,
+  }
+  export interface BetPlacedEvent {
+    topics: readonly ["bet_placed", bigint, string];
+    data: { side: number; wager: bigint };
+  }
+  export interface BetResolvedEvent {
+    topics: readonly ["bet_resolved", bigint, string];
+    data: { won: boolean; payout: bigint };
+  }
+  export interface BetRefundedEvent {
+    topics: readonly ["bet_refunded", bigint, string];
+    data: { amount: bigint };
+  }
+  export type ContractEvent = BetPlacedEvent | BetResolvedEvent | BetRefundedEvent;
+  export interface Methods {
+    /**
+     * Initialize the coin flip game.
+     *
+     * `house_edge_bps`: house edge in basis points (e.g., 250 = 2.5%).
+     */
+    init: { args: { admin: string; rng_contract: string; token: string; min_wager: bigint; max_wager: bigint; house_edge_bps: bigint }; returns: void };
+    /**
+     * Player places a bet. Tokens are transferred into the contract.
+     * A randomness request is submitted to the RNG contract.
+     *
+     * `side`: 0 = Heads, 1 = Tails.
+     * `client_seed`: optional player entropy mixed into the RNG result.
+     */
+    place_bet: { args: { player: string; side: number; wager: bigint; game_id: bigint; client_seed: Buffer | undefined }; returns: void };
+    /**
+     * Resolve a game after the oracle has fulfilled the RNG request.
+     * Anyone can call this — no auth needed since the outcome is deterministic.
+     */
+    resolve_bet: { args: { game_id: bigint }; returns: void };
+    /**
+     * Return the wager of a game whose RNG request expired unfulfilled.
+     * Anyone can call this; the wager only ever goes back to the player.
+     */
+    refund_expired: { args: { game_id: bigint }; returns: void };
+    /** View a game's state. */
+    get_game: { args: { game_id: bigint }; returns: Game };
+    /** Return a bounded page of recent game ids for a player, ordered newest first. */
+    get_recent_games: { args: { player: string; start: number; limit: number }; returns: PlayerGameHistoryPage };
+  }
+}
+
+/**
+ * Stellarcade Color Prediction Game Contract
+ *
+ * A prediction game where players wager on which color will be chosen next.
+ * An admin resolves each game by revealing the winning color. Winners split
+ * the pot proportionally; losers forfeit their wager to the pool.
+ *
+ * ## Game Flow
+ * 1. Admin calls `init` to configure the contract.
+ * 2. Player calls `place_prediction(player, color, wager, game_id)` to enter.
+ *    Multiple players can predict on the same game_id. Each player may only
+ *    submit one prediction per game.
+ * 3. Admin calls `resolve_prediction(game_id)` with the winning color.
+ *    Winners are determined and the pot split equally among correct predictors.
+ * 4. Anyone calls `get_game(game_id)` to inspect the final state.
+ *
+ * ## Colors
+ * Valid color values: 0 = Red, 1 = Green, 2 = Blue, 3 = Yellow.
+ *
+ * ## Storage Strategy
+ * - `instance()` storage: contract-level config (Admin, RngContract,
+ *   PrizePoolContract, BalanceContract). Small, bounded, single ledger entry.
+ * - `persistent()` storage: per-game and per-player data (GameData,
+ *   PlayerList, Prediction). Each is an independent ledger entry with its own
+ *   TTL extended on every write (~30 days).
+ *
+ * ## Security
+ * - Only admin may resolve predictions.
+ * - Each player may predict at most once per game.
+ * - Resolving an already-resolved game is rejected.
+ * - All arithmetic uses `checked_*` to prevent overflow.
+ */
+export declare namespace ColorPrediction {
+  /** Lifecycle state of a prediction game. */
+  export const enum GameStatus {
+    /** Accepting predictions. */
+    Open = 0,
+    /** Resolved — winning color known, outcome recorded. */
+    Resolved = 1,
+  }
+  /** Metadata and accumulated state for one prediction game. */
+  export interface GameData {
+    /** Total tokens wagered across all predictions. */
+    total_pot: bigint;
+    /** Number of distinct predictors. */
+    player_count: number;
+    /** Number of predictors who chose the winning color. */
+    winner_count: number;
+    /** Winning color (only valid when status == Resolved). */
+    winning_color: number;
+    status: GameStatus;
+  }
+  /** A single player's prediction for a game. */
+  export interface PredictionEntry {
+    color: number;
+    wager: bigint;
+  }
+  /**
+   * Storage key discriminants.
+   *
+   * Instance keys (Admin, RngContract, PrizePoolContract, BalanceContract)
+   * hold small contract-level config in a single ledger entry.
+   *
+   * Persistent keys (Game, PlayerList, Prediction) are per-game and per-player,
+   * each stored as an independent ledger entry with its own TTL.
+   */
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "RngContract"; values: void }
+    | { tag: "PrizePoolContract"; values: void }
+    | { tag: "BalanceContract"; values: void }
+    | { tag: "Game"; values: readonly [bigint] }
+    | { tag: "PlayerList"; values: readonly [bigint] }
+    | { tag: "Prediction"; values: readonly [bigint, string] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidColor = 4,
+    InvalidAmount = 5,
+    GameNotFound = 6,
+    GameAlreadyResolved = 7,
+    AlreadyPredicted = 8,
+    GameFull = 9,
+    Overflow = 10,
+  }
+  export interface PredictionPlacedEvent {
+    topics: readonly ["prediction_placed", bigint, string];
+    data: { color: number; wager: bigint };
+  }
+  export interface PredictionResolvedEvent {
+    topics: readonly ["prediction_resolved", bigint];
+    data: { winning_color: number; winner_count: number; total_pot: bigint };
+  }
+  export type ContractEvent = PredictionPlacedEvent | PredictionResolvedEvent;
+  export interface Methods {
+    /**
+     * Initialize the contract. May only be called once.
+     *
+     * Stores admin, rng_contract, prize_pool_contract, and balance_contract
+     * in instance storage. Subsequent calls return `AlreadyInitialized`.
+     */
+    init: { args: { admin: string; rng_contract: string; prize_pool_contract: string; balance_contract: string }; returns: void };
+    /**
+     * Place a color prediction for an open game.
+     *
+     * `color` must be one of COLOR_RED (0), COLOR_GREEN (1), COLOR_BLUE (2),
+     * COLOR_YELLOW (3). `wager` must be positive. Each player may predict
+     * exactly once per game. The game is created implicitly on the first
+     * prediction for a given `game_id`.
+     *
+     * Emits `PredictionPlaced`.
+     */
+    place_prediction: { args: { player: string; color: number; wager: bigint; game_id: bigint }; returns: void };
+    /**
+     * Resolve a game by declaring the winning color. Admin only.
+     *
+     * `winning_color` must be a valid color value (0–3). Iterates all player
+     * predictions (bounded by `MAX_PLAYERS_PER_GAME`) to count winners and
+     * transitions the game to `Resolved`.
+     *
+     * If there are no winners, the entire pot remains in the contract.
+     *
+     * Emits `PredictionResolved`.
+     */
+    resolve_prediction: { args: { game_id: bigint; winning_color: number }; returns: void };
+    /** Return the game state, or `None` if the game does not exist. */
+    get_game: { args: { game_id: bigint }; returns: GameData | undefined };
+  }
+}
+
+export declare namespace ComprehensiveTestSuite {
+  export interface SuiteConfig {
+    suite: string;
+    min_cases: number;
+    requires_integration: boolean;
+    requires_property: boolean;
+    active: boolean;
+  }
+  export interface RunRecord {
+    suite: string;
+    build_id: Buffer;
+    passed_cases: number;
+    failed_cases: number;
+    coverage_bps: number;
+    includes_integration: boolean;
+    includes_property: boolean;
+    gate_passed: boolean;
+    timestamp: bigint;
+  }
+  export interface RunKey {
+    suite: string;
+    build_id: Buffer;
+  }
+  export interface SuiteState {
+    admin: string;
+    reporter: string;
+    paused: boolean;
+    killed: boolean;
+    coverage_target_bps: number;
+    total_suites: number;
+    total_runs: number;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Reporter"; values: void }
+    | { tag: "Paused"; values: void }
+    | { tag: "Killed"; values: void }
+    | { tag: "CoverageTargetBps"; values: void }
+    | { tag: "TotalSuites"; values: void }
+    | { tag: "TotalRuns"; values: void }
+    | { tag: "Suite"; values: readonly [string] }
+    | { tag: "LastSuccessfulRun"; values: readonly [string] }
+    | { tag: "Run"; values: readonly [RunKey] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidInput = 4,
+    DuplicateSuite = 5,
+    SuiteNotFound = 6,
+    DuplicateRun = 7,
+    Overflow = 8,
+    InvalidState = 9,
+    ContractPaused = 10,
+    AlreadyPaused = 11,
+    NotPaused = 12,
+    ContractKilled = 13,
+  }
+  export interface InitializedEvent {
+    topics: readonly ["initialized"];
+    data: { admin: string; reporter: string; coverage_target_bps: number };
+  }
+  export interface ReporterChangedEvent {
+    topics: readonly ["reporter_changed"];
+    data: { old_reporter: string; new_reporter: string };
+  }
+  export interface SuiteRegisteredEvent {
+    topics: readonly ["suite_registered", string];
+    data: { min_cases: number; requires_integration: boolean; requires_property: boolean };
+  }
+  export interface SuiteUpdatedEvent {
+    topics: readonly ["suite_updated", string];
+    data: { min_cases: number; requires_integration: boolean; requires_property: boolean; active: boolean };
+  }
+  export interface RunRecordedEvent {
+    topics: readonly ["run_recorded", string];
+    data: { build_id: Buffer; gate_passed: boolean; coverage_bps: number };
+  }
+  export interface PauseChangedEvent {
+    topics: readonly ["pause_changed"];
+    data: { paused: boolean; admin: string };
+  }
+  export interface KillSwitchTriggeredEvent {
+    topics: readonly ["kill_switch_triggered"];
+    data: { admin: string; reason_hash: Buffer };
+  }
+  export type ContractEvent = InitializedEvent | ReporterChangedEvent | SuiteRegisteredEvent | SuiteUpdatedEvent | RunRecordedEvent | PauseChangedEvent | KillSwitchTriggeredEvent;
+  export interface Methods {
+    init: { args: { admin: string; reporter: string; coverage_target_bps: number }; returns: void };
+    pause: { args: { admin: string }; returns: void };
+    unpause: { args: { admin: string }; returns: void };
+    trigger_kill_switch: { args: { admin: string; reason_hash: Buffer }; returns: void };
+    set_reporter: { args: { admin: string; reporter: string }; returns: void };
+    register_suite: { args: { admin: string; suite: string; min_cases: number; requires_integration: boolean; requires_property: boolean }; returns: void };
+    update_suite: { args: { admin: string; suite: string; min_cases: number; requires_integration: boolean; requires_property: boolean; active: boolean }; returns: void };
+    record_run: { args: { reporter: string; suite: string; build_id: Buffer; passed_cases: number; failed_cases: number; coverage_bps: number; includes_integration: boolean; includes_property: boolean }; returns: boolean };
+    is_release_ready: { args: { suite: string }; returns: boolean };
+    get_suite: { args: { suite: string }; returns: SuiteConfig | undefined };
+    get_run: { args: { suite: string; build_id: Buffer }; returns: RunRecord | undefined };
+    state: { args: Record<string, never>; returns: SuiteState };
+  }
+}
+
+/**
+ * Stellarcade Contract Address Registry
+ *
+ * A centralized registry for tracking deployed contract addresses with version management.
+ * Enables dynamic contract resolution and maintains full historical audit trail.
+ *
+ * ## Purpose
+ * - Register and track all Stellarcade contract addresses
+ * - Support contract upgrades through versioning
+ * - Provide dynamic address resolution for cross-contract calls
+ * - Maintain immutable history of all contract versions
+ *
+ * ## Security Model
+ * - Admin-only registration and updates
+ * - Public read access for resolution and history
+ * - Immutable history prevents tampering
+ *
+ * ## Usage
+ * ```ignore
+ * // Initialize with admin
+ * registry.init(&admin_address);
+ *
+ * // Register a new contract
+ * registry.register(&env, "prize-pool", &contract_address, 1);
+ *
+ * // Resolve current address
+ * let address = registry.resolve(&env, "prize-pool");
+ *
+ * // Update to new version
+ * registry.update(&env, "prize-pool", &new_address, 2);
+ *
+ * // Query history
+ * let history = registry.history(&env, "prize-pool");
+ * ```
+ */
+export declare namespace ContractAddressRegistry {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Contract"; values: readonly [string] }
+    | { tag: "ContractHistory"; values: readonly [string, number] }
+    | { tag: "LatestVersion"; values: readonly [string] }
+    | { tag: "Initialized"; values: void }
+    | { tag: "AllNames"; values: void };
+  export interface ContractRecord {
+    /** Contract address (C... format) */
+    address: string;
+    /** Version number (starts at 1) */
+    version: number;
+    /** Ledger sequence when registered/updated */
+    registered_at: number;
+    /** Address that performed the registration/update */
+    registered_by: string;
+  }
+  export const enum IssueType {
+    Missing = 1,
+    Duplicate = 2,
+    Placeholder = 3,
+  }
+  export interface RegistryIssue {
+    contract_name: string;
+    issue_type: IssueType;
+    details: string;
+  }
+  export interface ValidationReport {
+    timestamp: number;
+    issues: Array<RegistryIssue>;
+    total_checked: number;
+  }
+  export const enum ErrorCode {
+    /** Registry has already been initialized */
+    AlreadyInitialized = 1,
+    /** Registry has not been initialized */
+    NotInitialized = 2,
+    /** Caller is not authorized to perform this action */
+    NotAuthorized = 3,
+    /** Contract name not found in registry */
+    ContractNotFound = 4,
+    /** Invalid contract address format */
+    InvalidAddress = 5,
+    /** Contract name already registered */
+    DuplicateRegistration = 6,
+    /** Invalid version number */
+    InvalidVersion = 7,
+    /** Invalid contract name format */
+    InvalidName = 8,
+  }
+  export interface Methods {
+    /**
+     * Initialize the registry with an admin address.
+     *
+     * # Arguments
+     * * `admin` - Address that will have full control over the registry
+     *
+     * # Errors
+     * * `AlreadyInitialized` - If registry has already been initialized
+     *
+     * # Events
+     * Emits `Initialized` event on success
+     */
+    init: { args: { admin: string }; returns: void };
+    /**
+     * Register a new contract in the registry.
+     *
+     * # Arguments
+     * * `name` - Unique identifier for the contract (e.g., "prize-pool")
+     * * `address` - Contract address (must start with 'C')
+     * * `version` - Initial version number (typically 1)
+     *
+     * # Errors
+     * * `NotInitialized` - If registry hasn't been initialized
+     * * `NotAuthorized` - If caller is not the admin
+     * * `InvalidName` - If name is empty, too long, or invalid format
+     * * `InvalidAddress` - If address format is invalid
+     * * `DuplicateRegistration` - If contract name already exists
+     * * `InvalidVersion` - If version is 0
+     *
+     * # Events
+     * Emits `ContractRegistered` event on success
+     */
+    register: { args: { name: string; address: string; version: number }; returns: void };
+    /**
+     * Update an existing contract to a new address and version.
+     *
+     * # Arguments
+     * * `name` - Contract name to update
+     * * `address` - New contract address
+     * * `version` - New version number (must be greater than current)
+     *
+     * # Errors
+     * * `NotInitialized` - If registry hasn't been initialized
+     * * `NotAuthorized` - If caller is not the admin
+     * * `ContractNotFound` - If contract name doesn't exist
+     * * `InvalidAddress` - If address format is invalid
+     * * `InvalidVersion` - If version is not greater than current version
+     *
+     * # Events
+     * Emits `ContractUpdated` event on success
+     */
+    update: { args: { name: string; address: string; version: number }; returns: void };
+    /**
+     * Performs a validation report of the registry.
+     *
+     * Flags missing required contracts, duplicate addresses across different aliases,
+     * and placeholder records.
+     *
+     * # Returns
+     * A structured report (`ValidationReport`) flagging:
+     * - **Missing**: Required core contracts (e.g., `prize-pool`) not registered.
+     * - **Duplicate**: Multiple aliases pointing to the identical address.
+     * - **Placeholder**: Address matches the zero-address placeholder (`CAAA...`).
+     *
+     * # Operator Guidance
+     * - **Missing** records: Deploy the missing contract and register it.
+     * - **Duplicate** records: Investigate alias misconfigurations.
+     * - **Placeholder** records: Replace with real addresses before production use.
+     */
+    validation_report: { args: Record<string, never>; returns: ValidationReport };
+    /**
+     * Resolve the current address for a contract name.
+     *
+     * # Arguments
+     * * `name` - Contract name to resolve
+     *
+     * # Returns
+     * The current contract address
+     *
+     * # Errors
+     * * `NotInitialized` - If registry hasn't been initialized
+     * * `ContractNotFound` - If contract name doesn't exist
+     *
+     * # Note
+     * This is a public read operation - no authorization required
+     */
+    resolve: { args: { name: string }; returns: string };
+    /**
+     * Get the full version history for a contract.
+     *
+     * # Arguments
+     * * `name` - Contract name to query
+     *
+     * # Returns
+     * Vector of all historical contract records, ordered by version
+     *
+     * # Errors
+     * * `NotInitialized` - If registry hasn't been initialized
+     * * `ContractNotFound` - If contract name doesn't exist
+     *
+     * # Note
+     * This is a public read operation - no authorization required
+     */
+    history: { args: { name: string }; returns: Array<ContractRecord> };
+    /**
+     * Get the current version number for a contract.
+     *
+     * # Arguments
+     * * `name` - Contract name to query
+     *
+     * # Returns
+     * The current version number
+     *
+     * # Errors
+     * * `NotInitialized` - If registry hasn't been initialized
+     * * `ContractNotFound` - If contract name doesn't exist
+     */
+    get_version: { args: { name: string }; returns: number };
+    /**
+     * Get the current admin address.
+     *
+     * # Returns
+     * The admin address
+     *
+     * # Errors
+     * * `NotInitialized` - If registry hasn't been initialized
+     */
+    get_admin: { args: Record<string, never>; returns: string };
+  }
+}
+
+export declare namespace ContractCircuitBreaker {
+  export const enum BreakerStatus {
+    Closed = 0,
+    Open = 1,
+  }
+  export interface BreakerData {
+    failure_count: number;
+    status: BreakerStatus;
+    last_failure_ledger: number;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Threshold"; values: void }
+    | { tag: "Breaker"; values: readonly [string] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidThreshold = 4,
+    BreakerNotFound = 5,
+  }
+  export interface ContractInitializedEvent {
+    topics: readonly ["contract_initialized"];
+    data: { admin: string; threshold: number };
+  }
+  export interface FailureRecordedEvent {
+    topics: readonly ["failure_recorded"];
+    data: { contract_id: string; failure_count: number; status: BreakerStatus };
+  }
+  export interface BreakerTrippedEvent {
+    topics: readonly ["breaker_tripped"];
+    data: { contract_id: string };
+  }
+  export interface BreakerResetEvent {
+    topics: readonly ["breaker_reset"];
+    data: { contract_id: string };
+  }
+  export type ContractEvent = ContractInitializedEvent | FailureRecordedEvent | BreakerTrippedEvent | BreakerResetEvent;
+  export interface Methods {
+    /** Initialise the circuit breaker contract. */
+    init: { args: { admin: string; threshold: number }; returns: void };
+    /**
+     * Record a failure for a specific contract.
+     * In production, this would likely be restricted to authorized callers (monitors).
+     */
+    record_failure: { args: { contract_id: string; _code: number }; returns: void };
+    /** Manually trip the circuit breaker for a contract. */
+    trip: { args: { contract_id: string }; returns: void };
+    /** Reset the circuit breaker for a contract to Closed state. */
+    reset: { args: { contract_id: string }; returns: void };
+    /** Query the current state of a circuit breaker. */
+    breaker_state: { args: { contract_id: string }; returns: BreakerData | undefined };
+  }
+}
+
+export declare namespace ContractHealthRegistry {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "HealthPolicy"; values: readonly [string] }
+    | { tag: "LatestHealth"; values: readonly [string] }
+    | { tag: "HealthHistory"; values: readonly [string] }
+    | { tag: "TrackedContracts"; values: void };
+  export type HealthStatus =
+    | { tag: "Healthy"; values: void }
+    | { tag: "Degraded"; values: void }
+    | { tag: "Critical"; values: void }
+    | { tag: "Unknown"; values: void };
+  export interface HealthReport {
+    contract_id: string;
+    status: HealthStatus;
+    /** Arbitrary details/metadata hash reported by the oracle/monitor. */
+    details_hash: string;
+    timestamp: bigint;
+    reported_by: string;
+  }
+  export interface HealthPolicy {
+    contract_id: string;
+    /** Symbol describing the policy (e.g., "strict", "lenient"). */
+    policy_type: string;
+    /** Max number of history entries to retain. */
+    max_history: number;
+  }
+  export interface HeartbeatFreshness {
+    contract_id: string;
+    has_heartbeat: boolean;
+    status: HealthStatus;
+    last_heartbeat_timestamp: bigint;
+    age_seconds: bigint;
+    stale_after_seconds: bigint;
+    is_stale: boolean;
+  }
+  export interface HealthReportedEvent {
+    topics: readonly ["health_reported"];
+    data: { contract_id: string; status: HealthStatus; timestamp: bigint };
+  }
+  export interface PolicySetEvent {
+    topics: readonly ["policy_set"];
+    data: { contract_id: string; policy_type: string };
+  }
+  export type ContractEvent = HealthReportedEvent | PolicySetEvent;
+  export interface Methods {
+    /** Initialize with the admin address. */
+    init: { args: { admin: string }; returns: void };
+    /**
+     * Report the health of a contract. The reporter must be authorized.
+     * Admin can report for any contract; other monitors must be pre-approved (future extension).
+     */
+    report_health: { args: { reporter: string; contract_id: string; status: HealthStatus; details_hash: string }; returns: void };
+    /** Set the health monitoring policy for a contract. Admin-only. */
+    set_health_policy: { args: { contract_id: string; policy: HealthPolicy }; returns: void };
+    /** Get the most recent health report for a contract. */
+    health_of: { args: { contract_id: string }; returns: HealthReport };
+    /** Get the full health history for a contract (up to max_history entries). */
+    history: { args: { contract_id: string }; returns: Array<HealthReport> };
+    /** Return freshness information for a monitored contract heartbeat. */
+    heartbeat_freshness: { args: { contract_id: string; stale_after_seconds: bigint }; returns: HeartbeatFreshness };
+    /** Return all tracked contracts whose latest heartbeat is older than the threshold. */
+    stale_contracts: { args: { stale_after_seconds: bigint }; returns: Array<string> };
+  }
+}
+
+/**
+ * # Contract Interaction Library
+ *
+ * A reusable on-chain SDK helper exposing type-safe wrappers, cross-contract
+ * call utilities, and event-decoding helpers so that other StellarCade
+ * contracts can interact with the ecosystem in a composable, safe way.
+ *
+ * **Capabilities:**
+ * 1. **Registry** – store and resolve canonical contract addresses by name,
+ *    with version tracking and activation state.
+ * 2. **Upgrade management** – update a registered contract's address while
+ *    preserving name-based routing.
+ * 3. **Call logging** – emit and persist immutable records of cross-contract
+ *    call outcomes for auditability.
+ */
+export declare namespace ContractInteractionLibrary {
+  export interface PrizePoolConfigSnapshot {
+    admin: string;
+    token: string;
+    available_balance: bigint;
+    reserved_amount: bigint;
+    payouts_count: bigint;
+    last_update_ledger: number;
+  }
+  export interface AccountSummary {
+    exists: boolean;
+    balance: bigint;
+    reserved: bigint;
+    last_update: number;
+  }
+  export interface ContractEntry {
+    name: string;
+    address: string;
+    version: number;
+    active: boolean;
+  }
+  export interface CallRecord {
+    callee_name: string;
+    caller: string;
+    timestamp: bigint;
+    success: boolean;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Registry"; values: void }
+    | { tag: "CallCounter"; values: void }
+    | { tag: "CallLog"; values: void };
+  export const enum ErrorCode {
+    NotInitialized = 2,
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    ContractNotFound = 4,
+    InvalidAddress = 5,
+    DuplicateRegistration = 6,
+    InvalidVersion = 7,
+    InvalidName = 8,
+    RegistryNotInitialized = 1,
+    RegistryEntryMissing = 2,
+    RegistryLookupFailed = 3,
+    CoreReadFailed = 4,
+  }
+  export interface LibraryInitializedEvent {
+    topics: readonly ["library_initialized"];
+    data: { admin: string };
+  }
+  export interface ContractRegisteredEvent {
+    topics: readonly ["contract_registered"];
+    data: { name: string; address: string; version: number };
+  }
+  export interface ContractDeactivatedEvent {
+    topics: readonly ["contract_deactivated"];
+    data: { name: string };
+  }
+  export interface CallLoggedEvent {
+    topics: readonly ["call_logged", bigint];
+    data: { callee_name: string; caller: string; success: boolean };
+  }
+  export type ContractEvent = LibraryInitializedEvent | ContractRegisteredEvent | ContractDeactivatedEvent | CallLoggedEvent;
+  export interface Methods {
+    /** Initialise the library contract. Must be called once. */
+    init: { args: { admin: string }; returns: void };
+    /** Register a contract under a human-readable `name` (1-32 chars, unique). */
+    register_contract: { args: { name: string; address: string; version: number }; returns: void };
+    /** Deactivate a registered contract by name. */
+    deactivate_contract: { args: { name: string }; returns: void };
+    /** Upgrade a registered contract to a new address + version. */
+    upgrade_contract: { args: { name: string; new_address: string; new_version: number }; returns: void };
+    /** Return the full registry entry for `name`. */
+    get_contract: { args: { name: string }; returns: ContractEntry };
+    /** Resolve the address of an active registered contract. */
+    resolve: { args: { name: string }; returns: string };
+    /** Record a cross-contract call result and return its log ID. */
+    log_call: { args: { callee_name: string; caller: string; success: boolean }; returns: bigint };
+    /** Fetch a call log entry by ID. */
+    get_call_log: { args: { log_id: bigint }; returns: CallRecord };
+    read_prize_pool_config: { args: { address_registry: string }; returns: PrizePoolConfigSnapshot };
+    read_balance_account_summary: { args: { address_registry: string; user: string }; returns: AccountSummary };
+  }
+}
+
+export declare namespace ContractMetadataRegistry {
+  export interface MetadataRecord {
+    version: number;
+    schema_hash: Buffer;
+    docs_uri: string;
+    updated_at: bigint;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Metadata"; values: readonly [string] }
+    | { tag: "History"; values: readonly [string, number] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    ContractAlreadyRegistered = 4,
+    ContractNotFound = 5,
+    InvalidVersion = 6,
+  }
+  export interface ContractInitializedEvent {
+    topics: readonly ["contract_initialized"];
+    data: { admin: string };
+  }
+  export interface MetadataRegisteredEvent {
+    topics: readonly ["metadata_registered"];
+    data: { contract_id: string; version: number };
+  }
+  export interface MetadataUpdatedEvent {
+    topics: readonly ["metadata_updated"];
+    data: { contract_id: string; old_version: number; new_version: number };
+  }
+  export type ContractEvent = ContractInitializedEvent | MetadataRegisteredEvent | MetadataUpdatedEvent;
+  export interface Methods {
+    /** Initialize the metadata registry with an admin. */
+    init: { args: { admin: string }; returns: void };
+    /** Register initial metadata for a contract. */
+    register_metadata: { args: { contract_id: string; version: number; schema_hash: Buffer; docs_uri: string }; returns: void };
+    /** Update metadata for an existing contract (incrementing version). */
+    update_metadata: { args: { contract_id: string; version: number; schema_hash: Buffer; docs_uri: string }; returns: void };
+    /** Query current metadata for a contract. */
+    metadata_of: { args: { contract_id: string }; returns: MetadataRecord | undefined };
+    /**
+     * Return the latest published metadata for a contract key.
+     *
+     * This is a direct lookup that avoids scanning version lists.
+     * Returns `None` for unknown contract keys.
+     */
+    latest_published: { args: { contract_id: string }; returns: MetadataRecord | undefined };
+    /**
+     * Query the complete history of metadata for a contract.
+     *
+     * Records are returned in ascending version order (oldest first).
+     */
+    history: { args: { contract_id: string }; returns: Array<MetadataRecord> };
+    /**
+     * Query a bounded window of version history for a contract.
+     *
+     * Returns at most `limit` records in ascending version order,
+     * starting from the most recent version and working backwards.
+     * If `limit` is 0 or the contract key is unknown, returns an empty vec.
+     */
+    history_bounded: { args: { contract_id: string; limit: number }; returns: Array<MetadataRecord> };
+  }
+}
+
+export declare namespace ContractMonitoring {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Paused"; values: void }
+    | { tag: "Metrics"; values: void }
+    | { tag: "Thresholds"; values: void }
+    | { tag: "SeenEvent"; values: readonly [bigint] }
+    | { tag: "RecentEvents"; values: void };
+  export const enum EventKind {
+    SettlementSuccess = 0,
+    SettlementFailed = 1,
+    ContractError = 2,
+    Paused = 3,
+    Resumed = 4,
+  }
+  export interface TimedEvent {
+    timestamp: bigint;
+    kind: EventKind;
+  }
+  export interface Metrics {
+    total_events: bigint;
+    settlement_success: bigint;
+    settlement_failed: bigint;
+    error_events: bigint;
+    paused_events: bigint;
+  }
+  export interface HealthSnapshot {
+    paused: boolean;
+    high_error_rate: boolean;
+    failed_settlement_alert: boolean;
+  }
+  export interface AlertThresholds {
+    failed_settlement_count: bigint;
+    error_rate_percent: bigint;
+    error_rate_min_sample: bigint;
+  }
+  export interface MonitoringSnapshot {
+    initialized: boolean;
+    thresholds: AlertThresholds;
+    metrics: Metrics;
+    health: HealthSnapshot;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    DuplicateEvent = 4,
+    InvalidWindowSize = 5,
+    InvalidThreshold = 6,
+  }
+  export interface EventIngestedEvent {
+    topics: readonly ["event_ingested", bigint];
+    data: { kind: EventKind };
+  }
+  export interface AlertRaisedEvent {
+    topics: readonly ["alert_raised", number];
+    data: Record<string, never>;
+  }
+  export type ContractEvent = EventIngestedEvent | AlertRaisedEvent;
+  export interface Methods {
+    init: { args: { admin: string }; returns: void };
+    ingest_event: { args: { admin: string; event_id: bigint; kind: EventKind }; returns: Metrics };
+    set_paused: { args: { admin: string; paused: boolean }; returns: void };
+    set_alert_thresholds: { args: { admin: string; thresholds: AlertThresholds }; returns: void };
+    get_alert_thresholds: { args: Record<string, never>; returns: AlertThresholds };
+    get_metrics: { args: Record<string, never>; returns: Metrics };
+    get_health: { args: Record<string, never>; returns: HealthSnapshot };
+    get_snapshot: { args: Record<string, never>; returns: MonitoringSnapshot };
+    get_sliding_window_metrics: { args: { window_seconds: bigint }; returns: Metrics };
+  }
+}
+
+export declare namespace ContractRoleRegistry {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Role"; values: readonly [string, string] };
+  export interface RoleAssignedEvent {
+    topics: readonly ["role_assigned", string, string];
+    data: Record<string, never>;
+  }
+  export interface RoleRevokedEvent {
+    topics: readonly ["role_revoked", string, string];
+    data: Record<string, never>;
+  }
+  export type ContractEvent = RoleAssignedEvent | RoleRevokedEvent;
+  export interface Methods {
+    /** Initializes the contract with an admin. */
+    init: { args: { admin: string }; returns: void };
+    /** Assigns a role to a given address. Requires admin authorization. */
+    assign_role: { args: { target: string; role: string }; returns: void };
+    /** Revokes a role. Requires admin authorization. */
+    revoke_role: { args: { target: string; role: string }; returns: void };
+    /** Public query method verifying if the target has the specific role. */
+    has_role: { args: { target: string; role: string }; returns: boolean };
+    /** Retrieves the current admin address. */
+    get_admin: { args: Record<string, never>; returns: string };
+    /** Assigns multiple roles in bulk. Requires admin authorization. */
+    bulk_assign_role: { args: { assignments: Array<readonly [string, string]> }; returns: void };
+    /** Revokes multiple roles in bulk. Requires admin authorization. */
+    bulk_revoke_role: { args: { revocations: Array<readonly [string, string]> }; returns: void };
+  }
+}
+
+export declare namespace ContractUpgradeTimelock {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "MinDelay"; values: void }
+    | { tag: "Upgrade"; values: readonly [bigint] }
+    | { tag: "NextUpgradeId"; values: void };
+  export type UpgradeStatus =
+    | { tag: "Queued"; values: void }
+    | { tag: "Executed"; values: void }
+    | { tag: "Cancelled"; values: void };
+  /**
+   * Snapshot returned by `get_queued_upgrade`.
+   * `None` when no upgrade is queued (never queued or already cleared).
+   * When `Some`, `is_ready` is `true` iff the current ledger timestamp >= `eta`.
+   */
+  export interface QueuedUpgradeView {
+    upgrade_id: bigint;
+    target_contract: string;
+    queued_at_ledger: number;
+    eta: bigint;
+    is_ready: boolean;
+  }
+  export interface UpgradeRecord {
+    upgrade_id: bigint;
+    target_contract: string;
+    payload_hash: string;
+    /** Ledger sequence number at which the upgrade was queued. */
+    queued_at_ledger: number;
+    /** Earliest timestamp (in seconds) at which execute_upgrade may be called. */
+    eta: bigint;
+    status: UpgradeStatus;
+  }
+  export interface UpgradeQueuedEvent {
+    topics: readonly ["upgrade_queued"];
+    data: { upgrade_id: bigint; target_contract: string; eta: bigint };
+  }
+  export interface UpgradeCancelledEvent {
+    topics: readonly ["upgrade_cancelled"];
+    data: { upgrade_id: bigint };
+  }
+  export interface UpgradeExecutedEvent {
+    topics: readonly ["upgrade_executed"];
+    data: { upgrade_id: bigint; target_contract: string };
+  }
+  export type ContractEvent = UpgradeQueuedEvent | UpgradeCancelledEvent | UpgradeExecutedEvent;
+  export interface Methods {
+    /** Initialize with admin and minimum timelock delay (seconds). */
+    init: { args: { admin: string; min_delay: bigint }; returns: void };
+    /**
+     * Queue an upgrade proposal. Admin-only.
+     * `eta` must be at least `now + min_delay`.
+     */
+    queue_upgrade: { args: { target_contract: string; payload_hash: string; eta: bigint }; returns: bigint };
+    /** Cancel a queued upgrade. Admin-only. */
+    cancel_upgrade: { args: { upgrade_id: bigint }; returns: void };
+    /** Execute a queued upgrade after the timelock has elapsed. Admin-only. */
+    execute_upgrade: { args: { upgrade_id: bigint }; returns: void };
+    /** Read the state of an upgrade record. */
+    upgrade_state: { args: { upgrade_id: bigint }; returns: UpgradeRecord };
+    /**
+     * Returns a single-read snapshot of the queued upgrade for `upgrade_id`.
+     *
+     * Returns `None` when no record exists or the upgrade is no longer in
+     * `Queued` status (executed or cancelled).  When `Some`, `is_ready` is
+     * `true` iff the current ledger timestamp has reached or passed `eta`.
+     */
+    get_queued_upgrade: { args: { upgrade_id: bigint }; returns: QueuedUpgradeView | undefined };
+  }
+}
+
+export declare namespace CrossChainBridge {
+  /** Direction of a bridge request from the perspective of this (Stellar) chain. */
+  export const enum BridgeDirection {
+    /** Outbound: assets leave Stellar (lock or burn_wrapped). */
+    Outbound = 0,
+    /** Inbound: assets arrive on Stellar (mint_wrapped or release). */
+    Inbound = 1,
+  }
+  /** Lifecycle status of a bridge request. */
+  export const enum BridgeRequestStatus {
+    /** Request initiated; awaiting validator confirmations on remote chain. */
+    Initiated = 0,
+    /** Request successfully finalized (proof accepted, assets transferred). */
+    Finalized = 1,
+    /** Request marked failed by admin (e.g. stuck or expired). */
+    Failed = 2,
+  }
+  /**
+   * Full observable state of a single bridge request.
+   *
+   * For outbound requests the `proof` field is zeroed (not yet known on-chain).
+   * For inbound requests `request_id` is 0 and direction is `Inbound`.
+   */
+  export interface BridgeRequestSummary {
+    /** Stable identifier assigned at request creation (outbound only). */
+    request_id: bigint;
+    direction: BridgeDirection;
+    asset: string;
+    amount: bigint;
+    /** Destination chain symbol (outbound) or source chain symbol (inbound). */
+    recipient_chain: string;
+    /** Human-readable recipient address on the remote chain. */
+    recipient: string;
+    status: BridgeRequestStatus;
+    /**
+     * For inbound requests: the 32-byte proof that was verified.
+     * For outbound requests: zeroed sentinel (proof not yet known on-chain).
+     */
+    proof: Buffer;
+    /** Ledger sequence number at which this record was written. */
+    ledger: number;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Validators"; values: void }
+    | { tag: "Quorum"; values: void }
+    | { tag: "TokenMapping"; values: readonly [string] }
+    | { tag: "WrappedTokenMapping"; values: readonly [string] }
+    | { tag: "ProcessedProofs"; values: readonly [Buffer] }
+    | { tag: "Paused"; values: void }
+    | { tag: "RequestNonce"; values: void }
+    | { tag: "BridgeRequest"; values: readonly [bigint] }
+    | { tag: "InboundByProof"; values: readonly [Buffer] };
+  export const enum ErrorCode {
+    NotAuthorized = 1,
+    AlreadyInitialized = 2,
+    InvalidAmount = 3,
+    Overflow = 4,
+    InsufficientBalance = 5,
+    InvalidProof = 6,
+    ProofAlreadyProcessed = 7,
+    TokenNotMapped = 8,
+    ContractPaused = 9,
+    InvalidQuorum = 10,
+    InvalidSignature = 11,
+    RequestNotFound = 12,
+  }
+  export interface BridgeInitializedEvent {
+    topics: readonly ["bridge_initialized"];
+    data: { admin: string; quorum: number };
+  }
+  export interface TokenLockedEvent {
+    topics: readonly ["token_locked", string, string];
+    data: { amount: bigint; recipient_chain: string; recipient: string; request_id: bigint };
+  }
+  export interface WrappedMintedEvent {
+    topics: readonly ["wrapped_minted", string, string];
+    data: { amount: bigint; proof: Buffer };
+  }
+  export interface WrappedBurnedEvent {
+    topics: readonly ["wrapped_burned", string, string];
+    data: { amount: bigint; recipient_chain: string; recipient: string; request_id: bigint };
+  }
+  export interface TokenReleasedEvent {
+    topics: readonly ["token_released", string, string];
+    data: { amount: bigint; proof: Buffer };
+  }
+  export type ContractEvent = BridgeInitializedEvent | TokenLockedEvent | WrappedMintedEvent | WrappedBurnedEvent | TokenReleasedEvent;
+  export interface Methods {
+    init: { args: { admin: string; validators: Array<Buffer>; quorum: number }; returns: void };
+    set_token_mapping: { args: { symbol: string; asset: string }; returns: void };
+    set_paused: { args: { paused: boolean }; returns: void };
+    lock: { args: { from: string; asset: string; amount: bigint; recipient_chain: string; recipient: string }; returns: void };
+    mint_wrapped: { args: { asset_symbol: string; amount: bigint; recipient: string; proof: Buffer; signatures: Map<Buffer, Buffer> }; returns: void };
+    burn_wrapped: { args: { from: string; asset: string; amount: bigint; recipient_chain: string; recipient: string }; returns: void };
+    release: { args: { asset: string; amount: bigint; recipient: string; proof: Buffer; signatures: Map<Buffer, Buffer> }; returns: void };
+    /**
+     * Return the full status summary for an outbound request by its sequential ID.
+     *
+     * Returns `None` when the ID is unknown; callers should treat absence as
+     * "not found" rather than an error.  The summary never exposes validator
+     * keys or signature material.
+     */
+    get_request: { args: { request_id: bigint }; returns: BridgeRequestSummary | undefined };
+    /**
+     * Return the finalization summary for an inbound operation identified by
+     * its 32-byte proof hash.
+     *
+     * Returns `None` when the proof has not been processed yet.
+     */
+    get_inbound_finalization: { args: { proof: Buffer }; returns: BridgeRequestSummary | undefined };
+    /**
+     * Admin-only: mark an outbound request as `Failed`.
+     *
+     * Intended for stuck or expired requests that will never be finalized.
+     * Returns `RequestNotFound` if `request_id` does not exist.
+     */
+    mark_request_failed: { args: { request_id: bigint }; returns: void };
+  }
+}
+
+export declare namespace CrossContractCallGuard {
+  export interface PolicyKey {
+    source: string;
+    target: string;
+    selector: string;
+  }
+  export interface DeniedCallAudit {
+    source: string;
+    target: string;
+    selector: string;
+    denied_at: bigint;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Policy"; values: readonly [PolicyKey] }
+    | { tag: "DeniedAudit"; values: readonly [PolicyKey] }
+    | { tag: "RuleCount"; values: readonly [string] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    CallDenied = 4,
+  }
+  export interface ContractInitializedEvent {
+    topics: readonly ["contract_initialized"];
+    data: { admin: string };
+  }
+  export interface CallAllowedEvent {
+    topics: readonly ["call_allowed"];
+    data: { source: string; target: string; selector: string };
+  }
+  export interface CallDeniedEvent {
+    topics: readonly ["call_denied"];
+    data: { source: string; target: string; selector: string };
+  }
+  export interface DeniedCallAuditEvent {
+    topics: readonly ["denied_call_audit_event"];
+    data: { source: string; target: string; selector: string; denied_at: bigint };
+  }
+  export type ContractEvent = ContractInitializedEvent | CallAllowedEvent | CallDeniedEvent | DeniedCallAuditEvent;
+  export interface Methods {
+    /** Initialize the guard with an admin address. */
+    init: { args: { admin: string }; returns: void };
+    /** Allow a specific cross-contract call. Admin only. */
+    allow_call: { args: { source: string; target: string; selector: string }; returns: void };
+    /** Deny (remove permission for) a specific cross-contract call. Admin only. */
+    deny_call: { args: { source: string; target: string; selector: string }; returns: void };
+    /** Assert that a call is allowed. Traps/Errs if not found or explicitly denied. */
+    assert_allowed: { args: { source: string; target: string; selector: string }; returns: void };
+    /** Check the state of a specific policy. */
+    policy_state: { args: { source: string; target: string; selector: string }; returns: boolean };
+    /** Read-only accessor: returns the audit snapshot for a denied call, or None. */
+    denied_audit: { args: { source: string; target: string; selector: string }; returns: DeniedCallAudit | undefined };
+    /** Read-only accessor: returns the count of active (allowed) rules for a source. */
+    rule_summary: { args: { source: string }; returns: number };
+  }
+}
+
+/**
+ * Stellarcade Cross-Contract Communication Handler
+ *
+ * Platform-core contract that routes and tracks cross-contract requests.
+ * Admin registers routes (source → target + selector); authorized callers
+ * dispatch requests and targets (or admin) acknowledge with results.
+ */
+export declare namespace CrossContractHandler {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "RegistryContract"; values: void }
+    | { tag: "NextRouteId"; values: void }
+    | { tag: "Route"; values: readonly [number] }
+    | { tag: "Request"; values: readonly [string] };
+  export interface Route {
+    source_contract: string;
+    target_contract: string;
+    selector: string;
+  }
+  export type RequestStatus =
+    | { tag: "Pending"; values: readonly [number, Buffer] }
+    | { tag: "Acknowledged"; values: readonly [number, Buffer] }
+    | { tag: "Failed"; values: readonly [number, Buffer] };
+  export interface CallSnapshot {
+    request_id: string;
+    route_id: number;
+    status: RequestStatus;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    RouteNotFound = 4,
+    RequestNotFound = 5,
+    DuplicateRequestId = 6,
+    RequestAlreadyAcknowledged = 7,
+    InvalidRoute = 8,
+    RequestAlreadyCompleted = 9,
+  }
+  export interface InitializedEvent {
+    topics: readonly ["initialized"];
+    data: { admin: string; registry_contract: string };
+  }
+  export interface RouteRegisteredEvent {
+    topics: readonly ["route_registered"];
+    data: { route_id: number; source_contract: string; target_contract: string; selector: string };
+  }
+  export interface DispatchedEvent {
+    topics: readonly ["dispatched", string];
+    data: { route_id: number; payload: Buffer };
+  }
+  export interface AcknowledgedEvent {
+    topics: readonly ["acknowledged", string];
+    data: { result: Buffer };
+  }
+  export type ContractEvent = InitializedEvent | RouteRegisteredEvent | DispatchedEvent | AcknowledgedEvent;
+  export interface Methods {
+    /** Initialize with admin and optional registry contract. Call once. */
+    init: { args: { admin: string; registry_contract: string }; returns: void };
+    /** Register a route: source_contract may dispatch to target_contract via selector. Admin only. */
+    register_route: { args: { admin: string; source_contract: string; target_contract: string; selector: string }; returns: number };
+    /** Dispatch a request along a registered route. Caller must be admin or source_contract for that route. */
+    dispatch: { args: { caller: string; request_id: string; route_id: number; payload: Buffer }; returns: void };
+    /** Acknowledge a pending request with a result. Caller must be admin or target_contract for that request's route. */
+    acknowledge: { args: { caller: string; request_id: string; result: Buffer }; returns: void };
+    /** Return the route for a given route_id, or None if not found. */
+    get_route: { args: { route_id: number }; returns: Route };
+    /**
+     * Get the status and metadata for a specific call by request_id.
+     * Returns a CallSnapshot with request_id, route_id, and current status.
+     * This accessor is read-only and does not mutate storage.
+     * Returns RequestNotFound error if the call ID does not exist.
+     */
+    get_call_status: { args: { request_id: string }; returns: CallSnapshot };
+    /** Mark a pending request as failed. Caller must be admin or target_contract for that request's route. */
+    mark_failed: { args: { caller: string; request_id: string; error_info: Buffer }; returns: void };
+  }
+}
+
+export declare namespace DailyRewardEmission {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "RewardPool"; values: void }
+    | { tag: "Schedule"; values: readonly [string] }
+    | { tag: "EpochState"; values: readonly [string] }
+    | { tag: "Claimed"; values: readonly [string, bigint, string] };
+  export interface EmissionConfig {
+    schedule_id: string;
+    /** Reward per epoch in stroops. */
+    rewards_per_epoch: bigint;
+    /** Epoch duration in ledger seconds. */
+    epoch_duration: bigint;
+    /** Token address used for rewards. */
+    token: string;
+    active: boolean;
+  }
+  export interface EmissionEpochState {
+    current_epoch: bigint;
+    epoch_start_time: bigint;
+    total_emitted: bigint;
+  }
+  export interface EmissionConfiguredEvent {
+    topics: readonly ["emission_configured", string];
+    data: { rewards_per_epoch: bigint };
+  }
+  export interface EpochEmittedEvent {
+    topics: readonly ["epoch_emitted", string];
+    data: { epoch_id: bigint; amount: bigint };
+  }
+  export interface RewardClaimedEvent {
+    topics: readonly ["reward_claimed", string];
+    data: { epoch_id: bigint; user: string; amount: bigint };
+  }
+  export type ContractEvent = EmissionConfiguredEvent | EpochEmittedEvent | RewardClaimedEvent;
+  export interface Methods {
+    /** Initialize with admin and reward pool address. */
+    init: { args: { admin: string; reward_pool_contract: string }; returns: void };
+    /** Configure or update an emission schedule. Admin-only. */
+    configure_emission: { args: { schedule_id: string; config: EmissionConfig }; returns: void };
+    /**
+     * Finalize the current epoch and advance to the next. Admin-only.
+     * Emits rewards from the reward pool into the contract for distribution.
+     */
+    emit_for_epoch: { args: { schedule_id: string }; returns: bigint };
+    /** Claim a daily reward for a specific epoch. User must not have claimed before. */
+    claim_daily_reward: { args: { user: string; schedule_id: string; epoch_id: bigint; reward_amount: bigint }; returns: void };
+    /** Read the current emission state for a schedule. */
+    emission_state: { args: { epoch_id: string }; returns: EmissionEpochState };
+  }
+}
+
+/**
+ * Stellarcade Daily Trivia Contract
+ *
+ * Players can submit one answer per round/day. Correct answers share a
+ * fixed reward amount reserved for that round.
+ *
+ * Answers use commit-reveal: until the round's `deadline` players commit
+ * `sha256(answer || salt || player)` (see [`answer_commitment`]), then reveal
+ * the answer and salt until `reveal_deadline`.
+ *
+ * Reveals can happen in two ways:
+ * - on-chain, with `reveal_answer`, which evaluates correctness immediately;
+ * - in bulk, off-chain to the operator. When closing the round the admin
+ *   publishes the plaintext answer and a Merkle root of `(player, correct)`
+ *   results (see [`result_leaf`]), and those players claim with a proof.
+ *   Closing stays O(1) however many players revealed this way.
+ *
+ * Commitments revealed neither way are forfeited.
+ */
+export declare namespace DailyTrivia {
+  export const enum RoundStatus {
+    Open = 0,
+    Closed = 1,
+  }
+  export interface RoundData {
+    answer_commitment: Buffer;
+    reward_amount: bigint;
+    payout_per_winner: bigint;
+    /** Correct reveals, on-chain and in bulk */
+    winner_count: number;
+    /** Winners in `results_root`, published at close */
+    bulk_winner_count: number;
+    /**
+     * Rewards claimed with a proof against `results_root`; never exceeds
+     * `bulk_winner_count`
+     */
+    bulk_claimed_count: number;
+    /** Commitments */
+    participant_count: number;
+    revealed_count: number;
+    status: RoundStatus;
+    opened_at: bigint;
+    /** Last timestamp at which answers can be committed */
+    deadline: bigint;
+    /** Last timestamp at which commitments can be revealed */
+    reveal_deadline: bigint;
+    closed_at: bigint;
+    /** Plaintext answer, published at close */
+    answer: Buffer;
+    /**
+     * Merkle root of the bulk-revealed results, published at close; all
+     * zeroes before
+     */
+    results_root: Buffer;
+  }
+  export interface Submission {
+    /** `sha256(answer || salt || player)`, see [`answer_commitment`] */
+    commitment: Buffer;
+    revealed: boolean;
+    correct: boolean;
+    claimed: boolean;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "PrizePoolContract"; values: void }
+    | { tag: "BalanceContract"; values: void }
+    | { tag: "LatestRoundId"; values: void }
+    | { tag: "Round"; values: readonly [bigint] }
+    | { tag: "Submission"; values: readonly [bigint, string] };
+  export const enum RoundSnapshotStatus {
+    Uninitialized = 0,
+    Active = 1,
+    Resolved = 2,
+  }
+  export interface RoundSnapshot {
+    status: RoundSnapshotStatus;
+    round_id: bigint;
+    is_open: boolean;
+    participant_count: number;
+    winner_count: number;
+    reward_amount: bigint;
+    payout_per_winner: bigint;
+    opened_at: bigint;
+    deadline: bigint;
+    reveal_deadline: bigint;
+    closed_at: bigint;
+  }
+  export interface ParticipantAnswerSummary {
+    status: RoundSnapshotStatus;
+    round_id: bigint;
+    is_open: boolean;
+    participant_count: number;
+    correct_count: number;
+    incorrect_count: number;
+    /** Commitments not revealed yet; forfeited once the reveal window closes */
+    unrevealed_count: number;
+  }
+  export interface RewardPoolSnapshot {
+    status: RoundSnapshotStatus;
+    round_id: bigint;
+    reward_amount: bigint;
+    payout_per_winner: bigint;
+    winner_count: number;
+    total_claimable: bigint;
+    total_distributed: bigint;
+    total_unclaimed: bigint;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    RoundAlreadyExists = 4,
+    RoundNotFound = 5,
+    RoundNotOpen = 6,
+    RoundClosed = 7,
+    AlreadySubmitted = 8,
+    AlreadyClaimed = 9,
+    NoRewardAvailable = 10,
+    InvalidAmount = 11,
+    Overflow = 12,
+    InvalidDeadline = 13,
+    PastDeadline = 14,
+    RevealNotOpen = 15,
+    RevealClosed = 16,
+    CommitmentNotFound = 17,
+    AlreadyRevealed = 18,
+    CommitmentMismatch = 19,
+    RevealInProgress = 20,
+    AnswerMismatch = 21,
+    InvalidProof = 22,
+    BulkClaimsExhausted = 23,
+  }
+  export interface RoundOpenedEvent {
+    topics: readonly ["round_opened", bigint];
+    data: { reward_amount: bigint };
+  }
+  export interface AnswerCommittedEvent {
+    topics: readonly ["answer_committed", bigint];
+    data: { player: string };
+  }
+  export interface AnswerRevealedEvent {
+    topics: readonly ["answer_revealed", bigint];
+    data: { player: string; correct: boolean };
+  }
+  export interface RoundClosedEvent {
+    topics: readonly ["round_closed", bigint];
+    data: { winners: number; payout_per_winner: bigint; answer: Buffer; results_root: Buffer };
+  }
+  export interface RewardClaimedEvent {
+    topics: readonly ["reward_claimed", bigint];
+    data: { player: string; amount: bigint };
+  }
+  export type ContractEvent = RoundOpenedEvent | AnswerCommittedEvent | AnswerRevealedEvent | RoundClosedEvent | RewardClaimedEvent;
+  export interface Methods {
+    init: { args: { admin: string; prize_pool_contract: string; balance_contract: string }; returns: void };
+    /** Answers are committed until `deadline` and revealed until `reveal_deadline`. */
+    open_round: { args: { round_id: bigint; answer_commitment: Buffer; deadline: bigint; reveal_deadline: bigint; reward_amount: bigint }; returns: void };
+    /** Commit to an answer while the answer window is open. */
+    commit_answer: { args: { player: string; round_id: bigint; commitment: Buffer }; returns: void };
+    /**
+     * Reveal a committed answer between `deadline` and `reveal_deadline`.
+     * Returns whether `sha256(answer_payload)` matches the round's commitment.
+     */
+    reveal_answer: { args: { player: string; round_id: bigint; answer_payload: Buffer; salt: Buffer }; returns: boolean };
+    /**
+     * Close the round after the reveal window, publishing the plaintext
+     * `answer` and the bulk reveal results.
+     *
+     * `results_root` is the Merkle root of [`result_leaf`]s for players who
+     * revealed off-chain, `bulk_winner_count` the number of those marked
+     * correct. Players who revealed on-chain must be left out of the tree;
+     * pass an all-zero root and 0 when nobody revealed in bulk. At most
+     * `bulk_winner_count` proofs are paid, so an understated count cannot
+     * push payouts past the reserved reward.
+     */
+    close_round: { args: { round_id: bigint; answer: Buffer; results_root: Buffer; bulk_winner_count: number }; returns: void };
+    /**
+     * Claim a winner's share of a closed round. Players who revealed
+     * on-chain pass an empty `proof`; players revealed in bulk pass the
+     * Merkle proof of their `(player, true)` result, until
+     * `bulk_winner_count` of them have claimed.
+     */
+    claim_reward: { args: { player: string; round_id: bigint; proof: Array<Buffer> }; returns: bigint };
+    get_round: { args: { round_id: bigint }; returns: RoundData | undefined };
+    /** Returns a single snapshot for the latest known round. */
+    get_round_snapshot: { args: Record<string, never>; returns: RoundSnapshot };
+    /**
+     * Returns participation/correctness counters for the latest round.
+     * `participant_count` counts commitments. `correct_count` includes bulk
+     * winners once the round is closed; `incorrect_count` and
+     * `unrevealed_count` only reflect on-chain reveals.
+     *
+     * If no round exists yet, returns an `Uninitialized` summary.
+     * If the latest round is closed, status is `Resolved` with final counters.
+     */
+    get_participant_answer_summary: { args: Record<string, never>; returns: ParticipantAnswerSummary };
+    /**
+     * Returns reward-pool relevant values for the latest round.
+     *
+     * If no round exists yet, returns an `Uninitialized` snapshot.
+     * During active rounds, distribution values remain zero until close.
+     */
+    get_reward_pool_snapshot: { args: Record<string, never>; returns: RewardPoolSnapshot };
+  }
+}
+
+/**
+ * Stellarcade Dice Roll Contract
+ *
+ * A dice betting game integrated with the Random Generator contract.
+ * Players predict a face (1–6), place a wager, and an oracle resolves
+ * the outcome via the RNG contract's request/fulfill model.
+ *
+ * ## Game Flow
+ * 1. Player calls `roll` → tokens transfer in, RNG requested, game stored.
+ * 2. Oracle fulfills randomness on the RNG contract (off-chain step).
+ * 3. Anyone calls `resolve_roll` → reads RNG result, settles payout.
+ *
+ * If the RNG request expires before the oracle fulfills it, anyone can call
+ * `refund_expired` to return the wager to the player instead.
+ *
+ * ## Payout
+ * A correct prediction pays `6 * wager - fee`, where the fee is
+ * `wager * house_edge_bps / 10000` applied to the winnings portion
+ * (5 * wager). For example, at 250 bps (2.5%) and a 100-token wager,
+ * winnings = 500, fee = 500 * 250 / 10000 = 12, payout = 600 - 12 = 588.
+ *
+ * ## House Edge
+ * Configured at init via `house_edge_bps` (basis points). Applied only
+ * to the profit portion of a winning bet (5 * wager).
+ */
+export declare namespace DiceRoll {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Token"; values: void }
+    | { tag: "RngContract"; values: void }
+    | { tag: "MinWager"; values: void }
+    | { tag: "MaxWager"; values: void }
+    | { tag: "HouseEdgeBps"; values: void }
+    | { tag: "Game"; values: readonly [bigint] };
+  export interface Roll {
+    player: string;
+    prediction: number;
+    wager: bigint;
+    resolved: boolean;
+    won: boolean;
+    result: number;
+    payout: bigint;
+    refunded: boolean;
+  }
+  export interface WagerLimits {
+    min_wager: bigint;
+    max_wager: bigint;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    InvalidPrediction = 5,
+    GameAlreadyExists = 6,
+    GameNotFound = 7,
+    GameAlreadyResolved = 8,
+    RngNotFulfilled = 9,
+    WagerTooLow = 10,
+    WagerTooHigh = 11,
+    Overflow = 12,
+    InvalidWagerRange = 13,
+    RngNotExpired = 14,
+  }
+  export interface RollPlacedEvent {
+    topics: readonly ["roll_placed", bigint, string];
+    data: { prediction: number; wager: bigint };
+  }
+  export interface RollResolvedEvent {
+    topics: readonly ["roll_resolved", bigint, string];
+    data: { result: number; won: boolean; payout: bigint };
+  }
+  export interface RollRefundedEvent {
+    topics: readonly ["roll_refunded", bigint, string];
+    data: { amount: bigint };
+  }
+  export type ContractEvent = RollPlacedEvent | RollResolvedEvent | RollRefundedEvent;
+  export interface Methods {
+    /**
+     * Initialize the dice roll game.
+     *
+     * `house_edge_bps`: house edge in basis points (e.g., 250 = 2.5%).
+     */
+    init: { args: { admin: string; rng_contract: string; token: string; min_wager: bigint; max_wager: bigint; house_edge_bps: bigint }; returns: void };
+    /**
+     * Player places a dice roll bet. Tokens are transferred into the contract.
+     * A randomness request is submitted to the RNG contract.
+     *
+     * `prediction`: the die face the player predicts (1–6).
+     * `client_seed`: optional player entropy mixed into the RNG result.
+     */
+    roll: { args: { player: string; prediction: number; wager: bigint; game_id: bigint; client_seed: Buffer | undefined }; returns: void };
+    /**
+     * Resolve a game after the oracle has fulfilled the RNG request.
+     * Anyone can call this — no auth needed since the outcome is deterministic.
+     */
+    resolve_roll: { args: { game_id: bigint }; returns: void };
+    /**
+     * Return the wager of a roll whose RNG request expired unfulfilled.
+     * Anyone can call this; the wager only ever goes back to the player.
+     */
+    refund_expired: { args: { game_id: bigint }; returns: void };
+    /** View a roll's state. */
+    get_roll: { args: { game_id: bigint }; returns: Roll };
+    /** Admin-only update for the on-chain min and max wager settings. */
+    set_wager_limits: { args: { admin: string; min_wager: bigint; max_wager: bigint }; returns: void };
+    /** Read the current wager limits used during bet placement. */
+    get_wager_limits: { args: Record<string, never>; returns: WagerLimits };
+  }
+}
+
+export declare namespace DynamicFeePolicy {
+  export interface FeeTier {
+    threshold: bigint;
+    fee_bps: number;
+  }
+  export interface FeeRuleConfig {
+    base_fee_bps: number;
+    tiers: Array<FeeTier> | undefined;
+    enabled: boolean;
+  }
+  export interface FeeContext {
+    multiplier_bps: number;
+    additional_data: Map<string, bigint>;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "FeeRule"; values: readonly [string] };
+  /** Read-only summary returned by `preview_fee`.  No event is emitted. */
+  export interface FeePreview {
+    /** The computed fee amount for the given inputs. */
+    fee_amount: bigint;
+    /** The effective basis-points rate that was applied (after multiplier). */
+    applied_bps: number;
+    /** The base fee rate from the rule config (before tier / multiplier). */
+    base_fee_bps: number;
+    /** `true` if a tiered rate was selected instead of the base rate. */
+    tier_applied: boolean;
+    /** Whether the fee rule is currently enabled. */
+    rule_enabled: boolean;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    RuleNotFound = 4,
+    RuleDisabled = 5,
+    Overflow = 6,
+    InvalidFeeConfig = 7,
+  }
+  export interface ContractInitializedEvent {
+    topics: readonly ["contract_initialized", string];
+    data: Record<string, never>;
+  }
+  export interface FeeRuleSetEvent {
+    topics: readonly ["fee_rule_set", string];
+    data: { base_fee_bps: number; has_tiers: boolean };
+  }
+  export interface FeeRuleStatusChangedEvent {
+    topics: readonly ["fee_rule_status_changed", string];
+    data: { enabled: boolean };
+  }
+  export interface FeeComputedEvent {
+    topics: readonly ["fee_computed", string];
+    data: { original_amount: bigint; fee_amount: bigint; applied_bps: number };
+  }
+  export type ContractEvent = ContractInitializedEvent | FeeRuleSetEvent | FeeRuleStatusChangedEvent | FeeComputedEvent;
+  export interface Methods {
+    /** Initialise the contract. */
+    init: { args: { admin: string }; returns: void };
+    /** Set a fee rule for a game. */
+    set_fee_rule: { args: { game_id: string; rule_config: FeeRuleConfig }; returns: void };
+    /** Compute the fee for a given amount and context. */
+    compute_fee: { args: { game_id: string; amount: bigint; context: FeeContext }; returns: bigint };
+    /** Enable a fee rule. */
+    enable_rule: { args: { game_id: string }; returns: void };
+    /** Disable a fee rule. */
+    disable_rule: { args: { game_id: string }; returns: void };
+    /** Query the state of a fee rule. */
+    fee_rule_state: { args: { game_id: string }; returns: FeeRuleConfig | undefined };
+    /**
+     * Preview the fee that would be charged for `amount` under `game_id`'s rule.
+     *
+     * Unlike `compute_fee`, this is a pure read — it emits no events and does
+     * not require the rule to be enabled.  Pass `None` for `context` to use a
+     * 1× multiplier (10 000 bps).
+     *
+     * Returns `Error::RuleNotFound` when no rule has been configured for
+     * `game_id`.
+     */
+    preview_fee: { args: { game_id: string; amount: bigint; context: FeeContext | undefined }; returns: FeePreview };
+  }
+}
+
+/**
+ * Stellarcade Emergency Pause Contract
+ *
+ * A reusable pause mechanism for halting critical operations during incidents.
+ * Can be deployed standalone or used as a library by other contracts.
+ *
+ * Game and admin contracts should call `require_not_paused` at the top of any
+ * sensitive function to fail fast when the platform is paused.
+ */
+export declare namespace EmergencyPause {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Paused"; values: void }
+    | { tag: "PauseMetadata"; values: void };
+  export interface PauseMetadata {
+    reason_code: number;
+    timestamp: bigint;
+    admin: string;
+  }
+  export interface PausedTargetSummary {
+    target: string;
+    reason_code: number;
+    paused_at: bigint;
+    admin: string;
+  }
+  export interface PauseWindowSnapshot {
+    is_paused: boolean;
+    active_target_count: number;
+    paused_at: bigint | undefined;
+    reason_code: number | undefined;
+    admin: string | undefined;
+    window_seconds: bigint;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    AlreadyPaused = 4,
+    NotPaused = 5,
+  }
+  export interface PausedEvent {
+    topics: readonly ["paused"];
+    data: { admin: string; reason_code: number };
+  }
+  export interface UnpausedEvent {
+    topics: readonly ["unpaused"];
+    data: { admin: string };
+  }
+  export type ContractEvent = PausedEvent | UnpausedEvent;
+  export interface Methods {
+    /** Initialize with an admin who can pause/unpause. Can only be called once. */
+    init: { args: { admin: string }; returns: void };
+    /** Pause the contract with a reason code. Only callable by admin. Errors if already paused. */
+    pause: { args: { admin: string; reason_code: number }; returns: void };
+    /** Unpause the contract. Only callable by admin. Errors if not paused. */
+    unpause: { args: { admin: string }; returns: void };
+    /** Check if the contract is currently paused. */
+    is_paused: { args: Record<string, never>; returns: boolean };
+    /** Get the current or latest pause metadata. */
+    get_pause_metadata: { args: Record<string, never>; returns: PauseMetadata | undefined };
+    /**
+     * Returns a deterministic list of paused targets.
+     * The current contract only supports a single global pause target.
+     */
+    paused_target_summary: { args: Record<string, never>; returns: Array<PausedTargetSummary> };
+    /** Returns a side-effect free snapshot of the active pause window. */
+    pause_window_snapshot: { args: Record<string, never>; returns: PauseWindowSnapshot };
+  }
+}
+
+export declare namespace EpochScheduler {
+  export interface TaskData {
+    epoch: bigint;
+    payload_hash: Buffer;
+    executed: boolean;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "EpochDuration"; values: void }
+    | { tag: "Task"; values: readonly [string] };
+  /**
+   * Read-only timing snapshot returned by `epoch_snapshot`.
+   *
+   * All ledger values are expressed in ledger-sequence units.
+   * Returns `None` from `epoch_snapshot` when the contract is not yet
+   * initialised.
+   */
+  export interface EpochSnapshot {
+    /** The epoch that is active at the current ledger sequence. */
+    current_epoch: bigint;
+    /** Number of ledgers per epoch as configured at init time. */
+    epoch_duration: number;
+    /** The ledger sequence at which the current epoch started (last rollover). */
+    current_epoch_start_ledger: bigint;
+    /** The ledger sequence at which the next epoch will begin. */
+    next_epoch_start_ledger: bigint;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidEpochDuration = 4,
+    InvalidScheduleEpoch = 5,
+    TaskAlreadyExecuted = 6,
+    TaskNotFound = 7,
+    EpochNotReached = 8,
+  }
+  export interface ContractInitializedEvent {
+    topics: readonly ["contract_initialized"];
+    data: { admin: string; epoch_duration: number };
+  }
+  export interface TaskScheduledEvent {
+    topics: readonly ["task_scheduled"];
+    data: { task_id: string; epoch: bigint };
+  }
+  export interface TaskExecutedEvent {
+    topics: readonly ["task_executed"];
+    data: { task_id: string };
+  }
+  export type ContractEvent = ContractInitializedEvent | TaskScheduledEvent | TaskExecutedEvent;
+  export interface Methods {
+    /** Initialise the epoch scheduler contract. */
+    init: { args: { admin: string; epoch_duration: number }; returns: void };
+    /** View the current epoch based on ledger sequence. */
+    current_epoch: { args: Record<string, never>; returns: bigint };
+    /** Schedule a task for a future or current epoch. */
+    schedule_task: { args: { task_id: string; epoch: bigint; payload_hash: Buffer }; returns: void };
+    /** Mark a task as executed. Restricted to Admin. */
+    mark_executed: { args: { task_id: string }; returns: void };
+    /** Query the state of a task. */
+    task_state: { args: { task_id: string }; returns: TaskData | undefined };
+    /**
+     * Returns a timing snapshot combining the current epoch, the next epoch
+     * boundary, and the ledger at which the current epoch began (last
+     * rollover).
+     *
+     * Returns `None` when the contract has not yet been initialised or the
+     * epoch duration is zero, making the uninitialized state explicit.
+     */
+    epoch_snapshot: { args: Record<string, never>; returns: EpochSnapshot | undefined };
+  }
+}
+
+export declare namespace EscrowVault {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Token"; values: void }
+    | { tag: "Escrow"; values: readonly [bigint] }
+    | { tag: "NextId"; values: void };
+  export type EscrowStatus =
+    | { tag: "Active"; values: void }
+    | { tag: "Released"; values: void }
+    | { tag: "Cancelled"; values: void }
+    | { tag: "Recovered"; values: void };
+  export interface EscrowState {
+    escrow_id: bigint;
+    payer: string;
+    payee: string;
+    amount: bigint;
+    terms_hash: string;
+    expiry: bigint;
+    status: EscrowStatus;
+  }
+  export interface EscrowCreatedEvent {
+    topics: readonly ["escrow_created", bigint];
+    data: { payer: string; payee: string; amount: bigint; terms_hash: string; expiry: bigint };
+  }
+  export interface EscrowRecoveredEvent {
+    topics: readonly ["escrow_recovered", bigint];
+    data: { admin: string; amount: bigint };
+  }
+  export interface EscrowReleasedEvent {
+    topics: readonly ["escrow_released", bigint];
+    data: { payee: string; amount: bigint };
+  }
+  export interface EscrowCancelledEvent {
+    topics: readonly ["escrow_cancelled", bigint];
+    data: { payer: string; amount: bigint };
+  }
+  export type ContractEvent = EscrowCreatedEvent | EscrowRecoveredEvent | EscrowReleasedEvent | EscrowCancelledEvent;
+  export interface Methods {
+    /** Initialize with the admin and the accepted token address. */
+    init: { args: { admin: string; token_address: string }; returns: void };
+    /** Create a new escrow. The payer locks `amount` tokens into the contract. */
+    create_escrow: { args: { payer: string; payee: string; amount: bigint; terms_hash: string; expiry: bigint }; returns: bigint };
+    /** Release escrow funds to the payee. Only the admin or payer may release. */
+    release_escrow: { args: { caller: string; escrow_id: bigint }; returns: void };
+    /** Cancel an active escrow and return funds to the payer. Admin-only. */
+    cancel_escrow: { args: { escrow_id: bigint }; returns: void };
+    /** Recover funds from an expired escrow. Admin-only. */
+    recover_escrow: { args: { escrow_id: bigint }; returns: void };
+    /** Read the state of an escrow. */
+    escrow_state: { args: { escrow_id: bigint }; returns: EscrowState };
+  }
+}
+
+export declare namespace ExploitPrevention {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Oracle"; values: void }
+    | { tag: "Paused"; values: void }
+    | { tag: "BoundConfig"; values: void }
+    | { tag: "Nonce"; values: readonly [bigint] }
+    | { tag: "TxHash"; values: readonly [Buffer] }
+    | { tag: "RateLimit"; values: readonly [string] };
+  export interface BoundConfig {
+    min_amount: bigint;
+    max_amount: bigint;
+    max_timestamp_delta: bigint;
+    rate_limit_window: bigint;
+    rate_limit_max_calls: number;
+  }
+  export interface RateLimitEntry {
+    window_start: bigint;
+    call_count: number;
+  }
+  /**
+   * Read-only snapshot of the current protection posture returned by
+   * `get_risk_summary`.
+   */
+  export interface RiskSummary {
+    /** `true` once the contract has been initialised. */
+    initialized: boolean;
+    /** `true` when the contract is paused (all state-mutating guards reject). */
+    is_paused: boolean;
+    /**
+     * Replay-attack protection (nonce + tx-hash deduplication) is always
+     * active after initialization.
+     */
+    replay_protection_active: boolean;
+    /**
+     * `true` when the configured rate-limit window and call ceiling are
+     * non-zero.
+     */
+    rate_limiting_active: boolean;
+    /** `true` when valid min/max amount bounds are configured. */
+    bounds_enforcement_active: boolean;
+    /** Minimum accepted amount (from `BoundConfig`). */
+    min_amount: bigint;
+    /** Maximum accepted amount (from `BoundConfig`). */
+    max_amount: bigint;
+    /** Maximum age (seconds) of an accepted timestamp (from `BoundConfig`). */
+    max_timestamp_delta: bigint;
+    /** Rate-limit window duration in seconds (from `BoundConfig`). */
+    rate_limit_window: bigint;
+    /** Maximum calls allowed per caller per window (from `BoundConfig`). */
+    rate_limit_max_calls: number;
+  }
+  export const enum ErrorCode {
+    Unauthorized = 1,
+    NotAdmin = 2,
+    NotOracle = 3,
+    AlreadyProcessed = 10,
+    NonceReused = 11,
+    InvalidInput = 20,
+    AmountTooLow = 21,
+    AmountTooHigh = 22,
+    StaleTimestamp = 24,
+    InvalidStateTransition = 30,
+    AlreadyInitialized = 31,
+    NotInitialized = 32,
+    ContractPaused = 33,
+    ArithmeticOverflow = 40,
+    InsufficientBalance = 41,
+    AccountingInvariant = 42,
+    InvalidRngProof = 50,
+    OracleDataTampered = 51,
+    SettlementManipulated = 52,
+    RateLimitExceeded = 60,
+  }
+  export interface ExploitInitializedEvent {
+    topics: readonly ["exploit_initialized"];
+    data: { admin: string };
+  }
+  export interface AdminChangedEvent {
+    topics: readonly ["admin_changed"];
+    data: { old_admin: string; new_admin: string };
+  }
+  export interface BoundsUpdatedEvent {
+    topics: readonly ["bounds_updated"];
+    data: Record<string, never>;
+  }
+  export interface ContractPausedEvent {
+    topics: readonly ["contract_paused"];
+    data: { admin: string };
+  }
+  export interface ContractUnpausedEvent {
+    topics: readonly ["contract_unpaused"];
+    data: { admin: string };
+  }
+  export interface ReplayBlockedEvent {
+    topics: readonly ["replay_blocked"];
+    data: { nonce: bigint };
+  }
+  export interface TxHashBlockedEvent {
+    topics: readonly ["tx_hash_blocked"];
+    data: { hash: Buffer };
+  }
+  export interface BoundsViolatedEvent {
+    topics: readonly ["bounds_violated"];
+    data: { amount: bigint; min_amount: bigint; max_amount: bigint };
+  }
+  export interface RateLimitHitEvent {
+    topics: readonly ["rate_limit_hit"];
+    data: { caller: string };
+  }
+  export interface RngRejectedEvent {
+    topics: readonly ["rng_rejected"];
+    data: { game_id: bigint };
+  }
+  export interface RngAcceptedEvent {
+    topics: readonly ["rng_accepted"];
+    data: { game_id: bigint };
+  }
+  export interface OracleRejectedEvent {
+    topics: readonly ["oracle_rejected"];
+    data: { round_id: bigint };
+  }
+  export interface OracleAcceptedEvent {
+    topics: readonly ["oracle_accepted"];
+    data: { round_id: bigint };
+  }
+  export interface SettlementRejectedEvent {
+    topics: readonly ["settlement_rejected"];
+    data: { total_in: bigint; total_out: bigint };
+  }
+  export interface SettlementAcceptedEvent {
+    topics: readonly ["settlement_accepted"];
+    data: { total_in: bigint; total_out: bigint };
+  }
+  export type ContractEvent = ExploitInitializedEvent | AdminChangedEvent | BoundsUpdatedEvent | ContractPausedEvent | ContractUnpausedEvent | ReplayBlockedEvent | TxHashBlockedEvent | BoundsViolatedEvent | RateLimitHitEvent | RngRejectedEvent | RngAcceptedEvent | OracleRejectedEvent | OracleAcceptedEvent | SettlementRejectedEvent | SettlementAcceptedEvent;
+  export interface Methods {
+    initialize: { args: { admin: string; oracle: string; config: BoundConfig }; returns: void };
+    change_admin: { args: { caller: string; new_admin: string }; returns: void };
+    /** Update the oracle address (admin only). */
+    set_oracle: { args: { caller: string; new_oracle: string }; returns: void };
+    update_bounds: { args: { caller: string; config: BoundConfig }; returns: void };
+    pause: { args: { caller: string }; returns: void };
+    unpause: { args: { caller: string }; returns: void };
+    consume_nonce: { args: { nonce: bigint }; returns: void };
+    consume_tx_hash: { args: { hash: Buffer }; returns: void };
+    check_amount: { args: { amount: bigint }; returns: void };
+    check_timestamp: { args: { timestamp: bigint }; returns: void };
+    check_rate_limit: { args: { caller: string }; returns: void };
+    validate_rng_proof: { args: { caller: string; game_id: bigint; vrf_output: Buffer; vrf_proof: Buffer }; returns: void };
+    validate_oracle_data: { args: { oracle_caller: string; round_id: bigint; data: Buffer; signature: Buffer }; returns: void };
+    validate_settlement: { args: { caller: string; pool_balance: bigint; total_in: bigint; total_out: bigint; payout: bigint }; returns: void };
+    get_admin: { args: Record<string, never>; returns: string };
+    is_paused: { args: Record<string, never>; returns: boolean };
+    get_bound_config: { args: Record<string, never>; returns: BoundConfig };
+    is_nonce_consumed: { args: { nonce: bigint }; returns: boolean };
+    is_tx_hash_consumed: { args: { hash: Buffer }; returns: boolean };
+    /**
+     * Returns a compact snapshot of the active protection posture.
+     *
+     * When the contract has not yet been initialised all flags are `false` /
+     * zero — callers should check `initialized` before interpreting the
+     * threshold fields.
+     */
+    get_risk_summary: { args: Record<string, never>; returns: RiskSummary };
+  }
+}
+
+/**
+ * Stellarcade Fee Management Contract
+ *
+ * Comprehensive fee management system for games and platform operations.
+ * Handles fee configuration, collection, accrual, and withdrawal with
+ * proper authorization and validation.
+ */
+export declare namespace FeeManagement {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "TreasuryContract"; values: void }
+    | { tag: "Paused"; values: void }
+    | { tag: "FeeConfig"; values: readonly [string] }
+    | { tag: "AccruedFees"; values: readonly [string] }
+    | { tag: "ProcessedCharge"; values: readonly [ChargeOp] };
+  export interface FeeConfig {
+    fee_bps: number;
+    recipient: string;
+  }
+  export interface ChargeOp {
+    game_id: string;
+    amount: bigint;
+    timestamp: bigint;
+  }
+  export interface FeeState {
+    total_accrued: bigint;
+    total_withdrawn: bigint;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    InsufficientFees = 5,
+    InvalidFeeConfig = 6,
+    GameNotFound = 7,
+    Overflow = 8,
+    ContractPaused = 9,
+    AlreadyPaused = 10,
+    NotPaused = 11,
+    InvalidRecipient = 12,
+    DuplicateOperation = 13,
+  }
+  export interface FeeConfigSetEvent {
+    topics: readonly ["fee_config_set"];
+    data: { game_id: string; fee_bps: number; recipient: string; admin: string };
+  }
+  export interface FeeChargedEvent {
+    topics: readonly ["fee_charged"];
+    data: { game_id: string; amount: bigint; fee_amount: bigint; net_amount: bigint };
+  }
+  export interface FeesWithdrawnEvent {
+    topics: readonly ["fees_withdrawn"];
+    data: { game_id: string; amount: bigint; recipient: string };
+  }
+  export interface ContractInitializedEvent {
+    topics: readonly ["contract_initialized"];
+    data: { admin: string; treasury_contract: string };
+  }
+  export interface ContractPausedEvent {
+    topics: readonly ["contract_paused"];
+    data: { admin: string };
+  }
+  export interface ContractUnpausedEvent {
+    topics: readonly ["contract_unpaused"];
+    data: { admin: string };
+  }
+  export type ContractEvent = FeeConfigSetEvent | FeeChargedEvent | FeesWithdrawnEvent | ContractInitializedEvent | ContractPausedEvent | ContractUnpausedEvent;
+  export interface Methods {
+    /**
+     * Initialize the fee management contract
+     *
+     * # Arguments
+     * * `env` - The contract environment
+     * * `admin` - The admin address with full control
+     * * `treasury_contract` - The treasury contract address
+     */
+    init: { args: { admin: string; treasury_contract: string }; returns: void };
+    /** Set fee configuration for a game */
+    set_fee_config: { args: { admin: string; game_id: string; bps: number; recipient: string }; returns: void };
+    /** Charge fee for a game transaction */
+    charge_fee: { args: { game_id: string; amount: bigint; token: string | undefined }; returns: bigint };
+    /** Get accrued fees for a game */
+    accrued_fees: { args: { game_id: string }; returns: bigint };
+    /** Withdraw accrued fees for a game */
+    withdraw_fees: { args: { admin: string; game_id: string; recipient: string | undefined; amount: bigint | undefined }; returns: void };
+    /** Pause the contract (admin only) */
+    pause: { args: { admin: string }; returns: void };
+    /** Unpause the contract (admin only) */
+    unpause: { args: { admin: string }; returns: void };
+  }
+}
+
+export declare namespace GasOptimizationAnalysis {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Methods"; values: void }
+    | { tag: "MethodProfile"; values: readonly [string] };
+  export interface MethodProfile {
+    calls: bigint;
+    total_cpu: bigint;
+    total_read_bytes: bigint;
+    total_write_bytes: bigint;
+  }
+  export interface MethodHotspot {
+    method: string;
+    score: bigint;
+    avg_cpu: bigint;
+  }
+  export interface OptimizationRecommendation {
+    method: string;
+    recommendation: string;
+    estimated_savings_bps: number;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidMetric = 4,
+  }
+  export interface Methods {
+    init: { args: { admin: string }; returns: void };
+    record_sample: { args: { admin: string; method: string; cpu: bigint; read_bytes: bigint; write_bytes: bigint }; returns: MethodProfile };
+    get_method_profile: { args: { method: string }; returns: MethodProfile };
+    get_hotspots: { args: { limit: number }; returns: Array<MethodHotspot> };
+    get_recommendations: { args: { limit: number }; returns: Array<OptimizationRecommendation> };
+  }
+}
+
+/**
+ * Stellarcade Governance Contract
+ *
+ * A timelock-based DAO for platform governance. Token holders propose and
+ * vote on proposals. Passed proposals enter a timelock queue before execution.
+ *
+ * ## Governance Flow
+ * 1. Proposer calls `propose` with proposal_id and payload_hash, or
+ *    `propose_actions` with the on-chain actions to run (hash committed automatically)
+ * 2. Token holders call `vote` (for/against) or `cast_vote` (for/against/abstain,
+ *    with an optional reason) weighted by their checkpointed voting power at
+ *    the proposal's `start_ledger`; a relayer may submit ed25519-signed votes
+ *    on a voter's behalf through `vote_by_sig`
+ * 3. After voting period ends, if quorum + threshold met: anyone calls `queue`
+ * 4. After timelock delay: anyone calls `execute` with payload
+ * 5. Contract verifies payload matches hash and invokes every committed action
+ *    via `invoke_contract`; a failing action reverts the whole execution
+ *
+ * Proposing can be gated by `set_proposal_config`: a minimum voting power,
+ * a refundable deposit in the governance token (slashed to the treasury if
+ * the proposal misses quorum, settled via `settle_deposit`) and a cap on
+ * concurrently active proposals per proposer.
+ *
+ * ## Security Model
+ * - Timelock prevents instant execution of malicious proposals
+ * - Quorum ensures minimum participation (measured against the supply at `start_ledger`)
+ * - Snapshot voting power prevents re-voting the same tokens from another address
+ * - Vote threshold prevents 51% attacks (requires super-majority)
+ * - Payload hash commitment prevents bait-and-switch
+ */
+export declare namespace Governance {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "GovernanceToken"; values: void }
+    | { tag: "VotingPeriod"; values: void }
+    | { tag: "TimelockDelay"; values: void }
+    | { tag: "QuorumBps"; values: void }
+    | { tag: "ThresholdBps"; values: void }
+    | { tag: "VoteChangeWindow"; values: void }
+    | { tag: "Proposal"; values: readonly [bigint] }
+    | { tag: "Vote"; values: readonly [bigint, string] }
+    | { tag: "ProposalActions"; values: readonly [bigint] }
+    | { tag: "VoteKey"; values: readonly [string] }
+    | { tag: "VoteNonce"; values: readonly [string] }
+    | { tag: "ProposalConfig"; values: void }
+    | { tag: "ProposalDeposit"; values: readonly [bigint] }
+    | { tag: "ActiveProposals"; values: readonly [string] };
+  /**
+   * Anti-spam rules applied in `propose` and `propose_actions`.
+   * Zero values disable the corresponding check.
+   */
+  export interface ProposalConfig {
+    min_proposal_power: bigint;
+    deposit_amount: bigint;
+    max_active_per_proposer: number;
+    treasury: string | undefined;
+  }
+  /**
+   * A deposit held for a proposal, with the treasury that receives it if
+   * slashed. Recorded at creation so later config changes do not affect it.
+   */
+  export interface Deposit {
+    amount: bigint;
+    treasury: string;
+  }
+  /** A contract call executed when a proposal passes. */
+  export interface ProposalAction {
+    target: string;
+    function: string;
+    args: Array<unknown>;
+  }
+  export interface Proposal {
+    proposer: string;
+    payload_hash: Buffer;
+    start_ledger: number;
+    end_ledger: number;
+    for_votes: bigint;
+    against_votes: bigint;
+    abstain_votes: bigint;
+    state: number;
+    eta: number;
+  }
+  /**
+   * The payload a voter signs for `vote_by_sig`. The signature covers the XDR
+   * encoding of this struct, as returned by `vote_message`.
+   */
+  export interface VoteMessage {
+    governance: string;
+    voter: string;
+    proposal_id: bigint;
+    support: number;
+    nonce: bigint;
+    expiry_ledger: number;
+  }
+  /** A signed vote submitted by a relayer. */
+  export interface SignedVote {
+    voter: string;
+    proposal_id: bigint;
+    support: number;
+    nonce: bigint;
+    expiry_ledger: number;
+    signature: Buffer;
+  }
+  /** A voter's recorded choice on a proposal. */
+  export interface VoteReceipt {
+    support: number;
+    weight: bigint;
+    cast_ledger: number;
+  }
+  export interface ProposalSummary {
+    exists: boolean;
+    proposal_id: bigint;
+    state: number;
+    for_votes: bigint;
+    against_votes: bigint;
+    abstain_votes: bigint;
+    total_votes: bigint;
+    quorum_votes_required: bigint;
+    quorum_votes_remaining: bigint;
+    quorum_progress_bps: number;
+    quorum_reached: boolean;
+    execution_eta: number;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    ProposalAlreadyExists = 4,
+    ProposalNotFound = 5,
+    InvalidProposalState = 6,
+    VotingPeriodActive = 7,
+    VotingPeriodEnded = 8,
+    QuorumNotReached = 9,
+    ThresholdNotMet = 10,
+    TimelockNotExpired = 11,
+    AlreadyVoted = 12,
+    InvalidPayload = 13,
+    Overflow = 14,
+    InvalidActions = 15,
+    InvalidSupport = 16,
+    ReasonTooLong = 17,
+    VoteKeyNotRegistered = 18,
+    InvalidNonce = 19,
+    SignatureExpired = 20,
+    BelowProposalThreshold = 21,
+    TooManyActiveProposals = 22,
+    NoDeposit = 23,
+    InvalidConfig = 24,
+  }
+  export interface ProposalCreatedEvent {
+    topics: readonly ["proposal_created", bigint];
+    data: { proposer: string; payload_hash: Buffer };
+  }
+  export interface VoteCastEvent {
+    topics: readonly ["vote_cast", bigint, string];
+    data: { support: number; weight: bigint; reason: string | undefined };
+  }
+  export interface VoteChangedEvent {
+    topics: readonly ["vote_changed", bigint, string];
+    data: { previous_support: number; support: number };
+  }
+  export interface VoteKeyRegisteredEvent {
+    topics: readonly ["vote_key_registered", string];
+    data: { public_key: Buffer };
+  }
+  export interface DepositRefundedEvent {
+    topics: readonly ["deposit_refunded", bigint];
+    data: { proposer: string; amount: bigint };
+  }
+  export interface DepositSlashedEvent {
+    topics: readonly ["deposit_slashed", bigint];
+    data: { treasury: string; amount: bigint };
+  }
+  export interface ProposalQueuedEvent {
+    topics: readonly ["proposal_queued", bigint];
+    data: { eta: number };
+  }
+  export interface ProposalExecutedEvent {
+    topics: readonly ["proposal_executed", bigint];
+    data: Record<string, never>;
+  }
+  export interface ProposalActionExecutedEvent {
+    topics: readonly ["proposal_action_executed", bigint];
+    data: { index: number; target: string; function: string };
+  }
+  export interface ProposalCancelledEvent {
+    topics: readonly ["proposal_cancelled", bigint];
+    data: Record<string, never>;
+  }
+  export type ContractEvent = ProposalCreatedEvent | VoteCastEvent | VoteChangedEvent | VoteKeyRegisteredEvent | DepositRefundedEvent | DepositSlashedEvent | ProposalQueuedEvent | ProposalExecutedEvent | ProposalActionExecutedEvent | ProposalCancelledEvent;
+  export interface Methods {
+    /**
+     * Initialize governance with token and parameters.
+     *
+     * `voting_period`: ledgers for voting (e.g., 17280 = ~1 day at 5s/ledger)
+     * `timelock_delay`: ledgers before execution (e.g., 86400 = ~5 days)
+     * `quorum_bps`: minimum participation (e.g., 400 = 4% of supply)
+     * `threshold_bps`: minimum approval (e.g., 6000 = 60% of votes cast)
+     */
+    init: { args: { admin: string; governance_token: string; voting_period: number; timelock_delay: number; quorum_bps: number; threshold_bps: number }; returns: void };
+    /**
+     * Create a new proposal. Anyone meeting the `ProposalConfig` rules can propose.
+     *
+     * `payload_hash`: SHA-256 of the action to execute (verified at execution)
+     */
+    propose: { args: { proposer: string; proposal_id: bigint; payload_hash: Buffer }; returns: void };
+    /**
+     * Create a proposal that executes `actions` on-chain when it passes.
+     *
+     * The actions are stored with the proposal so voters can inspect them via
+     * `get_proposal_actions`; `payload_hash` is set to `hash_actions(actions)`.
+     */
+    propose_actions: { args: { proposer: string; proposal_id: bigint; actions: Array<ProposalAction> }; returns: void };
+    /**
+     * Cast a vote on an active proposal.
+     *
+     * `support`: true = for, false = against
+     * `weight`: voter's voting power (own balance plus delegations) at the
+     * proposal's `start_ledger`, read from the token's checkpoints
+     */
+    vote: { args: { proposal_id: bigint; voter: string; support: boolean }; returns: void };
+    /**
+     * Cast a three-way vote with an optional reason.
+     *
+     * `support`: `VOTE_AGAINST`, `VOTE_FOR` or `VOTE_ABSTAIN`. Abstentions
+     * count toward quorum but not toward the approval threshold.
+     *
+     * A voter who already voted may call this again to change their vote
+     * while within `vote_change_window` ledgers of their first vote and
+     * before `end_ledger`.
+     */
+    cast_vote: { args: { proposal_id: bigint; voter: string; support: number; reason: string | undefined }; returns: void };
+    /**
+     * Register the ed25519 public key allowed to sign votes for `voter`.
+     *
+     * Replacing the key does not reset the nonce, so signatures made with
+     * an earlier key for an unused nonce remain invalid once that nonce is used.
+     */
+    register_vote_key: { args: { voter: string; public_key: Buffer }; returns: void };
+    /**
+     * Cast a vote signed off-chain by the voter's registered key. Anyone
+     * (typically the backend relayer) may submit it and pay the fee.
+     *
+     * `nonce` must equal `get_vote_nonce(voter)` and the call must land
+     * before `expiry_ledger`. An invalid signature aborts the call.
+     */
+    vote_by_sig: { args: { voter: string; proposal_id: bigint; support: number; nonce: bigint; expiry_ledger: number; signature: Buffer }; returns: void };
+    /**
+     * Submit several signed votes in one transaction. The batch is atomic:
+     * if any vote is rejected none are recorded, so relayers should check
+     * signatures and nonces off-chain first.
+     */
+    vote_by_sig_batch: { args: { votes: Array<SignedVote> }; returns: void };
+    /** Configure proposal thresholds, deposits and the per-proposer active cap. */
+    set_proposal_config: { args: { admin: string; config: ProposalConfig }; returns: void };
+    /**
+     * Return the deposit of a finished proposal. Anyone can call.
+     *
+     * Once voting has ended the deposit is refunded to the proposer if the
+     * proposal reached quorum, or sent to the treasury if it did not.
+     * Deposits of cancelled proposals are always refunded.
+     */
+    settle_deposit: { args: { proposal_id: bigint }; returns: void };
+    /**
+     * Set how many ledgers after their first vote a voter may change it.
+     * Zero (the default) disables vote changes.
+     */
+    set_vote_change_window: { args: { admin: string; ledgers: number }; returns: void };
+    /**
+     * Queue a succeeded proposal into the timelock. Anyone can call.
+     *
+     * Requirements: voting ended, quorum reached, threshold met
+     */
+    queue: { args: { proposal_id: bigint }; returns: void };
+    /**
+     * Execute a queued proposal after timelock. Anyone can call.
+     *
+     * `payload_hash_verify`: must match stored hash (prevents bait-and-switch)
+     *
+     * Proposals created with `propose_actions` invoke each action in order.
+     * A failing action aborts the call, reverting the whole execution.
+     */
+    execute: { args: { proposal_id: bigint; payload_hash_verify: Buffer }; returns: void };
+    /** Admin can cancel a proposal at any state (emergency function) */
+    cancel: { args: { admin: string; proposal_id: bigint }; returns: void };
+    /**
+     * Cancel a queued proposal that has exceeded the execution window.
+     *
+     * ## Execution Window Rules
+     * - Queued proposals must be executed within `execution_window` ledgers after `eta`
+     * - Default execution window: 2x the timelock delay (e.g., if timelock=50 ledgers, window=100)
+     * - Anyone can call this function to clean up stale proposals
+     * - Prevents indefinite queue accumulation and governance stagnation
+     *
+     * ## Requirements
+     * - Proposal must be in STATE_QUEUED
+     * - Current ledger must be >= eta + execution_window
+     * - Execution window = timelock_delay * 2 (conservative default)
+     *
+     * ## Security
+     * - Cannot cancel active, executed, or already cancelled proposals
+     * - Prevents malicious actors from flooding the queue with stale proposals
+     * - Allows governance to remain responsive and current
+     */
+    cancel_stale: { args: { proposal_id: bigint }; returns: void };
+    /** Get proposal details */
+    get_proposal: { args: { proposal_id: bigint }; returns: Proposal };
+    /**
+     * Return a display-ready proposal snapshot.
+     *
+     * Missing proposals return `exists = false` with zeroed numeric fields so
+     * downstream callers can distinguish an empty-state from a real proposal.
+     */
+    get_proposal_summary: { args: { proposal_id: bigint }; returns: ProposalSummary };
+    /** Get the actions committed by `propose_actions` (empty for hash-only proposals) */
+    get_proposal_actions: { args: { proposal_id: bigint }; returns: Array<ProposalAction> };
+    /** Compute the payload hash committed for `actions`: SHA-256 of their XDR encoding */
+    hash_actions: { args: { actions: Array<ProposalAction> }; returns: Buffer };
+    /** Get the ed25519 key registered for signed votes, if any */
+    get_vote_key: { args: { voter: string }; returns: Buffer | undefined };
+    /** Get the nonce the next signed vote from `voter` must use */
+    get_vote_nonce: { args: { voter: string }; returns: bigint };
+    /** Build the exact bytes a voter signs for `vote_by_sig` */
+    vote_message: { args: { voter: string; proposal_id: bigint; support: number; nonce: bigint; expiry_ledger: number }; returns: Buffer };
+    /** Get the proposal anti-spam configuration */
+    get_proposal_config: { args: Record<string, never>; returns: ProposalConfig };
+    /** Get the deposit still held for a proposal (0 once settled or if none) */
+    get_proposal_deposit: { args: { proposal_id: bigint }; returns: bigint };
+    /** Check if an address has voted on a proposal */
+    has_voted: { args: { proposal_id: bigint; voter: string }; returns: boolean };
+    /** Get the recorded vote of an address on a proposal */
+    get_vote: { args: { proposal_id: bigint; voter: string }; returns: VoteReceipt | undefined };
+  }
+}
+
+export declare namespace GovernanceToken {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Name"; values: void }
+    | { tag: "Symbol"; values: void }
+    | { tag: "Decimals"; values: void }
+    | { tag: "Balance"; values: readonly [string] }
+    | { tag: "TotalSupply"; values: void }
+    | { tag: "Delegate"; values: readonly [string] }
+    | { tag: "Checkpoint"; values: readonly [string, number] }
+    | { tag: "NumCheckpoints"; values: readonly [string] }
+    | { tag: "TotalSupplyCheckpoint"; values: readonly [number] }
+    | { tag: "NumTotalSupplyCheckpoints"; values: void };
+  /** Voting power of an account (or the total supply) as of `ledger`. */
+  export interface Checkpoint {
+    ledger: number;
+    votes: bigint;
+  }
+  export const enum ErrorCode {
+    NotAuthorized = 1,
+    AlreadyInitialized = 2,
+    InsufficientBalance = 3,
+    InvalidAmount = 4,
+    Overflow = 5,
+    FutureLookup = 6,
+  }
+  export interface TokenInitializedEvent {
+    topics: readonly ["token_initialized", string];
+    data: { name: string; symbol: string; decimals: number };
+  }
+  export interface TokenMintedEvent {
+    topics: readonly ["token_minted", string];
+    data: { amount: bigint };
+  }
+  export interface TokenBurnedEvent {
+    topics: readonly ["token_burned", string];
+    data: { amount: bigint };
+  }
+  export interface TokenTransferredEvent {
+    topics: readonly ["token_transferred", string, string];
+    data: { amount: bigint };
+  }
+  export interface DelegateChangedEvent {
+    topics: readonly ["delegate_changed", string];
+    data: { from_delegate: string; to_delegate: string };
+  }
+  export interface DelegateVotesChangedEvent {
+    topics: readonly ["delegate_votes_changed", string];
+    data: { previous_votes: bigint; new_votes: bigint };
+  }
+  export type ContractEvent = TokenInitializedEvent | TokenMintedEvent | TokenBurnedEvent | TokenTransferredEvent | DelegateChangedEvent | DelegateVotesChangedEvent;
+  export interface Methods {
+    /**
+     * Initializes the contract with the admin address and token setup.
+     * Requires admin authorization to prevent arbitrary initialization.
+     */
+    init: { args: { admin: string; name: string; symbol: string; decimals: number }; returns: void };
+    /** Mints new tokens to a recipient. Only admin can call. */
+    mint: { args: { to: string; amount: bigint }; returns: void };
+    /** Burns tokens from an account. Only admin can call. */
+    burn: { args: { from: string; amount: bigint }; returns: void };
+    /** Transfers tokens between accounts. Requires sender authorization. */
+    transfer: { args: { from: string; to: string; amount: bigint }; returns: void };
+    /**
+     * Delegates the voting power of `from` to `to`. Requires `from` authorization.
+     *
+     * Accounts that never delegated vote with their own balance, so delegating
+     * back to oneself restores the default.
+     */
+    delegate: { args: { from: string; to: string }; returns: void };
+    /** Returns the address `account` currently delegates to (itself by default). */
+    delegates: { args: { account: string }; returns: string };
+    /** Returns the current voting power of `account`, including delegated votes. */
+    get_votes: { args: { account: string }; returns: bigint };
+    /**
+     * Returns the voting power `account` held at the end of `ledger`.
+     *
+     * `ledger` must already be closed: checkpoints for the current ledger
+     * can still change, so looking it up fails with `FutureLookup`.
+     */
+    get_past_votes: { args: { account: string; ledger: number }; returns: bigint };
+    /**
+     * Returns the total supply at the end of `ledger`, which must already
+     * be closed.
+     */
+    get_past_total_supply: { args: { ledger: number }; returns: bigint };
+    balance: { args: { id: string }; returns: bigint };
+    total_supply: { args: Record<string, never>; returns: bigint };
+    name: { args: Record<string, never>; returns: string };
+    symbol: { args: Record<string, never>; returns: string };
+    decimals: { args: Record<string, never>; returns: number };
+  }
+}
+
+/**
+ * Stellarcade Higher or Lower Contract
+ *
+ * A simple prediction game: players wager on whether the outcome is higher
+ * or lower than a fixed anchor value.
+ */
+export declare namespace HigherLower {
+  export const enum Prediction {
+    Higher = 0,
+    Lower = 1,
+  }
+  export interface GameData {
+    player: string;
+    prediction: Prediction;
+    wager: bigint;
+    resolved: boolean;
+    expired: boolean;
+    outcome: number;
+    win: boolean;
+    payout: bigint;
+    /** Ledger sequence at which the round was opened. */
+    created_at: number;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "RngContract"; values: void }
+    | { tag: "PrizePoolContract"; values: void }
+    | { tag: "BalanceContract"; values: void }
+    | { tag: "Game"; values: readonly [bigint] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidPrediction = 4,
+    InvalidWager = 5,
+    GameAlreadyExists = 6,
+    GameNotFound = 7,
+    AlreadyResolved = 8,
+    RngNotReady = 9,
+    InsufficientBalance = 10,
+    HouseInsufficientFunds = 11,
+    Overflow = 12,
+    /** Cleanup called before the round's RNG request has expired. */
+    NotExpired = 13,
+    /** Attempt to resolve or interact with an already-expired game. */
+    GameExpired = 14,
+  }
+  export interface PredictionPlacedEvent {
+    topics: readonly ["prediction_placed", bigint];
+    data: { player: string; prediction: number; wager: bigint };
+  }
+  export interface GameResolvedEvent {
+    topics: readonly ["game_resolved", bigint];
+    data: { outcome: number; win: boolean; payout: bigint };
+  }
+  export interface RoundExpiredEvent {
+    topics: readonly ["round_expired", bigint];
+    data: { player: string; refund: bigint };
+  }
+  export type ContractEvent = PredictionPlacedEvent | GameResolvedEvent | RoundExpiredEvent;
+  export interface Methods {
+    init: { args: { admin: string; rng_contract: string; prize_pool_contract: string; balance_contract: string }; returns: void };
+    /**
+     * Escrow the wager and request the round's outcome from the RNG, using
+     * `game_id` as the request id. `client_seed` is optional player entropy
+     * mixed into the outcome.
+     */
+    place_prediction: { args: { player: string; prediction: number; wager: bigint; game_id: bigint; client_seed: Buffer | undefined }; returns: void };
+    resolve_game: { args: { game_id: bigint }; returns: void };
+    /**
+     * Expires a stale round whose RNG request expired before it was fulfilled.
+     *
+     * Callable by anyone. On success the wager is refunded to the player and
+     * the round is transitioned to the terminal `expired` state.
+     *
+     * # Expiry Model
+     * - Follows the random-generator's request expiry (`is_expired`), the same
+     *   rule as `refund_expired` in the other RNG games. A fulfilled request
+     *   never expires, so a round whose outcome is known can only be resolved.
+     * - A `RoundExpired` event is emitted on success, recording `game_id`, `player`, and `refund` amount.
+     * - Resolved or already-expired rounds are never re-targeted.
+     *
+     * # Errors
+     * * `NotInitialized` - Registry not initialised.
+     * * `GameNotFound`   - No round stored under this ID.
+     * * `AlreadyResolved` - Round was already properly resolved.
+     * * `GameExpired`   - Round was already cleaned up via `expire_round`.
+     * * `NotExpired`    - The RNG request is fulfilled or still pending.
+     */
+    expire_round: { args: { game_id: bigint }; returns: void };
+    get_game: { args: { game_id: bigint }; returns: GameData | undefined };
+  }
+}
+
+/**
+ * Stellarcade Leaderboard Contract
+ *
+ * Tracks player scores across different games and maintains a top-players list.
+ * The contract is permissioned, allowing only the admin or authorized game
+ * contracts to submit scores.
+ */
+export declare namespace Leaderboard {
+  export interface ScoreEntry {
+    player: string;
+    score: bigint;
+  }
+  export interface PlayerRankLookup {
+    ranked: boolean;
+    rank: number;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Authorized"; values: readonly [string] }
+    | { tag: "GameActive"; values: readonly [string] }
+    | { tag: "PlayerScore"; values: readonly [string, string] }
+    | { tag: "Leaderboard"; values: readonly [string] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidLimit = 4,
+    Overflow = 5,
+    GameNotFound = 6,
+  }
+  export interface ScoreSubmittedEvent {
+    topics: readonly ["score_submitted", string, string];
+    data: { score: bigint };
+  }
+  export interface LeaderboardUpdatedEvent {
+    topics: readonly ["leaderboard_updated", string];
+    data: Record<string, never>;
+  }
+  export interface GameStatusChangedEvent {
+    topics: readonly ["game_status_changed", string];
+    data: { active: boolean };
+  }
+  export type ContractEvent = ScoreSubmittedEvent | LeaderboardUpdatedEvent | GameStatusChangedEvent;
+  export interface Methods {
+    /** Initialize the leaderboard contract with an admin. */
+    init: { args: { admin: string }; returns: void };
+    /** Authorize or deauthorize an address (e.g., a game contract) to submit scores. */
+    set_authorized: { args: { admin: string; addr: string; auth: boolean }; returns: void };
+    /** Set a game's active status. */
+    set_game_active: { args: { admin: string; game_id: string; active: boolean }; returns: void };
+    /**
+     * Submit a score for a player in a game.
+     * Only authorized callers can submit scores.
+     */
+    submit_score: { args: { caller: string; player: string; game_id: string; score: bigint }; returns: void };
+    /**
+     * Explicitly request a ranking update for a game.
+     * In this implementation, it's mostly a placeholder as submit_score handles it,
+     * but can be used to re-validate the top list.
+     */
+    update_rankings: { args: { game_id: string }; returns: void };
+    /** Get the top players for a game, up to a certain limit. */
+    top_players: { args: { game_id: string; limit: number }; returns: Array<ScoreEntry> };
+    /**
+     * Get the rank of a player in a specific game (1-indexed).
+     * Returns 0 if player is not in the top leaderboard.
+     */
+    player_rank: { args: { game_id: string; player: string }; returns: number };
+    /** Returns explicit rank lookup metadata for a player. */
+    player_rank_lookup: { args: { game_id: string; player: string }; returns: PlayerRankLookup };
+    /**
+     * Returns a deterministic rank-ordered slice around `player`.
+     *
+     * `radius` controls how many neighbors above and below are included.
+     * Returns an empty slice if the player is not currently ranked.
+     */
+    neighboring_slice: { args: { game_id: string; player: string; radius: number }; returns: Array<ScoreEntry> };
+    /** Get a player's raw score. */
+    get_player_score: { args: { game_id: string; player: string }; returns: bigint };
+  }
+}
+
+export declare namespace MatchmakingQueue {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "QueueState"; values: readonly [string] }
+    | { tag: "NextMatchId"; values: void }
+    | { tag: "Match"; values: readonly [bigint] };
+  export interface MatchQueueState {
+    queue_id: string;
+    players: Array<string>;
+    criteria_hash: string;
+  }
+  export interface MatchRecord {
+    match_id: bigint;
+    queue_id: string;
+    players: Array<string>;
+  }
+  export interface QueuePositionSnapshot {
+    queue_id: string;
+    player: string;
+    position: number;
+    queue_depth: number;
+    criteria_hash: string;
+  }
+  export interface PlayerEnqueuedEvent {
+    topics: readonly ["player_enqueued", string];
+    data: { player: string };
+  }
+  export interface PlayerDequeuedEvent {
+    topics: readonly ["player_dequeued", string];
+    data: { player: string };
+  }
+  export interface MatchCreatedEvent {
+    topics: readonly ["match_created", bigint];
+    data: { queue_id: string };
+  }
+  export type ContractEvent = PlayerEnqueuedEvent | PlayerDequeuedEvent | MatchCreatedEvent;
+  export interface Methods {
+    /** Initialize the contract with an admin. */
+    init: { args: { admin: string }; returns: void };
+    /** Enqueue a player into a matchmaking queue. Player must auth. */
+    enqueue_player: { args: { queue_id: string; player: string; criteria_hash: string }; returns: void };
+    /** Remove a player from a queue. Only admin or the player themselves can dequeue. */
+    dequeue_player: { args: { caller: string; queue_id: string; player: string }; returns: void };
+    /**
+     * Create a match from a set of players. Admin-only.
+     * Players are removed from the queue on match creation.
+     */
+    create_match: { args: { queue_id: string; players: Array<string> }; returns: bigint };
+    /** Read the current state of a queue. */
+    queue_state: { args: { queue_id: string }; returns: MatchQueueState };
+    /**
+     * Read the number of players currently waiting in a queue.
+     * Missing queues report a depth of 0.
+     */
+    queue_depth: { args: { queue_id: string }; returns: number };
+    /**
+     * Read a stable player position snapshot for the current queue ordering.
+     * Returns None for missing queues, empty queues, or absent players.
+     */
+    player_position_snapshot: { args: { queue_id: string; player: string }; returns: QueuePositionSnapshot | undefined };
+    /** Read a match record. */
+    match_state: { args: { match_id: bigint }; returns: MatchRecord };
+  }
+}
+
+export declare namespace MultiplayerRoom {
+  export const enum RoomStatus {
+    Open = 0,
+    InMatch = 1,
+    Closed = 2,
+  }
+  export interface RoomData {
+    room_id: bigint;
+    config_hash: Buffer;
+    status: RoomStatus;
+    player_count: number;
+    capacity: number;
+    created_by: string;
+  }
+  export interface RoomSnapshot {
+    room_id: bigint;
+    config_hash: Buffer;
+    status: RoomStatus;
+    occupancy: number;
+    capacity: number;
+    remaining_slots: number;
+    host: string;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "FeeContract"; values: void }
+    | { tag: "Paused"; values: void }
+    | { tag: "Room"; values: readonly [bigint] }
+    | { tag: "RoomPlayers"; values: readonly [bigint] }
+    | { tag: "PlayerInRoom"; values: readonly [bigint, string] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidRoomId = 4,
+    InvalidConfigHash = 5,
+    RoomAlreadyExists = 6,
+    RoomNotFound = 7,
+    InvalidState = 8,
+    DuplicatePlayer = 9,
+    RoomFull = 10,
+    NotEnoughPlayers = 11,
+    ContractPaused = 12,
+    Overflow = 13,
+    InvalidCapacity = 14,
+  }
+  export interface InitializedEvent {
+    topics: readonly ["initialized", string];
+    data: { fee_contract: string };
+  }
+  export interface RoomCreatedEvent {
+    topics: readonly ["room_created", bigint];
+    data: { config_hash: Buffer; created_by: string };
+  }
+  export interface PlayerJoinedEvent {
+    topics: readonly ["player_joined", bigint, string];
+    data: { player_count: number };
+  }
+  export interface MatchStartedEvent {
+    topics: readonly ["match_started", bigint];
+    data: { player_count: number };
+  }
+  export interface RoomClosedEvent {
+    topics: readonly ["room_closed", bigint];
+    data: { final_player_count: number };
+  }
+  export type ContractEvent = InitializedEvent | RoomCreatedEvent | PlayerJoinedEvent | MatchStartedEvent | RoomClosedEvent;
+  export interface Methods {
+    init: { args: { admin: string; fee_contract: string }; returns: void };
+    /** Create a new room with a fixed player capacity. */
+    create_room: { args: { room_id: bigint; config_hash: Buffer; capacity: number }; returns: void };
+    /** Join an open room if capacity and authorization checks pass. */
+    join_room: { args: { room_id: bigint; player: string }; returns: void };
+    start_match: { args: { room_id: bigint }; returns: void };
+    close_room: { args: { room_id: bigint }; returns: void };
+    /** Read the full stored room record. */
+    get_room: { args: { room_id: bigint }; returns: RoomData };
+    /** Return a lobby-friendly room snapshot with occupancy and host metadata. */
+    room_snapshot: { args: { room_id: bigint }; returns: RoomSnapshot };
+    get_players: { args: { room_id: bigint }; returns: Array<string> };
+    get_fee_contract: { args: Record<string, never>; returns: string };
+  }
+}
+
+export declare namespace NftReward {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "NftContract"; values: void }
+    | { tag: "RewardContract"; values: void }
+    | { tag: "Campaign"; values: readonly [number] }
+    | { tag: "PendingReward"; values: readonly [string, number] }
+    | { tag: "Claimed"; values: readonly [string, number] };
+  export interface CampaignData {
+    metadata_uri: string;
+    supply: number;
+    remaining: number;
+    is_active: boolean;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    CampaignNotFound = 4,
+    CampaignAlreadyExists = 5,
+    CampaignExhausted = 6,
+    CampaignNotActive = 7,
+    NothingToClaim = 8,
+    AlreadyClaimed = 9,
+    InvalidAmount = 10,
+    Overflow = 11,
+  }
+  export interface CampaignDefinedEvent {
+    topics: readonly ["campaign_defined", number];
+    data: { metadata_uri: string; supply: number };
+  }
+  export interface RewardMintedEvent {
+    topics: readonly ["reward_minted", number, string];
+    data: Record<string, never>;
+  }
+  export interface RewardClaimedEvent {
+    topics: readonly ["reward_claimed", number, string];
+    data: Record<string, never>;
+  }
+  export type ContractEvent = CampaignDefinedEvent | RewardMintedEvent | RewardClaimedEvent;
+  export interface Methods {
+    /** Initialize the contract. */
+    init: { args: { admin: string; nft_contract: string; reward_contract: string }; returns: void };
+    /** Define a new NFT reward campaign. Admin only. */
+    define_nft_reward: { args: { campaign_id: number; metadata_uri: string; supply: number }; returns: void };
+    /** Mark a reward as "minted" (awarded) for a user. Admin only. */
+    mint_reward: { args: { user: string; campaign_id: number }; returns: void };
+    /** Claim the pending NFT reward. User only. */
+    claim_nft: { args: { user: string; campaign_id: number }; returns: void };
+    /** View campaign state. */
+    nft_reward_state: { args: { campaign_id: number }; returns: CampaignData | undefined };
+  }
+}
+
+/**
+ * Stellarcade Number Guess Contract
+ *
+ * Players pick a secret number within a chosen range [`min`, `max`] and wager
+ * tokens on their guess.  The actual secret is generated by the Random
+ * Generator contract's request/fulfill model, ensuring neither the house nor
+ * any observer can bias the outcome after the player's guess is locked in.
+ *
+ * ## Game Flow
+ * 1. Player calls `start_game` → range + wager locked, RNG requested, tokens
+ *    transferred from player to this contract.
+ * 2. Player calls `submit_guess` → guess committed on-chain while RNG is still
+ *    pending.  Auth required so only the player can set their own guess.
+ * 3. Oracle fulfills randomness on the RNG contract (off-chain step).
+ * 4. Anyone calls `resolve_game` → reads RNG result, derives secret number,
+ *    settles payout if guess matches.
+ *
+ * If the RNG request expires before the oracle fulfills it, anyone can call
+ * `refund_expired` to return the wager to the player instead.
+ *
+ * ## Fairness Model
+ * The player's guess is committed to storage **before** the oracle reveals the
+ * server seed, so the oracle cannot select a seed after observing the guess.
+ * After resolution, anyone can independently verify:
+ *   `sha256(server_seed || game_id_be)[0..8] % range_size + min == secret`
+ * using the seed stored by the RNG contract.
+ *
+ * ## Payout
+ * A winning player receives their full wager back plus a multiplier equal to
+ * `range_size - 1` times the wager, minus a configurable house edge in basis
+ * points:
+ *   `gross_payout = wager * range_size`
+ *   `fee          = gross_payout * house_edge_bps / 10_000`
+ *   `net_payout   = gross_payout - fee`
+ *
+ * ## Storage Strategy
+ * - `instance()`: Admin, RngContract, PrizePoolContract, BalanceContract,
+ *   MinWager, MaxWager, HouseEdgeBps.  Fixed-size contract config.
+ * - `persistent()`: one `Game` entry per `game_id`, TTL bumped on every write.
+ */
+export declare namespace NumberGuess {
+  /**
+   * Per-game lifecycle state.
+   *
+   * `Open`     — `start_game` called; waiting for player to submit a guess.
+   * `Guessed`  — `submit_guess` called; waiting for RNG fulfillment and resolution.
+   * `Won`      — Resolved; guess matched the secret number.
+   * `Lost`     — Resolved; guess did not match.
+   * `Refunded` — RNG request expired unfulfilled; wager returned to the player.
+   */
+  export const enum GameStatus {
+    Open = 0,
+    Guessed = 1,
+    Won = 2,
+    Lost = 3,
+    Refunded = 4,
+  }
+  /** Full state for a single number-guess game. */
+  export interface Game {
+    player: string;
+    /** Inclusive lower bound of the guessing range. */
+    min: number;
+    /** Inclusive upper bound of the guessing range. */
+    max: number;
+    wager: bigint;
+    /** Set by `submit_guess`; 0 until the player submits. */
+    guess: number;
+    status: GameStatus;
+    /** The revealed secret number; populated only after `resolve_game`. */
+    secret: number;
+    /** Token payout sent to the player on a win; 0 on a loss or before resolution. */
+    payout: bigint;
+  }
+  /** Storage key discriminants. */
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "RngContract"; values: void }
+    | { tag: "PrizePoolContract"; values: void }
+    | { tag: "BalanceContract"; values: void }
+    | { tag: "MinWager"; values: void }
+    | { tag: "MaxWager"; values: void }
+    | { tag: "HouseEdgeBps"; values: void }
+    | { tag: "Game"; values: readonly [bigint] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    /** `min >= max` or `range_size > MAX_RANGE_SIZE`. */
+    InvalidRange = 5,
+    GameAlreadyExists = 6,
+    GameNotFound = 7,
+    /** `submit_guess` called twice for the same game. */
+    AlreadyGuessed = 8,
+    /** `submit_guess` requires the game to be in the Open state. */
+    GameNotOpen = 9,
+    /** `resolve_game` requires a guess to have been submitted. */
+    GuessNotSubmitted = 10,
+    GameAlreadyResolved = 11,
+    RngNotFulfilled = 12,
+    /** Guess falls outside the declared `[min, max]` range. */
+    GuessOutOfRange = 13,
+    WagerTooLow = 14,
+    WagerTooHigh = 15,
+    Overflow = 16,
+    /** `refund_expired` requires the RNG request to have expired. */
+    RngNotExpired = 17,
+  }
+  export interface GameStartedEvent {
+    topics: readonly ["game_started", bigint, string];
+    data: { min: number; max: number; wager: bigint };
+  }
+  export interface GuessSubmittedEvent {
+    topics: readonly ["guess_submitted", bigint, string];
+    data: { guess: number };
+  }
+  export interface GameResolvedEvent {
+    topics: readonly ["game_resolved", bigint, string];
+    data: { guess: number; secret: number; won: boolean; payout: bigint };
+  }
+  export interface GameRefundedEvent {
+    topics: readonly ["game_refunded", bigint, string];
+    data: { amount: bigint };
+  }
+  export type ContractEvent = GameStartedEvent | GuessSubmittedEvent | GameResolvedEvent | GameRefundedEvent;
+  export interface Methods {
+    /**
+     * Initialize the contract. May only be called once.
+     *
+     * - `rng_contract`: address of the deployed Random Generator contract.
+     * - `prize_pool_contract`: reserved for future prize-pool integration.
+     * - `balance_contract`: the SEP-41 token used for wagers and payouts.
+     * - `min_wager` / `max_wager`: inclusive wager bounds enforced in
+     *   `start_game`.
+     * - `house_edge_bps`: house take in basis points (e.g. 250 = 2.5 %).
+     */
+    init: { args: { admin: string; rng_contract: string; prize_pool_contract: string; balance_contract: string; min_wager: bigint; max_wager: bigint; house_edge_bps: bigint }; returns: void };
+    /**
+     * Start a new number-guess game.
+     *
+     * The player selects a range `[min, max]` and places a `wager`.  Tokens
+     * are transferred from the player to this contract immediately.  A
+     * randomness request is submitted to the RNG contract using the
+     * `game_id` as the request identifier, so `game_id` must be globally
+     * unique across the RNG contract's request space.
+     *
+     * `game_id` is caller-provided and must not collide with any existing
+     * pending or fulfilled RNG request. `client_seed` is optional player
+     * entropy forwarded to the RNG request and mixed into the secret.
+     */
+    start_game: { args: { player: string; min: number; max: number; wager: bigint; game_id: bigint; client_seed: Buffer | undefined }; returns: void };
+    /**
+     * Lock in the player's guess for an open game.
+     *
+     * Authorization is required from `game.player` — only the player who
+     * started the game can submit their guess.  The guess must lie within the
+     * `[min, max]` range declared in `start_game`.
+     *
+     * The guess must be submitted while the RNG request is still pending so
+     * that the oracle cannot choose a server seed after observing the guess.
+     */
+    submit_guess: { args: { game_id: bigint; guess: number }; returns: void };
+    /**
+     * Resolve a game after the oracle has fulfilled the RNG request.
+     *
+     * No authorization required — the outcome is fully deterministic from
+     * on-chain data once the RNG is fulfilled.  The secret number is derived
+     * as `min + (rng_result % range_size)` where `rng_result` is the value
+     * stored by the RNG contract.
+     *
+     * Payout state is written before any token transfer to prevent reentrancy.
+     */
+    resolve_game: { args: { game_id: bigint }; returns: void };
+    /**
+     * Return the wager of a game whose RNG request expired unfulfilled.
+     *
+     * No authorization required — the wager can only go back to the player.
+     * Allowed from `Open` or `Guessed`, since the RNG request may expire
+     * before the player submits a guess.
+     */
+    refund_expired: { args: { game_id: bigint }; returns: void };
+    /** Return the full game state, or `GameNotFound` if the id is unknown. */
+    get_game: { args: { game_id: bigint }; returns: Game };
+  }
+}
+
+export declare namespace OracleIntegration {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "OracleSources"; values: void }
+    | { tag: "Request"; values: readonly [Buffer] }
+    | { tag: "Latest"; values: readonly [Buffer] };
+  export interface OracleRequest {
+    feed_id: Buffer;
+    fulfilled: boolean;
+    payload: Buffer;
+  }
+  export interface LatestPriceData {
+    payload: Buffer;
+    updated_ledger: number;
+  }
+  export interface PriceFreshness {
+    has_price: boolean;
+    payload: Buffer;
+    updated_ledger: number;
+    current_ledger: number;
+    age_ledgers: number;
+    stale_threshold_ledgers: number;
+    is_stale: boolean;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotAuthorized = 2,
+    RequestExists = 3,
+    RequestNotFound = 4,
+    AlreadyFulfilled = 5,
+    InvalidInput = 6,
+    OracleNotWhitelisted = 7,
+    Overflow = 8,
+  }
+  export interface InitializedEvent {
+    topics: readonly ["initialized"];
+    data: { admin: string };
+  }
+  export interface RequestCreatedEvent {
+    topics: readonly ["request_created"];
+    data: { request_id: Buffer; feed_id: Buffer };
+  }
+  export interface RequestFulfilledEvent {
+    topics: readonly ["request_fulfilled"];
+    data: { request_id: Buffer; feed_id: Buffer };
+  }
+  export type ContractEvent = InitializedEvent | RequestCreatedEvent | RequestFulfilledEvent;
+  export interface Methods {
+    init: { args: { admin: string; oracle_sources_config: Array<string> }; returns: void };
+    request_data: { args: { caller: string; feed_id: Buffer; request_id: Buffer }; returns: void };
+    fulfill_data: { args: { caller: string; request_id: Buffer; payload: Buffer; _proof: Buffer }; returns: void };
+    latest: { args: { feed_id: Buffer }; returns: Buffer | undefined };
+    get_request: { args: { request_id: Buffer }; returns: OracleRequest | undefined };
+    last_price_freshness: { args: { feed_id: Buffer }; returns: PriceFreshness };
+  }
+}
+
+/**
+ * Stellarcade Pattern Puzzle Contract
+ *
+ * Implements a commit-reveal puzzle game where players submit pattern guesses
+ * and winners share the prize pot after the admin reveals the correct answer.
+ *
+ * ## Game Flow
+ * 1. Admin calls `create_puzzle` with SHA-256(correct_pattern) as commitment.
+ * 2. Players call `submit_solution` with their guesses and pay the entry fee.
+ * 3. Admin calls `resolve_round` with the plaintext answer (verified against hash).
+ * 4. Winning players call `claim_reward` to receive their proportional share.
+ *
+ * ## Storage Strategy
+ * - `instance()` storage: contract-level config only (Admin, PrizePoolContract,
+ *   BalanceContract). Small, fixed size, bounded.
+ * - `persistent()` storage: all per-round and per-player data (Round, Players,
+ *   Submission, IsWinner, Claimed). Each key is an independent ledger entry
+ *   with constant-cost access and an explicit TTL extended on every write.
+ */
+export declare namespace PatternPuzzle {
+  /**
+   * Round lifecycle state machine: Open → Resolved.
+   * Per-player claimed state is tracked separately via DataKey::Claimed.
+   */
+  export const enum RoundStatus {
+    Open = 0,
+    Resolved = 1,
+  }
+  /** Metadata and accumulated state for a puzzle round. */
+  export interface RoundData {
+    /** SHA-256 hash of the correct pattern, committed before submissions open. */
+    pattern_commitment: Buffer;
+    status: RoundStatus;
+    /** Set during resolve_round after iterating all submissions. */
+    winner_count: number;
+    /** Populated only after resolve_round; empty Bytes while status is Open. */
+    correct_pattern: Buffer;
+    /** Amount each player must wager to enter (0 = free round). */
+    entry_fee: bigint;
+    /** Accumulated from all submitted entry fees. */
+    total_pot: bigint;
+    /** Current number of submissions; enforces MAX_PLAYERS_PER_ROUND. */
+    player_count: number;
+  }
+  /** A player's committed guess for a round. */
+  export interface PlayerSubmission {
+    solution: Buffer;
+    wager: bigint;
+  }
+  /**
+   * Storage key discriminants.
+   *
+   * Instance keys (Admin, PrizePoolContract, BalanceContract): contract config,
+   * small fixed set, stored in a single ledger entry.
+   *
+   * Persistent keys (Round, Players, Submission, IsWinner, Claimed): per-round
+   * and per-player data, each stored as an independent ledger entry with its own
+   * TTL so reads and writes are O(1) and cost does not scale with contract state.
+   */
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "PrizePoolContract"; values: void }
+    | { tag: "BalanceContract"; values: void }
+    | { tag: "LatestRoundId"; values: void }
+    | { tag: "Round"; values: readonly [number] }
+    | { tag: "Players"; values: readonly [number] }
+    | { tag: "Submission"; values: readonly [number, string] }
+    | { tag: "IsWinner"; values: readonly [number, string] }
+    | { tag: "Claimed"; values: readonly [number, string] };
+  export const enum SnapshotStatus {
+    Missing = 0,
+    Unresolved = 1,
+    Resolved = 2,
+  }
+  export interface RoundCommitmentSummary {
+    status: SnapshotStatus;
+    round_id: number;
+    pattern_commitment: Buffer;
+    player_count: number;
+    entry_fee: bigint;
+    total_pot: bigint;
+  }
+  export interface WinnerCountSnapshot {
+    status: SnapshotStatus;
+    round_id: number;
+    winner_count: number;
+  }
+  export const enum ErrorCode {
+    NotInitialized = 1,
+    NotAuthorized = 2,
+    RoundNotFound = 3,
+    RoundAlreadyExists = 4,
+    RoundNotOpen = 5,
+    RoundNotResolved = 6,
+    AlreadySubmitted = 7,
+    AlreadyClaimed = 8,
+    NoRewardAvailable = 9,
+    InvalidAmount = 10,
+    Overflow = 11,
+    CommitmentMismatch = 12,
+    RoundFull = 13,
+  }
+  export interface RoundCreatedEvent {
+    topics: readonly ["round_created", number];
+    data: { pattern_commitment: Buffer };
+  }
+  export interface SolutionSubmittedEvent {
+    topics: readonly ["solution_submitted", string, number];
+    data: { solution: Buffer };
+  }
+  export interface RoundResolvedEvent {
+    topics: readonly ["round_resolved", number];
+    data: { correct_pattern: Buffer; winner_count: number };
+  }
+  export interface RewardClaimedEvent {
+    topics: readonly ["reward_claimed", string, number];
+    data: { amount: bigint };
+  }
+  export type ContractEvent = RoundCreatedEvent | SolutionSubmittedEvent | RoundResolvedEvent | RewardClaimedEvent;
+  export interface Methods {
+    /**
+     * Initialize the contract. May only be called once.
+     *
+     * Stores the admin, prize pool contract address, and balance contract
+     * address in instance storage. Subsequent calls are rejected with
+     * `NotAuthorized`.
+     */
+    init: { args: { admin: string; prize_pool_contract: string; balance_contract: string }; returns: void };
+    /**
+     * Open a new puzzle round with a committed pattern hash. Admin only.
+     *
+     * `pattern_commitment` is `SHA-256(correct_pattern_bytes)` computed off-chain.
+     * `entry_fee` is the token amount each player must wager (0 for free rounds).
+     * Round data is stored in persistent storage with a 30-day TTL.
+     */
+    create_puzzle: { args: { admin: string; round_id: number; pattern_commitment: Buffer; entry_fee: bigint }; returns: void };
+    /**
+     * Submit a solution guess for an open round.
+     *
+     * Each player may submit exactly once per round, up to `MAX_PLAYERS_PER_ROUND`
+     * total. The `solution` bytes are stored and compared byte-for-byte against the
+     * revealed pattern during `resolve_round`. Entry fee accounting is updated here;
+     * actual token transfer should invoke the balance contract (see TODO below).
+     */
+    submit_solution: { args: { player: string; round_id: number; solution: Buffer }; returns: void };
+    /**
+     * Reveal the correct pattern, verify the commitment, and determine winners.
+     *
+     * Admin only. `correct_pattern` must satisfy `SHA-256(correct_pattern) ==
+     * stored pattern_commitment`. Iterates all submissions (bounded by
+     * `MAX_PLAYERS_PER_ROUND`) to mark winners and compute `winner_count`.
+     * Transitions the round to `Resolved`.
+     */
+    resolve_round: { args: { admin: string; round_id: number; correct_pattern: Buffer }; returns: void };
+    /**
+     * Claim the proportional reward share for a winning submission.
+     *
+     * Returns the reward amount (`total_pot / winner_count`). The `Claimed` flag
+     * is set before any external call to preserve reentrancy safety. Actual token
+     * transfer should invoke the prize pool contract (see TODO below).
+     */
+    claim_reward: { args: { player: string; round_id: number }; returns: bigint };
+    /** Returns round metadata, or `None` if the round does not exist. */
+    get_round: { args: { round_id: number }; returns: RoundData | undefined };
+    /** Returns the stored submission for a player in a round, or `None`. */
+    get_submission: { args: { round_id: number; player: string }; returns: PlayerSubmission | undefined };
+    /** Returns `true` if the player has already claimed their reward for a round. */
+    has_claimed: { args: { round_id: number; player: string }; returns: boolean };
+    /**
+     * Returns a leaderboard snapshot (addresses of players) for a given round.
+     * Supports an optional limit for pagination or "top N" views. Stable ordering
+     * is guaranteed by the underlying Vec storage.
+     */
+    get_leaderboard: { args: { round_id: number; limit: number | undefined }; returns: Array<string> };
+    /** Returns commitment metadata for the latest known round. */
+    get_round_commitment_summary: { args: Record<string, never>; returns: RoundCommitmentSummary };
+    /** Returns winner count for a specific round, with explicit status. */
+    get_winner_count_snapshot: { args: { round_id: number }; returns: WinnerCountSnapshot };
+  }
+}
+
+export declare namespace PenaltySlashing {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Treasury"; values: void }
+    | { tag: "Violation"; values: readonly [string] }
+    | { tag: "Penalty"; values: readonly [bigint] }
+    | { tag: "AccountSummary"; values: readonly [string] }
+    | { tag: "NextPenaltyId"; values: void };
+  export type PenaltyStatus =
+    | { tag: "Applied"; values: void }
+    | { tag: "Appealed"; values: void }
+    | { tag: "Resolved"; values: void };
+  export interface PenaltyRule {
+    code: string;
+    slash_amount: bigint;
+    description_hash: string;
+  }
+  export interface PenaltyRecord {
+    penalty_id: bigint;
+    account: string;
+    code: string;
+    slash_amount: bigint;
+    context_hash: string;
+    status: PenaltyStatus;
+  }
+  export interface SlashPreview {
+    account: string;
+    current_balance: bigint;
+    slash_amount: bigint;
+    post_slash_balance: bigint;
+  }
+  export interface PenaltySummary {
+    account: string;
+    total_slashed: bigint;
+    penalty_count: number;
+    last_penalty_id: bigint;
+    has_penalties: boolean;
+    current_status: PenaltyStatus;
+  }
+  export interface ViolationDefinedEvent {
+    topics: readonly ["violation_defined", string];
+    data: { slash_amount: bigint };
+  }
+  export interface PenaltyAppliedEvent {
+    topics: readonly ["penalty_applied", bigint, string];
+    data: { code: string; slash_amount: bigint };
+  }
+  export interface PenaltyAppealedEvent {
+    topics: readonly ["penalty_appealed", bigint];
+    data: { account: string };
+  }
+  export type ContractEvent = ViolationDefinedEvent | PenaltyAppliedEvent | PenaltyAppealedEvent;
+  export interface Methods {
+    /** Initialize with admin and treasury contract/address holding slashed funds. */
+    init: { args: { admin: string; treasury_contract: string }; returns: void };
+    /** Define or update a violation rule. Admin-only. */
+    define_violation: { args: { code: string; penalty_rule: PenaltyRule }; returns: void };
+    /**
+     * Apply a penalty to an account. Admin-only.
+     * Slashes tokens from `account` and transfers them to the treasury.
+     */
+    apply_penalty: { args: { account: string; code: string; context_hash: string; token_address: string }; returns: bigint };
+    /** File an appeal for a penalty. Only the penalized account may appeal. */
+    appeal_penalty: { args: { penalty_id: bigint }; returns: void };
+    /** Read current state of a penalty record. */
+    penalty_state: { args: { penalty_id: bigint }; returns: PenaltyRecord };
+    /** Preview a proposed slash against the account balance without mutating state. */
+    preview_slash: { args: { account: string; slash_amount: bigint; token_address: string }; returns: SlashPreview };
+    /** Summarize the slashing history and latest penalty state for an account. */
+    penalty_summary: { args: { account: string }; returns: PenaltySummary };
+  }
+}
+
+/**
+ * Stellarcade Price Prediction Contract
+ *
+ * A pari-mutuel prediction market where players wager on whether an
+ * asset's price will go UP or DOWN within a time window.
+ *
+ * ## Game Flow
+ * 1. Admin calls `open_market` → oracle provides opening price, round stored.
+ * 2. Players call `place_prediction` before `close_time` → tokens escrowed.
+ * 3. After `close_time`, anyone calls `settle_round` → oracle provides
+ *    closing price, outcome determined, net pool calculated.
+ * 4. Winners call `claim` → proportional share of net pool transferred.
+ *
+ * ## Pari-Mutuel Settlement
+ * - Total pool = sum of all wagers from both sides.
+ * - House fee = total_pool × house_edge_bps / 10000.
+ * - Net pool = total_pool − fee.
+ * - Each winner receives: net_pool × (their_wager / total_winning_side).
+ *
+ * ## Push Rules
+ * A round is a push (all bets refunded) when:
+ * - Close price equals open price (flat).
+ * - No bets were placed.
+ * - Only one side has bets (no opposing risk).
+ */
+export declare namespace PricePrediction {
+  export interface BetKey {
+    round_id: bigint;
+    player: string;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Token"; values: void }
+    | { tag: "OracleContract"; values: void }
+    | { tag: "MinWager"; values: void }
+    | { tag: "MaxWager"; values: void }
+    | { tag: "HouseEdgeBps"; values: void }
+    | { tag: "Round"; values: readonly [bigint] }
+    | { tag: "Bet"; values: readonly [BetKey] }
+    | { tag: "Participants"; values: readonly [bigint] };
+  export interface RoundData {
+    asset: string;
+    open_price: bigint;
+    close_price: bigint;
+    close_time: bigint;
+    total_up: bigint;
+    total_down: bigint;
+    settled: boolean;
+    outcome: number;
+    is_push: boolean;
+    net_pool: bigint;
+    winning_total: bigint;
+  }
+  export interface BetData {
+    direction: number;
+    wager: bigint;
+    claimed: boolean;
+  }
+  export interface ParticipantPosition {
+    player: string;
+    direction: number;
+    wager: bigint;
+  }
+  export interface RoundParticipantSummary {
+    has_round: boolean;
+    round_id: bigint;
+    accepting_predictions: boolean;
+    is_settled: boolean;
+    total_participants: number;
+    up_participants: number;
+    down_participants: number;
+    total_up_wager: bigint;
+    total_down_wager: bigint;
+    positions: Array<ParticipantPosition>;
+  }
+  export interface SettlementPreview {
+    has_round: boolean;
+    round_id: bigint;
+    is_provisional: boolean;
+    is_settled: boolean;
+    open_price: bigint;
+    reference_price: bigint;
+    projected_outcome: number;
+    is_push: boolean;
+    total_pool: bigint;
+    projected_net_pool: bigint;
+    projected_winning_total: bigint;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    InvalidDirection = 5,
+    RoundAlreadyExists = 6,
+    RoundNotFound = 7,
+    AlreadySettled = 8,
+    NotSettled = 9,
+    RoundNotClosed = 10,
+    RoundClosed = 11,
+    BetAlreadyPlaced = 12,
+    BetNotFound = 13,
+    AlreadyClaimed = 14,
+    NoPayout = 15,
+    WagerTooLow = 16,
+    WagerTooHigh = 17,
+    Overflow = 18,
+    InvalidCloseTime = 19,
+    InvalidPrice = 20,
+  }
+  export interface MarketOpenedEvent {
+    topics: readonly ["market_opened", bigint];
+    data: { asset: string; open_price: bigint; close_time: bigint };
+  }
+  export interface PredictionPlacedEvent {
+    topics: readonly ["prediction_placed", bigint, string];
+    data: { direction: number; wager: bigint };
+  }
+  export interface RoundSettledEvent {
+    topics: readonly ["round_settled", bigint];
+    data: { close_price: bigint; outcome: number; is_push: boolean; net_pool: bigint };
+  }
+  export interface ClaimedEvent {
+    topics: readonly ["claimed", bigint, string];
+    data: { payout: bigint };
+  }
+  export type ContractEvent = MarketOpenedEvent | PredictionPlacedEvent | RoundSettledEvent | ClaimedEvent;
+  export interface Methods {
+    /**
+     * Initialize the price prediction game.
+     *
+     * `house_edge_bps`: house edge in basis points (e.g., 500 = 5%).
+     */
+    init: { args: { admin: string; oracle_contract: string; token: string; min_wager: bigint; max_wager: bigint; house_edge_bps: bigint }; returns: void };
+    /**
+     * Open a new prediction market round. Admin only.
+     *
+     * Queries the oracle for the current price of `asset` to set the
+     * opening price. `close_time` must be in the future.
+     */
+    open_market: { args: { round_id: bigint; asset: string; close_time: bigint }; returns: void };
+    /**
+     * Player places a prediction on an open round.
+     *
+     * `direction`: 0 = Up, 1 = Down.
+     * Tokens are transferred from the player to the contract as escrow.
+     * Each player may only bet once per round.
+     */
+    place_prediction: { args: { player: string; round_id: bigint; direction: number; wager: bigint }; returns: void };
+    /**
+     * Settle a round after `close_time` has passed.
+     * Anyone can call this — the outcome is deterministic from the oracle.
+     *
+     * A round is a push (all bets refunded) when:
+     * - Close price equals open price (flat market).
+     * - No bets were placed.
+     * - Only one side has bets (no opposing risk).
+     */
+    settle_round: { args: { round_id: bigint }; returns: void };
+    /**
+     * Claim winnings for a settled round. Winners receive their
+     * proportional share of the net pool. In a push round, all
+     * players receive a full refund of their wager.
+     *
+     * Losers cannot claim (returns `NoPayout`).
+     */
+    claim: { args: { player: string; round_id: bigint }; returns: void };
+    /** View a round's state. */
+    get_round: { args: { round_id: bigint }; returns: RoundData };
+    /** View a player's bet in a round. */
+    get_bet: { args: { round_id: bigint; player: string }; returns: BetData };
+    /**
+     * Return a compact participant summary for a round.
+     *
+     * Missing rounds return `has_round = false` and an empty participant list.
+     */
+    participant_summary: { args: { round_id: bigint }; returns: RoundParticipantSummary };
+    /**
+     * Preview settlement using the round's current wagers and the oracle's
+     * current price feed. Before final settlement this view is provisional.
+     */
+    settlement_preview: { args: { round_id: bigint }; returns: SettlementPreview };
+  }
+}
+
+/**
+ * Stellarcade Prize Pool Contract
+ *
+ * Acts as the shared treasury for all game payouts and fee routing.
+ * Holds SEP-41 tokens deposited by funders, reserves amounts for active
+ * games, and transfers winnings to verified recipients.
+ *
+ * ## Storage Strategy
+ * - `instance()`: Admin, Token address. Small, fixed-size contract config;
+ *   all instance keys share one ledger entry and TTL.
+ * - `persistent()`: Available, TotalReserved, and per-game Reservation entries.
+ *   Each is a separate ledger entry with its own TTL, bumped on every write,
+ *   so cost does not scale with total contract state.
+ *
+ * ## Invariant
+ * `available + total_reserved == token.balance(contract_address)` at all
+ * times, assuming all token inflows go through `fund`. Any direct transfer
+ * to the contract address bypassing `fund` breaks this invariant.
+ */
+export declare namespace PrizePool {
+  /**
+   * Discriminants for all storage keys.
+   *
+   * Instance keys (Admin, Token): contract config, one ledger entry.
+   * Persistent keys (Available, TotalReserved, Reservation): accounting
+   * counters and per-game entries, each with their own TTL.
+   */
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Token"; values: void }
+    | { tag: "Available"; values: void }
+    | { tag: "TotalReserved"; values: void }
+    | { tag: "Reservation"; values: readonly [bigint] }
+    | { tag: "PayoutsCount"; values: void }
+    | { tag: "LastUpdateLedger"; values: void };
+  /** Per-game reservation record. */
+  export interface ReservationData {
+    /** Original reserved amount; stored for auditability. */
+    total: bigint;
+    /**
+     * Amount remaining to be paid out or released.
+     * Starts equal to `total`; decremented by `payout` and `release`.
+     */
+    remaining: bigint;
+  }
+  /** Snapshot of the pool's accounting state returned by `get_pool_state`. */
+  export interface PoolState {
+    /** Tokens free to be earmarked for new games. */
+    available: bigint;
+    /** Tokens currently earmarked across all active reservations. */
+    reserved: bigint;
+  }
+  /** Comprehensive snapshot of pool metrics for monitoring and analytics. */
+  export interface PrizePoolMetrics {
+    /** Currently liquid tokens available for reservation. */
+    available_balance: bigint;
+    /** Sum of all active, unconsumed reservations. */
+    reserved_amount: bigint;
+    /** Lifetime count of successful payout transfers. */
+    payouts_count: bigint;
+    /** Ledger index of the most recent state change. */
+    last_update_ledger: number;
+  }
+  /** Stable snapshot of admin/token configuration and basic pool metadata. */
+  export interface PrizePoolConfigSnapshot {
+    admin: string;
+    token: string;
+    available_balance: bigint;
+    reserved_amount: bigint;
+    payouts_count: bigint;
+    last_update_ledger: number;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    InsufficientFunds = 5,
+    GameAlreadyReserved = 6,
+    ReservationNotFound = 7,
+    PayoutExceedsReservation = 8,
+    Overflow = 9,
+  }
+  export interface FundedEvent {
+    topics: readonly ["funded", string];
+    data: { amount: bigint };
+  }
+  export interface ReservedEvent {
+    topics: readonly ["reserved", bigint];
+    data: { amount: bigint };
+  }
+  export interface ReleasedEvent {
+    topics: readonly ["released", bigint];
+    data: { amount: bigint };
+  }
+  export interface PaidOutEvent {
+    topics: readonly ["paid_out", string, bigint];
+    data: { amount: bigint };
+  }
+  export interface ReconciledEvent {
+    topics: readonly ["reconciled", string];
+    data: { amount: bigint; new_available: bigint };
+  }
+  export type ContractEvent = FundedEvent | ReservedEvent | ReleasedEvent | PaidOutEvent | ReconciledEvent;
+  export interface Methods {
+    /**
+     * Initialize the prize pool. May only be called once.
+     *
+     * `token` must be a deployed SEP-41 contract address (e.g., the USDC
+     * Stellar Asset Contract). All `fund` and `payout` operations transfer
+     * tokens through this contract exclusively.
+     */
+    init: { args: { admin: string; token: string }; returns: void };
+    /** Rotate the admin address. Only the current admin may perform this action. */
+    rotate_admin: { args: { admin: string; new_admin: string }; returns: void };
+    /**
+     * Transfer `amount` tokens from `from` into the pool.
+     *
+     * Any address may fund the pool (house top-up, admin, or a game contract
+     * forwarding a player's wager). The caller must sign an auth tree covering
+     * both this invocation and the downstream `token.transfer` sub-call.
+     */
+    fund: { args: { from: string; amount: bigint }; returns: void };
+    /**
+     * Earmark `amount` tokens from the available pool for a specific game.
+     *
+     * Moves `amount` from `available` into a `Reservation(game_id)` entry.
+     * Calling reserve with a `game_id` that already has a reservation returns
+     * `GameAlreadyReserved` — this is the idempotency guard preventing a
+     * buggy game contract from double-drawing from the pool.
+     */
+    reserve: { args: { admin: string; game_id: bigint; amount: bigint }; returns: void };
+    /**
+     * Return `amount` from a game's reservation back to the available pool.
+     *
+     * Used when a game ends with leftover funds (e.g., no winner, partial
+     * payout remainder, or game cancelled). A partial release (`amount <
+     * remaining`) is valid. When `remaining` reaches zero the reservation
+     * entry is removed to avoid stale storage.
+     */
+    release: { args: { admin: string; game_id: bigint; amount: bigint }; returns: void };
+    /**
+     * Transfer `amount` tokens to `to` from a game's reservation. Admin only.
+     *
+     * Multiple calls against the same `game_id` are permitted (e.g., one call
+     * per winner in a multi-winner game). Each call decrements `remaining`; the
+     * reservation is removed when `remaining` hits zero.
+     *
+     * All accounting state is updated BEFORE the external `token.transfer` to
+     * eliminate reentrancy risk: if the token call panics, state reflects the
+     * attempted debit, preventing a retry from double-paying.
+     */
+    payout: { args: { admin: string; to: string; game_id: bigint; amount: bigint }; returns: void };
+    /**
+     * Reconcile the contract's accounting with its actual token balance.
+     *
+     * If tokens were sent directly to the contract address (bypassing `fund`),
+     * this method allows an admin to sync the `available` balance upward
+     * to restore the invariant: `available + total_reserved == balance`.
+     *
+     * This method only moves accounting UPWARD. It will not reduce `available`
+     * or touch `total_reserved` or any active reservations.
+     */
+    sync: { args: { admin: string }; returns: bigint };
+    /** Returns a point-in-time snapshot of the pool's accounting state. */
+    get_pool_state: { args: Record<string, never>; returns: PoolState };
+    /**
+     * Returns a detailed snapshot of the pool's metrics, including cumulative
+     * payout counts and last sequence markers.
+     */
+    get_prize_pool_metrics: { args: Record<string, never>; returns: PrizePoolMetrics };
+    /** Returns a stable configuration snapshot for backend consumers and operators. */
+    get_config_snapshot: { args: Record<string, never>; returns: PrizePoolConfigSnapshot };
+  }
+}
+
+/**
+ * Stellarcade Random Generator Contract
+ *
+ * Provides provably fair, bounded randomness for game contracts via a
+ * two-phase request/fulfill model:
+ *
+ * 1. An authorized game contract calls `request_random`, registering a
+ *    pending request with a caller address, an upper bound (`max`) and an
+ *    optional player-supplied `client_seed`.
+ * 2. The designated oracle calls `fulfill_random` with a `server_seed`.
+ *    The result is computed deterministically from
+ *
+ *      `draw = sha256(server_seed || client_seed || request_id_be_bytes)[0..8]`
+ *
+ *    (without `client_seed` when none was supplied) and stored on-chain
+ *    alongside the seeds so anyone can verify. See "Derivation" below for
+ *    how `draw` is reduced into `[0, max)`.
+ *
+ * ## Derivation
+ * Each request records the `Derivation` in force when it was made, taken
+ * from the admin-set `EntropySourceMetadata`:
+ * - `Modulo` (v1): `draw % max`. Biased towards low values whenever `max`
+ *   does not divide 2^64.
+ * - `RejectionSampling` (v2, the default): `draw` is accepted only below the
+ *   largest multiple of `max` that fits in 64 bits, `2^64 - (2^64 % max)`.
+ *   Otherwise the preimage is re-hashed with a 4-byte big-endian counter
+ *   appended (1, 2, ...) until a draw is accepted; the result is that draw
+ *   `% max`. Accepted draws agree with `Modulo`, so the two only differ when
+ *   a draw is rejected.
+ *
+ * Results keep the derivation they were produced with, so switching the
+ * metadata never changes how earlier results verify.
+ *
+ * ## Batches and Shuffles
+ * `request_random_batch` and `request_shuffle` draw several values from the
+ * one seed a request is fulfilled with, in counter mode: draw `i` (from 0)
+ * hashes the request's preimage with `i` appended as 4 big-endian bytes and
+ * is reduced with the request's derivation (whose retries append their own
+ * counter after it). A batch of `count` values in `[0, max)` takes draws
+ * `0..count`. A shuffle of `n` is a Fisher-Yates shuffle of `[0, n)`: step
+ * `i` swaps position `n - 1 - i` with draw `i` reduced into `[0, n - i)`.
+ * `get_batch_result` returns the values with the fulfilled entry.
+ *
+ * ## Expiry
+ * Every request expires `RequestTimeout` ledgers after it is made (admin
+ * configurable, `DEFAULT_REQUEST_TIMEOUT_LEDGERS` until set). An expired
+ * request can no longer be fulfilled, and `is_expired` lets game contracts
+ * refund its wager. To keep the chain moving, the oracle closes an expired
+ * request with `close_expired`, revealing its seed without producing a
+ * result. Expired requests also count as settled when the oracle rotates to
+ * a new chain (see below).
+ *
+ * ## Fairness Model
+ * Server seeds come from a reverse hash chain the oracle commits to on-chain
+ * with `commit_seed_chain`: it picks a secret `s_n`, computes
+ * `s_{k-1} = sha256(s_k)` down to the tip `s_0`, and registers `s_0` and `n`.
+ * Each request is assigned the next chain position when it is submitted, and
+ * `fulfill_random` must reveal exactly that preimage: `sha256(s_k)` has to
+ * equal the last revealed link. The seed for every request is therefore
+ * fixed before the request exists, so the oracle cannot grind seeds at
+ * fulfillment time. The oracle can predict the next result, though, so it
+ * must not be able to move the next request onto another seed: a new chain
+ * can only be committed once every seed of the current one is assigned and
+ * each of those requests is fulfilled or expired. Because the oracle knows
+ * its chain in advance, a player can also pass a `client_seed` the oracle
+ * cannot know when committing the chain; the result then depends on both
+ * parties' entropy. After fulfillment, any party can re-derive the result
+ * from the stored seeds and derivation and check the seed against the chain
+ * with `sha256^k(seed) == tip`. `verify_result` performs both checks
+ * on-chain.
+ *
+ * ## Storage Strategy
+ * - `instance()`: Admin, Oracle, entropy metadata, the current seed chain
+ *   id. Fixed contract-level config.
+ * - `persistent()`: AuthorizedCaller entries, PendingRequest entries,
+ *   FulfilledRequest entries, SeedChain entries — each a separate ledger
+ *   entry with TTL bumped on every write so active requests never expire
+ *   mid-game.
+ */
+export declare namespace RandomGenerator {
+  /**
+   * All storage key discriminants.
+   *
+   * Instance keys (Admin, Oracle): contract config, small fixed set.
+   * Persistent keys: per-caller whitelist entries, per-request data.
+   */
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Oracle"; values: void }
+    | { tag: "EntropyMetadata"; values: void }
+    | { tag: "CurrentSeedChain"; values: void }
+    | { tag: "RequestTimeout"; values: void }
+    | { tag: "AuthorizedCaller"; values: readonly [string] }
+    | { tag: "PendingRequest"; values: readonly [bigint] }
+    | { tag: "FulfilledRequest"; values: readonly [bigint] }
+    | { tag: "BatchResult"; values: readonly [bigint] }
+    | { tag: "ExpiredRequest"; values: readonly [bigint] }
+    | { tag: "SeedChain"; values: readonly [number] };
+  /** How a hash draw is reduced into `[0, max)`. See the module docs. */
+  export type Derivation =
+    | { tag: "Modulo"; values: void }
+    | { tag: "RejectionSampling"; values: void };
+  /** What a request draws from its seed. */
+  export type RequestKind =
+    | { tag: "Single"; values: void }
+    | { tag: "Batch"; values: readonly [number] }
+    | { tag: "Shuffle"; values: void };
+  export const enum RequestState {
+    Missing = 0,
+    Pending = 1,
+    Fulfilled = 2,
+    Expired = 3,
+  }
+  /** A pending randomness request registered by an authorized game contract. */
+  export interface PendingEntry {
+    caller: string;
+    max: bigint;
+    kind: RequestKind;
+    /** Player-supplied entropy mixed into the result, if any. */
+    client_seed: Buffer | undefined;
+    /** Derivation in force when the request was made. */
+    derivation: Derivation;
+    /** Seed chain and position whose seed will fulfill the request. */
+    chain_id: number;
+    seed_index: number;
+    /** First ledger at which the request can no longer be fulfilled. */
+    expires_at: number;
+  }
+  /**
+   * A fulfilled request with its deterministic result and the oracle seed.
+   *
+   * Both `server_seed` and `result` are stored on-chain so any external party
+   * can verify correctness without trusting the oracle's off-chain claims.
+   */
+  export interface FulfilledEntry {
+    caller: string;
+    max: bigint;
+    kind: RequestKind;
+    /** Oracle-provided seed; stored to allow on-chain result verification. */
+    server_seed: Buffer;
+    /** Player-supplied entropy mixed into the result, if any. */
+    client_seed: Buffer | undefined;
+    /**
+     * Derived from `sha256(server_seed || client_seed || request_id_be)`
+     * with `derivation`; always in `[0, max)`. The first value for batch and
+     * shuffle requests.
+     */
+    result: bigint;
+    derivation: Derivation;
+    /**
+     * `server_seed` is link `seed_index` of chain `chain_id`:
+     * `sha256^seed_index(server_seed) == tip`.
+     */
+    chain_id: number;
+    seed_index: number;
+  }
+  /** A fulfilled batch or shuffle request with all of its values. */
+  export interface BatchResult {
+    entry: FulfilledEntry;
+    values: Array<bigint>;
+  }
+  /**
+   * A reverse hash chain of server seeds committed by the oracle.
+   *
+   * Link `k` is the seed for the `k`-th request assigned to the chain, and
+   * `sha256` of link `k` is link `k - 1`; link 0 is the committed `tip`.
+   */
+  export interface SeedChain {
+    chain_id: number;
+    tip: Buffer;
+    /** Number of seeds in the chain, excluding the tip. */
+    length: number;
+    /** Seeds assigned to requests so far. */
+    assigned: number;
+    /** Seeds revealed by `fulfill_random` so far. */
+    revealed: number;
+    /** Last revealed link; `tip` before the first reveal. */
+    head: Buffer;
+    /** Latest `expires_at` of the requests assigned to the chain. */
+    last_expiry: number;
+  }
+  export interface RequestStatus {
+    request_id: bigint;
+    state: RequestState;
+    caller: string | undefined;
+    max: bigint | undefined;
+    result: bigint | undefined;
+    server_seed: Buffer | undefined;
+    client_seed: Buffer | undefined;
+    chain_id: number | undefined;
+    seed_index: number | undefined;
+    expires_at: number | undefined;
+  }
+  /**
+   * Describes the entropy source used by this contract.
+   *
+   * Stored in instance storage so it lives as long as the contract itself.
+   * `derivation` applies to requests made after the metadata is set; the other
+   * fields are informational and do not alter randomness output.
+   */
+  export interface EntropySourceMetadata {
+    /** Semantic version of the entropy source implementation (e.g. "1.0.0"). */
+    version: string;
+    /** Human-readable description of the entropy source type. */
+    source_type: string;
+    /** Hash algorithm used to derive random output. */
+    hash_algorithm: string;
+    /** Number of bytes from the hash digest used for the random value. */
+    output_bytes: number;
+    /** Derivation used for new requests. */
+    derivation: Derivation;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    /** `max < 2` — a range of [0, 0] produces no randomness. */
+    InvalidBound = 4,
+    /** A request with this `request_id` already exists (pending or fulfilled). */
+    DuplicateRequestId = 5,
+    RequestNotFound = 6,
+    /** `fulfill_random` was called a second time for the same `request_id`. */
+    AlreadyFulfilled = 7,
+    /** The `caller` passed to `request_random` is not in the whitelist. */
+    UnauthorizedCaller = 8,
+    /** The oracle has not committed a seed chain yet. */
+    SeedChainNotSet = 9,
+    /** Every seed of the current chain is assigned to a request. */
+    SeedChainExhausted = 10,
+    /**
+     * A new chain was committed before every seed of the current one was
+     * assigned and every request on it fulfilled or expired.
+     */
+    SeedChainInUse = 11,
+    /** `sha256(server_seed)` is not the last revealed link of the chain. */
+    InvalidSeed = 12,
+    /** An earlier request must be fulfilled first. */
+    SeedOutOfOrder = 13,
+    /** A seed chain must hold at least one seed. */
+    InvalidChainLength = 14,
+    /**
+     * Batch `count` or shuffle size outside `1..=MAX_BATCH_SIZE`
+     * (`2..=MAX_BATCH_SIZE` for shuffles).
+     */
+    InvalidBatchSize = 15,
+    /** The request passed its expiry ledger before it was fulfilled. */
+    RequestExpired = 16,
+    /** `close_expired` called on a request that has not expired yet. */
+    NotExpired = 17,
+    /** A request timeout must be at least one ledger. */
+    InvalidTimeout = 18,
+  }
+  export interface RandomRequestedEvent {
+    topics: readonly ["random_requested", bigint, string];
+    data: { max: bigint; kind: RequestKind; client_seed: Buffer | undefined };
+  }
+  /** Emits the server_seed so off-chain verifiers do not need a `get_result` call. */
+  export interface RandomFulfilledEvent {
+    topics: readonly ["random_fulfilled", bigint];
+    data: { result: bigint; server_seed: Buffer };
+  }
+  export interface SeedChainCommittedEvent {
+    topics: readonly ["seed_chain_committed", number];
+    data: { tip: Buffer; length: number };
+  }
+  export interface RandomRequestExpiredEvent {
+    topics: readonly ["random_request_expired", bigint];
+    data: { seed_index: number };
+  }
+  export interface RandomFulfillmentRejectedEvent {
+    topics: readonly ["random_fulfillment_rejected", bigint];
+    data: { reason: number };
+  }
+  export type ContractEvent = RandomRequestedEvent | RandomFulfilledEvent | SeedChainCommittedEvent | RandomRequestExpiredEvent | RandomFulfillmentRejectedEvent;
+  export interface Methods {
+    /**
+     * Initialize the contract. May only be called once.
+     *
+     * `oracle` is the sole address permitted to call `fulfill_random`. It is
+     * expected to be a backend service that pre-commits server seeds off-chain
+     * before each game round begins.
+     */
+    init: { args: { admin: string; oracle: string }; returns: void };
+    /** Add a game contract to the caller whitelist. Admin only. */
+    authorize: { args: { admin: string; caller: string }; returns: void };
+    /** Remove a game contract from the caller whitelist. Admin only. */
+    revoke: { args: { admin: string; caller: string }; returns: void };
+    /**
+     * Commit a new reverse hash chain of server seeds. Oracle only.
+     *
+     * `tip` is `sha256^length(secret)`. Later requests are assigned the
+     * chain's seeds in order, starting with the preimage of `tip`. Rejected
+     * until every seed of the current chain is assigned and every request on
+     * it is fulfilled or expired, so the oracle can neither swap seeds under
+     * a live request nor steer the next request away from a seed whose
+     * result it dislikes.
+     */
+    commit_seed_chain: { args: { oracle: string; tip: Buffer; length: number }; returns: number };
+    /**
+     * Submit a randomness request. Only whitelisted callers may call this.
+     *
+     * `max` must be >= 2. The fulfilled result will be in `[0, max - 1]`.
+     * `request_id` must be globally unique — rejected if a pending or
+     * fulfilled entry for the same ID already exists. The request is assigned
+     * the next seed of the current seed chain. `client_seed`, when given, is
+     * mixed into the result so it does not depend on the oracle's seed alone.
+     */
+    request_random: { args: { caller: string; request_id: bigint; max: bigint; client_seed: Buffer | undefined }; returns: void };
+    /**
+     * Submit a request for `count` values in `[0, max - 1]`, drawn from one
+     * seed in counter mode. Same rules as `request_random`; `count` must be
+     * in `1..=MAX_BATCH_SIZE`. Values are read with `get_batch_result`.
+     */
+    request_random_batch: { args: { caller: string; request_id: bigint; max: bigint; count: number; client_seed: Buffer | undefined }; returns: void };
+    /**
+     * Submit a request for a uniformly random permutation of `[0, n - 1]`.
+     * Same rules as `request_random`; `n` must be in `2..=MAX_BATCH_SIZE`.
+     * The permutation is read with `get_batch_result`.
+     */
+    request_shuffle: { args: { caller: string; request_id: bigint; n: number; client_seed: Buffer | undefined }; returns: void };
+    /**
+     * Fulfill a pending randomness request. Oracle only.
+     *
+     * The result is derived from:
+     *   `sha256(server_seed || client_seed || request_id_be_bytes)[0..8]`
+     *
+     * with `client_seed` omitted when the request has none; batch and shuffle
+     * requests derive all of their values in counter mode. The seeds and
+     * results are persisted for on-chain verification.
+     * `server_seed` must be the seed assigned to the request: the next link of
+     * its chain, with `sha256(server_seed)` equal to the last revealed link.
+     * Requests are therefore fulfilled in the order they were submitted.
+     */
+    fulfill_random: { args: { oracle: string; request_id: bigint; server_seed: Buffer }; returns: void };
+    /**
+     * Close an expired request by revealing its seed. Oracle only.
+     *
+     * No result is derived; the seed only advances the chain so the requests
+     * behind it can be fulfilled. The same ordering and seed checks as
+     * `fulfill_random` apply.
+     */
+    close_expired: { args: { oracle: string; request_id: bigint; server_seed: Buffer }; returns: void };
+    /**
+     * Set how many ledgers new requests stay fulfillable. Admin only.
+     * Pending requests keep the expiry they were given.
+     */
+    set_request_timeout: { args: { admin: string; ledgers: number }; returns: void };
+    /** Return the timeout applied to new requests, in ledgers. */
+    get_request_timeout: { args: Record<string, never>; returns: number };
+    /**
+     * Whether a request expired before it was fulfilled, so the game that
+     * made it can refund the wager. `false` for missing requests.
+     */
+    is_expired: { args: { request_id: bigint }; returns: boolean };
+    /**
+     * Set entropy source version metadata. Admin only.
+     *
+     * `metadata.derivation` selects the derivation for requests made from
+     * now on; pending and fulfilled requests keep the one they recorded.
+     */
+    set_entropy_metadata: { args: { admin: string; metadata: EntropySourceMetadata }; returns: void };
+    /** Read the current entropy source version metadata. */
+    get_entropy_metadata: { args: Record<string, never>; returns: EntropySourceMetadata };
+    /**
+     * Return the fulfilled result for a `request_id`.
+     *
+     * Returns `RequestNotFound` if the request is still pending or never existed.
+     */
+    get_result: { args: { request_id: bigint }; returns: FulfilledEntry };
+    /**
+     * Return the fulfilled entry for a `request_id` with all of its values.
+     *
+     * `values` holds the batch or the permutation; for a single-value
+     * request it is just `result`. Returns `RequestNotFound` if the request
+     * is still pending or never existed.
+     */
+    get_batch_result: { args: { request_id: bigint }; returns: BatchResult };
+    /**
+     * Recompute a fulfilled request on-chain.
+     *
+     * Returns `true` when the stored values match the stored seeds under the
+     * request's recorded derivation and `server_seed` hashes to the tip of
+     * its seed chain in `seed_index` steps. Costs one hash per chain position
+     * and per value, so large batches or late positions of a long chain are
+     * best verified off-chain.
+     */
+    verify_result: { args: { request_id: bigint }; returns: boolean };
+    /** Return the seed chain requests are currently assigned to. */
+    get_current_seed_chain: { args: Record<string, never>; returns: SeedChain };
+    /** Return a committed seed chain by id, including rotated-out chains. */
+    get_seed_chain: { args: { chain_id: number }; returns: SeedChain };
+    /** Return the lifecycle status for a request id. */
+    get_request_status: { args: { request_id: bigint }; returns: RequestStatus };
+  }
+}
+
+export declare namespace ReferralSystem {
+  /** Referral event types that generate rewards. */
+  export const enum EventType {
+    GamePlayed = 0,
+    Deposit = 1,
+    PrizeClaimed = 2,
+  }
+  /** Per-user referral state persisted on-chain. */
+  export interface ReferralState {
+    /** The referrer who referred this user (zero-address means none). */
+    referrer: string;
+    /** Addresses this user has referred. */
+    referees: Array<string>;
+    /** Total reward earned (lifetime). */
+    total_earned: bigint;
+    /** Pending reward available to claim. */
+    pending_reward: bigint;
+    /** Number of referral events recorded. */
+    event_count: bigint;
+  }
+  export interface ReferralRewardPreview {
+    qualifies: boolean;
+    reason: string;
+    referrer: string | undefined;
+    referrer_reward: bigint;
+    referee_reward: bigint;
+    reward_bps: number;
+    minimum_amount: bigint;
+    /** `0` indicates no cap is configured in current settlement rules. */
+    reward_cap: bigint;
+    cap_applied: boolean;
+  }
+  /** Storage key layout. */
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "RewardContract"; values: void }
+    | { tag: "RewardBps"; values: void }
+    | { tag: "State"; values: readonly [string] }
+    | { tag: "ReferredBy"; values: readonly [string] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    AlreadyReferred = 5,
+    SelfReferral = 6,
+    ReferrerNotRegistered = 7,
+    NoPendingRewards = 8,
+    AlreadyClaimed = 9,
+    InvalidEventType = 10,
+    Overflow = 99,
+  }
+  export interface InitializedEvent {
+    topics: readonly ["initialized", string];
+    data: { reward_contract: string; reward_bps: number };
+  }
+  export interface ReferrerRegisteredEvent {
+    topics: readonly ["referrer_registered", string, string];
+    data: Record<string, never>;
+  }
+  export interface ReferralEventRecordedEvent {
+    topics: readonly ["referral_event_recorded", string, string];
+    data: { event_type: EventType; amount: bigint; reward: bigint };
+  }
+  export interface RewardClaimedEvent {
+    topics: readonly ["reward_claimed", string];
+    data: { amount: bigint };
+  }
+  export type ContractEvent = InitializedEvent | ReferrerRegisteredEvent | ReferralEventRecordedEvent | RewardClaimedEvent;
+  export interface Methods {
+    /**
+     * Initializes the referral system contract.
+     *
+     * * `admin`           – address that can call privileged methods.
+     * * `reward_contract` – address of the contract/account that funds rewards.
+     */
+    init: { args: { admin: string; reward_contract: string }; returns: void };
+    /** Update the reward percentage (in basis points). Admin only. */
+    set_reward_bps: { args: { admin: string; bps: number }; returns: void };
+    /** Update the reward contract address. Admin only. */
+    set_reward_contract: { args: { admin: string; reward_contract: string }; returns: void };
+    /**
+     * Register `referrer` as the referrer of `user`.
+     *
+     * * Both `user` and `referrer` must authorize the call.
+     * * A user cannot refer themselves.
+     * * A user can only be referred once.
+     */
+    register_referrer: { args: { user: string; referrer: string }; returns: void };
+    /**
+     * Record a referral event for `user`.
+     *
+     * Called by an admin/operator when a qualifying action occurs
+     * (e.g. game played, deposit made). The `amount` is the transaction value
+     * and the reward is computed as `amount * reward_bps / 10_000`.
+     *
+     * The reward is credited to the **referrer** of `user`.
+     */
+    record_referral_event: { args: { admin: string; user: string; event_type: EventType; amount: bigint }; returns: void };
+    /**
+     * Claim all pending referral rewards for `user`.
+     *
+     * Marks the pending balance as claimed. The actual token transfer is
+     * expected to be handled by the reward contract integration; this method
+     * records the accounting and emits an event for off-chain settlement or
+     * cross-contract calls.
+     */
+    claim_referral_reward: { args: { user: string }; returns: bigint };
+    /** Return the full referral state for a user. */
+    referral_state: { args: { user: string }; returns: ReferralState };
+    /** Return the referrer of a user, if any. */
+    get_referrer: { args: { user: string }; returns: string | undefined };
+    /** Return the reward contract address. */
+    get_reward_contract: { args: Record<string, never>; returns: string };
+    /** Return the current reward basis points. */
+    get_reward_bps: { args: Record<string, never>; returns: number };
+    /** Preview reward outcomes for a referral event without mutating storage. */
+    preview_referral_reward: { args: { user: string; amount: bigint }; returns: ReferralRewardPreview };
+  }
+}
+
+export declare namespace RevenueSplit {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Token"; values: void }
+    | { tag: "SplitConfig"; values: readonly [string] }
+    | { tag: "StreamBalance"; values: readonly [string] }
+    | { tag: "RecipientBalance"; values: readonly [string, string] };
+  export interface RecipientWeight {
+    recipient: string;
+    /** Weight in basis points (0–10000). All recipients must sum to 10000. */
+    weight_bps: number;
+  }
+  export interface SplitConfig {
+    stream_id: string;
+    recipients: Array<RecipientWeight>;
+  }
+  export interface SplitConfiguredEvent {
+    topics: readonly ["split_configured", string];
+    data: Record<string, never>;
+  }
+  export interface RevenueDepositedEvent {
+    topics: readonly ["revenue_deposited", string];
+    data: { amount: bigint };
+  }
+  export interface RevenueDistributedEvent {
+    topics: readonly ["revenue_distributed", string];
+    data: { total: bigint };
+  }
+  export type ContractEvent = SplitConfiguredEvent | RevenueDepositedEvent | RevenueDistributedEvent;
+  export interface Methods {
+    /** Initialize with admin and the token used for splits. */
+    init: { args: { admin: string; token_address: string }; returns: void };
+    /**
+     * Configure or update a split for a stream. Admin-only.
+     * Recipient weights must sum to exactly 10000 BPS.
+     */
+    set_split_config: { args: { stream_id: string; recipients: Array<RecipientWeight> }; returns: void };
+    /** Deposit revenue into a stream. Any caller may deposit; they must auth. */
+    deposit_revenue: { args: { depositor: string; stream_id: string; amount: bigint }; returns: void };
+    /** Distribute all pending revenue in a stream to recipients. Admin-only. */
+    distribute: { args: { stream_id: string }; returns: void };
+    /** Query cumulative amount distributed to a recipient for a stream. */
+    recipient_balance: { args: { stream_id: string; recipient: string }; returns: bigint };
+  }
+}
+
+export declare namespace RewardDistribution {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "TreasuryContract"; values: void }
+    | { tag: "BalanceContract"; values: void }
+    | { tag: "Campaign"; values: readonly [number] }
+    | { tag: "Accrued"; values: readonly [number, string] }
+    | { tag: "Claimed"; values: readonly [number, string] };
+  export const enum CampaignStatus {
+    Active = 0,
+    Exhausted = 1,
+    Closed = 2,
+  }
+  export interface CampaignData {
+    /** SHA-256 of off-chain rules document */
+    rules_hash: Buffer;
+    /** Total budget allocated when the campaign was defined */
+    budget: bigint;
+    /** Remaining distributable balance (budget − already accrued) */
+    remaining: bigint;
+    status: CampaignStatus;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    CampaignNotFound = 4,
+    CampaignAlreadyExists = 5,
+    CampaignExhausted = 6,
+    CampaignNotActive = 7,
+    NothingToClaim = 8,
+    AlreadyClaimed = 9,
+    InvalidAmount = 10,
+    Overflow = 11,
+  }
+  export interface ContractInitializedEvent {
+    topics: readonly ["contract_initialized", string];
+    data: { treasury_contract: string; balance_contract: string };
+  }
+  export interface CampaignDefinedEvent {
+    topics: readonly ["campaign_defined", number];
+    data: { budget: bigint };
+  }
+  export interface RewardAccruedEvent {
+    topics: readonly ["reward_accrued", number];
+    data: { user: string; amount: bigint; new_total: bigint };
+  }
+  export interface RewardClaimedEvent {
+    topics: readonly ["reward_claimed", number];
+    data: { user: string; amount: bigint };
+  }
+  export type ContractEvent = ContractInitializedEvent | CampaignDefinedEvent | RewardAccruedEvent | RewardClaimedEvent;
+  export interface Methods {
+    /**
+     * Initialise the contract.  Can only be called once.
+     *
+     * * `admin`             — privileged account for campaign management.
+     * * `treasury_contract` — address of the on-chain treasury holding
+     *                         campaign budgets (stored for composability).
+     * * `balance_contract`  — address of the token / balance contract used
+     *                         to settle claims.
+     */
+    init: { args: { admin: string; treasury_contract: string; balance_contract: string }; returns: void };
+    /**
+     * Define a new reward campaign.  Admin only.
+     *
+     * * `campaign_id` — unique numeric identifier.
+     * * `rules_hash`  — SHA-256 of the off-chain eligibility rules document.
+     * * `budget`      — maximum tokens distributable; must be > 0.
+     */
+    define_reward_campaign: { args: { campaign_id: number; rules_hash: Buffer; budget: bigint }; returns: void };
+    /**
+     * Record a pending reward for `user`.  Admin only.
+     *
+     * The call is additive — repeated calls accumulate until the user claims.
+     * The campaign's `remaining` balance is decremented immediately to uphold
+     * the invariant `Σ accrued ≤ budget`.
+     */
+    accrue_reward: { args: { user: string; campaign_id: number; amount: bigint }; returns: void };
+    /**
+     * Claim all accrued rewards for `user` in a campaign.
+     *
+     * * The user must authenticate.
+     * * The reentrancy guard (`Claimed` flag) is set **before** any external
+     *   settlement call.
+     * * Returns the amount of tokens claimed.
+     */
+    claim_reward: { args: { user: string; campaign_id: number }; returns: bigint };
+    /** Return the current state of a campaign, or `None` if it does not exist. */
+    campaign_state: { args: { campaign_id: number }; returns: CampaignData | undefined };
+    /** Return the unclaimed accrued balance for `user` in a campaign. */
+    accrued_for: { args: { user: string; campaign_id: number }; returns: bigint };
+    /** Return whether `user` has already claimed from `campaign_id`. */
+    has_claimed: { args: { user: string; campaign_id: number }; returns: boolean };
+  }
+}
+
+/**
+ * # Reward Vesting Contract
+ *
+ * Deterministic vesting of game rewards over a configurable cliff + linear
+ * schedule. Rewards may be revoked by the admin before full vesting.
+ */
+export declare namespace RewardVesting {
+  export interface VestingSchedule {
+    schedule_id: bigint;
+    user: string;
+    amount: bigint;
+    start_timestamp: bigint;
+    cliff_seconds: bigint;
+    duration_seconds: bigint;
+    claimed: bigint;
+    revoked: boolean;
+  }
+  export interface VestingSummary {
+    total_allocation: bigint;
+    claimed_amount: bigint;
+    claimable_amount: bigint;
+    remaining_amount: bigint;
+    current_timestamp: bigint;
+    is_fully_vested: boolean;
+    has_active_schedules: boolean;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Token"; values: void }
+    | { tag: "NextScheduleId"; values: void }
+    | { tag: "ScheduleMap"; values: void }
+    | { tag: "UserSchedules"; values: readonly [string] };
+  export interface VestingInitializedEvent {
+    topics: readonly ["vesting_initialized"];
+    data: { admin: string; token_address: string };
+  }
+  export interface VestingScheduledEvent {
+    topics: readonly ["vesting_scheduled"];
+    data: { user: string; schedule_id: bigint; amount: bigint };
+  }
+  export interface VestingClaimedEvent {
+    topics: readonly ["vesting_claimed"];
+    data: { user: string; total_claim: bigint };
+  }
+  export interface VestingRevokedEvent {
+    topics: readonly ["vesting_revoked"];
+    data: { schedule_id: bigint; user: string; unvested: bigint };
+  }
+  export type ContractEvent = VestingInitializedEvent | VestingScheduledEvent | VestingClaimedEvent | VestingRevokedEvent;
+  export interface Methods {
+    /** Initialise the vesting contract. Must be called once. */
+    init: { args: { admin: string; token_address: string }; returns: void };
+    /**
+     * Create a new vesting schedule for `user`.
+     *
+     * * `amount`             – tokens to vest (> 0)
+     * * `start_timestamp`    – when vesting begins (UNIX seconds)
+     * * `cliff_seconds`      – seconds from start before any claim
+     * * `duration_seconds`   – total linear-vesting window (> 0)
+     */
+    create_vesting_schedule: { args: { user: string; amount: bigint; start_timestamp: bigint; cliff_seconds: bigint; duration_seconds: bigint }; returns: bigint };
+    /** Claim all currently vested tokens for `user`. Returns amount transferred. */
+    claim_vested: { args: { user: string }; returns: bigint };
+    /** Revoke a vesting schedule. Unvested tokens are returned to the admin. */
+    revoke_schedule: { args: { schedule_id: bigint }; returns: bigint };
+    /** Return all vesting schedules for `user`. */
+    vesting_state: { args: { user: string }; returns: Array<VestingSchedule> };
+    /**
+     * Return a vesting summary for `user` with allocation, claimed, claimable, and remaining amounts.
+     * Returns empty summary with zero values if user has no vesting schedules.
+     */
+    get_vesting_summary: { args: { user: string }; returns: VestingSummary };
+  }
+}
+
+/**
+ * # Session Nonce Manager Contract
+ *
+ * A foundational anti-replay primitive for write-heavy contracts and
+ * signature-based actions. Each nonce is issued for a specific `(account,
+ * purpose)` pair, tracked as active until consumed, and may be
+ * administratively revoked before use.
+ */
+export declare namespace SessionNonceManager {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "NextNonce"; values: readonly [string, string] }
+    | { tag: "NonceRecord"; values: readonly [string, string, bigint] };
+  export type NonceState =
+    | { tag: "Active"; values: void }
+    | { tag: "Consumed"; values: void }
+    | { tag: "Revoked"; values: void }
+    | { tag: "Expired"; values: void }
+    | { tag: "Missing"; values: void };
+  export interface NonceRecord {
+    state: NonceState;
+    expires_at_ledger: number;
+  }
+  export interface NonceStatus {
+    is_present: boolean;
+    state: NonceState;
+    remaining_ttl: number | undefined;
+  }
+  export interface NonceManagerInitializedEvent {
+    topics: readonly ["nonce_manager_initialized"];
+    data: { admin: string };
+  }
+  export interface NonceIssuedEvent {
+    topics: readonly ["nonce_issued"];
+    data: { account: string; purpose: string; nonce: bigint };
+  }
+  export interface NonceConsumedEvent {
+    topics: readonly ["nonce_consumed"];
+    data: { account: string; purpose: string; nonce: bigint };
+  }
+  export interface NonceRevokedEvent {
+    topics: readonly ["nonce_revoked"];
+    data: { account: string; purpose: string; nonce: bigint };
+  }
+  export type ContractEvent = NonceManagerInitializedEvent | NonceIssuedEvent | NonceConsumedEvent | NonceRevokedEvent;
+  export interface Methods {
+    /** Initialise the contract and set the admin. Must be called exactly once. */
+    init: { args: { admin: string }; returns: void };
+    /** Issue the next nonce for `(account, purpose)` and return its value. */
+    issue_nonce: { args: { account: string; purpose: string }; returns: bigint };
+    /** Consume `nonce` for `(account, purpose)`, marking it as used. */
+    consume_nonce: { args: { account: string; nonce: bigint; purpose: string }; returns: void };
+    /** Return `true` if `nonce` for `(account, purpose)` is still active. */
+    is_nonce_valid: { args: { account: string; nonce: bigint; purpose: string }; returns: boolean };
+    /**
+     * Return lifecycle and TTL metadata for `(account, purpose, nonce)`.
+     * The returned status distinguishes active, consumed, revoked, expired,
+     * and missing nonces.
+     */
+    nonce_status: { args: { account: string; nonce: bigint; purpose: string }; returns: NonceStatus };
+    /** Revoke `nonce` for `(account, purpose)`. Only the admin may revoke nonces. */
+    revoke_nonce: { args: { account: string; purpose: string; nonce: bigint }; returns: void };
+  }
+}
+
+export declare namespace SettlementQueue {
+  export const enum SettlementStatus {
+    Pending = 0,
+    Processed = 1,
+    Failed = 2,
+    Processing = 3,
+  }
+  export interface BatchStatus {
+    pending: number;
+    processing: number;
+    succeeded: number;
+    failed: number;
+  }
+  export interface SettlementData {
+    account: string;
+    amount: bigint;
+    reason: string;
+    status: SettlementStatus;
+    error_code: number | undefined;
+  }
+  export interface QueueMetrics {
+    depth: bigint;
+    oldest_pending: string | undefined;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "RewardContract"; values: void }
+    | { tag: "TreasuryContract"; values: void }
+    | { tag: "Settlement"; values: readonly [string] }
+    | { tag: "QueueHead"; values: void }
+    | { tag: "QueueTail"; values: void }
+    | { tag: "QueueItem"; values: readonly [bigint] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidBatchSize = 4,
+    SettlementNotFound = 5,
+    InvalidState = 6,
+    Overflow = 7,
+  }
+  export interface ContractInitializedEvent {
+    topics: readonly ["contract_initialized", string];
+    data: { reward_contract: string; treasury_contract: string };
+  }
+  export interface SettlementEnqueuedEvent {
+    topics: readonly ["settlement_enqueued", string, string];
+    data: { amount: bigint };
+  }
+  export interface SettlementProcessedEvent {
+    topics: readonly ["settlement_processed", string];
+    data: { status: SettlementStatus };
+  }
+  export interface SettlementFailedEvent {
+    topics: readonly ["settlement_failed", string];
+    data: { error_code: number };
+  }
+  export interface SettlementReplayedEvent {
+    topics: readonly ["settlement_replayed", string, string];
+    data: Record<string, never>;
+  }
+  export type ContractEvent = ContractInitializedEvent | SettlementEnqueuedEvent | SettlementProcessedEvent | SettlementFailedEvent | SettlementReplayedEvent;
+  export interface Methods {
+    /** Initialise the contract. */
+    init: { args: { admin: string; reward_contract: string; treasury_contract: string }; returns: void };
+    /** Enqueue a new settlement. */
+    enqueue_settlement: { args: { settlement_id: string; account: string; amount: bigint; reason: string }; returns: void };
+    /** Process the next batch of settlements. */
+    process_next: { args: { batch_size: number }; returns: number };
+    /** Mark a settlement as failed. */
+    mark_failed: { args: { settlement_id: string; error_code: number }; returns: void };
+    /**
+     * Replay a failed settlement by restoring it to pending and appending it
+     * to the tail of the queue.
+     */
+    replay_settlement: { args: { admin: string; settlement_id: string }; returns: void };
+    /** Query the state of a settlement. */
+    settlement_state: { args: { settlement_id: string }; returns: SettlementData | undefined };
+    /** Query current batch status by index range. */
+    get_batch_status: { args: { start_index: bigint; end_index: bigint }; returns: BatchStatus };
+    /** Return queue depth from the current head and tail pointers. */
+    queue_depth: { args: Record<string, never>; returns: bigint };
+    /** Return the oldest pending settlement id, if any. */
+    oldest_pending_settlement: { args: Record<string, never>; returns: string | undefined };
+    /** Return both queue depth and the oldest pending marker in one read. */
+    queue_metrics: { args: Record<string, never>; returns: QueueMetrics };
+  }
+}
+
+/**
+ * Stellarcade Speed Trivia Contract
+ *
+ * Players compete to answer a question as quickly as possible.
+ * Rewards are shared among correct answers submitted before the deadline,
+ * split according to the round's [`PayoutCurve`]. Answers are ranked by the
+ * ledger timestamp of their commitment, ties broken by player address.
+ *
+ * ## Commit-Reveal
+ * Answers are submitted in two phases so nobody can copy a correct answer
+ * from pending transactions:
+ * 1. Until `deadline`, players call `commit_answer` with
+ *    `sha256(answer || salt || player)`, where `player` is the address's XDR
+ *    encoding (see [`answer_commitment`]).
+ * 2. After `deadline` and until `reveal_deadline`, players call
+ *    `reveal_answer` with the plaintext answer and salt. Correctness is
+ *    evaluated at reveal; speed is still measured at commit time.
+ *
+ * Commitments that are not revealed in time are forfeited: they never count
+ * as correct and cannot claim a reward. The round can only be finalized once
+ * the reveal window has closed.
+ */
+export declare namespace SpeedTrivia {
+  export const enum RoundStatus {
+    Open = 0,
+    Finalized = 1,
+  }
+  /**
+   * How a round's `reward_amount` is split among correct answers, in
+   * leaderboard order. Amounts nobody qualifies for, including rounding dust,
+   * are released back to the prize pool at finalization.
+   */
+  export type PayoutCurve =
+    | { tag: "Equal"; values: void }
+    | { tag: "LinearDecay"; values: void }
+    | { tag: "Tiers"; values: readonly [Array<number>] }
+    | { tag: "FirstCorrect"; values: readonly [number] };
+  export interface RoundData {
+    answer_commitment: Buffer;
+    reward_amount: bigint;
+    payout_curve: PayoutCurve;
+    /** Share of each winner under `PayoutCurve::Equal`, 0 for other curves */
+    payout_per_winner: bigint;
+    /** Sum of all winners' shares, set at finalization */
+    total_payout: bigint;
+    winner_count: number;
+    status: RoundStatus;
+    /** Last timestamp at which answers can be committed */
+    deadline: bigint;
+    /** Last timestamp at which commitments can be revealed */
+    reveal_deadline: bigint;
+    opened_at: bigint;
+  }
+  export const enum RoundSnapshotStatus {
+    Uninitialized = 0,
+    Open = 1,
+    Finalized = 2,
+  }
+  export interface RoundSnapshot {
+    status: RoundSnapshotStatus;
+    round_id: bigint;
+    opened_at: bigint;
+    deadline: bigint;
+    reveal_deadline: bigint;
+    now: bigint;
+    answer_window_open: boolean;
+    reveal_window_open: boolean;
+    reward_amount: bigint;
+    payout_curve: PayoutCurve;
+    winner_count: number;
+    payout_per_winner: bigint;
+    total_payout: bigint;
+  }
+  export interface LeaderboardEntry {
+    player: string;
+    correct: boolean;
+    timestamp: bigint;
+    /**
+     * Reward under the round's payout curve given the answers revealed so
+     * far; final once the round is finalized
+     */
+    share: bigint;
+  }
+  export interface LeaderboardSnapshot {
+    status: RoundSnapshotStatus;
+    round_id: bigint;
+    entries: Array<LeaderboardEntry>;
+  }
+  export interface Submission {
+    /** `sha256(answer || salt || player)`, see [`answer_commitment`] */
+    commitment: Buffer;
+    revealed: boolean;
+    /** Only meaningful once `revealed` */
+    correct: boolean;
+    claimed: boolean;
+    timestamp: bigint;
+    /** Reward owed, set at finalization */
+    payout: bigint;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "PrizePoolContract"; values: void }
+    | { tag: "BalanceContract"; values: void }
+    | { tag: "LatestRoundId"; values: void }
+    | { tag: "Round"; values: readonly [bigint] }
+    | { tag: "Submission"; values: readonly [bigint, string] }
+    | { tag: "Leaderboard"; values: readonly [bigint] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    RoundAlreadyExists = 4,
+    RoundNotFound = 5,
+    RoundNotOpen = 6,
+    RoundClosed = 7,
+    AlreadySubmitted = 8,
+    AlreadyClaimed = 9,
+    NoRewardAvailable = 10,
+    InvalidAmount = 11,
+    Overflow = 12,
+    InvalidDeadline = 13,
+    PastDeadline = 14,
+    RevealNotOpen = 15,
+    RevealClosed = 16,
+    CommitmentNotFound = 17,
+    AlreadyRevealed = 18,
+    CommitmentMismatch = 19,
+    RevealInProgress = 20,
+    InvalidPayoutCurve = 21,
+  }
+  export interface QuestionOpenedEvent {
+    topics: readonly ["question_opened", bigint];
+    data: { reward_amount: bigint; deadline: bigint };
+  }
+  export interface AnswerCommittedEvent {
+    topics: readonly ["answer_committed", bigint];
+    data: { player: string; timestamp: bigint };
+  }
+  export interface AnswerRevealedEvent {
+    topics: readonly ["answer_revealed", bigint];
+    data: { player: string; correct: boolean; timestamp: bigint };
+  }
+  export interface RoundFinalizedEvent {
+    topics: readonly ["round_finalized", bigint];
+    data: { winners: number; payout_per_winner: bigint; total_payout: bigint };
+  }
+  export interface RewardClaimedEvent {
+    topics: readonly ["reward_claimed", bigint];
+    data: { player: string; amount: bigint };
+  }
+  export type ContractEvent = QuestionOpenedEvent | AnswerCommittedEvent | AnswerRevealedEvent | RoundFinalizedEvent | RewardClaimedEvent;
+  export interface Methods {
+    /** Initialize the contract with core dependencies. */
+    init: { args: { admin: string; prize_pool_contract: string; balance_contract: string }; returns: void };
+    /**
+     * Open a new trivia question.
+     * Added `reward_amount` to facilitate prize pool reservation.
+     * Answers are committed until `deadline` and revealed until `reveal_deadline`.
+     * `payout_curve` decides how the reward is split among correct answers.
+     */
+    open_question: { args: { round_id: bigint; answer_commitment: Buffer; deadline: bigint; reveal_deadline: bigint; reward_amount: bigint; payout_curve: PayoutCurve }; returns: void };
+    /**
+     * Commit to an answer for a specific round.
+     * `commitment` is `sha256(answer || salt || player)`; see [`answer_commitment`].
+     * The ledger timestamp of the commitment is what the answer is ranked by.
+     */
+    commit_answer: { args: { player: string; round_id: bigint; commitment: Buffer }; returns: void };
+    /**
+     * Reveal a committed answer after the answer deadline.
+     * The answer is correct when `sha256(answer)` matches the round's `answer_commitment`.
+     */
+    reveal_answer: { args: { player: string; round_id: bigint; answer: Buffer; salt: Buffer }; returns: boolean };
+    /** Finalize the round, closing it and calculating the payout per winner. */
+    finalize_round: { args: { round_id: bigint }; returns: void };
+    /** Claim reward for a correct answer. */
+    claim_reward: { args: { player: string; round_id: bigint }; returns: bigint };
+    /** Get round data. */
+    get_round: { args: { round_id: bigint }; returns: RoundData | undefined };
+    get_round_snapshot: { args: Record<string, never>; returns: RoundSnapshot };
+    /** Revealed answers in ranking order with each player's share of the reward. */
+    get_leaderboard_snapshot: { args: Record<string, never>; returns: LeaderboardSnapshot };
+  }
+}
+
+export declare namespace Staking {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "StakingToken"; values: void }
+    | { tag: "RewardToken"; values: void }
+    | { tag: "GlobalState"; values: void }
+    | { tag: "Position"; values: readonly [string] };
+  export interface GlobalState {
+    total_staked: bigint;
+    last_update_timestamp: bigint;
+    reward_per_share_acc: bigint;
+    reward_rate: bigint;
+  }
+  export interface UserPosition {
+    amount: bigint;
+    reward_debt: bigint;
+    pending_rewards: bigint;
+    last_claim_timestamp: bigint;
+  }
+  export interface RewardPreview {
+    staked_amount: bigint;
+    pending_rewards: bigint;
+    claimable_now: bigint;
+    cooldown_seconds: bigint;
+    eligible_now: boolean;
+  }
+  export interface ClaimEligibility {
+    eligible_now: boolean;
+    next_claim_timestamp: bigint;
+    cooldown_remaining_seconds: bigint;
+    cooldown_seconds: bigint;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    Overflow = 5,
+    InsufficientBalance = 6,
+    ClaimCooldownActive = 7,
+  }
+  export interface StakedEvent {
+    topics: readonly ["staked", string];
+    data: { amount: bigint };
+  }
+  export interface UnstakedEvent {
+    topics: readonly ["unstaked", string];
+    data: { amount: bigint };
+  }
+  export interface RewardsClaimedEvent {
+    topics: readonly ["rewards_claimed", string];
+    data: { amount: bigint };
+  }
+  export type ContractEvent = StakedEvent | UnstakedEvent | RewardsClaimedEvent;
+  export interface Methods {
+    /** Initialise the staking contract. */
+    init: { args: { admin: string; staking_token: string; reward_token: string }; returns: void };
+    /** Set the reward rate (admin only). */
+    set_reward_rate: { args: { admin: string; rate: bigint }; returns: void };
+    /** Stake tokens to earn rewards. */
+    stake: { args: { user: string; amount: bigint }; returns: void };
+    /** Withdraw staked tokens and claim rewards. */
+    unstake: { args: { user: string; amount: bigint }; returns: void };
+    /** Claim accrued rewards. */
+    claim_rewards: { args: { user: string }; returns: bigint };
+    /** View user position. */
+    position_of: { args: { user: string }; returns: UserPosition };
+    /** Preview pending rewards and current claimability without mutating state. */
+    preview_rewards: { args: { user: string }; returns: RewardPreview };
+    /** Return deterministic next-claim eligibility metadata for a staker. */
+    next_claim: { args: { user: string }; returns: ClaimEligibility };
+  }
+}
+
+/**
+ * Stellarcade Streak Bonus Contract
+ *
+ * Tracks user activity streaks and allows claiming bonuses when streak thresholds
+ * are met. Admin sets reward contract and rules; users record activity and claim.
+ */
+export declare namespace StreakBonus {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "RewardContract"; values: void }
+    | { tag: "Rules"; values: void }
+    | { tag: "UserData"; values: readonly [string] };
+  export interface StreakRules {
+    min_streak_to_claim: number;
+    reward_per_streak: bigint;
+    /** Max seconds between activities to count as same streak (e.g. 86400 = 24h). */
+    streak_window_secs: bigint;
+  }
+  export interface UserStreakData {
+    last_activity_ts: bigint;
+    current_streak: number;
+    last_claimed_streak: number;
+  }
+  export const enum StreakSummaryStatus {
+    Missing = 0,
+    Active = 1,
+    Reset = 2,
+  }
+  export interface StreakSummary {
+    status: StreakSummaryStatus;
+    active_streak: number;
+    last_recorded_streak: number;
+    last_claimed_streak: number;
+    last_activity_ts: bigint;
+    streak_window_ends_at: bigint;
+  }
+  export interface NextBonusPreview {
+    status: StreakSummaryStatus;
+    active_streak: number;
+    threshold_streak: number;
+    streaks_needed: number;
+    projected_reward: bigint;
+    claimable_now: boolean;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidConfig = 4,
+    NothingToClaim = 5,
+    Overflow = 6,
+  }
+  export interface InitializedEvent {
+    topics: readonly ["initialized"];
+    data: { admin: string; reward_contract: string };
+  }
+  export interface ActivityRecordedEvent {
+    topics: readonly ["activity_recorded", string];
+    data: { activity_type: string; ts: bigint; new_streak: number };
+  }
+  export interface StreakBonusClaimedEvent {
+    topics: readonly ["streak_bonus_claimed", string];
+    data: { streak: number; amount: bigint };
+  }
+  export interface RulesResetEvent {
+    topics: readonly ["rules_reset"];
+    data: { min_streak_to_claim: number; reward_per_streak: bigint; streak_window_secs: bigint };
+  }
+  export type ContractEvent = InitializedEvent | ActivityRecordedEvent | StreakBonusClaimedEvent | RulesResetEvent;
+  export interface Methods {
+    /** Initialize with admin and reward contract address. Call once. */
+    init: { args: { admin: string; reward_contract: string }; returns: void };
+    /** Record an activity for a user. Caller must be the user (require_auth) or admin. */
+    record_activity: { args: { caller: string; user: string; activity_type: string; ts: bigint }; returns: number };
+    /** Return the current streak count for a user. */
+    current_streak: { args: { user: string }; returns: number };
+    /**
+     * Return a UI-friendly summary of a player's streak at `as_of_ts`.
+     *
+     * Missing players return a zeroed summary with `status = Missing`.
+     * Players whose latest activity window has elapsed return `status = Reset`
+     * with `active_streak = 0` while preserving the last recorded streak.
+     */
+    streak_summary: { args: { user: string; as_of_ts: bigint }; returns: StreakSummary };
+    /**
+     * Preview the next streak bonus target for a player at `as_of_ts`.
+     *
+     * The preview is side-effect free and uses the effective active streak,
+     * making reset streaks render as `active_streak = 0`.
+     */
+    next_bonus_preview: { args: { user: string; as_of_ts: bigint }; returns: NextBonusPreview };
+    /** Claim streak bonus for the current streak. User must authorize. Updates last_claimed_streak. */
+    claim_streak_bonus: { args: { user: string }; returns: bigint };
+    /** Reset streak rules. Admin only. */
+    reset_rules: { args: { admin: string; config: StreakRules }; returns: void };
+  }
+}
+
+/**
+ * StellarCade Tournament System Contract
+ *
+ * Manages the lifecycle of gaming tournaments, including creation, player
+ * registration, result recording, and finalization.
+ *
+ * ## Storage Strategy
+ * - `instance()`: Admin, FeeContract, RewardContract. Shared config.
+ * - `persistent()`: TournamentData, PlayerRegistration, Scores.
+ *   Each tournament and registration is a separate ledger entry.
+ */
+export declare namespace TournamentSystem {
+  export const enum TournamentStatus {
+    Active = 0,
+    Finalized = 1,
+  }
+  export interface TournamentData {
+    rules_hash: Buffer;
+    entry_fee: bigint;
+    status: TournamentStatus;
+  }
+  export interface BracketSummary {
+    current_round: number;
+    remaining_participants: number;
+  }
+  export interface Matchup {
+    player1: string;
+    player2: string | undefined;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "FeeContract"; values: void }
+    | { tag: "RewardContract"; values: void }
+    | { tag: "Tournament"; values: readonly [bigint] }
+    | { tag: "PlayerJoined"; values: readonly [bigint, string] }
+    | { tag: "PlayerScore"; values: readonly [bigint, number, string] }
+    | { tag: "CurrentRound"; values: readonly [bigint] }
+    | { tag: "RoundParticipants"; values: readonly [bigint, number] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    TournamentNotFound = 5,
+    TournamentAlreadyExists = 6,
+    TournamentNotActive = 7,
+    TournamentAlreadyFinalized = 8,
+    PlayerAlreadyJoined = 9,
+    PlayerNotJoined = 10,
+    InvalidStateTransition = 11,
+    Overflow = 12,
+  }
+  export interface TournamentCreatedEvent {
+    topics: readonly ["tournament_created", bigint];
+    data: { rules_hash: Buffer; entry_fee: bigint };
+  }
+  export interface PlayerJoinedEvent {
+    topics: readonly ["player_joined", bigint, string];
+    data: { fee_paid: bigint };
+  }
+  export interface ResultRecordedEvent {
+    topics: readonly ["result_recorded", bigint, string];
+    data: { score: bigint };
+  }
+  export interface TournamentFinalizedEvent {
+    topics: readonly ["tournament_finalized", bigint];
+    data: Record<string, never>;
+  }
+  export type ContractEvent = TournamentCreatedEvent | PlayerJoinedEvent | ResultRecordedEvent | TournamentFinalizedEvent;
+  export interface Methods {
+    /** Initialize the tournament system. May only be called once. */
+    init: { args: { admin: string; fee_contract: string; reward_contract: string }; returns: void };
+    /** Create a new tournament. Admin only. */
+    create_tournament: { args: { admin: string; id: bigint; rules_hash: Buffer; entry_fee: bigint }; returns: void };
+    /** Join an active tournament. Player pays entry fee. */
+    join_tournament: { args: { player: string; id: bigint }; returns: void };
+    /** Record a score for a player in a tournament. Admin/Authorized only. */
+    record_result: { args: { admin: string; id: bigint; player: string; score: bigint }; returns: void };
+    /**
+     * Finalize a tournament. Admin only.
+     * Prevents further joins or result recording.
+     */
+    finalize_tournament: { args: { admin: string; id: bigint }; returns: void };
+    get_tournament: { args: { id: bigint }; returns: TournamentData | undefined };
+    get_score: { args: { id: bigint; player: string }; returns: bigint | undefined };
+    is_joined: { args: { id: bigint; player: string }; returns: boolean };
+    get_bracket_summary: { args: { id: bigint }; returns: BracketSummary };
+    get_next_matches: { args: { id: bigint }; returns: Array<Matchup> };
+    advance_round: { args: { admin: string; id: bigint }; returns: void };
+  }
+}
+
+/**
+ * Stellarcade Treasury Contract
+ *
+ * Core platform treasury for custody and controlled settlement of SEP-41
+ * funds. Supports deposits from external funders and admin-authorized
+ * allocations/releases to downstream contracts and recipients.
+ */
+export declare namespace Treasury {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Token"; values: void }
+    | { tag: "Paused"; values: void }
+    | { tag: "Available"; values: void }
+    | { tag: "TotalDeposited"; values: void }
+    | { tag: "TotalAllocated"; values: void }
+    | { tag: "TotalReleased"; values: void }
+    | { tag: "ProcessedDeposit"; values: readonly [DepositOp] }
+    | { tag: "ProcessedAllocation"; values: readonly [AllocateOp] }
+    | { tag: "ProcessedRelease"; values: readonly [ReleaseOp] };
+  export interface DepositOp {
+    from: string;
+    reason: string;
+  }
+  export interface AllocateOp {
+    to_contract: string;
+    purpose: string;
+  }
+  export interface ReleaseOp {
+    to: string;
+    purpose: string;
+  }
+  export interface TreasuryState {
+    admin: string;
+    token_address: string;
+    paused: boolean;
+    available_balance: bigint;
+    total_deposited: bigint;
+    total_allocated: bigint;
+    total_released: bigint;
+  }
+  export interface TreasuryPolicySnapshot {
+    initialized: boolean;
+    paused: boolean;
+    admin: string;
+    token_address: string;
+    signer_count: number;
+    approval_threshold: number;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    InsufficientFunds = 5,
+    DuplicateOperation = 6,
+    Overflow = 7,
+    ContractPaused = 8,
+    AlreadyPaused = 9,
+    NotPaused = 10,
+  }
+  export interface InitializedEvent {
+    topics: readonly ["initialized"];
+    data: { admin: string; token_address: string };
+  }
+  export interface DepositedEvent {
+    topics: readonly ["deposited", string];
+    data: { amount: bigint; reason: string };
+  }
+  export interface AllocatedEvent {
+    topics: readonly ["allocated", string];
+    data: { amount: bigint; purpose: string };
+  }
+  export interface ReleasedEvent {
+    topics: readonly ["released", string];
+    data: { amount: bigint; purpose: string };
+  }
+  export interface PauseChangedEvent {
+    topics: readonly ["pause_changed"];
+    data: { paused: boolean; admin: string };
+  }
+  export type ContractEvent = InitializedEvent | DepositedEvent | AllocatedEvent | ReleasedEvent | PauseChangedEvent;
+  export interface Methods {
+    init: { args: { admin: string; token_address: string }; returns: void };
+    pause: { args: { admin: string }; returns: void };
+    unpause: { args: { admin: string }; returns: void };
+    deposit: { args: { from: string; amount: bigint; reason: string }; returns: void };
+    allocate: { args: { to_contract: string; amount: bigint; purpose: string }; returns: void };
+    release: { args: { to: string; amount: bigint; purpose: string }; returns: void };
+    treasury_state: { args: Record<string, never>; returns: TreasuryState };
+    /** Returns treasury policy and signer-threshold metadata. */
+    policy_snapshot: { args: Record<string, never>; returns: TreasuryPolicySnapshot };
+  }
+}
+
+export declare namespace TreasuryAllocation {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "TreasuryContract"; values: void }
+    | { tag: "NextRequestId"; values: void }
+    | { tag: "Budget"; values: readonly [string] }
+    | { tag: "AllocationRequest"; values: readonly [number] };
+  export interface BudgetInfo {
+    limit: bigint;
+    allocated: bigint;
+    period: bigint;
+  }
+  export type RequestStatus =
+    | { tag: "Pending"; values: void }
+    | { tag: "Approved"; values: void }
+    | { tag: "Rejected"; values: void };
+  export interface RequestInfo {
+    bucket_id: string;
+    requester: string;
+    amount: bigint;
+    reason: string;
+    status: RequestStatus;
+  }
+  export interface AllocationPreview {
+    bucket_id: string;
+    current_limit: bigint;
+    current_allocated: bigint;
+    remaining_budget: bigint;
+    requested_amount: bigint;
+    would_exceed_budget: boolean;
+    excess_amount: bigint;
+    approval_likely: boolean;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidAmount = 4,
+    BudgetExceeded = 5,
+    RequestNotFound = 6,
+    RequestAlreadyProcessed = 7,
+  }
+  export interface BudgetCreatedEvent {
+    topics: readonly ["budget_created", string];
+    data: { limit: bigint; period: bigint };
+  }
+  export interface AllocationRequestedEvent {
+    topics: readonly ["allocation_requested", number];
+    data: { bucket_id: string; requester: string; amount: bigint };
+  }
+  export interface AllocationApprovedEvent {
+    topics: readonly ["allocation_approved", number];
+    data: { bucket_id: string; amount: bigint };
+  }
+  export interface AllocationRejectedEvent {
+    topics: readonly ["allocation_rejected", number];
+    data: { bucket_id: string };
+  }
+  export type ContractEvent = BudgetCreatedEvent | AllocationRequestedEvent | AllocationApprovedEvent | AllocationRejectedEvent;
+  export interface Methods {
+    init: { args: { admin: string; treasury_contract: string }; returns: void };
+    create_budget: { args: { bucket_id: string; limit: bigint; period: bigint }; returns: void };
+    request_allocation: { args: { requester: string; bucket_id: string; amount: bigint; reason: string }; returns: number };
+    approve_allocation: { args: { request_id: number }; returns: void };
+    reject_allocation: { args: { request_id: number }; returns: void };
+    budget_state: { args: { bucket_id: string }; returns: BudgetInfo };
+    request_state: { args: { request_id: number }; returns: RequestInfo };
+    /**
+     * Preview allocation outcome without modifying state
+     * Returns detailed preview showing if request would exceed budget and approval likelihood
+     */
+    preview_allocation: { args: { bucket_id: string; amount: bigint }; returns: AllocationPreview };
+  }
+}
+
+/**
+ * Stellarcade Daily Trivia Game Contract
+ *
+ * Runs multi-question trivia rounds. Clients can inspect the currently
+ * active round and its question-set metadata.
+ *
+ * ## Round Flow
+ * 1. The admin calls `configure_round` with the Merkle root of the round's
+ *    answers; the reward is reserved in the prize pool.
+ * 2. The admin activates the round. Between `starts_at` and `ends_at`,
+ *    players call `submit_answer` once per question with a commitment to
+ *    their answer, `sha256(answer || salt || player)` (see
+ *    [`answer_commitment`]), so nobody can copy an answer from the ledger.
+ * 3. After `ends_at` the admin calls `close_round`, and until
+ *    `reveal_ends_at` players open their commitments with
+ *    `reveal_submission`. Each opened answer increments a counter for its
+ *    `(question, answer_hash)` pair.
+ * 4. After `reveal_ends_at` anyone reveals each question's answer with
+ *    `reveal_answer`, proving it against the committed root. The counter of
+ *    the correct answer is that question's number of correct players, so a
+ *    reveal costs the same however many players answered. Once every
+ *    question is revealed the round is settled. If some answers are still
+ *    unrevealed [`ANSWER_REVEAL_TIMEOUT`] seconds after `reveal_ends_at`,
+ *    anyone can settle it with `expire_round` instead.
+ * 5. Players call `claim_reward`, which scores their opened answers against
+ *    the revealed ones, and are paid `reward_amount * score / total_score`
+ *    through the prize pool. Unopened commitments score nothing.
+ *
+ * ## Answer Commitment
+ * Leaves are `sha256(question_id_be || salt || answer)` (see
+ * [`answer_leaf`]), with `question_id` as 4 big-endian bytes, a secret 32-byte
+ * `salt` per question and `answer` as its raw UTF-8 bytes; answers must match
+ * byte for byte. The salt keeps the answers from being brute-forced from the
+ * root while the round is open, and is published with `reveal_answer`. The tree has `2^depth` leaves with
+ * `depth = ceil(log2(question_count))`, and question `q` sits at index
+ * `q - 1`; unused leaves may hold any 32 bytes. Interior nodes are
+ * `sha256(left || right)`, and the bits of the leaf index pick the side at
+ * each level, so the root holds exactly one answer per question.
+ */
+export declare namespace TriviaGame {
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "PrizePoolContract"; values: void }
+    | { tag: "ActiveRoundId"; values: void }
+    | { tag: "Round"; values: readonly [bigint] }
+    | { tag: "Participation"; values: readonly [bigint, string] }
+    | { tag: "Submission"; values: readonly [bigint, number, string] }
+    | { tag: "AnswerCount"; values: readonly [bigint, number, Buffer] }
+    | { tag: "Revealed"; values: readonly [bigint, number] };
+  export const enum RoundStatus {
+    Configured = 0,
+    Active = 1,
+    Closed = 2,
+    /** Every answer revealed; rewards can be claimed */
+    Settled = 3,
+  }
+  export interface RoundData {
+    question_set_id: bigint;
+    question_count: number;
+    category: string;
+    difficulty: number;
+    starts_at: bigint;
+    ends_at: bigint;
+    /** Last timestamp at which players can open their commitments */
+    reveal_ends_at: bigint;
+    participant_count: number;
+    submission_count: number;
+    status: RoundStatus;
+    /** Merkle root of the round's answer leaves */
+    answers_root: Buffer;
+    reward_amount: bigint;
+    revealed_count: number;
+    /** Sum of all players' scores over the revealed questions */
+    total_score: number;
+  }
+  /** A player's progress in one round */
+  export interface Participation {
+    answered: number;
+    claimed: boolean;
+  }
+  /** A player's answer to one question */
+  export interface Submission {
+    /** `sha256(answer || salt || player)`, see [`answer_commitment`] */
+    commitment: Buffer;
+    /** [`answer_hash`] of the opened answer; `None` until opened */
+    answer_hash: Buffer | undefined;
+  }
+  export interface QuestionSetMetadata {
+    round_id: bigint;
+    question_set_id: bigint;
+    question_count: number;
+    category: string;
+    difficulty: number;
+    starts_at: bigint;
+    ends_at: bigint;
+    reveal_ends_at: bigint;
+    status: RoundStatus;
+  }
+  export interface ActiveRoundSnapshot {
+    has_active_round: boolean;
+    round_id: bigint;
+    question_set_id: bigint;
+    starts_at: bigint;
+    ends_at: bigint;
+    now: bigint;
+    participant_count: number;
+    submission_count: number;
+    is_accepting_answers: boolean;
+  }
+  export interface PlayerScore {
+    round_id: bigint;
+    answered: number;
+    /** Opened answers matching the revealed ones */
+    score: number;
+    /** Reward owed once the round is settled, 0 before */
+    reward: bigint;
+    claimed: boolean;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    RoundAlreadyExists = 4,
+    RoundNotFound = 5,
+    InvalidConfig = 6,
+    NoActiveRound = 7,
+    AnswersClosed = 8,
+    InvalidQuestion = 9,
+    AlreadyAnswered = 10,
+    InvalidRoundStatus = 11,
+    RoundInProgress = 12,
+    AlreadyRevealed = 13,
+    InvalidProof = 14,
+    NoRewardAvailable = 15,
+    AlreadyClaimed = 16,
+    Overflow = 17,
+    RevealNotOpen = 18,
+    RevealClosed = 19,
+    CommitmentNotFound = 20,
+    CommitmentMismatch = 21,
+    RevealInProgress = 22,
+    RoundNotExpired = 23,
+  }
+  export interface AnswerSubmittedEvent {
+    topics: readonly ["answer_submitted", bigint];
+    data: { player: string; question_id: number };
+  }
+  export interface SubmissionRevealedEvent {
+    topics: readonly ["submission_revealed", bigint];
+    data: { player: string; question_id: number; answer: string };
+  }
+  export interface RoundClosedEvent {
+    topics: readonly ["round_closed", bigint];
+    data: { participant_count: number; submission_count: number };
+  }
+  export interface AnswerRevealedEvent {
+    topics: readonly ["answer_revealed", bigint];
+    data: { question_id: number; answer: string; correct_count: number };
+  }
+  export interface RoundSettledEvent {
+    topics: readonly ["round_settled", bigint];
+    data: { total_score: number };
+  }
+  export interface RewardClaimedEvent {
+    topics: readonly ["reward_claimed", bigint];
+    data: { player: string; score: number; amount: bigint };
+  }
+  export type ContractEvent = AnswerSubmittedEvent | SubmissionRevealedEvent | RoundClosedEvent | AnswerRevealedEvent | RoundSettledEvent | RewardClaimedEvent;
+  export interface Methods {
+    /**
+     * Initialize the contract once with an admin who can configure rounds
+     * and the prize pool that funds them.
+     */
+    init: { args: { admin: string; prize_pool_contract: string }; returns: void };
+    /**
+     * Configure a round, its question-set metadata and the Merkle root of
+     * its answers, reserving `reward_amount` in the prize pool. Answers are
+     * committed until `ends_at` and opened until `reveal_ends_at`.
+     */
+    configure_round: { args: { admin: string; round_id: bigint; question_set_id: bigint; question_count: number; category: string; difficulty: number; starts_at: bigint; ends_at: bigint; reveal_ends_at: bigint; answers_root: Buffer; reward_amount: bigint }; returns: void };
+    /** Mark a configured round as active. */
+    activate_round: { args: { admin: string; round_id: bigint }; returns: void };
+    /**
+     * Commit to an answer to one question of the active round.
+     *
+     * Questions are numbered from 1 to `question_count` and each can be
+     * answered once per player. `commitment` is
+     * `sha256(answer || salt || player)` (see [`answer_commitment`]); the
+     * answer is opened with `reveal_submission` after the round ends.
+     */
+    submit_answer: { args: { player: string; question_id: number; commitment: Buffer }; returns: void };
+    /**
+     * Open a committed answer after `ends_at` and until `reveal_ends_at`.
+     *
+     * `answer` and `salt` must hash to the submission's commitment. The
+     * opened answer is counted under its [`answer_hash`] so `reveal_answer`
+     * can read the number of correct players without visiting them.
+     */
+    reveal_submission: { args: { player: string; round_id: bigint; question_id: number; answer: string; salt: Buffer }; returns: void };
+    /**
+     * Stop accepting answers once the round has ended so its answers can be
+     * revealed.
+     */
+    close_round: { args: { admin: string; round_id: bigint }; returns: void };
+    /**
+     * Reveal the answer to one question of a closed round once players can
+     * no longer open their submissions, proving it against the committed
+     * `answers_root`. Revealing the last question settles the round.
+     *
+     * Anyone holding the answer and its proof may reveal it. Returns the
+     * number of players whose opened answer is correct, read from the
+     * answer's counter.
+     */
+    reveal_answer: { args: { round_id: bigint; question_id: number; answer: string; salt: Buffer; proof: Array<Buffer> }; returns: number };
+    /**
+     * Settle a round whose answers were not all revealed within
+     * [`ANSWER_REVEAL_TIMEOUT`] seconds of `reveal_ends_at`, so its reward
+     * cannot stay reserved for good.
+     *
+     * Anyone may call it. Players are scored on the questions revealed so
+     * far; if nobody scored, the reward is released back to the prize pool.
+     */
+    expire_round: { args: { round_id: bigint }; returns: void };
+    /**
+     * Claim a settled round's reward in proportion to the player's score,
+     * which is computed here from their opened answers.
+     */
+    claim_reward: { args: { player: string; round_id: bigint }; returns: bigint };
+    /** Return the display-safe question-set metadata for a configured round. */
+    question_set_metadata: { args: { round_id: bigint }; returns: QuestionSetMetadata };
+    /**
+     * Return a player's answers, score and reward for a round.
+     *
+     * The score only counts revealed questions; `reward` stays 0 until the
+     * round is settled.
+     */
+    player_score: { args: { round_id: bigint; player: string }; returns: PlayerScore };
+    /**
+     * Return a deterministic snapshot of the active round, if one exists.
+     *
+     * When no round is active the snapshot is zeroed with
+     * `has_active_round = false`.
+     */
+    active_round_snapshot: { args: Record<string, never>; returns: ActiveRoundSnapshot };
+  }
+}
+
+export declare namespace UpgradeMechanism {
+  export interface ReleaseRecord {
+    version: number;
+    wasm_hash: Buffer;
+    schema_version: number;
+    migration_hash: Buffer;
+    changelog_hash: Buffer;
+    activated_at: bigint;
+  }
+  export interface PendingUpgrade {
+    version: number;
+    wasm_hash: Buffer;
+    target_schema_version: number;
+    migration_hash: Buffer;
+    changelog_hash: Buffer;
+    staged_at: bigint;
+  }
+  export interface TestGateConfig {
+    enabled: boolean;
+    gate_contract: string;
+    suite: string;
+  }
+  export interface UpgradeState {
+    admin: string;
+    paused: boolean;
+    killed: boolean;
+    current_version: number;
+    current_schema_version: number;
+    current_wasm_hash: Buffer;
+    has_pending_upgrade: boolean;
+    pending_version: number;
+    has_rollback_point: boolean;
+    rollback_version: number;
+    has_test_gate: boolean;
+    test_gate_enabled: boolean;
+  }
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Paused"; values: void }
+    | { tag: "Killed"; values: void }
+    | { tag: "CurrentVersion"; values: void }
+    | { tag: "CurrentSchemaVersion"; values: void }
+    | { tag: "CurrentWasmHash"; values: void }
+    | { tag: "PendingUpgrade"; values: void }
+    | { tag: "RollbackPoint"; values: void }
+    | { tag: "TestGate"; values: void }
+    | { tag: "Release"; values: readonly [number] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    InvalidInput = 4,
+    InvalidStateTransition = 5,
+    DuplicateVersion = 6,
+    UpgradeNotStaged = 7,
+    ContractPaused = 8,
+    AlreadyPaused = 9,
+    NotPaused = 10,
+    ContractKilled = 11,
+    TestGateFailed = 12,
+  }
+  export interface InitializedEvent {
+    topics: readonly ["initialized"];
+    data: { admin: string; version: number; schema_version: number; wasm_hash: Buffer };
+  }
+  export interface UpgradeStagedEvent {
+    topics: readonly ["upgrade_staged"];
+    data: { version: number; target_schema_version: number; staged_at: bigint };
+  }
+  export interface UpgradeExecutedEvent {
+    topics: readonly ["upgrade_executed"];
+    data: { from_version: number; to_version: number; schema_version: number; activated_at: bigint };
+  }
+  export interface RollbackExecutedEvent {
+    topics: readonly ["rollback_executed"];
+    data: { from_version: number; to_version: number; reason_hash: Buffer };
+  }
+  export interface PauseChangedEvent {
+    topics: readonly ["pause_changed"];
+    data: { paused: boolean; admin: string };
+  }
+  export interface KillSwitchTriggeredEvent {
+    topics: readonly ["kill_switch_triggered"];
+    data: { admin: string; reason_hash: Buffer };
+  }
+  export interface TestGateConfiguredEvent {
+    topics: readonly ["test_gate_configured"];
+    data: { enabled: boolean; gate_contract: string; suite: string };
+  }
+  export type ContractEvent = InitializedEvent | UpgradeStagedEvent | UpgradeExecutedEvent | RollbackExecutedEvent | PauseChangedEvent | KillSwitchTriggeredEvent | TestGateConfiguredEvent;
+  export interface Methods {
+    init: { args: { admin: string; initial_wasm_hash: Buffer; initial_schema_version: number }; returns: void };
+    pause: { args: { admin: string }; returns: void };
+    unpause: { args: { admin: string }; returns: void };
+    trigger_kill_switch: { args: { admin: string; reason_hash: Buffer }; returns: void };
+    configure_test_gate: { args: { admin: string; gate_contract: string; suite: string; enabled: boolean }; returns: void };
+    stage_upgrade: { args: { admin: string; version: number; wasm_hash: Buffer; target_schema_version: number; migration_hash: Buffer; changelog_hash: Buffer }; returns: void };
+    execute_upgrade: { args: { admin: string }; returns: void };
+    rollback: { args: { admin: string; reason_hash: Buffer }; returns: void };
+    state: { args: Record<string, never>; returns: UpgradeState };
+    get_release: { args: { version: number }; returns: ReleaseRecord | undefined };
+  }
+}
+
+/**
+ * Stellarcade VIP/Subscription Contract
+ *
+ * Manages VIP subscription plans and user subscriptions on the StellarCade
+ * platform. Admins define plans with a price, duration, and benefits hash.
+ * Users subscribe or renew by paying via a treasury contract. The contract
+ * tracks per-user subscription state and expiry.
+ *
+ * ## Storage Strategy
+ * - `instance()`: Admin and TreasuryContract address. Small, fixed config
+ *   shared across all entries in one ledger entry with a single TTL.
+ * - `persistent()`: PlanDefinition per plan_id, SubscriptionRecord per user.
+ *   Each is a separate ledger entry with its own TTL, bumped on every write.
+ *
+ * ## State Machine
+ * A user subscription transitions as follows:
+ *
+ *   (none) --subscribe--> Active(expires_at)
+ *   Active  --renew-->    Active(expires_at + duration)   [extends from now or expiry, whichever is later]
+ *   Active  --time passes-> Expired (status reflects ledger timestamp)
+ *
+ * Renewal on an expired subscription reactivates it from `current_time + duration`.
+ *
+ * ## Invariants
+ * - A plan_id can only be defined once (`define_plan` is idempotent-guarded).
+ * - `subscribe` is rejected if the user already has an active or future subscription
+ *   for that plan (use `renew` to extend).
+ * - Arithmetic on timestamps uses `checked_add` to guard against overflow.
+ */
+export declare namespace VipSubscription {
+  /**
+   * Discriminants for all storage keys.
+   *
+   * Instance keys (Admin, Treasury): contract config, one ledger entry.
+   * Persistent keys (Plan, Subscription): per-plan definitions and per-user
+   * subscription records, each with their own TTL.
+   */
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "Treasury"; values: void }
+    | { tag: "Plan"; values: readonly [number] }
+    | { tag: "Subscription"; values: readonly [string] };
+  /**
+   * Definition of a VIP subscription plan.
+   *
+   * `benefits_hash` is a 32-byte SHA-256 hash of the off-chain benefits
+   * document, providing a tamper-evident commitment without on-chain verbosity.
+   * `price` is the token amount charged per subscription period.
+   * `duration` is the subscription length in seconds.
+   */
+  export interface PlanDefinition {
+    /** Token amount charged when subscribing or renewing. */
+    price: bigint;
+    /** Duration of one subscription period in seconds. */
+    duration: bigint;
+    /** SHA-256 hash of the off-chain benefits specification (32 bytes). */
+    benefits_hash: Buffer;
+  }
+  /** Per-user subscription record. */
+  export interface SubscriptionRecord {
+    /** The plan this subscription is for. */
+    plan_id: number;
+    /** Unix timestamp (seconds) at which this subscription expires. */
+    expires_at: bigint;
+  }
+  /** Public view of a user's subscription status. */
+  export interface SubscriptionStatus {
+    /** Whether the user currently has a subscription record. */
+    has_subscription: boolean;
+    /** The plan_id if subscribed, or 0 if none. */
+    plan_id: number;
+    /** Expiry timestamp in seconds. 0 if not subscribed. */
+    expires_at: bigint;
+    /** Whether the subscription is currently active (not expired). */
+    is_active: boolean;
+  }
+  /** Frontend-friendly state for a user's subscription lifecycle. */
+  export const enum SubscriptionState {
+    NeverSubscribed = 0,
+    Active = 1,
+    Expired = 2,
+  }
+  /** Extended status view that distinguishes active, expired, and missing users. */
+  export interface UserSubscriptionStatus {
+    state: SubscriptionState;
+    plan_id: number;
+    expires_at: bigint;
+    seconds_until_expiry: bigint;
+  }
+  /** Side-effect free preview of the next renewal for a user. */
+  export interface RenewalPreview {
+    state: SubscriptionState;
+    plan_id: number;
+    can_renew: boolean;
+    renewal_cost: bigint;
+    renewal_duration: bigint;
+    effective_from: bigint;
+    next_expires_at: bigint;
+  }
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    PlanNotFound = 4,
+    PlanAlreadyExists = 5,
+    AlreadySubscribed = 6,
+    InvalidInput = 7,
+    Overflow = 8,
+  }
+  export interface PlanDefinedEvent {
+    topics: readonly ["plan_defined", number];
+    data: { price: bigint; duration: bigint; benefits_hash: Buffer };
+  }
+  export interface SubscribedEvent {
+    topics: readonly ["subscribed", string, number];
+    data: { expires_at: bigint; amount_paid: bigint };
+  }
+  export interface RenewedEvent {
+    topics: readonly ["renewed", string, number];
+    data: { expires_at: bigint; amount_paid: bigint };
+  }
+  export type ContractEvent = PlanDefinedEvent | SubscribedEvent | RenewedEvent;
+  export interface Methods {
+    /**
+     * Initialize the contract. May only be called once.
+     *
+     * `admin` is the only address authorized to define plans.
+     * `treasury_contract` is the address that receives subscription payments.
+     */
+    init: { args: { admin: string; treasury_contract: string }; returns: void };
+    /**
+     * Define a new VIP subscription plan. Admin only.
+     *
+     * `plan_id` must be unique; re-defining an existing plan returns
+     * `PlanAlreadyExists`. `price` must be positive. `duration` must be
+     * positive (in seconds). `benefits_hash` is the 32-byte SHA-256 hash of
+     * the off-chain benefits document.
+     */
+    define_plan: { args: { admin: string; plan_id: number; price: bigint; duration: bigint; benefits_hash: Buffer }; returns: void };
+    /**
+     * Subscribe `user` to `plan_id`. The user pays the plan price via the
+     * treasury contract.
+     *
+     * Rejected if the user already has an active (non-expired) subscription.
+     * Use `renew` to extend an active subscription. A user whose subscription
+     * has already expired may call `subscribe` again to start fresh.
+     */
+    subscribe: { args: { user: string; plan_id: number }; returns: void };
+    /**
+     * Renew `user`'s subscription to `plan_id`. The user pays the plan price.
+     *
+     * If the subscription is still active the duration is added to the current
+     * `expires_at` (so renewals always stack). If already expired, the new
+     * expiry is `now + duration`. The plan_id in the record is updated to
+     * match the renewed plan (allowing cross-plan renewal).
+     *
+     * Rejected if no subscription record exists for the user.
+     */
+    renew: { args: { user: string; plan_id: number }; returns: void };
+    /**
+     * Return the subscription status for `user`.
+     *
+     * Returns a `SubscriptionStatus` with `has_subscription = false` if the
+     * user has never subscribed. If a record exists, `is_active` reflects
+     * whether the current ledger timestamp is before `expires_at`.
+     */
+    status_of: { args: { user: string }; returns: SubscriptionStatus };
+    /**
+     * Return a frontend-friendly subscription status for `user`.
+     *
+     * Missing users return `NeverSubscribed`, while expired users retain
+     * their stored `plan_id` and `expires_at` for renewal messaging.
+     */
+    subscription_status: { args: { user: string }; returns: UserSubscriptionStatus };
+    /**
+     * Preview the effect of renewing the user's current subscription now.
+     *
+     * This accessor never mutates state. Active subscriptions preview a stacked
+     * renewal from the current expiry; expired subscriptions preview a renewal
+     * starting from `now`; never-subscribed users return `can_renew = false`.
+     */
+    renewal_preview: { args: { user: string }; returns: RenewalPreview };
+  }
+}
+
+/**
+ * Stellarcade Wordle Clone Contract
+ *
+ * A daily on-chain Wordle game where players submit up to 6 guesses for a
+ * hidden 5-letter word. Each guess is scored against the answer after the
+ * puzzle is finalized by the admin using commit-reveal.
+ *
+ * ## Game Flow
+ * 1. Admin calls `create_daily_puzzle` with SHA-256(answer) as `answer_commitment`.
+ * 2. Players call `submit_attempt` with their 5-letter guess (up to 6 times).
+ * 3. Admin calls `reveal_answer` with the plaintext answer (verifies commitment).
+ * 4. Admin calls `finalize_result(player, puzzle_id)` per the issue interface;
+ *    all player attempts are scored and winners recorded.
+ * 5. Players call `get_attempts` to read their scored attempt history.
+ *
+ * ## Guess Scoring
+ * Each character in a guess is scored per position:
+ * - `2` (CORRECT)  — right letter, right position.
+ * - `1` (PRESENT)  — right letter, wrong position.
+ * - `0` (ABSENT)   — letter not in the answer at all.
+ *
+ * Scoring mirrors the standard Wordle algorithm: exact matches are resolved
+ * first, then remaining answer characters are consumed for PRESENT matches,
+ * so each answer character accounts for at most one PRESENT mark.
+ *
+ * ## Oracle Scoring
+ * A puzzle may name a scorer who knows the answer. After each attempt the
+ * scorer calls `post_scores` with the scores, signing them against the
+ * puzzle's `answer_commitment`, and the player sees them before guessing
+ * again. A scorer that does not score an attempt within
+ * `SCORE_TIMEOUT_LEDGERS` no longer holds the player up: the next guess is
+ * accepted without it. `finalize_result` rescores every attempt against the
+ * revealed answer; a single posted score that differs invalidates the
+ * puzzle. An invalidated puzzle records no winners or disqualifications and
+ * keeps every attempt with the scores as posted, so the bad score stays on
+ * record.
+ *
+ * ## Puzzle Rules
+ * - A puzzle may commit to a dictionary: the Merkle root of its valid words
+ *   (see [`word_leaf`]). Every guess must then carry a membership proof.
+ * - In hard mode every guess must reuse the hints of the player's earlier
+ *   guesses (see [`follows_hints`]). With a scorer this is checked as each
+ *   guess is submitted; otherwise guesses are only scored once the answer is
+ *   revealed, and a player who ignored a hint is disqualified at
+ *   finalization.
+ *
+ * ## Storage Strategy
+ * - `instance()` storage: contract-level config (Admin, PrizePoolContract,
+ *   BalanceContract). Small, bounded, stored in a single ledger entry.
+ * - `persistent()` storage: per-puzzle and per-player data (Puzzle, AttemptList,
+ *   Winner). Each key is an independent ledger entry with its own TTL extended
+ *   on every write (~30 days).
+ *
+ * ## Security
+ * - Only the admin may create puzzles, reveal answers, or finalize results.
+ * - Players may submit at most `MAX_ATTEMPTS` (6) guesses per puzzle.
+ * - Guesses must be exactly `WORD_LENGTH` (5) bytes.
+ * - Finalization verifies the commitment before scoring, preventing answer
+ *   manipulation after guesses are locked in.
+ * - All arithmetic uses `checked_*` to prevent overflow.
+ */
+export declare namespace WordleClone {
+  /** Lifecycle state of a puzzle. */
+  export const enum PuzzleStatus {
+    /** Accepting player guesses. */
+    Open = 0,
+    /** Admin has revealed the answer; waiting for finalization. */
+    Revealed = 1,
+    /** All attempts scored; puzzle complete. */
+    Finalized = 2,
+    /**
+     * A score posted by the scorer did not match the revealed answer; no
+     * winners or disqualifications are recorded and attempts keep their
+     * posted scores.
+     */
+    Invalidated = 3,
+  }
+  /** Puzzle metadata and result summary. */
+  export interface PuzzleData {
+    /** SHA-256(plaintext_answer) committed at creation time. */
+    answer_commitment: Buffer;
+    status: PuzzleStatus;
+    /** Plaintext answer — empty until `reveal_answer` is called. */
+    answer: Buffer;
+    /** Number of players who solved the puzzle. */
+    winner_count: number;
+    /** Number of distinct players who submitted at least one attempt. */
+    player_count: number;
+    /** Merkle root of the valid guesses; any 5 bytes are accepted when unset. */
+    dictionary_root: Buffer | undefined;
+    /** Guesses must reuse the hints of earlier guesses. */
+    hard_mode: boolean;
+    /** Number of players disqualified for breaking hard mode. */
+    disqualified_count: number;
+    /**
+     * Posts scores after each attempt; attempts are only scored at
+     * finalization when unset.
+     */
+    scorer: string | undefined;
+  }
+  /** A single scored guess. */
+  export interface Attempt {
+    /** The 5-letter guess submitted by the player. */
+    guess: Buffer;
+    /**
+     * Per-character scores: Vec of SCORE_* constants, length == WORD_LENGTH.
+     * Empty until the scorer posts them or the puzzle is finalized.
+     */
+    scores: Array<number>;
+    /** Ledger sequence the guess was submitted at. */
+    submitted_at: number;
+  }
+  export const enum PuzzleSnapshotState {
+    Missing = 0,
+    Active = 1,
+    Completed = 2,
+    Invalidated = 3,
+  }
+  export interface PuzzleSnapshot {
+    state: PuzzleSnapshotState;
+    guesses: Array<Attempt>;
+    answer_revealed: boolean;
+    answer: Buffer;
+    remaining_attempts: number;
+  }
+  /**
+   * Storage key discriminants.
+   *
+   * Instance keys (Admin, PrizePoolContract, BalanceContract) live in a single
+   * ledger entry and hold small, fixed-size contract config.
+   *
+   * Persistent keys (Puzzle, PlayerList, Attempts, Winner) are per-puzzle and
+   * per-player, each stored as an independent ledger entry with its own TTL.
+   */
+  export type DataKey =
+    | { tag: "Admin"; values: void }
+    | { tag: "PrizePoolContract"; values: void }
+    | { tag: "BalanceContract"; values: void }
+    | { tag: "Puzzle"; values: readonly [bigint] }
+    | { tag: "PlayerList"; values: readonly [bigint] }
+    | { tag: "Attempts"; values: readonly [bigint, string] }
+    | { tag: "Winner"; values: readonly [bigint, string] }
+    | { tag: "Disqualified"; values: readonly [bigint, string] };
+  export const enum ErrorCode {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    PuzzleAlreadyExists = 4,
+    PuzzleNotFound = 5,
+    PuzzleNotOpen = 6,
+    PuzzleAlreadyFinalized = 7,
+    TooManyAttempts = 8,
+    InvalidWordLength = 9,
+    CommitmentMismatch = 10,
+    Overflow = 11,
+    PuzzleFull = 12,
+    AnswerNotRevealed = 13,
+    NotInDictionary = 14,
+    NoScorer = 15,
+    AwaitingScore = 16,
+    AlreadyScored = 17,
+    AttemptNotFound = 18,
+    InvalidScores = 19,
+    HardModeViolation = 20,
+  }
+  export interface PuzzleCreatedEvent {
+    topics: readonly ["puzzle_created", bigint];
+    data: { answer_commitment: Buffer; dictionary_root: Buffer | undefined; hard_mode: boolean; scorer: string | undefined };
+  }
+  export interface AttemptSubmittedEvent {
+    topics: readonly ["attempt_submitted", bigint, string];
+    data: { attempt_number: number; guess: Buffer };
+  }
+  export interface ScoresPostedEvent {
+    topics: readonly ["scores_posted", bigint, string];
+    data: { attempt_number: number; scores: Array<number> };
+  }
+  export interface AnswerRevealedEvent {
+    topics: readonly ["answer_revealed", bigint];
+    data: Record<string, never>;
+  }
+  export interface PuzzleFinalizedEvent {
+    topics: readonly ["puzzle_finalized", bigint];
+    data: { answer: Buffer; winner_count: number };
+  }
+  export interface PuzzleInvalidatedEvent {
+    topics: readonly ["puzzle_invalidated", bigint];
+    data: { player: string; attempt_number: number };
+  }
+  export type ContractEvent = PuzzleCreatedEvent | AttemptSubmittedEvent | ScoresPostedEvent | AnswerRevealedEvent | PuzzleFinalizedEvent | PuzzleInvalidatedEvent;
+  export interface Methods {
+    /**
+     * Initialize the contract. May only be called once.
+     *
+     * Stores admin, prize pool contract address, and balance contract address
+     * in instance storage. Subsequent calls return `AlreadyInitialized`.
+     */
+    init: { args: { admin: string; prize_pool_contract: string; balance_contract: string }; returns: void };
+    /**
+     * Create a new daily puzzle. Admin only.
+     *
+     * `puzzle_id` must be unique. `answer_commitment` is `SHA-256(answer_bytes)`
+     * computed off-chain. The plaintext answer is never stored until the admin
+     * calls `reveal_answer`.
+     *
+     * `dictionary_root`, when set, is the Merkle root of the words accepted
+     * as guesses; it should include the answer. `hard_mode` requires every
+     * guess to reuse the hints of the player's earlier guesses. `scorer`,
+     * when set, posts the scores of each attempt with `post_scores`.
+     *
+     * Emits `PuzzleCreated`.
+     */
+    create_daily_puzzle: { args: { puzzle_id: bigint; answer_commitment: Buffer; dictionary_root: Buffer | undefined; hard_mode: boolean; scorer: string | undefined }; returns: void };
+    /**
+     * Submit a 5-letter guess for an open puzzle.
+     *
+     * A player may submit up to `MAX_ATTEMPTS` (6) guesses. Guesses must be
+     * exactly `WORD_LENGTH` (5) bytes. Scores are computed after finalization;
+     * the `scores` field is empty until then.
+     *
+     * With a scorer, the previous attempt must be scored before the next is
+     * accepted, unless `SCORE_TIMEOUT_LEDGERS` have passed since it was
+     * submitted. In hard mode the guess must follow the posted hints.
+     *
+     * If the puzzle has a dictionary, `proof` is the Merkle proof of the
+     * guess's [`word_leaf`]; otherwise it is ignored.
+     *
+     * Emits `AttemptSubmitted`.
+     */
+    submit_attempt: { args: { player: string; puzzle_id: bigint; attempt: Buffer; proof: Array<Buffer> }; returns: void };
+    /**
+     * Post the scores of a player's attempt. Scorer only.
+     *
+     * `answer_commitment` must be the puzzle's: the scorer's authorization
+     * covers every argument, so each call is a signed statement that `scores`
+     * are what `score_guess` gives for the guess against that commitment.
+     * `finalize_result` holds the scorer to it. Accepted until the puzzle is
+     * finalized; each attempt is scored once.
+     *
+     * Emits `ScoresPosted`.
+     */
+    post_scores: { args: { puzzle_id: bigint; player: string; attempt_number: number; answer_commitment: Buffer; scores: Array<number> }; returns: void };
+    /**
+     * Reveal the plaintext answer for an open puzzle. Admin only.
+     *
+     * Verifies `SHA-256(answer) == answer_commitment`. Transitions the puzzle
+     * to `Revealed` state; no new player guesses are accepted after this call.
+     * Must be called before `finalize_result`.
+     */
+    reveal_answer: { args: { puzzle_id: bigint; answer: Buffer }; returns: void };
+    /**
+     * Score all player attempts and record winners. Admin only.
+     *
+     * The `player` parameter is included per the issue interface; the contract
+     * scores ALL players in a single pass for consistency, then transitions
+     * the puzzle to `Finalized`. Must be called after `reveal_answer`.
+     *
+     * Iterates all submissions (bounded by `MAX_PLAYERS_PER_PUZZLE × MAX_ATTEMPTS`).
+     * A player is a winner if any of their attempts matches the answer exactly.
+     * In hard mode without a scorer, a player with an attempt that ignores
+     * the hints of an earlier one is disqualified instead. With a scorer,
+     * hard mode was already enforced against the posted hints as guesses
+     * came in.
+     *
+     * Scores posted by the scorer are checked first. If any differs from
+     * `score_guess`, the puzzle is marked `Invalidated` and
+     * `PuzzleInvalidated` is emitted instead: attempts are left with their
+     * posted scores and no winners or disqualifications are recorded.
+     *
+     * Emits `PuzzleFinalized`.
+     */
+    finalize_result: { args: { player: string; puzzle_id: bigint }; returns: void };
+    /** Return all attempts (with scores after finalization) for a player. */
+    get_attempts: { args: { player: string; puzzle_id: bigint }; returns: Array<Attempt> };
+    /** Returns puzzle metadata, or `None` if not found. */
+    get_puzzle: { args: { puzzle_id: bigint }; returns: PuzzleData | undefined };
+    /** Returns `true` if the player solved the puzzle. */
+    is_winner: { args: { puzzle_id: bigint; player: string }; returns: boolean };
+    /** Returns `true` if the player was disqualified for breaking hard mode. */
+    is_disqualified: { args: { puzzle_id: bigint; player: string }; returns: boolean };
+    /** Return a compact puzzle snapshot for restoring in-progress or completed play. */
+    get_puzzle_snapshot: { args: { player: string; puzzle_id: bigint }; returns: PuzzleSnapshot };
+  }
+}